@@ -411,6 +411,15 @@ impl Builder {
         Builder { span: span, ..self }
     }
 
+    pub fn generic_over(self, param: GenericParam) -> Self {
+        let mut generics = self.generics;
+        generics.params.push(param);
+        Builder {
+            generics: generics,
+            ..self
+        }
+    }
+
     /// Set the `NodeId` of the constructed AST.
     ///
     /// **Warning**: Be careful with this option!  Parts of the rewriter expect nodes with matching
@@ -472,6 +481,24 @@ impl Builder {
         }
     }
 
+    /// Add a doc comment attribute, printed as is: `text` includes the `///`
+    pub fn doc_comment<S>(self, text: S) -> Self
+    where
+        S: IntoSymbol,
+    {
+        let mut attrs = self.attrs;
+        attrs.push(Attribute {
+            id: AttrId(0),
+            style: AttrStyle::Outer,
+            kind: AttrKind::DocComment(text.into_symbol()),
+            span: DUMMY_SP,
+        });
+        Builder {
+            attrs: attrs,
+            ..self
+        }
+    }
+
     pub fn call_attr<K, V>(self, func: K, arguments: Vec<V>) -> Self
     where
         K: Make<PathSegment>,
@@ -859,6 +886,21 @@ impl Builder {
         })
     }
 
+    pub fn range_expr<E1, E2>(self, lo: Option<E1>, hi: Option<E2>, limits: RangeLimits) -> P<Expr>
+    where
+        E1: Make<P<Expr>>,
+        E2: Make<P<Expr>>,
+    {
+        let lo = lo.map(|e| e.make(&self));
+        let hi = hi.map(|e| e.make(&self));
+        P(Expr {
+            id: self.id,
+            kind: ExprKind::Range(lo, hi, limits),
+            span: self.span,
+            attrs: self.attrs.into(),
+        })
+    }
+
     pub fn path_expr<Pa>(self, path: Pa) -> P<Expr>
     where
         Pa: Make<Path>,
@@ -1209,6 +1251,20 @@ impl Builder {
         })
     }
 
+    pub fn tuple_struct_pat<Pa, Pt>(self, path: Pa, pats: Vec<Pt>) -> P<Pat>
+    where
+        Pa: Make<Path>,
+        Pt: Make<P<Pat>>,
+    {
+        let path = path.make(&self);
+        let pats: Vec<P<Pat>> = pats.into_iter().map(|x| x.make(&self)).collect();
+        P(Pat {
+            id: self.id,
+            kind: PatKind::TupleStruct(path, pats),
+            span: self.span,
+        })
+    }
+
     pub fn qpath_pat<Pa>(self, qself: Option<QSelf>, path: Pa) -> P<Pat>
     where
         Pa: Make<Path>,
@@ -1759,6 +1815,34 @@ impl Builder {
         )
     }
 
+    pub fn trait_impl_item<Pa, T>(self, trait_path: Pa, ty: T, items: Vec<ImplItem>) -> P<Item>
+    where
+        Pa: Make<Path>,
+        T: Make<P<Ty>>,
+    {
+        let trait_ref = TraitRef {
+            path: trait_path.make(&self),
+            ref_id: DUMMY_NODE_ID,
+        };
+        let ty = ty.make(&self);
+        Self::item(
+            Ident::invalid(),
+            self.attrs,
+            self.vis,
+            self.span,
+            self.id,
+            ItemKind::Impl(
+                self.unsafety,
+                ImplPolarity::Positive,
+                Defaultness::Final,
+                self.generics,
+                Some(trait_ref),
+                ty,
+                items,
+            ),
+        )
+    }
+
     pub fn extern_crate_item<I>(self, name: I, rename: Option<I>) -> P<Item>
     where
         I: Make<Ident>,
@@ -1911,6 +1995,70 @@ impl Builder {
         }
     }
 
+    pub fn const_impl_item<I, T, E>(self, name: I, ty: T, init: E) -> ImplItem
+    where
+        I: Make<Ident>,
+        T: Make<P<Ty>>,
+        E: Make<P<Expr>>,
+    {
+        let name = name.make(&self);
+        let ty = ty.make(&self);
+        let init = init.make(&self);
+        let kind = ImplItemKind::Const(ty, init);
+        Self::impl_item_(
+            name,
+            self.attrs,
+            self.vis,
+            Defaultness::Final,
+            self.generics,
+            self.span,
+            self.id,
+            kind,
+        )
+    }
+
+    pub fn fn_impl_item<I, S, B>(self, name: I, sig: S, block: B) -> ImplItem
+    where
+        I: Make<Ident>,
+        S: Make<FnSig>,
+        B: Make<P<Block>>,
+    {
+        let name = name.make(&self);
+        let sig = sig.make(&self);
+        let block = block.make(&self);
+        let kind = ImplItemKind::Method(sig, block);
+        Self::impl_item_(
+            name,
+            self.attrs,
+            self.vis,
+            Defaultness::Final,
+            self.generics,
+            self.span,
+            self.id,
+            kind,
+        )
+    }
+
+    pub fn ty_impl_item<I, T>(self, name: I, ty: T) -> ImplItem
+    where
+        I: Make<Ident>,
+        T: Make<P<Ty>>,
+    {
+        let name = name.make(&self);
+        let ty = ty.make(&self);
+        let kind = ImplItemKind::TyAlias(ty);
+        Self::impl_item_(
+            name,
+            self.attrs,
+            self.vis,
+            Defaultness::Final,
+            self.generics,
+            self.span,
+            self.id,
+            kind,
+        )
+    }
+
     pub fn mac_impl_item<M>(self, mac: M) -> ImplItem
     where
         M: Make<Mac>,
@@ -473,6 +473,10 @@ class TranslateASTVisitor final
     SmallVector<MacroInfo*, 1> curMacroExpansionStack;
     StringRef curMacroExpansionSource;
 
+    // Temporaries copied into a POD by a trivial constructor, which C treats
+    // as plain values, rather than bound to a reference
+    std::unordered_set<MaterializeTemporaryExpr *> copiedTemporaries;
+
     // Returns true when a new entry is added to exportedTags
     bool markForExport(void *ptr, ASTEntryTag tag) {
         return exportedTags.emplace(ptr, tag).second;
@@ -1525,6 +1529,144 @@ class TranslateASTVisitor final
         return true;
     }
 
+    //
+    // C++ subset
+    //
+    // Only the parts of C++ that have a direct C equivalent are exported:
+    // named and functional casts become C-style casts and `bool`/`nullptr`
+    // literals become integer literals. Anything else is reported as an error.
+
+    bool VisitCXXNamedCastExpr(CXXNamedCastExpr *E) {
+        if (isa<CXXDynamicCastExpr>(E)) {
+            printError("dynamic_cast is outside the supported C++ subset", E);
+            return true;
+        }
+
+        std::vector<void *> childIds = {E->getSubExpr()};
+        encode_entry(E, TagCStyleCastExpr, childIds, [E](CborEncoder *array) {
+            cbor_encode_text_stringz(array, E->getCastKindName());
+        });
+        return true;
+    }
+
+    bool VisitCXXFunctionalCastExpr(CXXFunctionalCastExpr *E) {
+        std::vector<void *> childIds = {E->getSubExpr()};
+        encode_entry(E, TagCStyleCastExpr, childIds, [E](CborEncoder *array) {
+            cbor_encode_text_stringz(array, E->getCastKindName());
+        });
+        return true;
+    }
+
+    bool VisitCXXBoolLiteralExpr(CXXBoolLiteralExpr *E) {
+        auto value = E->getValue() ? 1U : 0U;
+        std::vector<void *> childIds;
+        encode_entry(E, TagIntegerLiteral, childIds,
+                     [value](CborEncoder *array) {
+                         cbor_encode_uint(array, value);
+                         cbor_encode_uint(array, 10U);
+                     });
+        return true;
+    }
+
+    bool VisitCXXNullPtrLiteralExpr(CXXNullPtrLiteralExpr *E) {
+        // `nullptr_t` has no C counterpart; exporting the literal as an `int`
+        // zero lets the enclosing NullToPointer cast translate as it does
+        // for `NULL`.
+        auto ty = Context->IntTy;
+        std::vector<void *> childIds;
+        encode_entry_raw(E, TagIntegerLiteral, E->getSourceRange(), ty, true,
+                         false, true, childIds, [](CborEncoder *array) {
+                             cbor_encode_uint(array, 0U);
+                             cbor_encode_uint(array, 10U);
+                         });
+        typeEncoder.VisitQualType(ty);
+        return true;
+    }
+
+    static Expr *getTemporaryExpr(MaterializeTemporaryExpr *E) {
+#if CLANG_VERSION_MAJOR < 10
+        return E->GetTemporaryExpr();
+#else
+        return E->getSubExpr();
+#endif // CLANG_VERSION_MAJOR
+    }
+
+    bool VisitMaterializeTemporaryExpr(MaterializeTemporaryExpr *E) {
+        // Copies are encoded by `VisitCXXConstructExpr` from the value of the
+        // temporary.
+        if (copiedTemporaries.count(E))
+            return true;
+
+        printError("binding a reference to a temporary is outside the "
+                   "supported C++ subset", E);
+        return true;
+    }
+
+    bool VisitCXXMemberCallExpr(CXXMemberCallExpr *E) {
+        printError("member function calls are outside the supported C++ "
+                   "subset", E);
+        return true;
+    }
+
+    bool VisitCXXConstructExpr(CXXConstructExpr *E) {
+        if (!E->getConstructor()->isTrivial()) {
+            printError("constructor calls are outside the supported C++ "
+                       "subset", E);
+            return true;
+        }
+
+        // Trivial default construction of a POD is a no-op in C, see
+        // `VisitVarDecl`.
+        if (E->getNumArgs() == 0)
+            return true;
+
+        // A trivial copy or move of a POD is a plain read of its source. A
+        // POD returned by value is copied out of a temporary, whose value is
+        // used as is.
+        Expr *source = E->getArg(0);
+        auto MTE = dyn_cast<MaterializeTemporaryExpr>(source->IgnoreImpCasts());
+        if (MTE) {
+            copiedTemporaries.insert(MTE);
+            source = getTemporaryExpr(MTE);
+        }
+        std::vector<void *> childIds = {source};
+        encode_entry(E, TagImplicitCastExpr, childIds, [MTE](CborEncoder *array) {
+            cbor_encode_text_stringz(array, MTE ? "NoOp" : "LValueToRValue");
+        });
+        return true;
+    }
+
+    bool VisitCXXNewExpr(CXXNewExpr *E) {
+        printError("new expressions are outside the supported C++ subset", E);
+        return true;
+    }
+
+    bool VisitCXXDeleteExpr(CXXDeleteExpr *E) {
+        printError("delete expressions are outside the supported C++ subset",
+                   E);
+        return true;
+    }
+
+    bool VisitCXXThisExpr(CXXThisExpr *E) {
+        printError("this is outside the supported C++ subset", E);
+        return true;
+    }
+
+    bool VisitCXXThrowExpr(CXXThrowExpr *E) {
+        printError("exceptions are outside the supported C++ subset", E);
+        return true;
+    }
+
+    bool VisitCXXTryStmt(CXXTryStmt *S) {
+        printError("exceptions are outside the supported C++ subset", S);
+        return true;
+    }
+
+    bool VisitLambdaExpr(LambdaExpr *E) {
+        printError("lambdas are outside the supported C++ subset", E);
+        return true;
+    }
+
     bool VisitUnaryOperator(UnaryOperator *UO) {
         std::vector<void *> childIds = {UO->getSubExpr()};
         encode_entry(UO, TagUnaryOperator, childIds, [UO](CborEncoder *array) {
@@ -1695,6 +1837,22 @@ class TranslateASTVisitor final
     // Some function declarations are also function definitions.
     // This method handles both types of declarations.
     bool VisitFunctionDecl(FunctionDecl *FD) {
+        if (auto MD = dyn_cast<CXXMethodDecl>(FD)) {
+            // Implicit special members of POD structs have no C counterpart
+            // and are never referenced by the supported subset.
+            if (!MD->isImplicit())
+                printError("member functions are outside the supported C++ "
+                           "subset", FD);
+            return true;
+        }
+
+        if (Context->getLangOpts().CPlusPlus && FD->isCanonicalDecl() &&
+            FD->isExternallyVisible() && !FD->isExternC() && !FD->isMain()) {
+            printError("externally visible functions without extern \"C\" "
+                       "linkage are outside the supported C++ subset", FD);
+            return true;
+        }
+
         if (!FD->isCanonicalDecl()) {
             // Emit non-canonical decl so we have a placeholder to attach comments to
             std::vector<void *> childIds = {FD->getCanonicalDecl()};
@@ -1792,6 +1950,22 @@ class TranslateASTVisitor final
         return true;
     }
 
+    // The function a template describes has dependent types that cannot be
+    // encoded, so it is reported without visiting it.
+    bool TraverseFunctionTemplateDecl(FunctionTemplateDecl *D) {
+        return WalkUpFromFunctionTemplateDecl(D);
+    }
+
+    bool VisitFunctionTemplateDecl(FunctionTemplateDecl *D) {
+        printError("templates are outside the supported C++ subset", D);
+        return true;
+    }
+
+    bool VisitNamespaceDecl(NamespaceDecl *D) {
+        printError("namespaces are outside the supported C++ subset", D);
+        return true;
+    }
+
     /* I don't think this adds anything that we don't get from VarDecl
     bool VisitParmVarDecl(ParmVarDecl *PVD)
     {
@@ -1841,7 +2015,14 @@ class TranslateASTVisitor final
         // Non static (externally visible) non definitions shouldn't receive an initializer,
         // otherwise get one
         if (!(is_externally_visible && !is_defn)) {
-            childIds.push_back((void *)VD->getAnyInitializer());
+            auto init = VD->getAnyInitializer();
+            // C++ default-initializes POD variables through a trivial
+            // constructor, which corresponds to no initializer at all in C.
+            if (auto CE = dyn_cast_or_null<CXXConstructExpr>(init)) {
+                if (CE->getConstructor()->isTrivial() && CE->getNumArgs() == 0)
+                    init = nullptr;
+            }
+            childIds.push_back((void *)init);
         }
 
         // Use the type from the definition in case the extern was an incomplete
@@ -1919,6 +2100,20 @@ class TranslateASTVisitor final
         }
 
         auto def = D->getDefinition();
+        if (auto CXXD = dyn_cast_or_null<CXXRecordDecl>(def)) {
+            if (CXXD->getDescribedClassTemplate() ||
+                isa<ClassTemplateSpecializationDecl>(CXXD)) {
+                printError("templates are outside the supported C++ subset",
+                           D);
+                return true;
+            }
+            if (!CXXD->isPOD()) {
+                printError("only POD structs are in the supported C++ subset",
+                           D);
+                return true;
+            }
+        }
+
         auto recordAlignment = 0;
         auto byteSize = 0;
 
@@ -2402,7 +2597,7 @@ class TranslateConsumer : public clang::ASTConsumer {
             cbor_encoder_init(&encoder, buffer, len, 0);
 
             CborEncoder outer;
-            cbor_encoder_create_array(&encoder, &outer, 5);
+            cbor_encoder_create_array(&encoder, &outer, 6);
 
             CborEncoder array;
 
@@ -2416,7 +2611,21 @@ class TranslateConsumer : public clang::ASTConsumer {
 
             // 2. Track all of the top-level declarations
             cbor_encoder_create_array(&outer, &array, CborIndefiniteLength);
-            for (auto d : translation_unit->decls()) {
+            // Declarations inside `extern "C"` blocks are top-level as far
+            // as C is concerned, so linkage specifications are flattened.
+            std::vector<Decl *> top_level;
+            std::function<void(DeclContext *)> collect =
+                [&top_level, &collect](DeclContext *DC) {
+                    for (auto d : DC->decls()) {
+                        if (auto LS = dyn_cast<LinkageSpecDecl>(d))
+                            collect(LS);
+                        else
+                            top_level.push_back(d);
+                    }
+                };
+            collect(translation_unit);
+
+            for (auto d : top_level) {
                 if(!d->isCanonicalDecl() && isa<VarDecl>(d)) {
                     auto canonical_decl = d->getCanonicalDecl();
                     auto var_decl = cast<VarDecl>(canonical_decl);
@@ -2504,6 +2713,20 @@ class TranslateConsumer : public clang::ASTConsumer {
             // 5. Target VaList type as BuiltiVaListKind
             cbor_encode_uint(&outer, static_cast<std::uintptr_t>(Context.getTargetInfo().getBuiltinVaListKind()));
 
+            // 6. Target C type layout as [char signed, char width, short width,
+            //    int width, long width, long long width, pointer width] with
+            //    widths in bits
+            auto &target = Context.getTargetInfo();
+            cbor_encoder_create_array(&outer, &array, 7);
+            cbor_encode_boolean(&array, Context.CharTy->isSignedIntegerType());
+            cbor_encode_uint(&array, target.getCharWidth());
+            cbor_encode_uint(&array, target.getShortWidth());
+            cbor_encode_uint(&array, target.getIntWidth());
+            cbor_encode_uint(&array, target.getLongWidth());
+            cbor_encode_uint(&array, target.getLongLongWidth());
+            cbor_encode_uint(&array, target.getPointerWidth(0));
+            cbor_encoder_close_container(&outer, &array);
+
             cbor_encoder_close_container(&encoder, &outer);
         };
 
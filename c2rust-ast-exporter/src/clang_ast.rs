@@ -119,6 +119,19 @@ pub struct AstContext {
     pub comments: Vec<CommentNode>,
     pub files: Vec<SrcFile>,
     pub va_list_kind: BuiltinVaListKind,
+    pub target_info: TargetInfo,
+}
+
+/// Layout of the C integer types on the target, widths in bits
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TargetInfo {
+    pub char_signed: bool,
+    pub char_width: u64,
+    pub short_width: u64,
+    pub int_width: u64,
+    pub long_width: u64,
+    pub long_long_width: u64,
+    pub pointer_width: u64,
 }
 
 pub fn expect_opt_str(val: &Value) -> Option<Option<&str>> {
@@ -160,15 +173,34 @@ pub fn process(items: Value) -> error::Result<AstContext> {
     let mut types: HashMap<u64, TypeNode> = HashMap::new();
     let mut comments: Vec<CommentNode> = vec![];
 
-    let (all_nodes, top_nodes, files, raw_comments, va_list_kind): (
+    let (all_nodes, top_nodes, files, raw_comments, va_list_kind, target_info): (
         Vec<VecDeque<Value>>,
         Vec<u64>,
         Vec<(String, Option<(u64, u64, u64)>)>,
         Vec<(u64, u64, u64, ByteBuf)>,
         u64,
+        (bool, u64, u64, u64, u64, u64, u64),
     ) = from_value(items)?;
 
     let va_list_kind = import_va_list_kind(va_list_kind);
+    let (
+        char_signed,
+        char_width,
+        short_width,
+        int_width,
+        long_width,
+        long_long_width,
+        pointer_width,
+    ) = target_info;
+    let target_info = TargetInfo {
+        char_signed,
+        char_width,
+        short_width,
+        int_width,
+        long_width,
+        long_long_width,
+        pointer_width,
+    };
 
     for (fileid, line, column, bytes) in raw_comments {
         comments.push(CommentNode {
@@ -259,5 +291,6 @@ pub fn process(items: Value) -> error::Result<AstContext> {
         comments,
         files,
         va_list_kind,
+        target_info,
     })
 }
@@ -1156,6 +1156,7 @@ impl<'a> State<'a> {
                               default: Option<&ast::Expr>,
                               vis: &ast::Visibility)
     {
+        self.ibox(INDENT_UNIT);
         self.s.word(visibility_qualified(vis, ""));
         self.word_space("const");
         self.print_ident(ident);
@@ -1166,7 +1167,8 @@ impl<'a> State<'a> {
             self.word_space("=");
             self.print_expr(expr);
         }
-        self.s.word(";")
+        self.s.word(";");
+        self.end();
     }
 
     fn print_associated_type(&mut self,
@@ -1174,6 +1176,7 @@ impl<'a> State<'a> {
                              bounds: Option<&ast::GenericBounds>,
                              ty: Option<&ast::Ty>)
                              {
+        self.ibox(INDENT_UNIT);
         self.word_space("type");
         self.print_ident(ident);
         if let Some(bounds) = bounds {
@@ -1184,7 +1187,8 @@ impl<'a> State<'a> {
             self.word_space("=");
             self.print_type(ty);
         }
-        self.s.word(";")
+        self.s.word(";");
+        self.end();
     }
 
     /// Pretty-prints an item.
@@ -1550,6 +1554,7 @@ impl<'a> State<'a> {
                     self.word_nbsp(":");
                     self.print_type(&field.ty);
                     self.s.word(",");
+                    self.maybe_print_trailing_comment(field.span, None);
                 }
 
                 self.bclose(span)
@@ -1748,7 +1753,15 @@ impl<'a> State<'a> {
                     self.maybe_print_comment(st.span.lo());
                     self.space_if_not_bol();
                     self.print_expr_outer_attr_style(expr, false);
-                    self.maybe_print_trailing_comment(expr.span, Some(blk.span.hi()));
+                    // Trailing comments are attached to the statement if it
+                    // has a span, and come before the comments closing the block
+                    if st.span.is_dummy() {
+                        self.maybe_print_trailing_comment(expr.span, Some(blk.span.hi()));
+                    } else if blk.span.hi() == BytePos(0) {
+                        self.maybe_print_trailing_comment(st.span, None);
+                    } else {
+                        self.maybe_print_trailing_comment(st.span, Some(blk.span.hi()));
+                    }
                 }
                 _ => self.print_stmt(st),
             }
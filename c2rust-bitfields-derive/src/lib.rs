@@ -5,6 +5,7 @@ extern crate quote;
 extern crate syn;
 
 use proc_macro::{Span, TokenStream};
+use proc_macro2::{Literal, TokenStream as TokenStream2};
 use quote::quote;
 use syn::parse::Error;
 use syn::punctuated::Punctuated;
@@ -14,9 +15,6 @@ use syn::{
     PathArguments, PathSegment, Token,
 };
 
-#[cfg(target_endian = "big")]
-compile_error!("Big endian architectures are not currently supported");
-
 /// This struct keeps track of a single bitfield attr's params
 /// as well as the bitfield's field name.
 #[derive(Debug)]
@@ -25,6 +23,7 @@ struct BFFieldAttr {
     name: String,
     ty: String,
     bits: (String, proc_macro2::Span),
+    enum_type: bool,
 }
 
 fn parse_bitfield_attr(
@@ -35,6 +34,7 @@ fn parse_bitfield_attr(
     let mut ty = None;
     let mut bits = None;
     let mut bits_span = None;
+    let mut enum_type = false;
 
     if let Meta::List(meta_list) = attr.parse_meta()? {
         for nested_meta in meta_list.nested {
@@ -70,6 +70,9 @@ fn parse_bitfield_attr(
                     if ident == "padding" {
                         return Ok(None);
                     }
+                    if ident == "enum_type" {
+                        enum_type = true;
+                    }
                 }
             }
         }
@@ -101,6 +104,7 @@ fn parse_bitfield_attr(
         name: name.unwrap(),
         ty: ty.unwrap(),
         bits: (bits.unwrap(), bits_span.unwrap()),
+        enum_type,
     }))
 }
 
@@ -155,6 +159,107 @@ fn parse_bitfield_ty_path(field: &BFFieldAttr) -> Path {
     }
 }
 
+fn parse_bitfield_bits(field: &BFFieldAttr) -> Result<(usize, usize), Error> {
+    let bit_string = &field.bits.0;
+    let nums: Vec<_> = bit_string.split("..=").collect();
+    let err_str = "bits param must be in the format \"1..=4\"";
+
+    if nums.len() != 2 {
+        return Err(Error::new(field.bits.1, err_str));
+    }
+
+    let lhs = nums[0].parse::<usize>();
+    let rhs = nums[1].parse::<usize>();
+
+    let (lhs, rhs) = match (lhs, rhs) {
+        (Err(_), _) | (_, Err(_)) => return Err(Error::new(field.bits.1, err_str)),
+        (Ok(lhs), Ok(rhs)) => (lhs, rhs),
+    };
+
+    if lhs > rhs || rhs - lhs >= 128 {
+        let err_str = "bits param must span between 1 and 128 bits";
+
+        return Err(Error::new(field.bits.1, err_str));
+    }
+
+    Ok((lhs, rhs))
+}
+
+/// Computes which bits of which bytes hold a bitfield, as the index of each
+/// byte, the mask of the bitfield's bits within it, and the shift moving
+/// those bits to their place in the value (negative for a right shift).
+///
+/// On little endian targets, bit `n` of the bitfield's storage is bit `n % 8`
+/// of byte `n / 8` and the value is laid out from its least significant bit.
+/// On big endian targets, bits are allocated from the most significant bit of
+/// each byte and the value is laid out from its most significant bit.
+fn byte_masks(lhs: usize, rhs: usize, big_endian: bool) -> Vec<(usize, u8, isize)> {
+    (lhs / 8..=rhs / 8)
+        .map(|byte| {
+            let first = lhs.max(byte * 8) - byte * 8;
+            let last = rhs.min(byte * 8 + 7) - byte * 8;
+            let mask = (first..=last).fold(0u8, |mask, bit| {
+                let bit = if big_endian { 7 - bit } else { bit };
+
+                mask | 1 << bit
+            });
+            let shift = if big_endian {
+                rhs as isize - (byte * 8 + 7) as isize
+            } else {
+                (byte * 8) as isize - lhs as isize
+            };
+
+            (byte, mask, shift)
+        })
+        .collect()
+}
+
+/// Generates an expression reading the bits of a bitfield into a `u128`
+fn read_bits(field_name: &Ident, lhs: usize, rhs: usize, big_endian: bool) -> TokenStream2 {
+    let terms = byte_masks(lhs, rhs, big_endian)
+        .into_iter()
+        .map(|(byte, mask, shift)| {
+            let byte = Literal::usize_unsuffixed(byte);
+            let mask = Literal::u8_suffixed(mask);
+            let bits = quote! { ((self.#field_name[#byte] & #mask) as u128) };
+
+            if shift < 0 {
+                let shift = Literal::usize_unsuffixed(-shift as usize);
+                quote! { #bits >> #shift }
+            } else {
+                let shift = Literal::usize_unsuffixed(shift as usize);
+                quote! { #bits << #shift }
+            }
+        });
+
+    quote! { 0u128 #(| (#terms))* }
+}
+
+/// Generates statements writing the low bits of a `u128` named `bits` into
+/// a bitfield, leaving the other bits of its bytes untouched
+fn write_bits(field_name: &Ident, lhs: usize, rhs: usize, big_endian: bool) -> TokenStream2 {
+    let stmts = byte_masks(lhs, rhs, big_endian)
+        .into_iter()
+        .map(|(byte, mask, shift)| {
+            let byte = Literal::usize_unsuffixed(byte);
+            let mask = Literal::u8_suffixed(mask);
+            let shifted = if shift < 0 {
+                let shift = Literal::usize_unsuffixed(-shift as usize);
+                quote! { (bits << #shift) }
+            } else {
+                let shift = Literal::usize_unsuffixed(shift as usize);
+                quote! { (bits >> #shift) }
+            };
+
+            quote! {
+                self.#field_name[#byte] =
+                    self.#field_name[#byte] & !#mask | #shifted as u8 & #mask;
+            }
+        });
+
+    quote! { #(#stmts)* }
+}
+
 #[proc_macro_derive(BitfieldStruct, attributes(bitfield))]
 pub fn bitfield_struct(input: TokenStream) -> TokenStream {
     let struct_item = parse_macro_input!(input as ItemStruct);
@@ -187,79 +292,85 @@ fn bitfield_struct_impl(struct_item: ItemStruct) -> Result<TokenStream, Error> {
     let bitfields: Result<Vec<BFFieldAttr>, Error> =
         fields.iter().flat_map(filter_and_parse_fields).collect();
     let bitfields = bitfields?;
-    let field_types: Vec<_> = bitfields.iter().map(parse_bitfield_ty_path).collect();
-    let field_types_return = &field_types;
-    let field_types_typedef = &field_types;
-    let field_types_setter_arg = &field_types;
-    let method_names: Vec<_> = bitfields
-        .iter()
-        .map(|field| Ident::new(&field.name, Span::call_site().into()))
-        .collect();
-    let field_names: Vec<_> = bitfields.iter().map(|field| &field.field_name).collect();
-    let field_names_setters = &field_names;
-    let field_names_getters = &field_names;
-    let method_name_setters: Vec<_> = method_names
-        .iter()
-        .map(|field_ident| {
-            let span = Span::call_site().into();
-            let setter_name = &format!("set_{}", field_ident);
-
-            Ident::new(setter_name, span)
-        })
-        .collect();
-    let field_bit_info: Result<Vec<_>, Error> = bitfields
-        .iter()
-        .map(|field| {
-            let bit_string = &field.bits.0;
-            let nums: Vec<_> = bit_string.split("..=").collect();
-            let err_str = "bits param must be in the format \"1..=4\"";
-
-            if nums.len() != 2 {
-                return Err(Error::new(field.bits.1, err_str));
-            }
-
-            let lhs = nums[0].parse::<usize>();
-            let rhs = nums[1].parse::<usize>();
-
-            let (lhs, rhs) = match (lhs, rhs) {
-                (Err(_), _) | (_, Err(_)) => return Err(Error::new(field.bits.1, err_str)),
-                (Ok(lhs), Ok(rhs)) => (lhs, rhs),
-            };
-
-            Ok(quote! { (#lhs, #rhs) })
-        })
-        .collect();
-    let field_bit_info = field_bit_info?;
-    let field_bit_info_setters = &field_bit_info;
-    let field_bit_info_getters = &field_bit_info;
+    let methods: Result<Vec<_>, Error> = bitfields.iter().map(bitfield_methods).collect();
+    let methods = methods?;
 
     // TODO: Method visibility determined by struct field visibility?
     let q = quote! {
         #[automatically_derived]
         impl #struct_ident {
-            #(
-                /// This method allows you to write to a bitfield with a value
-                pub fn #method_name_setters(&mut self, int: #field_types_setter_arg) {
-                    use c2rust_bitfields::FieldType;
-
-                    let field = &mut self.#field_names_setters;
-                    let (lhs_bit, rhs_bit) = #field_bit_info_setters;
-                    int.set_field(field, (lhs_bit, rhs_bit));
-                }
+            #(#methods)*
+        }
+    };
 
-                /// This method allows you to read from a bitfield to a value
-                pub fn #method_names(&self) -> #field_types_return {
-                    use c2rust_bitfields::FieldType;
+    Ok(q.into())
+}
 
-                    type IntType = #field_types_typedef;
+/// Generates the setter and getter of a bitfield. Getters of integer and
+/// `bool` bitfields are `const fn`s, while types marked as `enum_type` are
+/// converted through their `FieldType` implementation.
+fn bitfield_methods(field: &BFFieldAttr) -> Result<TokenStream2, Error> {
+    let field_type = parse_bitfield_ty_path(field);
+    let field_name = &field.field_name;
+    let method_name = Ident::new(&field.name, Span::call_site().into());
+    let setter_name = Ident::new(&format!("set_{}", field.name), Span::call_site().into());
+    let (lhs, rhs) = parse_bitfield_bits(field)?;
+    let width = Literal::u32_unsuffixed((rhs - lhs + 1) as u32);
+
+    let read_le = read_bits(field_name, lhs, rhs, false);
+    let read_be = read_bits(field_name, lhs, rhs, true);
+    let write_le = write_bits(field_name, lhs, rhs, false);
+    let write_be = write_bits(field_name, lhs, rhs, true);
+
+    let (constness, to_bits, from_bits) = if field.enum_type {
+        (
+            quote! {},
+            quote! { c2rust_bitfields::FieldType::into_bits(int) },
+            quote! { <#field_type as c2rust_bitfields::FieldType>::from_bits(bits) },
+        )
+    } else if field.ty == "bool" {
+        (
+            quote! { const },
+            quote! { int as u128 },
+            quote! { bits != 0 },
+        )
+    } else {
+        // Shifting the value up to the sign bit of its type and back sign
+        // extends it when the type is signed, and clears the bits above the
+        // bitfield otherwise
+        (
+            quote! { const },
+            quote! { int as u128 },
+            quote! {{
+                let shift = (0 as #field_type).count_zeros() - #width;
+                ((bits as #field_type) << shift) >> shift
+            }},
+        )
+    };
 
-                    let field = &self.#field_names_getters;
-                    let (lhs_bit, rhs_bit) = #field_bit_info_getters;
-                    <IntType as FieldType>::get_field(field, (lhs_bit, rhs_bit))
-                }
-            )*
+    Ok(quote! {
+        /// This method allows you to write to a bitfield with a value
+        pub fn #setter_name(&mut self, int: #field_type) {
+            let bits: u128 = #to_bits;
+
+            #[cfg(target_endian = "little")]
+            {
+                #write_le
+            }
+            #[cfg(target_endian = "big")]
+            {
+                #write_be
+            }
         }
-    };
 
-    Ok(q.into())
+        /// This method allows you to read from a bitfield to a value
+        pub #constness fn #method_name(&self) -> #field_type {
+            #[cfg(target_endian = "little")]
+            let bits = #read_le;
+            #[cfg(target_endian = "big")]
+            let bits = #read_be;
+
+            #from_bits
+        }
+    })
 }
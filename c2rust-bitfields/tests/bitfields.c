@@ -176,3 +176,36 @@ void assign_three_byte_date(three_byte_date* bf, uchar day, uchar month, ushort
     bf->month = month;
     bf->year = year;
 }
+
+// *** Dumping AST Record Layout
+//          0 | struct colored
+//      0:0-1 |   color c
+//      0:2-7 |   unsigned char n
+//            | [sizeof=4, align=4]
+typedef enum {
+    RED,
+    GREEN,
+    BLUE,
+} color;
+
+typedef struct {
+    color c: 2;
+    unsigned char n: 6;
+} colored;
+
+unsigned int check_colored(colored const* bf, color c, uchar n) {
+    if (bf->c != c) {
+        return 2;
+    }
+
+    if (bf->n != n) {
+        return 3;
+    }
+
+    return 1;
+}
+
+void assign_colored(colored* bf, color c, uchar n) {
+    bf->c = c;
+    bf->n = n;
+}
@@ -0,0 +1,182 @@
+#!/usr/bin/env python3
+# -*- coding: utf-8 -*-
+"""This script generates the bitfield layout tests in `layouts.c` and
+`test_layouts.rs`.
+
+Each generated struct is made of bitfields of random types and widths. Its
+Rust counterpart stores them in a byte array at the offsets given by the
+SysV x86_64 bitfield layout rules, which is what clang reports for the C
+struct. The tests then cross-check the two layouts:
+* the sizes of the structs must match
+* fields written in C must read back in Rust
+* fields written in Rust must read back in C
+* both must produce the same bytes
+
+The generator is seeded, so running it again reproduces the same files.
+"""
+
+import os
+import random
+
+NUM_STRUCTS = 128
+MAX_FIELDS = 8
+SEED = 0xb17f1e1d
+
+# (rust type, C type, size in bits, signed, libc alias)
+TYPES = [
+    ("u8", "unsigned char", 8, False, "libc::c_uchar"),
+    ("i8", "signed char", 8, True, "libc::c_schar"),
+    ("u16", "unsigned short", 16, False, "libc::c_ushort"),
+    ("i16", "short", 16, True, "libc::c_short"),
+    ("u32", "unsigned int", 32, False, "libc::c_uint"),
+    ("i32", "int", 32, True, "libc::c_int"),
+    ("u64", "unsigned long long", 64, False, "libc::c_ulonglong"),
+    ("i64", "long long", 64, True, "libc::c_longlong"),
+    ("u128", "unsigned __int128", 128, False, None),
+    ("i128", "__int128", 128, True, None),
+    ("bool", "_Bool", 8, False, None),
+]
+
+TESTS_DIR = os.path.dirname(os.path.abspath(__file__))
+
+
+class Field:
+    def __init__(self, rng, index, offset):
+        (self.rust_base, self.c_ty, self.size, self.signed,
+         alias) = rng.choice(TYPES)
+        self.rust_ty = alias if alias and rng.random() < 0.5 else self.rust_base
+        self.name = "f{}".format(index)
+        if self.rust_base == "bool":
+            self.width = 1
+        else:
+            self.width = rng.randint(1, self.size)
+
+        # A bitfield starts a new storage unit of its type rather than
+        # straddling two of them
+        if offset % self.size + self.width > self.size:
+            offset += self.size - offset % self.size
+        self.offset = offset
+
+        if self.signed:
+            lo, hi = -(1 << (self.width - 1)), (1 << (self.width - 1)) - 1
+        else:
+            lo, hi = 0, (1 << self.width) - 1
+        self.value = rng.choice([lo, hi, rng.randint(lo, hi)])
+
+    def c_value(self):
+        bits = self.value % (1 << 128)
+        if self.size <= 64:
+            return "({})0x{:x}ULL".format(self.c_ty, bits % (1 << 64))
+        return "({})(((unsigned __int128)0x{:x}ULL << 64) | 0x{:x}ULL)".format(
+            self.c_ty, bits >> 64, bits % (1 << 64))
+
+    def rust_value(self):
+        if self.rust_base == "bool":
+            return "true" if self.value else "false"
+        literal = "{}{}".format(self.value, self.rust_base)
+        if self.rust_ty == self.rust_base:
+            return literal
+        return "{} as {}".format(literal, self.rust_ty)
+
+
+class Struct:
+    def __init__(self, rng, index):
+        self.c_name = "layout{}".format(index)
+        self.rust_name = "Layout{}".format(index)
+        self.fields = []
+        offset = 0
+        for i in range(rng.randint(1, MAX_FIELDS)):
+            field = Field(rng, i, offset)
+            self.fields.append(field)
+            offset = field.offset + field.width
+        self.align = max(field.size for field in self.fields) // 8
+        size = (offset + 7) // 8
+        self.size = (size + self.align - 1) // self.align * self.align
+
+
+def write_c(structs, out):
+    out.write("// Generated by gen_layouts.py, do not edit\n\n")
+    out.write("#include <stddef.h>\n#include <string.h>\n")
+    for s in structs:
+        out.write("\nstruct {} {{\n".format(s.c_name))
+        for f in s.fields:
+            out.write("    {} {}: {};\n".format(f.c_ty, f.name, f.width))
+        out.write("};\n\n")
+        out.write("size_t {0}_size(void) {{\n    return sizeof(struct {0});\n}}\n\n"
+                  .format(s.c_name))
+        out.write("void {0}_fill(struct {0} *s) {{\n".format(s.c_name))
+        out.write("    memset(s, 0, sizeof(*s));\n")
+        for f in s.fields:
+            out.write("    s->{} = {};\n".format(f.name, f.c_value()))
+        out.write("}\n\n")
+        out.write("int {0}_check(struct {0} const *s) {{\n".format(s.c_name))
+        for i, f in enumerate(s.fields):
+            out.write("    if (s->{} != {}) return {};\n"
+                      .format(f.name, f.c_value(), i + 1))
+        out.write("    return 0;\n}\n")
+
+
+def write_rust(structs, out):
+    out.write("""\
+// Generated by gen_layouts.py, do not edit
+
+extern crate c2rust_bitfields;
+extern crate libc;
+
+use c2rust_bitfields::BitfieldStruct;
+use std::mem::size_of;
+
+#[link(name = "test")]
+extern "C" {}
+
+macro_rules! check_layout {
+    ($ty:ident, $size:ident, $fill:ident, $check:ident,
+     $($field:ident, $setter:ident = $value:expr;)+) => {
+        extern "C" {
+            fn $size() -> libc::size_t;
+            fn $fill(_: *mut $ty);
+            fn $check(_: *const $ty) -> libc::c_int;
+        }
+
+        unsafe {
+            assert_eq!(size_of::<$ty>(), $size());
+
+            let mut from_c: $ty = std::mem::zeroed();
+            $fill(&mut from_c);
+            $(assert_eq!(from_c.$field(), $value, stringify!($field));)+
+
+            let mut from_rust: $ty = std::mem::zeroed();
+            $(from_rust.$setter($value);)+
+            assert_eq!($check(&from_rust), 0);
+            assert_eq!(&from_rust.bits[..], &from_c.bits[..]);
+        }
+    };
+}
+""")
+    for s in structs:
+        out.write("\n#[repr(C, align({}))]\n".format(s.align))
+        out.write("#[derive(BitfieldStruct)]\n")
+        out.write("struct {} {{\n".format(s.rust_name))
+        for f in s.fields:
+            out.write('    #[bitfield(name = "{}", ty = "{}", bits = "{}..={}")]\n'
+                      .format(f.name, f.rust_ty, f.offset, f.offset + f.width - 1))
+        out.write("    bits: [u8; {}],\n}}\n\n".format(s.size))
+        out.write("#[test]\nfn test_{}() {{\n".format(s.c_name))
+        out.write("    check_layout!(\n        {0}, {1}_size, {1}_fill, {1}_check,\n"
+                  .format(s.rust_name, s.c_name))
+        for f in s.fields:
+            out.write("        {0}, set_{0} = {1};\n".format(f.name, f.rust_value()))
+        out.write("    );\n}\n")
+
+
+def main():
+    rng = random.Random(SEED)
+    structs = [Struct(rng, i) for i in range(NUM_STRUCTS)]
+    with open(os.path.join(TESTS_DIR, "layouts.c"), "w") as out:
+        write_c(structs, out)
+    with open(os.path.join(TESTS_DIR, "test_layouts.rs"), "w") as out:
+        write_rust(structs, out)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,3574 @@
+// Generated by gen_layouts.py, do not edit
+
+#include <stddef.h>
+#include <string.h>
+
+struct layout0 {
+    long long f0: 31;
+    __int128 f1: 110;
+    unsigned __int128 f2: 101;
+    unsigned __int128 f3: 61;
+};
+
+size_t layout0_size(void) {
+    return sizeof(struct layout0);
+}
+
+void layout0_fill(struct layout0 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0x3fffffffULL;
+    s->f1 = (__int128)(((unsigned __int128)0xadab0f0d095ULL << 64) | 0xa6379d547fdf67f6ULL);
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x10f47e0ef2ULL << 64) | 0xaeb5b4af01370451ULL);
+    s->f3 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x1fffffffffffffffULL);
+}
+
+int layout0_check(struct layout0 const *s) {
+    if (s->f0 != (long long)0x3fffffffULL) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0xadab0f0d095ULL << 64) | 0xa6379d547fdf67f6ULL)) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x10f47e0ef2ULL << 64) | 0xaeb5b4af01370451ULL)) return 3;
+    if (s->f3 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x1fffffffffffffffULL)) return 4;
+    return 0;
+}
+
+struct layout1 {
+    int f0: 29;
+    unsigned short f1: 15;
+    __int128 f2: 15;
+};
+
+size_t layout1_size(void) {
+    return sizeof(struct layout1);
+}
+
+void layout1_fill(struct layout1 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xfffffffULL;
+    s->f1 = (unsigned short)0x7fffULL;
+    s->f2 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0x3fffULL);
+}
+
+int layout1_check(struct layout1 const *s) {
+    if (s->f0 != (int)0xfffffffULL) return 1;
+    if (s->f1 != (unsigned short)0x7fffULL) return 2;
+    if (s->f2 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0x3fffULL)) return 3;
+    return 0;
+}
+
+struct layout2 {
+    unsigned char f0: 3;
+    short f1: 12;
+    unsigned char f2: 4;
+    unsigned char f3: 4;
+    signed char f4: 5;
+    long long f5: 30;
+};
+
+size_t layout2_size(void) {
+    return sizeof(struct layout2);
+}
+
+void layout2_fill(struct layout2 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x5ULL;
+    s->f1 = (short)0x7ffULL;
+    s->f2 = (unsigned char)0x0ULL;
+    s->f3 = (unsigned char)0xfULL;
+    s->f4 = (signed char)0xfffffffffffffff0ULL;
+    s->f5 = (long long)0xffffffffe0000000ULL;
+}
+
+int layout2_check(struct layout2 const *s) {
+    if (s->f0 != (unsigned char)0x5ULL) return 1;
+    if (s->f1 != (short)0x7ffULL) return 2;
+    if (s->f2 != (unsigned char)0x0ULL) return 3;
+    if (s->f3 != (unsigned char)0xfULL) return 4;
+    if (s->f4 != (signed char)0xfffffffffffffff0ULL) return 5;
+    if (s->f5 != (long long)0xffffffffe0000000ULL) return 6;
+    return 0;
+}
+
+struct layout3 {
+    unsigned short f0: 4;
+};
+
+size_t layout3_size(void) {
+    return sizeof(struct layout3);
+}
+
+void layout3_fill(struct layout3 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0xfULL;
+}
+
+int layout3_check(struct layout3 const *s) {
+    if (s->f0 != (unsigned short)0xfULL) return 1;
+    return 0;
+}
+
+struct layout4 {
+    unsigned __int128 f0: 90;
+    unsigned long long f1: 56;
+    unsigned __int128 f2: 100;
+};
+
+size_t layout4_size(void) {
+    return sizeof(struct layout4);
+}
+
+void layout4_fill(struct layout4 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x3d6bb90ULL << 64) | 0x55f32292e433af6eULL);
+    s->f1 = (unsigned long long)0x0ULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+}
+
+int layout4_check(struct layout4 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x3d6bb90ULL << 64) | 0x55f32292e433af6eULL)) return 1;
+    if (s->f1 != (unsigned long long)0x0ULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 3;
+    return 0;
+}
+
+struct layout5 {
+    unsigned short f0: 9;
+    unsigned long long f1: 58;
+    unsigned __int128 f2: 109;
+    long long f3: 8;
+    unsigned __int128 f4: 84;
+    unsigned char f5: 5;
+};
+
+size_t layout5_size(void) {
+    return sizeof(struct layout5);
+}
+
+void layout5_fill(struct layout5 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x0ULL;
+    s->f1 = (unsigned long long)0x0ULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x1fffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f3 = (long long)0xffffffffffffffdbULL;
+    s->f4 = (unsigned __int128)(((unsigned __int128)0xfffffULL << 64) | 0xffffffffffffffffULL);
+    s->f5 = (unsigned char)0x0ULL;
+}
+
+int layout5_check(struct layout5 const *s) {
+    if (s->f0 != (unsigned short)0x0ULL) return 1;
+    if (s->f1 != (unsigned long long)0x0ULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x1fffffffffffULL << 64) | 0xffffffffffffffffULL)) return 3;
+    if (s->f3 != (long long)0xffffffffffffffdbULL) return 4;
+    if (s->f4 != (unsigned __int128)(((unsigned __int128)0xfffffULL << 64) | 0xffffffffffffffffULL)) return 5;
+    if (s->f5 != (unsigned char)0x0ULL) return 6;
+    return 0;
+}
+
+struct layout6 {
+    signed char f0: 8;
+    unsigned int f1: 32;
+    __int128 f2: 62;
+    short f3: 1;
+};
+
+size_t layout6_size(void) {
+    return sizeof(struct layout6);
+}
+
+void layout6_fill(struct layout6 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0xffffffffffffffaaULL;
+    s->f1 = (unsigned int)0xffffffffULL;
+    s->f2 = (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xe000000000000000ULL);
+    s->f3 = (short)0x0ULL;
+}
+
+int layout6_check(struct layout6 const *s) {
+    if (s->f0 != (signed char)0xffffffffffffffaaULL) return 1;
+    if (s->f1 != (unsigned int)0xffffffffULL) return 2;
+    if (s->f2 != (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xe000000000000000ULL)) return 3;
+    if (s->f3 != (short)0x0ULL) return 4;
+    return 0;
+}
+
+struct layout7 {
+    __int128 f0: 98;
+    _Bool f1: 1;
+    unsigned char f2: 2;
+    int f3: 24;
+    __int128 f4: 116;
+    unsigned __int128 f5: 101;
+};
+
+size_t layout7_size(void) {
+    return sizeof(struct layout7);
+}
+
+void layout7_fill(struct layout7 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xffffffff49c04f27ULL << 64) | 0xa12764c2429eee3ULL);
+    s->f1 = (_Bool)0x0ULL;
+    s->f2 = (unsigned char)0x1ULL;
+    s->f3 = (int)0x7fffffULL;
+    s->f4 = (__int128)(((unsigned __int128)0xfff8000000000000ULL << 64) | 0x0ULL);
+    s->f5 = (unsigned __int128)(((unsigned __int128)0x1fffffffffULL << 64) | 0xffffffffffffffffULL);
+}
+
+int layout7_check(struct layout7 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xffffffff49c04f27ULL << 64) | 0xa12764c2429eee3ULL)) return 1;
+    if (s->f1 != (_Bool)0x0ULL) return 2;
+    if (s->f2 != (unsigned char)0x1ULL) return 3;
+    if (s->f3 != (int)0x7fffffULL) return 4;
+    if (s->f4 != (__int128)(((unsigned __int128)0xfff8000000000000ULL << 64) | 0x0ULL)) return 5;
+    if (s->f5 != (unsigned __int128)(((unsigned __int128)0x1fffffffffULL << 64) | 0xffffffffffffffffULL)) return 6;
+    return 0;
+}
+
+struct layout8 {
+    unsigned int f0: 16;
+    short f1: 9;
+    __int128 f2: 59;
+    unsigned long long f3: 3;
+    int f4: 31;
+    unsigned __int128 f5: 25;
+    signed char f6: 8;
+    unsigned char f7: 7;
+};
+
+size_t layout8_size(void) {
+    return sizeof(struct layout8);
+}
+
+void layout8_fill(struct layout8 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned int)0x0ULL;
+    s->f1 = (short)0xffULL;
+    s->f2 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0x3ffffffffffffffULL);
+    s->f3 = (unsigned long long)0x7ULL;
+    s->f4 = (int)0xffffffffc0000000ULL;
+    s->f5 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x10f3b1bULL);
+    s->f6 = (signed char)0xffffffffffffff90ULL;
+    s->f7 = (unsigned char)0x60ULL;
+}
+
+int layout8_check(struct layout8 const *s) {
+    if (s->f0 != (unsigned int)0x0ULL) return 1;
+    if (s->f1 != (short)0xffULL) return 2;
+    if (s->f2 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0x3ffffffffffffffULL)) return 3;
+    if (s->f3 != (unsigned long long)0x7ULL) return 4;
+    if (s->f4 != (int)0xffffffffc0000000ULL) return 5;
+    if (s->f5 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x10f3b1bULL)) return 6;
+    if (s->f6 != (signed char)0xffffffffffffff90ULL) return 7;
+    if (s->f7 != (unsigned char)0x60ULL) return 8;
+    return 0;
+}
+
+struct layout9 {
+    long long f0: 33;
+    _Bool f1: 1;
+    unsigned long long f2: 3;
+    signed char f3: 1;
+};
+
+size_t layout9_size(void) {
+    return sizeof(struct layout9);
+}
+
+void layout9_fill(struct layout9 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0xffffffff00000000ULL;
+    s->f1 = (_Bool)0x1ULL;
+    s->f2 = (unsigned long long)0x0ULL;
+    s->f3 = (signed char)0xffffffffffffffffULL;
+}
+
+int layout9_check(struct layout9 const *s) {
+    if (s->f0 != (long long)0xffffffff00000000ULL) return 1;
+    if (s->f1 != (_Bool)0x1ULL) return 2;
+    if (s->f2 != (unsigned long long)0x0ULL) return 3;
+    if (s->f3 != (signed char)0xffffffffffffffffULL) return 4;
+    return 0;
+}
+
+struct layout10 {
+    unsigned long long f0: 20;
+    unsigned short f1: 9;
+    unsigned short f2: 13;
+    int f3: 19;
+    unsigned char f4: 8;
+    _Bool f5: 1;
+    unsigned int f6: 4;
+    _Bool f7: 1;
+};
+
+size_t layout10_size(void) {
+    return sizeof(struct layout10);
+}
+
+void layout10_fill(struct layout10 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x6075aULL;
+    s->f1 = (unsigned short)0x1ffULL;
+    s->f2 = (unsigned short)0x1fffULL;
+    s->f3 = (int)0x3ffffULL;
+    s->f4 = (unsigned char)0x9aULL;
+    s->f5 = (_Bool)0x1ULL;
+    s->f6 = (unsigned int)0xfULL;
+    s->f7 = (_Bool)0x1ULL;
+}
+
+int layout10_check(struct layout10 const *s) {
+    if (s->f0 != (unsigned long long)0x6075aULL) return 1;
+    if (s->f1 != (unsigned short)0x1ffULL) return 2;
+    if (s->f2 != (unsigned short)0x1fffULL) return 3;
+    if (s->f3 != (int)0x3ffffULL) return 4;
+    if (s->f4 != (unsigned char)0x9aULL) return 5;
+    if (s->f5 != (_Bool)0x1ULL) return 6;
+    if (s->f6 != (unsigned int)0xfULL) return 7;
+    if (s->f7 != (_Bool)0x1ULL) return 8;
+    return 0;
+}
+
+struct layout11 {
+    unsigned short f0: 8;
+    unsigned int f1: 18;
+    unsigned int f2: 27;
+    short f3: 2;
+    unsigned short f4: 11;
+};
+
+size_t layout11_size(void) {
+    return sizeof(struct layout11);
+}
+
+void layout11_fill(struct layout11 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0xffULL;
+    s->f1 = (unsigned int)0x9b1aULL;
+    s->f2 = (unsigned int)0x7ffffffULL;
+    s->f3 = (short)0xfffffffffffffffeULL;
+    s->f4 = (unsigned short)0x0ULL;
+}
+
+int layout11_check(struct layout11 const *s) {
+    if (s->f0 != (unsigned short)0xffULL) return 1;
+    if (s->f1 != (unsigned int)0x9b1aULL) return 2;
+    if (s->f2 != (unsigned int)0x7ffffffULL) return 3;
+    if (s->f3 != (short)0xfffffffffffffffeULL) return 4;
+    if (s->f4 != (unsigned short)0x0ULL) return 5;
+    return 0;
+}
+
+struct layout12 {
+    unsigned long long f0: 8;
+};
+
+size_t layout12_size(void) {
+    return sizeof(struct layout12);
+}
+
+void layout12_fill(struct layout12 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0xffULL;
+}
+
+int layout12_check(struct layout12 const *s) {
+    if (s->f0 != (unsigned long long)0xffULL) return 1;
+    return 0;
+}
+
+struct layout13 {
+    unsigned char f0: 8;
+    unsigned __int128 f1: 85;
+};
+
+size_t layout13_size(void) {
+    return sizeof(struct layout13);
+}
+
+void layout13_fill(struct layout13 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0xffULL;
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x128764ULL << 64) | 0x1e6e5694544055d3ULL);
+}
+
+int layout13_check(struct layout13 const *s) {
+    if (s->f0 != (unsigned char)0xffULL) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x128764ULL << 64) | 0x1e6e5694544055d3ULL)) return 2;
+    return 0;
+}
+
+struct layout14 {
+    _Bool f0: 1;
+    unsigned long long f1: 16;
+    unsigned int f2: 1;
+    _Bool f3: 1;
+    short f4: 1;
+    unsigned __int128 f5: 107;
+    _Bool f6: 1;
+    unsigned long long f7: 47;
+};
+
+size_t layout14_size(void) {
+    return sizeof(struct layout14);
+}
+
+void layout14_fill(struct layout14 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x0ULL;
+    s->f1 = (unsigned long long)0x6566ULL;
+    s->f2 = (unsigned int)0x1ULL;
+    s->f3 = (_Bool)0x0ULL;
+    s->f4 = (short)0x0ULL;
+    s->f5 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f6 = (_Bool)0x1ULL;
+    s->f7 = (unsigned long long)0x0ULL;
+}
+
+int layout14_check(struct layout14 const *s) {
+    if (s->f0 != (_Bool)0x0ULL) return 1;
+    if (s->f1 != (unsigned long long)0x6566ULL) return 2;
+    if (s->f2 != (unsigned int)0x1ULL) return 3;
+    if (s->f3 != (_Bool)0x0ULL) return 4;
+    if (s->f4 != (short)0x0ULL) return 5;
+    if (s->f5 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 6;
+    if (s->f6 != (_Bool)0x1ULL) return 7;
+    if (s->f7 != (unsigned long long)0x0ULL) return 8;
+    return 0;
+}
+
+struct layout15 {
+    short f0: 7;
+    unsigned int f1: 1;
+    long long f2: 29;
+    _Bool f3: 1;
+    unsigned short f4: 7;
+};
+
+size_t layout15_size(void) {
+    return sizeof(struct layout15);
+}
+
+void layout15_fill(struct layout15 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xffffffffffffffc6ULL;
+    s->f1 = (unsigned int)0x0ULL;
+    s->f2 = (long long)0xfffffffffc363c9aULL;
+    s->f3 = (_Bool)0x1ULL;
+    s->f4 = (unsigned short)0x46ULL;
+}
+
+int layout15_check(struct layout15 const *s) {
+    if (s->f0 != (short)0xffffffffffffffc6ULL) return 1;
+    if (s->f1 != (unsigned int)0x0ULL) return 2;
+    if (s->f2 != (long long)0xfffffffffc363c9aULL) return 3;
+    if (s->f3 != (_Bool)0x1ULL) return 4;
+    if (s->f4 != (unsigned short)0x46ULL) return 5;
+    return 0;
+}
+
+struct layout16 {
+    __int128 f0: 35;
+    __int128 f1: 56;
+    long long f2: 32;
+    unsigned char f3: 3;
+    unsigned __int128 f4: 68;
+    __int128 f5: 32;
+};
+
+size_t layout16_size(void) {
+    return sizeof(struct layout16);
+}
+
+void layout16_fill(struct layout16 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xfffffffc00000000ULL);
+    s->f1 = (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xff80000000000000ULL);
+    s->f2 = (long long)0x1fb7a7f6ULL;
+    s->f3 = (unsigned char)0x7ULL;
+    s->f4 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f5 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0x7fffffffULL);
+}
+
+int layout16_check(struct layout16 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xfffffffc00000000ULL)) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xff80000000000000ULL)) return 2;
+    if (s->f2 != (long long)0x1fb7a7f6ULL) return 3;
+    if (s->f3 != (unsigned char)0x7ULL) return 4;
+    if (s->f4 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 5;
+    if (s->f5 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0x7fffffffULL)) return 6;
+    return 0;
+}
+
+struct layout17 {
+    _Bool f0: 1;
+    unsigned short f1: 10;
+    short f2: 2;
+};
+
+size_t layout17_size(void) {
+    return sizeof(struct layout17);
+}
+
+void layout17_fill(struct layout17 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x1ULL;
+    s->f1 = (unsigned short)0x110ULL;
+    s->f2 = (short)0x1ULL;
+}
+
+int layout17_check(struct layout17 const *s) {
+    if (s->f0 != (_Bool)0x1ULL) return 1;
+    if (s->f1 != (unsigned short)0x110ULL) return 2;
+    if (s->f2 != (short)0x1ULL) return 3;
+    return 0;
+}
+
+struct layout18 {
+    short f0: 14;
+};
+
+size_t layout18_size(void) {
+    return sizeof(struct layout18);
+}
+
+void layout18_fill(struct layout18 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0x1fffULL;
+}
+
+int layout18_check(struct layout18 const *s) {
+    if (s->f0 != (short)0x1fffULL) return 1;
+    return 0;
+}
+
+struct layout19 {
+    unsigned __int128 f0: 109;
+    unsigned short f1: 11;
+    unsigned int f2: 16;
+};
+
+size_t layout19_size(void) {
+    return sizeof(struct layout19);
+}
+
+void layout19_fill(struct layout19 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f1 = (unsigned short)0x7ffULL;
+    s->f2 = (unsigned int)0x0ULL;
+}
+
+int layout19_check(struct layout19 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 1;
+    if (s->f1 != (unsigned short)0x7ffULL) return 2;
+    if (s->f2 != (unsigned int)0x0ULL) return 3;
+    return 0;
+}
+
+struct layout20 {
+    int f0: 2;
+    unsigned __int128 f1: 68;
+};
+
+size_t layout20_size(void) {
+    return sizeof(struct layout20);
+}
+
+void layout20_fill(struct layout20 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xfffffffffffffffeULL;
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+}
+
+int layout20_check(struct layout20 const *s) {
+    if (s->f0 != (int)0xfffffffffffffffeULL) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 2;
+    return 0;
+}
+
+struct layout21 {
+    unsigned char f0: 2;
+};
+
+size_t layout21_size(void) {
+    return sizeof(struct layout21);
+}
+
+void layout21_fill(struct layout21 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x3ULL;
+}
+
+int layout21_check(struct layout21 const *s) {
+    if (s->f0 != (unsigned char)0x3ULL) return 1;
+    return 0;
+}
+
+struct layout22 {
+    _Bool f0: 1;
+    unsigned short f1: 3;
+};
+
+size_t layout22_size(void) {
+    return sizeof(struct layout22);
+}
+
+void layout22_fill(struct layout22 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x0ULL;
+    s->f1 = (unsigned short)0x7ULL;
+}
+
+int layout22_check(struct layout22 const *s) {
+    if (s->f0 != (_Bool)0x0ULL) return 1;
+    if (s->f1 != (unsigned short)0x7ULL) return 2;
+    return 0;
+}
+
+struct layout23 {
+    int f0: 3;
+};
+
+size_t layout23_size(void) {
+    return sizeof(struct layout23);
+}
+
+void layout23_fill(struct layout23 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0x3ULL;
+}
+
+int layout23_check(struct layout23 const *s) {
+    if (s->f0 != (int)0x3ULL) return 1;
+    return 0;
+}
+
+struct layout24 {
+    __int128 f0: 77;
+    unsigned int f1: 3;
+    unsigned __int128 f2: 38;
+    _Bool f3: 1;
+};
+
+size_t layout24_size(void) {
+    return sizeof(struct layout24);
+}
+
+void layout24_fill(struct layout24 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xfffULL << 64) | 0xffffffffffffffffULL);
+    s->f1 = (unsigned int)0x7ULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f3 = (_Bool)0x1ULL;
+}
+
+int layout24_check(struct layout24 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xfffULL << 64) | 0xffffffffffffffffULL)) return 1;
+    if (s->f1 != (unsigned int)0x7ULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 3;
+    if (s->f3 != (_Bool)0x1ULL) return 4;
+    return 0;
+}
+
+struct layout25 {
+    unsigned __int128 f0: 97;
+};
+
+size_t layout25_size(void) {
+    return sizeof(struct layout25);
+}
+
+void layout25_fill(struct layout25 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x1ffffffffULL << 64) | 0xffffffffffffffffULL);
+}
+
+int layout25_check(struct layout25 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x1ffffffffULL << 64) | 0xffffffffffffffffULL)) return 1;
+    return 0;
+}
+
+struct layout26 {
+    int f0: 31;
+    int f1: 26;
+    long long f2: 60;
+};
+
+size_t layout26_size(void) {
+    return sizeof(struct layout26);
+}
+
+void layout26_fill(struct layout26 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xffffffffc0000000ULL;
+    s->f1 = (int)0x1a84393ULL;
+    s->f2 = (long long)0x7ffffffffffffffULL;
+}
+
+int layout26_check(struct layout26 const *s) {
+    if (s->f0 != (int)0xffffffffc0000000ULL) return 1;
+    if (s->f1 != (int)0x1a84393ULL) return 2;
+    if (s->f2 != (long long)0x7ffffffffffffffULL) return 3;
+    return 0;
+}
+
+struct layout27 {
+    unsigned long long f0: 54;
+    __int128 f1: 109;
+    long long f2: 37;
+};
+
+size_t layout27_size(void) {
+    return sizeof(struct layout27);
+}
+
+void layout27_fill(struct layout27 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x3fffffffffffffULL;
+    s->f1 = (__int128)(((unsigned __int128)0xfffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f2 = (long long)0xfffffffffULL;
+}
+
+int layout27_check(struct layout27 const *s) {
+    if (s->f0 != (unsigned long long)0x3fffffffffffffULL) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0xfffffffffffULL << 64) | 0xffffffffffffffffULL)) return 2;
+    if (s->f2 != (long long)0xfffffffffULL) return 3;
+    return 0;
+}
+
+struct layout28 {
+    unsigned __int128 f0: 119;
+    long long f1: 5;
+};
+
+size_t layout28_size(void) {
+    return sizeof(struct layout28);
+}
+
+void layout28_fill(struct layout28 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x7fffffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f1 = (long long)0xfffffffffffffffcULL;
+}
+
+int layout28_check(struct layout28 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x7fffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 1;
+    if (s->f1 != (long long)0xfffffffffffffffcULL) return 2;
+    return 0;
+}
+
+struct layout29 {
+    signed char f0: 4;
+};
+
+size_t layout29_size(void) {
+    return sizeof(struct layout29);
+}
+
+void layout29_fill(struct layout29 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0xfffffffffffffff8ULL;
+}
+
+int layout29_check(struct layout29 const *s) {
+    if (s->f0 != (signed char)0xfffffffffffffff8ULL) return 1;
+    return 0;
+}
+
+struct layout30 {
+    unsigned short f0: 4;
+};
+
+size_t layout30_size(void) {
+    return sizeof(struct layout30);
+}
+
+void layout30_fill(struct layout30 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x0ULL;
+}
+
+int layout30_check(struct layout30 const *s) {
+    if (s->f0 != (unsigned short)0x0ULL) return 1;
+    return 0;
+}
+
+struct layout31 {
+    short f0: 11;
+    unsigned char f1: 6;
+    unsigned short f2: 4;
+    _Bool f3: 1;
+    unsigned char f4: 3;
+    unsigned int f5: 26;
+    unsigned long long f6: 58;
+    signed char f7: 6;
+};
+
+size_t layout31_size(void) {
+    return sizeof(struct layout31);
+}
+
+void layout31_fill(struct layout31 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xfffffffffffffc00ULL;
+    s->f1 = (unsigned char)0x3fULL;
+    s->f2 = (unsigned short)0x1ULL;
+    s->f3 = (_Bool)0x0ULL;
+    s->f4 = (unsigned char)0x1ULL;
+    s->f5 = (unsigned int)0x0ULL;
+    s->f6 = (unsigned long long)0x3ffffffffffffffULL;
+    s->f7 = (signed char)0xffffffffffffffffULL;
+}
+
+int layout31_check(struct layout31 const *s) {
+    if (s->f0 != (short)0xfffffffffffffc00ULL) return 1;
+    if (s->f1 != (unsigned char)0x3fULL) return 2;
+    if (s->f2 != (unsigned short)0x1ULL) return 3;
+    if (s->f3 != (_Bool)0x0ULL) return 4;
+    if (s->f4 != (unsigned char)0x1ULL) return 5;
+    if (s->f5 != (unsigned int)0x0ULL) return 6;
+    if (s->f6 != (unsigned long long)0x3ffffffffffffffULL) return 7;
+    if (s->f7 != (signed char)0xffffffffffffffffULL) return 8;
+    return 0;
+}
+
+struct layout32 {
+    _Bool f0: 1;
+    unsigned int f1: 12;
+    signed char f2: 6;
+    __int128 f3: 1;
+    int f4: 4;
+};
+
+size_t layout32_size(void) {
+    return sizeof(struct layout32);
+}
+
+void layout32_fill(struct layout32 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x1ULL;
+    s->f1 = (unsigned int)0x0ULL;
+    s->f2 = (signed char)0x1fULL;
+    s->f3 = (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f4 = (int)0x7ULL;
+}
+
+int layout32_check(struct layout32 const *s) {
+    if (s->f0 != (_Bool)0x1ULL) return 1;
+    if (s->f1 != (unsigned int)0x0ULL) return 2;
+    if (s->f2 != (signed char)0x1fULL) return 3;
+    if (s->f3 != (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 4;
+    if (s->f4 != (int)0x7ULL) return 5;
+    return 0;
+}
+
+struct layout33 {
+    __int128 f0: 5;
+    unsigned char f1: 2;
+    unsigned char f2: 3;
+    signed char f3: 7;
+    unsigned __int128 f4: 40;
+    unsigned __int128 f5: 84;
+};
+
+size_t layout33_size(void) {
+    return sizeof(struct layout33);
+}
+
+void layout33_fill(struct layout33 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0xfULL);
+    s->f1 = (unsigned char)0x0ULL;
+    s->f2 = (unsigned char)0x5ULL;
+    s->f3 = (signed char)0xffffffffffffffd6ULL;
+    s->f4 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0xffffffffffULL);
+    s->f5 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+}
+
+int layout33_check(struct layout33 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0xfULL)) return 1;
+    if (s->f1 != (unsigned char)0x0ULL) return 2;
+    if (s->f2 != (unsigned char)0x5ULL) return 3;
+    if (s->f3 != (signed char)0xffffffffffffffd6ULL) return 4;
+    if (s->f4 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0xffffffffffULL)) return 5;
+    if (s->f5 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 6;
+    return 0;
+}
+
+struct layout34 {
+    unsigned long long f0: 60;
+    signed char f1: 3;
+    __int128 f2: 23;
+    signed char f3: 8;
+};
+
+size_t layout34_size(void) {
+    return sizeof(struct layout34);
+}
+
+void layout34_fill(struct layout34 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0xfffffffffffffffULL;
+    s->f1 = (signed char)0x3ULL;
+    s->f2 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0x9458aULL);
+    s->f3 = (signed char)0xffffffffffffff80ULL;
+}
+
+int layout34_check(struct layout34 const *s) {
+    if (s->f0 != (unsigned long long)0xfffffffffffffffULL) return 1;
+    if (s->f1 != (signed char)0x3ULL) return 2;
+    if (s->f2 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0x9458aULL)) return 3;
+    if (s->f3 != (signed char)0xffffffffffffff80ULL) return 4;
+    return 0;
+}
+
+struct layout35 {
+    unsigned char f0: 2;
+    unsigned long long f1: 34;
+    __int128 f2: 96;
+};
+
+size_t layout35_size(void) {
+    return sizeof(struct layout35);
+}
+
+void layout35_fill(struct layout35 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x0ULL;
+    s->f1 = (unsigned long long)0x27126d805ULL;
+    s->f2 = (__int128)(((unsigned __int128)0xffffffff80000000ULL << 64) | 0x0ULL);
+}
+
+int layout35_check(struct layout35 const *s) {
+    if (s->f0 != (unsigned char)0x0ULL) return 1;
+    if (s->f1 != (unsigned long long)0x27126d805ULL) return 2;
+    if (s->f2 != (__int128)(((unsigned __int128)0xffffffff80000000ULL << 64) | 0x0ULL)) return 3;
+    return 0;
+}
+
+struct layout36 {
+    _Bool f0: 1;
+};
+
+size_t layout36_size(void) {
+    return sizeof(struct layout36);
+}
+
+void layout36_fill(struct layout36 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x0ULL;
+}
+
+int layout36_check(struct layout36 const *s) {
+    if (s->f0 != (_Bool)0x0ULL) return 1;
+    return 0;
+}
+
+struct layout37 {
+    int f0: 30;
+};
+
+size_t layout37_size(void) {
+    return sizeof(struct layout37);
+}
+
+void layout37_fill(struct layout37 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0x1ee2d36bULL;
+}
+
+int layout37_check(struct layout37 const *s) {
+    if (s->f0 != (int)0x1ee2d36bULL) return 1;
+    return 0;
+}
+
+struct layout38 {
+    unsigned short f0: 6;
+    unsigned short f1: 11;
+    unsigned int f2: 22;
+    unsigned int f3: 31;
+};
+
+size_t layout38_size(void) {
+    return sizeof(struct layout38);
+}
+
+void layout38_fill(struct layout38 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x3fULL;
+    s->f1 = (unsigned short)0x691ULL;
+    s->f2 = (unsigned int)0x3fffffULL;
+    s->f3 = (unsigned int)0x0ULL;
+}
+
+int layout38_check(struct layout38 const *s) {
+    if (s->f0 != (unsigned short)0x3fULL) return 1;
+    if (s->f1 != (unsigned short)0x691ULL) return 2;
+    if (s->f2 != (unsigned int)0x3fffffULL) return 3;
+    if (s->f3 != (unsigned int)0x0ULL) return 4;
+    return 0;
+}
+
+struct layout39 {
+    short f0: 12;
+    unsigned __int128 f1: 108;
+    __int128 f2: 102;
+    long long f3: 49;
+    unsigned char f4: 5;
+    signed char f5: 5;
+    unsigned short f6: 9;
+};
+
+size_t layout39_size(void) {
+    return sizeof(struct layout39);
+}
+
+void layout39_fill(struct layout39 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xfffffffffffff800ULL;
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x94e1f51026fULL << 64) | 0x6ed4fa5110d76ef1ULL);
+    s->f2 = (__int128)(((unsigned __int128)0xffffffe000000000ULL << 64) | 0x0ULL);
+    s->f3 = (long long)0x165340ca14b5ULL;
+    s->f4 = (unsigned char)0x1fULL;
+    s->f5 = (signed char)0xfffffffffffffffaULL;
+    s->f6 = (unsigned short)0x1ffULL;
+}
+
+int layout39_check(struct layout39 const *s) {
+    if (s->f0 != (short)0xfffffffffffff800ULL) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x94e1f51026fULL << 64) | 0x6ed4fa5110d76ef1ULL)) return 2;
+    if (s->f2 != (__int128)(((unsigned __int128)0xffffffe000000000ULL << 64) | 0x0ULL)) return 3;
+    if (s->f3 != (long long)0x165340ca14b5ULL) return 4;
+    if (s->f4 != (unsigned char)0x1fULL) return 5;
+    if (s->f5 != (signed char)0xfffffffffffffffaULL) return 6;
+    if (s->f6 != (unsigned short)0x1ffULL) return 7;
+    return 0;
+}
+
+struct layout40 {
+    unsigned long long f0: 46;
+    signed char f1: 7;
+    short f2: 15;
+};
+
+size_t layout40_size(void) {
+    return sizeof(struct layout40);
+}
+
+void layout40_fill(struct layout40 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x59e10271bbbULL;
+    s->f1 = (signed char)0xfffffffffffffff1ULL;
+    s->f2 = (short)0x3fffULL;
+}
+
+int layout40_check(struct layout40 const *s) {
+    if (s->f0 != (unsigned long long)0x59e10271bbbULL) return 1;
+    if (s->f1 != (signed char)0xfffffffffffffff1ULL) return 2;
+    if (s->f2 != (short)0x3fffULL) return 3;
+    return 0;
+}
+
+struct layout41 {
+    unsigned __int128 f0: 114;
+    int f1: 16;
+    unsigned long long f2: 38;
+    long long f3: 24;
+    signed char f4: 4;
+};
+
+size_t layout41_size(void) {
+    return sizeof(struct layout41);
+}
+
+void layout41_fill(struct layout41 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f1 = (int)0x7fffULL;
+    s->f2 = (unsigned long long)0x3fffffffffULL;
+    s->f3 = (long long)0xffffffffff800000ULL;
+    s->f4 = (signed char)0xfffffffffffffffdULL;
+}
+
+int layout41_check(struct layout41 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 1;
+    if (s->f1 != (int)0x7fffULL) return 2;
+    if (s->f2 != (unsigned long long)0x3fffffffffULL) return 3;
+    if (s->f3 != (long long)0xffffffffff800000ULL) return 4;
+    if (s->f4 != (signed char)0xfffffffffffffffdULL) return 5;
+    return 0;
+}
+
+struct layout42 {
+    int f0: 19;
+    unsigned char f1: 1;
+};
+
+size_t layout42_size(void) {
+    return sizeof(struct layout42);
+}
+
+void layout42_fill(struct layout42 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0x1b370ULL;
+    s->f1 = (unsigned char)0x0ULL;
+}
+
+int layout42_check(struct layout42 const *s) {
+    if (s->f0 != (int)0x1b370ULL) return 1;
+    if (s->f1 != (unsigned char)0x0ULL) return 2;
+    return 0;
+}
+
+struct layout43 {
+    long long f0: 52;
+    unsigned long long f1: 6;
+};
+
+size_t layout43_size(void) {
+    return sizeof(struct layout43);
+}
+
+void layout43_fill(struct layout43 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0xfff8000000000000ULL;
+    s->f1 = (unsigned long long)0x0ULL;
+}
+
+int layout43_check(struct layout43 const *s) {
+    if (s->f0 != (long long)0xfff8000000000000ULL) return 1;
+    if (s->f1 != (unsigned long long)0x0ULL) return 2;
+    return 0;
+}
+
+struct layout44 {
+    int f0: 29;
+    _Bool f1: 1;
+    _Bool f2: 1;
+    int f3: 21;
+    long long f4: 11;
+    unsigned char f5: 7;
+    short f6: 5;
+};
+
+size_t layout44_size(void) {
+    return sizeof(struct layout44);
+}
+
+void layout44_fill(struct layout44 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xfffffffff0000000ULL;
+    s->f1 = (_Bool)0x1ULL;
+    s->f2 = (_Bool)0x0ULL;
+    s->f3 = (int)0xfffffULL;
+    s->f4 = (long long)0xfffffffffffffc00ULL;
+    s->f5 = (unsigned char)0x16ULL;
+    s->f6 = (short)0xfULL;
+}
+
+int layout44_check(struct layout44 const *s) {
+    if (s->f0 != (int)0xfffffffff0000000ULL) return 1;
+    if (s->f1 != (_Bool)0x1ULL) return 2;
+    if (s->f2 != (_Bool)0x0ULL) return 3;
+    if (s->f3 != (int)0xfffffULL) return 4;
+    if (s->f4 != (long long)0xfffffffffffffc00ULL) return 5;
+    if (s->f5 != (unsigned char)0x16ULL) return 6;
+    if (s->f6 != (short)0xfULL) return 7;
+    return 0;
+}
+
+struct layout45 {
+    unsigned short f0: 8;
+    signed char f1: 3;
+    unsigned char f2: 6;
+    unsigned char f3: 3;
+    int f4: 21;
+    _Bool f5: 1;
+    unsigned short f6: 4;
+};
+
+size_t layout45_size(void) {
+    return sizeof(struct layout45);
+}
+
+void layout45_fill(struct layout45 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0xffULL;
+    s->f1 = (signed char)0x3ULL;
+    s->f2 = (unsigned char)0x33ULL;
+    s->f3 = (unsigned char)0x6ULL;
+    s->f4 = (int)0xfffffffffff00000ULL;
+    s->f5 = (_Bool)0x1ULL;
+    s->f6 = (unsigned short)0x0ULL;
+}
+
+int layout45_check(struct layout45 const *s) {
+    if (s->f0 != (unsigned short)0xffULL) return 1;
+    if (s->f1 != (signed char)0x3ULL) return 2;
+    if (s->f2 != (unsigned char)0x33ULL) return 3;
+    if (s->f3 != (unsigned char)0x6ULL) return 4;
+    if (s->f4 != (int)0xfffffffffff00000ULL) return 5;
+    if (s->f5 != (_Bool)0x1ULL) return 6;
+    if (s->f6 != (unsigned short)0x0ULL) return 7;
+    return 0;
+}
+
+struct layout46 {
+    unsigned __int128 f0: 71;
+    signed char f1: 6;
+    long long f2: 5;
+};
+
+size_t layout46_size(void) {
+    return sizeof(struct layout46);
+}
+
+void layout46_fill(struct layout46 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0xcULL << 64) | 0x37dcb4857bfbab88ULL);
+    s->f1 = (signed char)0xffffffffffffffe0ULL;
+    s->f2 = (long long)0xfffffffffffffff7ULL;
+}
+
+int layout46_check(struct layout46 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0xcULL << 64) | 0x37dcb4857bfbab88ULL)) return 1;
+    if (s->f1 != (signed char)0xffffffffffffffe0ULL) return 2;
+    if (s->f2 != (long long)0xfffffffffffffff7ULL) return 3;
+    return 0;
+}
+
+struct layout47 {
+    long long f0: 35;
+    unsigned long long f1: 3;
+    signed char f2: 1;
+    unsigned __int128 f3: 42;
+    _Bool f4: 1;
+};
+
+size_t layout47_size(void) {
+    return sizeof(struct layout47);
+}
+
+void layout47_fill(struct layout47 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0xfffffffc00000000ULL;
+    s->f1 = (unsigned long long)0x4ULL;
+    s->f2 = (signed char)0x0ULL;
+    s->f3 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x9ce6c60900ULL);
+    s->f4 = (_Bool)0x1ULL;
+}
+
+int layout47_check(struct layout47 const *s) {
+    if (s->f0 != (long long)0xfffffffc00000000ULL) return 1;
+    if (s->f1 != (unsigned long long)0x4ULL) return 2;
+    if (s->f2 != (signed char)0x0ULL) return 3;
+    if (s->f3 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x9ce6c60900ULL)) return 4;
+    if (s->f4 != (_Bool)0x1ULL) return 5;
+    return 0;
+}
+
+struct layout48 {
+    int f0: 22;
+};
+
+size_t layout48_size(void) {
+    return sizeof(struct layout48);
+}
+
+void layout48_fill(struct layout48 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xffffffffffef4ccfULL;
+}
+
+int layout48_check(struct layout48 const *s) {
+    if (s->f0 != (int)0xffffffffffef4ccfULL) return 1;
+    return 0;
+}
+
+struct layout49 {
+    _Bool f0: 1;
+    unsigned int f1: 19;
+    unsigned char f2: 1;
+    unsigned long long f3: 21;
+    int f4: 21;
+};
+
+size_t layout49_size(void) {
+    return sizeof(struct layout49);
+}
+
+void layout49_fill(struct layout49 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x1ULL;
+    s->f1 = (unsigned int)0x0ULL;
+    s->f2 = (unsigned char)0x0ULL;
+    s->f3 = (unsigned long long)0x1fffffULL;
+    s->f4 = (int)0xfffffULL;
+}
+
+int layout49_check(struct layout49 const *s) {
+    if (s->f0 != (_Bool)0x1ULL) return 1;
+    if (s->f1 != (unsigned int)0x0ULL) return 2;
+    if (s->f2 != (unsigned char)0x0ULL) return 3;
+    if (s->f3 != (unsigned long long)0x1fffffULL) return 4;
+    if (s->f4 != (int)0xfffffULL) return 5;
+    return 0;
+}
+
+struct layout50 {
+    unsigned long long f0: 64;
+    unsigned int f1: 27;
+    short f2: 3;
+    unsigned __int128 f3: 115;
+    int f4: 9;
+    _Bool f5: 1;
+    unsigned short f6: 16;
+};
+
+size_t layout50_size(void) {
+    return sizeof(struct layout50);
+}
+
+void layout50_fill(struct layout50 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x9ddedace1755641ULL;
+    s->f1 = (unsigned int)0x0ULL;
+    s->f2 = (short)0xfffffffffffffffcULL;
+    s->f3 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f4 = (int)0x74ULL;
+    s->f5 = (_Bool)0x1ULL;
+    s->f6 = (unsigned short)0x0ULL;
+}
+
+int layout50_check(struct layout50 const *s) {
+    if (s->f0 != (unsigned long long)0x9ddedace1755641ULL) return 1;
+    if (s->f1 != (unsigned int)0x0ULL) return 2;
+    if (s->f2 != (short)0xfffffffffffffffcULL) return 3;
+    if (s->f3 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 4;
+    if (s->f4 != (int)0x74ULL) return 5;
+    if (s->f5 != (_Bool)0x1ULL) return 6;
+    if (s->f6 != (unsigned short)0x0ULL) return 7;
+    return 0;
+}
+
+struct layout51 {
+    unsigned short f0: 13;
+    signed char f1: 2;
+    unsigned int f2: 18;
+    short f3: 12;
+    unsigned char f4: 8;
+};
+
+size_t layout51_size(void) {
+    return sizeof(struct layout51);
+}
+
+void layout51_fill(struct layout51 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x1fffULL;
+    s->f1 = (signed char)0xfffffffffffffffeULL;
+    s->f2 = (unsigned int)0x361d7ULL;
+    s->f3 = (short)0xfffffffffffffa79ULL;
+    s->f4 = (unsigned char)0xffULL;
+}
+
+int layout51_check(struct layout51 const *s) {
+    if (s->f0 != (unsigned short)0x1fffULL) return 1;
+    if (s->f1 != (signed char)0xfffffffffffffffeULL) return 2;
+    if (s->f2 != (unsigned int)0x361d7ULL) return 3;
+    if (s->f3 != (short)0xfffffffffffffa79ULL) return 4;
+    if (s->f4 != (unsigned char)0xffULL) return 5;
+    return 0;
+}
+
+struct layout52 {
+    _Bool f0: 1;
+    long long f1: 41;
+    _Bool f2: 1;
+};
+
+size_t layout52_size(void) {
+    return sizeof(struct layout52);
+}
+
+void layout52_fill(struct layout52 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x0ULL;
+    s->f1 = (long long)0xffffffffffULL;
+    s->f2 = (_Bool)0x1ULL;
+}
+
+int layout52_check(struct layout52 const *s) {
+    if (s->f0 != (_Bool)0x0ULL) return 1;
+    if (s->f1 != (long long)0xffffffffffULL) return 2;
+    if (s->f2 != (_Bool)0x1ULL) return 3;
+    return 0;
+}
+
+struct layout53 {
+    int f0: 3;
+    unsigned short f1: 15;
+};
+
+size_t layout53_size(void) {
+    return sizeof(struct layout53);
+}
+
+void layout53_fill(struct layout53 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0x3ULL;
+    s->f1 = (unsigned short)0x0ULL;
+}
+
+int layout53_check(struct layout53 const *s) {
+    if (s->f0 != (int)0x3ULL) return 1;
+    if (s->f1 != (unsigned short)0x0ULL) return 2;
+    return 0;
+}
+
+struct layout54 {
+    signed char f0: 6;
+    unsigned long long f1: 19;
+};
+
+size_t layout54_size(void) {
+    return sizeof(struct layout54);
+}
+
+void layout54_fill(struct layout54 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0x1fULL;
+    s->f1 = (unsigned long long)0x2a6a3ULL;
+}
+
+int layout54_check(struct layout54 const *s) {
+    if (s->f0 != (signed char)0x1fULL) return 1;
+    if (s->f1 != (unsigned long long)0x2a6a3ULL) return 2;
+    return 0;
+}
+
+struct layout55 {
+    unsigned long long f0: 3;
+    unsigned short f1: 7;
+    unsigned __int128 f2: 55;
+    unsigned __int128 f3: 115;
+    signed char f4: 8;
+    unsigned long long f5: 9;
+    unsigned int f6: 23;
+    unsigned char f7: 5;
+};
+
+size_t layout55_size(void) {
+    return sizeof(struct layout55);
+}
+
+void layout55_fill(struct layout55 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x7ULL;
+    s->f1 = (unsigned short)0x52ULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x7fffffffffffffULL);
+    s->f3 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f4 = (signed char)0xffffffffffffffc7ULL;
+    s->f5 = (unsigned long long)0x1ffULL;
+    s->f6 = (unsigned int)0x7fffffULL;
+    s->f7 = (unsigned char)0x1fULL;
+}
+
+int layout55_check(struct layout55 const *s) {
+    if (s->f0 != (unsigned long long)0x7ULL) return 1;
+    if (s->f1 != (unsigned short)0x52ULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x7fffffffffffffULL)) return 3;
+    if (s->f3 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 4;
+    if (s->f4 != (signed char)0xffffffffffffffc7ULL) return 5;
+    if (s->f5 != (unsigned long long)0x1ffULL) return 6;
+    if (s->f6 != (unsigned int)0x7fffffULL) return 7;
+    if (s->f7 != (unsigned char)0x1fULL) return 8;
+    return 0;
+}
+
+struct layout56 {
+    short f0: 9;
+    unsigned int f1: 14;
+    _Bool f2: 1;
+    _Bool f3: 1;
+    short f4: 8;
+    unsigned int f5: 26;
+};
+
+size_t layout56_size(void) {
+    return sizeof(struct layout56);
+}
+
+void layout56_fill(struct layout56 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xffULL;
+    s->f1 = (unsigned int)0x3fffULL;
+    s->f2 = (_Bool)0x1ULL;
+    s->f3 = (_Bool)0x0ULL;
+    s->f4 = (short)0xffffffffffffff80ULL;
+    s->f5 = (unsigned int)0x0ULL;
+}
+
+int layout56_check(struct layout56 const *s) {
+    if (s->f0 != (short)0xffULL) return 1;
+    if (s->f1 != (unsigned int)0x3fffULL) return 2;
+    if (s->f2 != (_Bool)0x1ULL) return 3;
+    if (s->f3 != (_Bool)0x0ULL) return 4;
+    if (s->f4 != (short)0xffffffffffffff80ULL) return 5;
+    if (s->f5 != (unsigned int)0x0ULL) return 6;
+    return 0;
+}
+
+struct layout57 {
+    long long f0: 62;
+    long long f1: 18;
+    unsigned short f2: 10;
+};
+
+size_t layout57_size(void) {
+    return sizeof(struct layout57);
+}
+
+void layout57_fill(struct layout57 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0x1fffffffffffffffULL;
+    s->f1 = (long long)0x1ffffULL;
+    s->f2 = (unsigned short)0x0ULL;
+}
+
+int layout57_check(struct layout57 const *s) {
+    if (s->f0 != (long long)0x1fffffffffffffffULL) return 1;
+    if (s->f1 != (long long)0x1ffffULL) return 2;
+    if (s->f2 != (unsigned short)0x0ULL) return 3;
+    return 0;
+}
+
+struct layout58 {
+    int f0: 21;
+    long long f1: 18;
+    int f2: 21;
+    int f3: 13;
+    unsigned int f4: 13;
+};
+
+size_t layout58_size(void) {
+    return sizeof(struct layout58);
+}
+
+void layout58_fill(struct layout58 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xfffffULL;
+    s->f1 = (long long)0x1ffffULL;
+    s->f2 = (int)0x843a3ULL;
+    s->f3 = (int)0x400ULL;
+    s->f4 = (unsigned int)0x0ULL;
+}
+
+int layout58_check(struct layout58 const *s) {
+    if (s->f0 != (int)0xfffffULL) return 1;
+    if (s->f1 != (long long)0x1ffffULL) return 2;
+    if (s->f2 != (int)0x843a3ULL) return 3;
+    if (s->f3 != (int)0x400ULL) return 4;
+    if (s->f4 != (unsigned int)0x0ULL) return 5;
+    return 0;
+}
+
+struct layout59 {
+    __int128 f0: 104;
+    unsigned short f1: 5;
+    _Bool f2: 1;
+    int f3: 15;
+    __int128 f4: 99;
+    unsigned int f5: 22;
+    signed char f6: 4;
+};
+
+size_t layout59_size(void) {
+    return sizeof(struct layout59);
+}
+
+void layout59_fill(struct layout59 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xffffff8000000000ULL << 64) | 0x0ULL);
+    s->f1 = (unsigned short)0x1fULL;
+    s->f2 = (_Bool)0x1ULL;
+    s->f3 = (int)0x3fffULL;
+    s->f4 = (__int128)(((unsigned __int128)0xfffffffc00000000ULL << 64) | 0x0ULL);
+    s->f5 = (unsigned int)0x3fffffULL;
+    s->f6 = (signed char)0xfffffffffffffff8ULL;
+}
+
+int layout59_check(struct layout59 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xffffff8000000000ULL << 64) | 0x0ULL)) return 1;
+    if (s->f1 != (unsigned short)0x1fULL) return 2;
+    if (s->f2 != (_Bool)0x1ULL) return 3;
+    if (s->f3 != (int)0x3fffULL) return 4;
+    if (s->f4 != (__int128)(((unsigned __int128)0xfffffffc00000000ULL << 64) | 0x0ULL)) return 5;
+    if (s->f5 != (unsigned int)0x3fffffULL) return 6;
+    if (s->f6 != (signed char)0xfffffffffffffff8ULL) return 7;
+    return 0;
+}
+
+struct layout60 {
+    long long f0: 59;
+    __int128 f1: 49;
+    unsigned char f2: 1;
+};
+
+size_t layout60_size(void) {
+    return sizeof(struct layout60);
+}
+
+void layout60_fill(struct layout60 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0x3ffffffffffffffULL;
+    s->f1 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0xffffffffffffULL);
+    s->f2 = (unsigned char)0x0ULL;
+}
+
+int layout60_check(struct layout60 const *s) {
+    if (s->f0 != (long long)0x3ffffffffffffffULL) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0xffffffffffffULL)) return 2;
+    if (s->f2 != (unsigned char)0x0ULL) return 3;
+    return 0;
+}
+
+struct layout61 {
+    unsigned char f0: 3;
+    long long f1: 26;
+    unsigned char f2: 8;
+    unsigned long long f3: 11;
+    unsigned char f4: 7;
+    unsigned int f5: 12;
+};
+
+size_t layout61_size(void) {
+    return sizeof(struct layout61);
+}
+
+void layout61_fill(struct layout61 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x7ULL;
+    s->f1 = (long long)0x1ffffffULL;
+    s->f2 = (unsigned char)0xb2ULL;
+    s->f3 = (unsigned long long)0x7ffULL;
+    s->f4 = (unsigned char)0x0ULL;
+    s->f5 = (unsigned int)0x0ULL;
+}
+
+int layout61_check(struct layout61 const *s) {
+    if (s->f0 != (unsigned char)0x7ULL) return 1;
+    if (s->f1 != (long long)0x1ffffffULL) return 2;
+    if (s->f2 != (unsigned char)0xb2ULL) return 3;
+    if (s->f3 != (unsigned long long)0x7ffULL) return 4;
+    if (s->f4 != (unsigned char)0x0ULL) return 5;
+    if (s->f5 != (unsigned int)0x0ULL) return 6;
+    return 0;
+}
+
+struct layout62 {
+    int f0: 1;
+    unsigned __int128 f1: 87;
+};
+
+size_t layout62_size(void) {
+    return sizeof(struct layout62);
+}
+
+void layout62_fill(struct layout62 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xffffffffffffffffULL;
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+}
+
+int layout62_check(struct layout62 const *s) {
+    if (s->f0 != (int)0xffffffffffffffffULL) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 2;
+    return 0;
+}
+
+struct layout63 {
+    __int128 f0: 84;
+};
+
+size_t layout63_size(void) {
+    return sizeof(struct layout63);
+}
+
+void layout63_fill(struct layout63 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xfffffffffffd8534ULL << 64) | 0x2ed05b521539b1c0ULL);
+}
+
+int layout63_check(struct layout63 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xfffffffffffd8534ULL << 64) | 0x2ed05b521539b1c0ULL)) return 1;
+    return 0;
+}
+
+struct layout64 {
+    _Bool f0: 1;
+    unsigned int f1: 31;
+    unsigned short f2: 4;
+    unsigned long long f3: 55;
+};
+
+size_t layout64_size(void) {
+    return sizeof(struct layout64);
+}
+
+void layout64_fill(struct layout64 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x1ULL;
+    s->f1 = (unsigned int)0x7fffffffULL;
+    s->f2 = (unsigned short)0x0ULL;
+    s->f3 = (unsigned long long)0x11db3a0f6f4b02ULL;
+}
+
+int layout64_check(struct layout64 const *s) {
+    if (s->f0 != (_Bool)0x1ULL) return 1;
+    if (s->f1 != (unsigned int)0x7fffffffULL) return 2;
+    if (s->f2 != (unsigned short)0x0ULL) return 3;
+    if (s->f3 != (unsigned long long)0x11db3a0f6f4b02ULL) return 4;
+    return 0;
+}
+
+struct layout65 {
+    short f0: 14;
+    int f1: 26;
+    long long f2: 30;
+    unsigned short f3: 1;
+};
+
+size_t layout65_size(void) {
+    return sizeof(struct layout65);
+}
+
+void layout65_fill(struct layout65 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0x1fffULL;
+    s->f1 = (int)0xfab0fdULL;
+    s->f2 = (long long)0xffffffffe0000000ULL;
+    s->f3 = (unsigned short)0x0ULL;
+}
+
+int layout65_check(struct layout65 const *s) {
+    if (s->f0 != (short)0x1fffULL) return 1;
+    if (s->f1 != (int)0xfab0fdULL) return 2;
+    if (s->f2 != (long long)0xffffffffe0000000ULL) return 3;
+    if (s->f3 != (unsigned short)0x0ULL) return 4;
+    return 0;
+}
+
+struct layout66 {
+    unsigned short f0: 7;
+    signed char f1: 1;
+};
+
+size_t layout66_size(void) {
+    return sizeof(struct layout66);
+}
+
+void layout66_fill(struct layout66 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x0ULL;
+    s->f1 = (signed char)0x0ULL;
+}
+
+int layout66_check(struct layout66 const *s) {
+    if (s->f0 != (unsigned short)0x0ULL) return 1;
+    if (s->f1 != (signed char)0x0ULL) return 2;
+    return 0;
+}
+
+struct layout67 {
+    int f0: 24;
+    int f1: 8;
+};
+
+size_t layout67_size(void) {
+    return sizeof(struct layout67);
+}
+
+void layout67_fill(struct layout67 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0x7fffffULL;
+    s->f1 = (int)0xffffffffffffff9fULL;
+}
+
+int layout67_check(struct layout67 const *s) {
+    if (s->f0 != (int)0x7fffffULL) return 1;
+    if (s->f1 != (int)0xffffffffffffff9fULL) return 2;
+    return 0;
+}
+
+struct layout68 {
+    unsigned long long f0: 49;
+    short f1: 7;
+    unsigned __int128 f2: 31;
+    unsigned long long f3: 45;
+    unsigned char f4: 6;
+    unsigned char f5: 2;
+};
+
+size_t layout68_size(void) {
+    return sizeof(struct layout68);
+}
+
+void layout68_fill(struct layout68 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x0ULL;
+    s->f1 = (short)0x3fULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x7cd0e7eeULL);
+    s->f3 = (unsigned long long)0x0ULL;
+    s->f4 = (unsigned char)0x3fULL;
+    s->f5 = (unsigned char)0x2ULL;
+}
+
+int layout68_check(struct layout68 const *s) {
+    if (s->f0 != (unsigned long long)0x0ULL) return 1;
+    if (s->f1 != (short)0x3fULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x7cd0e7eeULL)) return 3;
+    if (s->f3 != (unsigned long long)0x0ULL) return 4;
+    if (s->f4 != (unsigned char)0x3fULL) return 5;
+    if (s->f5 != (unsigned char)0x2ULL) return 6;
+    return 0;
+}
+
+struct layout69 {
+    unsigned long long f0: 38;
+    int f1: 22;
+    unsigned short f2: 7;
+    int f3: 5;
+    unsigned short f4: 12;
+    unsigned short f5: 4;
+    _Bool f6: 1;
+    unsigned int f7: 3;
+};
+
+size_t layout69_size(void) {
+    return sizeof(struct layout69);
+}
+
+void layout69_fill(struct layout69 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x0ULL;
+    s->f1 = (int)0xffffffffffe00000ULL;
+    s->f2 = (unsigned short)0x49ULL;
+    s->f3 = (int)0xfULL;
+    s->f4 = (unsigned short)0x80dULL;
+    s->f5 = (unsigned short)0xfULL;
+    s->f6 = (_Bool)0x1ULL;
+    s->f7 = (unsigned int)0x5ULL;
+}
+
+int layout69_check(struct layout69 const *s) {
+    if (s->f0 != (unsigned long long)0x0ULL) return 1;
+    if (s->f1 != (int)0xffffffffffe00000ULL) return 2;
+    if (s->f2 != (unsigned short)0x49ULL) return 3;
+    if (s->f3 != (int)0xfULL) return 4;
+    if (s->f4 != (unsigned short)0x80dULL) return 5;
+    if (s->f5 != (unsigned short)0xfULL) return 6;
+    if (s->f6 != (_Bool)0x1ULL) return 7;
+    if (s->f7 != (unsigned int)0x5ULL) return 8;
+    return 0;
+}
+
+struct layout70 {
+    long long f0: 34;
+    signed char f1: 8;
+    long long f2: 51;
+    unsigned long long f3: 42;
+    __int128 f4: 17;
+    unsigned char f5: 5;
+    short f6: 13;
+};
+
+size_t layout70_size(void) {
+    return sizeof(struct layout70);
+}
+
+void layout70_fill(struct layout70 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0xfffffffe00000000ULL;
+    s->f1 = (signed char)0x4fULL;
+    s->f2 = (long long)0x3ffffffffffffULL;
+    s->f3 = (unsigned long long)0x3ffffffffffULL;
+    s->f4 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0xffffULL);
+    s->f5 = (unsigned char)0x0ULL;
+    s->f6 = (short)0xfffffffffffff000ULL;
+}
+
+int layout70_check(struct layout70 const *s) {
+    if (s->f0 != (long long)0xfffffffe00000000ULL) return 1;
+    if (s->f1 != (signed char)0x4fULL) return 2;
+    if (s->f2 != (long long)0x3ffffffffffffULL) return 3;
+    if (s->f3 != (unsigned long long)0x3ffffffffffULL) return 4;
+    if (s->f4 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0xffffULL)) return 5;
+    if (s->f5 != (unsigned char)0x0ULL) return 6;
+    if (s->f6 != (short)0xfffffffffffff000ULL) return 7;
+    return 0;
+}
+
+struct layout71 {
+    unsigned short f0: 3;
+    signed char f1: 4;
+    unsigned int f2: 25;
+};
+
+size_t layout71_size(void) {
+    return sizeof(struct layout71);
+}
+
+void layout71_fill(struct layout71 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x0ULL;
+    s->f1 = (signed char)0x7ULL;
+    s->f2 = (unsigned int)0x0ULL;
+}
+
+int layout71_check(struct layout71 const *s) {
+    if (s->f0 != (unsigned short)0x0ULL) return 1;
+    if (s->f1 != (signed char)0x7ULL) return 2;
+    if (s->f2 != (unsigned int)0x0ULL) return 3;
+    return 0;
+}
+
+struct layout72 {
+    unsigned __int128 f0: 69;
+    unsigned __int128 f1: 30;
+    long long f2: 40;
+    unsigned char f3: 8;
+    unsigned short f4: 12;
+    signed char f5: 8;
+    __int128 f6: 116;
+};
+
+size_t layout72_size(void) {
+    return sizeof(struct layout72);
+}
+
+void layout72_fill(struct layout72 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x1eULL << 64) | 0x8997d4f56b836290ULL);
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f2 = (long long)0x7fffffffffULL;
+    s->f3 = (unsigned char)0xacULL;
+    s->f4 = (unsigned short)0xfffULL;
+    s->f5 = (signed char)0xffffffffffffffdaULL;
+    s->f6 = (__int128)(((unsigned __int128)0x7ffffffffffffULL << 64) | 0xffffffffffffffffULL);
+}
+
+int layout72_check(struct layout72 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x1eULL << 64) | 0x8997d4f56b836290ULL)) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 2;
+    if (s->f2 != (long long)0x7fffffffffULL) return 3;
+    if (s->f3 != (unsigned char)0xacULL) return 4;
+    if (s->f4 != (unsigned short)0xfffULL) return 5;
+    if (s->f5 != (signed char)0xffffffffffffffdaULL) return 6;
+    if (s->f6 != (__int128)(((unsigned __int128)0x7ffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 7;
+    return 0;
+}
+
+struct layout73 {
+    unsigned long long f0: 22;
+    __int128 f1: 116;
+    unsigned long long f2: 11;
+    unsigned char f3: 8;
+    unsigned short f4: 8;
+    signed char f5: 7;
+    signed char f6: 1;
+};
+
+size_t layout73_size(void) {
+    return sizeof(struct layout73);
+}
+
+void layout73_fill(struct layout73 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x3fffffULL;
+    s->f1 = (__int128)(((unsigned __int128)0xfff8000000000000ULL << 64) | 0x0ULL);
+    s->f2 = (unsigned long long)0x0ULL;
+    s->f3 = (unsigned char)0xffULL;
+    s->f4 = (unsigned short)0x0ULL;
+    s->f5 = (signed char)0x3fULL;
+    s->f6 = (signed char)0xffffffffffffffffULL;
+}
+
+int layout73_check(struct layout73 const *s) {
+    if (s->f0 != (unsigned long long)0x3fffffULL) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0xfff8000000000000ULL << 64) | 0x0ULL)) return 2;
+    if (s->f2 != (unsigned long long)0x0ULL) return 3;
+    if (s->f3 != (unsigned char)0xffULL) return 4;
+    if (s->f4 != (unsigned short)0x0ULL) return 5;
+    if (s->f5 != (signed char)0x3fULL) return 6;
+    if (s->f6 != (signed char)0xffffffffffffffffULL) return 7;
+    return 0;
+}
+
+struct layout74 {
+    long long f0: 52;
+};
+
+size_t layout74_size(void) {
+    return sizeof(struct layout74);
+}
+
+void layout74_fill(struct layout74 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0xfffb4bc3f23095ccULL;
+}
+
+int layout74_check(struct layout74 const *s) {
+    if (s->f0 != (long long)0xfffb4bc3f23095ccULL) return 1;
+    return 0;
+}
+
+struct layout75 {
+    unsigned __int128 f0: 71;
+    long long f1: 6;
+    unsigned short f2: 1;
+    unsigned long long f3: 11;
+    short f4: 5;
+    unsigned char f5: 5;
+    unsigned char f6: 5;
+    signed char f7: 1;
+};
+
+size_t layout75_size(void) {
+    return sizeof(struct layout75);
+}
+
+void layout75_fill(struct layout75 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x67ULL << 64) | 0xe5ce845e2cdea7feULL);
+    s->f1 = (long long)0xffffffffffffffe0ULL;
+    s->f2 = (unsigned short)0x1ULL;
+    s->f3 = (unsigned long long)0x0ULL;
+    s->f4 = (short)0xfffffffffffffff7ULL;
+    s->f5 = (unsigned char)0x4ULL;
+    s->f6 = (unsigned char)0x1fULL;
+    s->f7 = (signed char)0xffffffffffffffffULL;
+}
+
+int layout75_check(struct layout75 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x67ULL << 64) | 0xe5ce845e2cdea7feULL)) return 1;
+    if (s->f1 != (long long)0xffffffffffffffe0ULL) return 2;
+    if (s->f2 != (unsigned short)0x1ULL) return 3;
+    if (s->f3 != (unsigned long long)0x0ULL) return 4;
+    if (s->f4 != (short)0xfffffffffffffff7ULL) return 5;
+    if (s->f5 != (unsigned char)0x4ULL) return 6;
+    if (s->f6 != (unsigned char)0x1fULL) return 7;
+    if (s->f7 != (signed char)0xffffffffffffffffULL) return 8;
+    return 0;
+}
+
+struct layout76 {
+    long long f0: 29;
+    unsigned long long f1: 26;
+    unsigned short f2: 4;
+    signed char f3: 4;
+};
+
+size_t layout76_size(void) {
+    return sizeof(struct layout76);
+}
+
+void layout76_fill(struct layout76 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0xfffffffff0000000ULL;
+    s->f1 = (unsigned long long)0x3ffffffULL;
+    s->f2 = (unsigned short)0x0ULL;
+    s->f3 = (signed char)0x7ULL;
+}
+
+int layout76_check(struct layout76 const *s) {
+    if (s->f0 != (long long)0xfffffffff0000000ULL) return 1;
+    if (s->f1 != (unsigned long long)0x3ffffffULL) return 2;
+    if (s->f2 != (unsigned short)0x0ULL) return 3;
+    if (s->f3 != (signed char)0x7ULL) return 4;
+    return 0;
+}
+
+struct layout77 {
+    signed char f0: 7;
+    signed char f1: 7;
+    unsigned __int128 f2: 106;
+    __int128 f3: 126;
+    unsigned long long f4: 2;
+    unsigned long long f5: 55;
+    unsigned char f6: 4;
+    unsigned __int128 f7: 38;
+};
+
+size_t layout77_size(void) {
+    return sizeof(struct layout77);
+}
+
+void layout77_fill(struct layout77 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0x3dULL;
+    s->f1 = (signed char)0x3fULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x19c79ae5e6fULL << 64) | 0x5d28fb24a8768165ULL);
+    s->f3 = (__int128)(((unsigned __int128)0xea6fe09ae8c73862ULL << 64) | 0xd52ec930e5219fecULL);
+    s->f4 = (unsigned long long)0x3ULL;
+    s->f5 = (unsigned long long)0x0ULL;
+    s->f6 = (unsigned char)0x3ULL;
+    s->f7 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+}
+
+int layout77_check(struct layout77 const *s) {
+    if (s->f0 != (signed char)0x3dULL) return 1;
+    if (s->f1 != (signed char)0x3fULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x19c79ae5e6fULL << 64) | 0x5d28fb24a8768165ULL)) return 3;
+    if (s->f3 != (__int128)(((unsigned __int128)0xea6fe09ae8c73862ULL << 64) | 0xd52ec930e5219fecULL)) return 4;
+    if (s->f4 != (unsigned long long)0x3ULL) return 5;
+    if (s->f5 != (unsigned long long)0x0ULL) return 6;
+    if (s->f6 != (unsigned char)0x3ULL) return 7;
+    if (s->f7 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 8;
+    return 0;
+}
+
+struct layout78 {
+    unsigned int f0: 24;
+    unsigned long long f1: 13;
+    int f2: 19;
+    long long f3: 28;
+    __int128 f4: 115;
+    short f5: 5;
+    unsigned int f6: 16;
+};
+
+size_t layout78_size(void) {
+    return sizeof(struct layout78);
+}
+
+void layout78_fill(struct layout78 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned int)0xffffffULL;
+    s->f1 = (unsigned long long)0x1fffULL;
+    s->f2 = (int)0xfffffffffffc0000ULL;
+    s->f3 = (long long)0xfffffffff8000000ULL;
+    s->f4 = (__int128)(((unsigned __int128)0xfffc000000000000ULL << 64) | 0x0ULL);
+    s->f5 = (short)0xfULL;
+    s->f6 = (unsigned int)0xffffULL;
+}
+
+int layout78_check(struct layout78 const *s) {
+    if (s->f0 != (unsigned int)0xffffffULL) return 1;
+    if (s->f1 != (unsigned long long)0x1fffULL) return 2;
+    if (s->f2 != (int)0xfffffffffffc0000ULL) return 3;
+    if (s->f3 != (long long)0xfffffffff8000000ULL) return 4;
+    if (s->f4 != (__int128)(((unsigned __int128)0xfffc000000000000ULL << 64) | 0x0ULL)) return 5;
+    if (s->f5 != (short)0xfULL) return 6;
+    if (s->f6 != (unsigned int)0xffffULL) return 7;
+    return 0;
+}
+
+struct layout79 {
+    _Bool f0: 1;
+    signed char f1: 1;
+};
+
+size_t layout79_size(void) {
+    return sizeof(struct layout79);
+}
+
+void layout79_fill(struct layout79 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x1ULL;
+    s->f1 = (signed char)0x0ULL;
+}
+
+int layout79_check(struct layout79 const *s) {
+    if (s->f0 != (_Bool)0x1ULL) return 1;
+    if (s->f1 != (signed char)0x0ULL) return 2;
+    return 0;
+}
+
+struct layout80 {
+    unsigned short f0: 7;
+    unsigned __int128 f1: 100;
+    unsigned short f2: 1;
+    short f3: 16;
+    int f4: 31;
+    unsigned long long f5: 30;
+    unsigned long long f6: 3;
+};
+
+size_t layout80_size(void) {
+    return sizeof(struct layout80);
+}
+
+void layout80_fill(struct layout80 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x7fULL;
+    s->f1 = (unsigned __int128)(((unsigned __int128)0xfffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f2 = (unsigned short)0x0ULL;
+    s->f3 = (short)0xfffffffffffff879ULL;
+    s->f4 = (int)0x3eb7340bULL;
+    s->f5 = (unsigned long long)0x0ULL;
+    s->f6 = (unsigned long long)0x7ULL;
+}
+
+int layout80_check(struct layout80 const *s) {
+    if (s->f0 != (unsigned short)0x7fULL) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0xfffffffffULL << 64) | 0xffffffffffffffffULL)) return 2;
+    if (s->f2 != (unsigned short)0x0ULL) return 3;
+    if (s->f3 != (short)0xfffffffffffff879ULL) return 4;
+    if (s->f4 != (int)0x3eb7340bULL) return 5;
+    if (s->f5 != (unsigned long long)0x0ULL) return 6;
+    if (s->f6 != (unsigned long long)0x7ULL) return 7;
+    return 0;
+}
+
+struct layout81 {
+    unsigned __int128 f0: 55;
+    unsigned char f1: 8;
+};
+
+size_t layout81_size(void) {
+    return sizeof(struct layout81);
+}
+
+void layout81_fill(struct layout81 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f1 = (unsigned char)0x0ULL;
+}
+
+int layout81_check(struct layout81 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 1;
+    if (s->f1 != (unsigned char)0x0ULL) return 2;
+    return 0;
+}
+
+struct layout82 {
+    __int128 f0: 68;
+    unsigned int f1: 24;
+    signed char f2: 7;
+    signed char f3: 7;
+};
+
+size_t layout82_size(void) {
+    return sizeof(struct layout82);
+}
+
+void layout82_fill(struct layout82 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xfffffffffffffff8ULL << 64) | 0x0ULL);
+    s->f1 = (unsigned int)0x6f3f2aULL;
+    s->f2 = (signed char)0x3fULL;
+    s->f3 = (signed char)0xcULL;
+}
+
+int layout82_check(struct layout82 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xfffffffffffffff8ULL << 64) | 0x0ULL)) return 1;
+    if (s->f1 != (unsigned int)0x6f3f2aULL) return 2;
+    if (s->f2 != (signed char)0x3fULL) return 3;
+    if (s->f3 != (signed char)0xcULL) return 4;
+    return 0;
+}
+
+struct layout83 {
+    short f0: 13;
+    unsigned long long f1: 40;
+    unsigned long long f2: 17;
+    unsigned short f3: 13;
+};
+
+size_t layout83_size(void) {
+    return sizeof(struct layout83);
+}
+
+void layout83_fill(struct layout83 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xfffffffffffff8b7ULL;
+    s->f1 = (unsigned long long)0x0ULL;
+    s->f2 = (unsigned long long)0x1f336ULL;
+    s->f3 = (unsigned short)0x1ddbULL;
+}
+
+int layout83_check(struct layout83 const *s) {
+    if (s->f0 != (short)0xfffffffffffff8b7ULL) return 1;
+    if (s->f1 != (unsigned long long)0x0ULL) return 2;
+    if (s->f2 != (unsigned long long)0x1f336ULL) return 3;
+    if (s->f3 != (unsigned short)0x1ddbULL) return 4;
+    return 0;
+}
+
+struct layout84 {
+    unsigned char f0: 2;
+    unsigned char f1: 7;
+    unsigned __int128 f2: 115;
+    __int128 f3: 18;
+    unsigned char f4: 7;
+    _Bool f5: 1;
+    long long f6: 51;
+    __int128 f7: 118;
+};
+
+size_t layout84_size(void) {
+    return sizeof(struct layout84);
+}
+
+void layout84_fill(struct layout84 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x0ULL;
+    s->f1 = (unsigned char)0x0ULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f3 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0x3cfcULL);
+    s->f4 = (unsigned char)0x0ULL;
+    s->f5 = (_Bool)0x0ULL;
+    s->f6 = (long long)0xfffc000000000000ULL;
+    s->f7 = (__int128)(((unsigned __int128)0x1fffffffffffffULL << 64) | 0xffffffffffffffffULL);
+}
+
+int layout84_check(struct layout84 const *s) {
+    if (s->f0 != (unsigned char)0x0ULL) return 1;
+    if (s->f1 != (unsigned char)0x0ULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 3;
+    if (s->f3 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0x3cfcULL)) return 4;
+    if (s->f4 != (unsigned char)0x0ULL) return 5;
+    if (s->f5 != (_Bool)0x0ULL) return 6;
+    if (s->f6 != (long long)0xfffc000000000000ULL) return 7;
+    if (s->f7 != (__int128)(((unsigned __int128)0x1fffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 8;
+    return 0;
+}
+
+struct layout85 {
+    __int128 f0: 6;
+    short f1: 13;
+};
+
+size_t layout85_size(void) {
+    return sizeof(struct layout85);
+}
+
+void layout85_fill(struct layout85 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xffffffffffffffe0ULL);
+    s->f1 = (short)0xfffULL;
+}
+
+int layout85_check(struct layout85 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xffffffffffffffe0ULL)) return 1;
+    if (s->f1 != (short)0xfffULL) return 2;
+    return 0;
+}
+
+struct layout86 {
+    unsigned short f0: 10;
+    long long f1: 54;
+    long long f2: 57;
+    unsigned short f3: 4;
+    unsigned long long f4: 40;
+    short f5: 15;
+    int f6: 8;
+};
+
+size_t layout86_size(void) {
+    return sizeof(struct layout86);
+}
+
+void layout86_fill(struct layout86 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x13fULL;
+    s->f1 = (long long)0x836e4d9fe2c1aULL;
+    s->f2 = (long long)0xff00000000000000ULL;
+    s->f3 = (unsigned short)0xfULL;
+    s->f4 = (unsigned long long)0xffffffffffULL;
+    s->f5 = (short)0xffffffffffffc000ULL;
+    s->f6 = (int)0x7fULL;
+}
+
+int layout86_check(struct layout86 const *s) {
+    if (s->f0 != (unsigned short)0x13fULL) return 1;
+    if (s->f1 != (long long)0x836e4d9fe2c1aULL) return 2;
+    if (s->f2 != (long long)0xff00000000000000ULL) return 3;
+    if (s->f3 != (unsigned short)0xfULL) return 4;
+    if (s->f4 != (unsigned long long)0xffffffffffULL) return 5;
+    if (s->f5 != (short)0xffffffffffffc000ULL) return 6;
+    if (s->f6 != (int)0x7fULL) return 7;
+    return 0;
+}
+
+struct layout87 {
+    unsigned char f0: 7;
+    __int128 f1: 45;
+    unsigned long long f2: 48;
+    short f3: 6;
+    _Bool f4: 1;
+    signed char f5: 8;
+    unsigned int f6: 9;
+};
+
+size_t layout87_size(void) {
+    return sizeof(struct layout87);
+}
+
+void layout87_fill(struct layout87 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x7fULL;
+    s->f1 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0xfffffffffffULL);
+    s->f2 = (unsigned long long)0xffffffffffffULL;
+    s->f3 = (short)0x1fULL;
+    s->f4 = (_Bool)0x0ULL;
+    s->f5 = (signed char)0xffffffffffffff80ULL;
+    s->f6 = (unsigned int)0x1ffULL;
+}
+
+int layout87_check(struct layout87 const *s) {
+    if (s->f0 != (unsigned char)0x7fULL) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0xfffffffffffULL)) return 2;
+    if (s->f2 != (unsigned long long)0xffffffffffffULL) return 3;
+    if (s->f3 != (short)0x1fULL) return 4;
+    if (s->f4 != (_Bool)0x0ULL) return 5;
+    if (s->f5 != (signed char)0xffffffffffffff80ULL) return 6;
+    if (s->f6 != (unsigned int)0x1ffULL) return 7;
+    return 0;
+}
+
+struct layout88 {
+    long long f0: 50;
+    _Bool f1: 1;
+    unsigned short f2: 3;
+    unsigned short f3: 7;
+    _Bool f4: 1;
+    unsigned short f5: 4;
+};
+
+size_t layout88_size(void) {
+    return sizeof(struct layout88);
+}
+
+void layout88_fill(struct layout88 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (long long)0x1e58793ac901dULL;
+    s->f1 = (_Bool)0x0ULL;
+    s->f2 = (unsigned short)0x4ULL;
+    s->f3 = (unsigned short)0x7fULL;
+    s->f4 = (_Bool)0x0ULL;
+    s->f5 = (unsigned short)0x0ULL;
+}
+
+int layout88_check(struct layout88 const *s) {
+    if (s->f0 != (long long)0x1e58793ac901dULL) return 1;
+    if (s->f1 != (_Bool)0x0ULL) return 2;
+    if (s->f2 != (unsigned short)0x4ULL) return 3;
+    if (s->f3 != (unsigned short)0x7fULL) return 4;
+    if (s->f4 != (_Bool)0x0ULL) return 5;
+    if (s->f5 != (unsigned short)0x0ULL) return 6;
+    return 0;
+}
+
+struct layout89 {
+    _Bool f0: 1;
+};
+
+size_t layout89_size(void) {
+    return sizeof(struct layout89);
+}
+
+void layout89_fill(struct layout89 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x0ULL;
+}
+
+int layout89_check(struct layout89 const *s) {
+    if (s->f0 != (_Bool)0x0ULL) return 1;
+    return 0;
+}
+
+struct layout90 {
+    signed char f0: 7;
+    unsigned short f1: 10;
+    unsigned long long f2: 53;
+    signed char f3: 1;
+};
+
+size_t layout90_size(void) {
+    return sizeof(struct layout90);
+}
+
+void layout90_fill(struct layout90 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0xffffffffffffffc0ULL;
+    s->f1 = (unsigned short)0x3ffULL;
+    s->f2 = (unsigned long long)0x179537aafb3d4eULL;
+    s->f3 = (signed char)0x0ULL;
+}
+
+int layout90_check(struct layout90 const *s) {
+    if (s->f0 != (signed char)0xffffffffffffffc0ULL) return 1;
+    if (s->f1 != (unsigned short)0x3ffULL) return 2;
+    if (s->f2 != (unsigned long long)0x179537aafb3d4eULL) return 3;
+    if (s->f3 != (signed char)0x0ULL) return 4;
+    return 0;
+}
+
+struct layout91 {
+    signed char f0: 2;
+    unsigned long long f1: 21;
+    long long f2: 13;
+    short f3: 6;
+    unsigned int f4: 22;
+    int f5: 3;
+    __int128 f6: 1;
+    unsigned long long f7: 44;
+};
+
+size_t layout91_size(void) {
+    return sizeof(struct layout91);
+}
+
+void layout91_fill(struct layout91 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0x1ULL;
+    s->f1 = (unsigned long long)0x1fffffULL;
+    s->f2 = (long long)0xfffffffffffff000ULL;
+    s->f3 = (short)0x11ULL;
+    s->f4 = (unsigned int)0x33f782ULL;
+    s->f5 = (int)0xfffffffffffffffcULL;
+    s->f6 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f7 = (unsigned long long)0xb7f2e50452dULL;
+}
+
+int layout91_check(struct layout91 const *s) {
+    if (s->f0 != (signed char)0x1ULL) return 1;
+    if (s->f1 != (unsigned long long)0x1fffffULL) return 2;
+    if (s->f2 != (long long)0xfffffffffffff000ULL) return 3;
+    if (s->f3 != (short)0x11ULL) return 4;
+    if (s->f4 != (unsigned int)0x33f782ULL) return 5;
+    if (s->f5 != (int)0xfffffffffffffffcULL) return 6;
+    if (s->f6 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 7;
+    if (s->f7 != (unsigned long long)0xb7f2e50452dULL) return 8;
+    return 0;
+}
+
+struct layout92 {
+    short f0: 3;
+    short f1: 16;
+};
+
+size_t layout92_size(void) {
+    return sizeof(struct layout92);
+}
+
+void layout92_fill(struct layout92 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xfffffffffffffffcULL;
+    s->f1 = (short)0xffffffffffff8000ULL;
+}
+
+int layout92_check(struct layout92 const *s) {
+    if (s->f0 != (short)0xfffffffffffffffcULL) return 1;
+    if (s->f1 != (short)0xffffffffffff8000ULL) return 2;
+    return 0;
+}
+
+struct layout93 {
+    short f0: 12;
+    long long f1: 61;
+    __int128 f2: 115;
+    int f3: 8;
+    signed char f4: 6;
+    unsigned __int128 f5: 120;
+    unsigned char f6: 7;
+};
+
+size_t layout93_size(void) {
+    return sizeof(struct layout93);
+}
+
+void layout93_fill(struct layout93 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xfffffffffffff899ULL;
+    s->f1 = (long long)0xf000000000000000ULL;
+    s->f2 = (__int128)(((unsigned __int128)0x20aa3012fef0eULL << 64) | 0x31c22e89be0f29c7ULL);
+    s->f3 = (int)0x7fULL;
+    s->f4 = (signed char)0x1fULL;
+    s->f5 = (unsigned __int128)(((unsigned __int128)0xffffffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f6 = (unsigned char)0x14ULL;
+}
+
+int layout93_check(struct layout93 const *s) {
+    if (s->f0 != (short)0xfffffffffffff899ULL) return 1;
+    if (s->f1 != (long long)0xf000000000000000ULL) return 2;
+    if (s->f2 != (__int128)(((unsigned __int128)0x20aa3012fef0eULL << 64) | 0x31c22e89be0f29c7ULL)) return 3;
+    if (s->f3 != (int)0x7fULL) return 4;
+    if (s->f4 != (signed char)0x1fULL) return 5;
+    if (s->f5 != (unsigned __int128)(((unsigned __int128)0xffffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 6;
+    if (s->f6 != (unsigned char)0x14ULL) return 7;
+    return 0;
+}
+
+struct layout94 {
+    short f0: 12;
+    unsigned int f1: 24;
+    unsigned short f2: 5;
+};
+
+size_t layout94_size(void) {
+    return sizeof(struct layout94);
+}
+
+void layout94_fill(struct layout94 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0x70cULL;
+    s->f1 = (unsigned int)0xffffffULL;
+    s->f2 = (unsigned short)0x5ULL;
+}
+
+int layout94_check(struct layout94 const *s) {
+    if (s->f0 != (short)0x70cULL) return 1;
+    if (s->f1 != (unsigned int)0xffffffULL) return 2;
+    if (s->f2 != (unsigned short)0x5ULL) return 3;
+    return 0;
+}
+
+struct layout95 {
+    int f0: 25;
+    unsigned __int128 f1: 98;
+    unsigned __int128 f2: 66;
+    unsigned char f3: 1;
+    unsigned int f4: 25;
+    unsigned char f5: 5;
+    __int128 f6: 50;
+    unsigned int f7: 15;
+};
+
+size_t layout95_size(void) {
+    return sizeof(struct layout95);
+}
+
+void layout95_fill(struct layout95 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xffffffffff25c8eeULL;
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x3ffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f3 = (unsigned char)0x0ULL;
+    s->f4 = (unsigned int)0x1ffffffULL;
+    s->f5 = (unsigned char)0x16ULL;
+    s->f6 = (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xfffe000000000000ULL);
+    s->f7 = (unsigned int)0x0ULL;
+}
+
+int layout95_check(struct layout95 const *s) {
+    if (s->f0 != (int)0xffffffffff25c8eeULL) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x3ffffffffULL << 64) | 0xffffffffffffffffULL)) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 3;
+    if (s->f3 != (unsigned char)0x0ULL) return 4;
+    if (s->f4 != (unsigned int)0x1ffffffULL) return 5;
+    if (s->f5 != (unsigned char)0x16ULL) return 6;
+    if (s->f6 != (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xfffe000000000000ULL)) return 7;
+    if (s->f7 != (unsigned int)0x0ULL) return 8;
+    return 0;
+}
+
+struct layout96 {
+    unsigned short f0: 9;
+    unsigned int f1: 27;
+};
+
+size_t layout96_size(void) {
+    return sizeof(struct layout96);
+}
+
+void layout96_fill(struct layout96 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x0ULL;
+    s->f1 = (unsigned int)0x7ffffffULL;
+}
+
+int layout96_check(struct layout96 const *s) {
+    if (s->f0 != (unsigned short)0x0ULL) return 1;
+    if (s->f1 != (unsigned int)0x7ffffffULL) return 2;
+    return 0;
+}
+
+struct layout97 {
+    unsigned int f0: 18;
+    short f1: 14;
+    unsigned short f2: 12;
+};
+
+size_t layout97_size(void) {
+    return sizeof(struct layout97);
+}
+
+void layout97_fill(struct layout97 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned int)0x5394ULL;
+    s->f1 = (short)0xffffffffffffe3feULL;
+    s->f2 = (unsigned short)0xfffULL;
+}
+
+int layout97_check(struct layout97 const *s) {
+    if (s->f0 != (unsigned int)0x5394ULL) return 1;
+    if (s->f1 != (short)0xffffffffffffe3feULL) return 2;
+    if (s->f2 != (unsigned short)0xfffULL) return 3;
+    return 0;
+}
+
+struct layout98 {
+    unsigned char f0: 2;
+    _Bool f1: 1;
+};
+
+size_t layout98_size(void) {
+    return sizeof(struct layout98);
+}
+
+void layout98_fill(struct layout98 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x3ULL;
+    s->f1 = (_Bool)0x1ULL;
+}
+
+int layout98_check(struct layout98 const *s) {
+    if (s->f0 != (unsigned char)0x3ULL) return 1;
+    if (s->f1 != (_Bool)0x1ULL) return 2;
+    return 0;
+}
+
+struct layout99 {
+    _Bool f0: 1;
+    __int128 f1: 114;
+    unsigned long long f2: 35;
+    __int128 f3: 96;
+    unsigned long long f4: 56;
+};
+
+size_t layout99_size(void) {
+    return sizeof(struct layout99);
+}
+
+void layout99_fill(struct layout99 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x1ULL;
+    s->f1 = (__int128)(((unsigned __int128)0xfffe000000000000ULL << 64) | 0x0ULL);
+    s->f2 = (unsigned long long)0x50478d88aULL;
+    s->f3 = (__int128)(((unsigned __int128)0xffffffff80000000ULL << 64) | 0x0ULL);
+    s->f4 = (unsigned long long)0x0ULL;
+}
+
+int layout99_check(struct layout99 const *s) {
+    if (s->f0 != (_Bool)0x1ULL) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0xfffe000000000000ULL << 64) | 0x0ULL)) return 2;
+    if (s->f2 != (unsigned long long)0x50478d88aULL) return 3;
+    if (s->f3 != (__int128)(((unsigned __int128)0xffffffff80000000ULL << 64) | 0x0ULL)) return 4;
+    if (s->f4 != (unsigned long long)0x0ULL) return 5;
+    return 0;
+}
+
+struct layout100 {
+    signed char f0: 6;
+    short f1: 5;
+    unsigned short f2: 4;
+    int f3: 31;
+    signed char f4: 4;
+};
+
+size_t layout100_size(void) {
+    return sizeof(struct layout100);
+}
+
+void layout100_fill(struct layout100 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0x1fULL;
+    s->f1 = (short)0xfffffffffffffff0ULL;
+    s->f2 = (unsigned short)0xfULL;
+    s->f3 = (int)0xfffffffff0f3b3bfULL;
+    s->f4 = (signed char)0x7ULL;
+}
+
+int layout100_check(struct layout100 const *s) {
+    if (s->f0 != (signed char)0x1fULL) return 1;
+    if (s->f1 != (short)0xfffffffffffffff0ULL) return 2;
+    if (s->f2 != (unsigned short)0xfULL) return 3;
+    if (s->f3 != (int)0xfffffffff0f3b3bfULL) return 4;
+    if (s->f4 != (signed char)0x7ULL) return 5;
+    return 0;
+}
+
+struct layout101 {
+    int f0: 4;
+    unsigned char f1: 8;
+    unsigned __int128 f2: 46;
+    __int128 f3: 112;
+};
+
+size_t layout101_size(void) {
+    return sizeof(struct layout101);
+}
+
+void layout101_fill(struct layout101 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xfffffffffffffff8ULL;
+    s->f1 = (unsigned char)0xffULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x3fffffffffffULL);
+    s->f3 = (__int128)(((unsigned __int128)0xffffae9c49540f40ULL << 64) | 0x53864ee73dd9f446ULL);
+}
+
+int layout101_check(struct layout101 const *s) {
+    if (s->f0 != (int)0xfffffffffffffff8ULL) return 1;
+    if (s->f1 != (unsigned char)0xffULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x3fffffffffffULL)) return 3;
+    if (s->f3 != (__int128)(((unsigned __int128)0xffffae9c49540f40ULL << 64) | 0x53864ee73dd9f446ULL)) return 4;
+    return 0;
+}
+
+struct layout102 {
+    unsigned short f0: 10;
+    long long f1: 8;
+};
+
+size_t layout102_size(void) {
+    return sizeof(struct layout102);
+}
+
+void layout102_fill(struct layout102 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x3ffULL;
+    s->f1 = (long long)0x39ULL;
+}
+
+int layout102_check(struct layout102 const *s) {
+    if (s->f0 != (unsigned short)0x3ffULL) return 1;
+    if (s->f1 != (long long)0x39ULL) return 2;
+    return 0;
+}
+
+struct layout103 {
+    unsigned int f0: 19;
+    long long f1: 35;
+    _Bool f2: 1;
+    unsigned long long f3: 8;
+};
+
+size_t layout103_size(void) {
+    return sizeof(struct layout103);
+}
+
+void layout103_fill(struct layout103 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned int)0x7ffffULL;
+    s->f1 = (long long)0xfffffffc00000000ULL;
+    s->f2 = (_Bool)0x0ULL;
+    s->f3 = (unsigned long long)0xffULL;
+}
+
+int layout103_check(struct layout103 const *s) {
+    if (s->f0 != (unsigned int)0x7ffffULL) return 1;
+    if (s->f1 != (long long)0xfffffffc00000000ULL) return 2;
+    if (s->f2 != (_Bool)0x0ULL) return 3;
+    if (s->f3 != (unsigned long long)0xffULL) return 4;
+    return 0;
+}
+
+struct layout104 {
+    int f0: 17;
+    unsigned long long f1: 19;
+};
+
+size_t layout104_size(void) {
+    return sizeof(struct layout104);
+}
+
+void layout104_fill(struct layout104 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0x95bfULL;
+    s->f1 = (unsigned long long)0x7ffffULL;
+}
+
+int layout104_check(struct layout104 const *s) {
+    if (s->f0 != (int)0x95bfULL) return 1;
+    if (s->f1 != (unsigned long long)0x7ffffULL) return 2;
+    return 0;
+}
+
+struct layout105 {
+    short f0: 11;
+    long long f1: 43;
+    unsigned char f2: 7;
+    unsigned short f3: 7;
+    _Bool f4: 1;
+};
+
+size_t layout105_size(void) {
+    return sizeof(struct layout105);
+}
+
+void layout105_fill(struct layout105 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xfffffffffffffc00ULL;
+    s->f1 = (long long)0xffffff8ed50f210eULL;
+    s->f2 = (unsigned char)0x7fULL;
+    s->f3 = (unsigned short)0x72ULL;
+    s->f4 = (_Bool)0x1ULL;
+}
+
+int layout105_check(struct layout105 const *s) {
+    if (s->f0 != (short)0xfffffffffffffc00ULL) return 1;
+    if (s->f1 != (long long)0xffffff8ed50f210eULL) return 2;
+    if (s->f2 != (unsigned char)0x7fULL) return 3;
+    if (s->f3 != (unsigned short)0x72ULL) return 4;
+    if (s->f4 != (_Bool)0x1ULL) return 5;
+    return 0;
+}
+
+struct layout106 {
+    __int128 f0: 125;
+    unsigned short f1: 8;
+    _Bool f2: 1;
+    signed char f3: 4;
+    int f4: 11;
+    int f5: 32;
+    unsigned __int128 f6: 66;
+};
+
+size_t layout106_size(void) {
+    return sizeof(struct layout106);
+}
+
+void layout106_fill(struct layout106 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xfffffffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f1 = (unsigned short)0x0ULL;
+    s->f2 = (_Bool)0x0ULL;
+    s->f3 = (signed char)0xfffffffffffffff8ULL;
+    s->f4 = (int)0xfffffffffffffc00ULL;
+    s->f5 = (int)0xffffffffe5cff4c7ULL;
+    s->f6 = (unsigned __int128)(((unsigned __int128)0x3ULL << 64) | 0xffffffffffffffffULL);
+}
+
+int layout106_check(struct layout106 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xfffffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 1;
+    if (s->f1 != (unsigned short)0x0ULL) return 2;
+    if (s->f2 != (_Bool)0x0ULL) return 3;
+    if (s->f3 != (signed char)0xfffffffffffffff8ULL) return 4;
+    if (s->f4 != (int)0xfffffffffffffc00ULL) return 5;
+    if (s->f5 != (int)0xffffffffe5cff4c7ULL) return 6;
+    if (s->f6 != (unsigned __int128)(((unsigned __int128)0x3ULL << 64) | 0xffffffffffffffffULL)) return 7;
+    return 0;
+}
+
+struct layout107 {
+    signed char f0: 2;
+    _Bool f1: 1;
+    unsigned long long f2: 44;
+    short f3: 15;
+    unsigned short f4: 4;
+    _Bool f5: 1;
+    long long f6: 46;
+};
+
+size_t layout107_size(void) {
+    return sizeof(struct layout107);
+}
+
+void layout107_fill(struct layout107 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0xfffffffffffffffeULL;
+    s->f1 = (_Bool)0x0ULL;
+    s->f2 = (unsigned long long)0xfffffffffffULL;
+    s->f3 = (short)0x3fffULL;
+    s->f4 = (unsigned short)0xfULL;
+    s->f5 = (_Bool)0x1ULL;
+    s->f6 = (long long)0xffffe00000000000ULL;
+}
+
+int layout107_check(struct layout107 const *s) {
+    if (s->f0 != (signed char)0xfffffffffffffffeULL) return 1;
+    if (s->f1 != (_Bool)0x0ULL) return 2;
+    if (s->f2 != (unsigned long long)0xfffffffffffULL) return 3;
+    if (s->f3 != (short)0x3fffULL) return 4;
+    if (s->f4 != (unsigned short)0xfULL) return 5;
+    if (s->f5 != (_Bool)0x1ULL) return 6;
+    if (s->f6 != (long long)0xffffe00000000000ULL) return 7;
+    return 0;
+}
+
+struct layout108 {
+    short f0: 4;
+    unsigned char f1: 6;
+    _Bool f2: 1;
+    unsigned char f3: 5;
+    unsigned long long f4: 61;
+    unsigned int f5: 17;
+    signed char f6: 5;
+};
+
+size_t layout108_size(void) {
+    return sizeof(struct layout108);
+}
+
+void layout108_fill(struct layout108 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0x7ULL;
+    s->f1 = (unsigned char)0x0ULL;
+    s->f2 = (_Bool)0x1ULL;
+    s->f3 = (unsigned char)0x0ULL;
+    s->f4 = (unsigned long long)0x0ULL;
+    s->f5 = (unsigned int)0x0ULL;
+    s->f6 = (signed char)0xfULL;
+}
+
+int layout108_check(struct layout108 const *s) {
+    if (s->f0 != (short)0x7ULL) return 1;
+    if (s->f1 != (unsigned char)0x0ULL) return 2;
+    if (s->f2 != (_Bool)0x1ULL) return 3;
+    if (s->f3 != (unsigned char)0x0ULL) return 4;
+    if (s->f4 != (unsigned long long)0x0ULL) return 5;
+    if (s->f5 != (unsigned int)0x0ULL) return 6;
+    if (s->f6 != (signed char)0xfULL) return 7;
+    return 0;
+}
+
+struct layout109 {
+    unsigned int f0: 24;
+    unsigned int f1: 5;
+    _Bool f2: 1;
+    unsigned __int128 f3: 41;
+    _Bool f4: 1;
+    __int128 f5: 53;
+};
+
+size_t layout109_size(void) {
+    return sizeof(struct layout109);
+}
+
+void layout109_fill(struct layout109 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned int)0xffffffULL;
+    s->f1 = (unsigned int)0x1eULL;
+    s->f2 = (_Bool)0x1ULL;
+    s->f3 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f4 = (_Bool)0x0ULL;
+    s->f5 = (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xfff0000000000000ULL);
+}
+
+int layout109_check(struct layout109 const *s) {
+    if (s->f0 != (unsigned int)0xffffffULL) return 1;
+    if (s->f1 != (unsigned int)0x1eULL) return 2;
+    if (s->f2 != (_Bool)0x1ULL) return 3;
+    if (s->f3 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 4;
+    if (s->f4 != (_Bool)0x0ULL) return 5;
+    if (s->f5 != (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xfff0000000000000ULL)) return 6;
+    return 0;
+}
+
+struct layout110 {
+    unsigned char f0: 8;
+};
+
+size_t layout110_size(void) {
+    return sizeof(struct layout110);
+}
+
+void layout110_fill(struct layout110 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0xffULL;
+}
+
+int layout110_check(struct layout110 const *s) {
+    if (s->f0 != (unsigned char)0xffULL) return 1;
+    return 0;
+}
+
+struct layout111 {
+    _Bool f0: 1;
+    int f1: 21;
+    int f2: 29;
+    unsigned char f3: 6;
+    unsigned char f4: 1;
+};
+
+size_t layout111_size(void) {
+    return sizeof(struct layout111);
+}
+
+void layout111_fill(struct layout111 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x1ULL;
+    s->f1 = (int)0x35a0aULL;
+    s->f2 = (int)0xfffffffULL;
+    s->f3 = (unsigned char)0x3eULL;
+    s->f4 = (unsigned char)0x0ULL;
+}
+
+int layout111_check(struct layout111 const *s) {
+    if (s->f0 != (_Bool)0x1ULL) return 1;
+    if (s->f1 != (int)0x35a0aULL) return 2;
+    if (s->f2 != (int)0xfffffffULL) return 3;
+    if (s->f3 != (unsigned char)0x3eULL) return 4;
+    if (s->f4 != (unsigned char)0x0ULL) return 5;
+    return 0;
+}
+
+struct layout112 {
+    short f0: 1;
+    signed char f1: 3;
+    unsigned __int128 f2: 41;
+    unsigned __int128 f3: 72;
+    unsigned __int128 f4: 112;
+    long long f5: 36;
+};
+
+size_t layout112_size(void) {
+    return sizeof(struct layout112);
+}
+
+void layout112_fill(struct layout112 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0x0ULL;
+    s->f1 = (signed char)0x3ULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f3 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f4 = (unsigned __int128)(((unsigned __int128)0xffffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f5 = (long long)0xfffffff910837b42ULL;
+}
+
+int layout112_check(struct layout112 const *s) {
+    if (s->f0 != (short)0x0ULL) return 1;
+    if (s->f1 != (signed char)0x3ULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 3;
+    if (s->f3 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 4;
+    if (s->f4 != (unsigned __int128)(((unsigned __int128)0xffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 5;
+    if (s->f5 != (long long)0xfffffff910837b42ULL) return 6;
+    return 0;
+}
+
+struct layout113 {
+    unsigned short f0: 16;
+    _Bool f1: 1;
+};
+
+size_t layout113_size(void) {
+    return sizeof(struct layout113);
+}
+
+void layout113_fill(struct layout113 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x4107ULL;
+    s->f1 = (_Bool)0x0ULL;
+}
+
+int layout113_check(struct layout113 const *s) {
+    if (s->f0 != (unsigned short)0x4107ULL) return 1;
+    if (s->f1 != (_Bool)0x0ULL) return 2;
+    return 0;
+}
+
+struct layout114 {
+    _Bool f0: 1;
+    unsigned __int128 f1: 12;
+    unsigned short f2: 10;
+};
+
+size_t layout114_size(void) {
+    return sizeof(struct layout114);
+}
+
+void layout114_fill(struct layout114 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (_Bool)0x1ULL;
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0xffdULL);
+    s->f2 = (unsigned short)0x23eULL;
+}
+
+int layout114_check(struct layout114 const *s) {
+    if (s->f0 != (_Bool)0x1ULL) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0xffdULL)) return 2;
+    if (s->f2 != (unsigned short)0x23eULL) return 3;
+    return 0;
+}
+
+struct layout115 {
+    short f0: 9;
+    __int128 f1: 86;
+    unsigned long long f2: 8;
+    signed char f3: 6;
+    long long f4: 9;
+    short f5: 5;
+};
+
+size_t layout115_size(void) {
+    return sizeof(struct layout115);
+}
+
+void layout115_fill(struct layout115 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (short)0xffULL;
+    s->f1 = (__int128)(((unsigned __int128)0xffffffffffe907afULL << 64) | 0x96d4446e4c3f0346ULL);
+    s->f2 = (unsigned long long)0x14ULL;
+    s->f3 = (signed char)0x7ULL;
+    s->f4 = (long long)0xb1ULL;
+    s->f5 = (short)0x2ULL;
+}
+
+int layout115_check(struct layout115 const *s) {
+    if (s->f0 != (short)0xffULL) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0xffffffffffe907afULL << 64) | 0x96d4446e4c3f0346ULL)) return 2;
+    if (s->f2 != (unsigned long long)0x14ULL) return 3;
+    if (s->f3 != (signed char)0x7ULL) return 4;
+    if (s->f4 != (long long)0xb1ULL) return 5;
+    if (s->f5 != (short)0x2ULL) return 6;
+    return 0;
+}
+
+struct layout116 {
+    unsigned char f0: 3;
+};
+
+size_t layout116_size(void) {
+    return sizeof(struct layout116);
+}
+
+void layout116_fill(struct layout116 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x0ULL;
+}
+
+int layout116_check(struct layout116 const *s) {
+    if (s->f0 != (unsigned char)0x0ULL) return 1;
+    return 0;
+}
+
+struct layout117 {
+    int f0: 9;
+    short f1: 8;
+    unsigned short f2: 1;
+    int f3: 6;
+    unsigned int f4: 27;
+    __int128 f5: 42;
+    unsigned short f6: 13;
+};
+
+size_t layout117_size(void) {
+    return sizeof(struct layout117);
+}
+
+void layout117_fill(struct layout117 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xd8ULL;
+    s->f1 = (short)0xffffffffffffff80ULL;
+    s->f2 = (unsigned short)0x1ULL;
+    s->f3 = (int)0x1fULL;
+    s->f4 = (unsigned int)0x0ULL;
+    s->f5 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0x1ffffffffffULL);
+    s->f6 = (unsigned short)0x1fffULL;
+}
+
+int layout117_check(struct layout117 const *s) {
+    if (s->f0 != (int)0xd8ULL) return 1;
+    if (s->f1 != (short)0xffffffffffffff80ULL) return 2;
+    if (s->f2 != (unsigned short)0x1ULL) return 3;
+    if (s->f3 != (int)0x1fULL) return 4;
+    if (s->f4 != (unsigned int)0x0ULL) return 5;
+    if (s->f5 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0x1ffffffffffULL)) return 6;
+    if (s->f6 != (unsigned short)0x1fffULL) return 7;
+    return 0;
+}
+
+struct layout118 {
+    unsigned long long f0: 21;
+};
+
+size_t layout118_size(void) {
+    return sizeof(struct layout118);
+}
+
+void layout118_fill(struct layout118 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x122a27ULL;
+}
+
+int layout118_check(struct layout118 const *s) {
+    if (s->f0 != (unsigned long long)0x122a27ULL) return 1;
+    return 0;
+}
+
+struct layout119 {
+    unsigned char f0: 4;
+    long long f1: 56;
+    short f2: 12;
+    _Bool f3: 1;
+    signed char f4: 8;
+};
+
+size_t layout119_size(void) {
+    return sizeof(struct layout119);
+}
+
+void layout119_fill(struct layout119 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x0ULL;
+    s->f1 = (long long)0xff93d0b82be03e75ULL;
+    s->f2 = (short)0x2cULL;
+    s->f3 = (_Bool)0x0ULL;
+    s->f4 = (signed char)0xffffffffffffff80ULL;
+}
+
+int layout119_check(struct layout119 const *s) {
+    if (s->f0 != (unsigned char)0x0ULL) return 1;
+    if (s->f1 != (long long)0xff93d0b82be03e75ULL) return 2;
+    if (s->f2 != (short)0x2cULL) return 3;
+    if (s->f3 != (_Bool)0x0ULL) return 4;
+    if (s->f4 != (signed char)0xffffffffffffff80ULL) return 5;
+    return 0;
+}
+
+struct layout120 {
+    unsigned long long f0: 52;
+    short f1: 1;
+    long long f2: 44;
+    signed char f3: 4;
+    signed char f4: 6;
+    unsigned char f5: 3;
+};
+
+size_t layout120_size(void) {
+    return sizeof(struct layout120);
+}
+
+void layout120_fill(struct layout120 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned long long)0x960ddfbd1a78fULL;
+    s->f1 = (short)0x0ULL;
+    s->f2 = (long long)0x7ffffffffffULL;
+    s->f3 = (signed char)0x7ULL;
+    s->f4 = (signed char)0xffffffffffffffe0ULL;
+    s->f5 = (unsigned char)0x5ULL;
+}
+
+int layout120_check(struct layout120 const *s) {
+    if (s->f0 != (unsigned long long)0x960ddfbd1a78fULL) return 1;
+    if (s->f1 != (short)0x0ULL) return 2;
+    if (s->f2 != (long long)0x7ffffffffffULL) return 3;
+    if (s->f3 != (signed char)0x7ULL) return 4;
+    if (s->f4 != (signed char)0xffffffffffffffe0ULL) return 5;
+    if (s->f5 != (unsigned char)0x5ULL) return 6;
+    return 0;
+}
+
+struct layout121 {
+    signed char f0: 5;
+    unsigned __int128 f1: 93;
+    long long f2: 47;
+    long long f3: 56;
+    _Bool f4: 1;
+    int f5: 32;
+    signed char f6: 4;
+};
+
+size_t layout121_size(void) {
+    return sizeof(struct layout121);
+}
+
+void layout121_fill(struct layout121 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (signed char)0xfULL;
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x1f03b74bULL << 64) | 0xb3b2ccf9718cbfeaULL);
+    s->f2 = (long long)0x3fffffffffffULL;
+    s->f3 = (long long)0xff80000000000000ULL;
+    s->f4 = (_Bool)0x0ULL;
+    s->f5 = (int)0x74325c70ULL;
+    s->f6 = (signed char)0xfffffffffffffffcULL;
+}
+
+int layout121_check(struct layout121 const *s) {
+    if (s->f0 != (signed char)0xfULL) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x1f03b74bULL << 64) | 0xb3b2ccf9718cbfeaULL)) return 2;
+    if (s->f2 != (long long)0x3fffffffffffULL) return 3;
+    if (s->f3 != (long long)0xff80000000000000ULL) return 4;
+    if (s->f4 != (_Bool)0x0ULL) return 5;
+    if (s->f5 != (int)0x74325c70ULL) return 6;
+    if (s->f6 != (signed char)0xfffffffffffffffcULL) return 7;
+    return 0;
+}
+
+struct layout122 {
+    unsigned short f0: 4;
+    unsigned long long f1: 9;
+    unsigned __int128 f2: 22;
+    __int128 f3: 97;
+    unsigned char f4: 3;
+    unsigned __int128 f5: 51;
+    unsigned long long f6: 48;
+    __int128 f7: 118;
+};
+
+size_t layout122_size(void) {
+    return sizeof(struct layout122);
+}
+
+void layout122_fill(struct layout122 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x0ULL;
+    s->f1 = (unsigned long long)0x1ffULL;
+    s->f2 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x3fffffULL);
+    s->f3 = (__int128)(((unsigned __int128)0xffffffff00000000ULL << 64) | 0x0ULL);
+    s->f4 = (unsigned char)0x2ULL;
+    s->f5 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f6 = (unsigned long long)0xffffffffffffULL;
+    s->f7 = (__int128)(((unsigned __int128)0x1fffffffffffffULL << 64) | 0xffffffffffffffffULL);
+}
+
+int layout122_check(struct layout122 const *s) {
+    if (s->f0 != (unsigned short)0x0ULL) return 1;
+    if (s->f1 != (unsigned long long)0x1ffULL) return 2;
+    if (s->f2 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x3fffffULL)) return 3;
+    if (s->f3 != (__int128)(((unsigned __int128)0xffffffff00000000ULL << 64) | 0x0ULL)) return 4;
+    if (s->f4 != (unsigned char)0x2ULL) return 5;
+    if (s->f5 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 6;
+    if (s->f6 != (unsigned long long)0xffffffffffffULL) return 7;
+    if (s->f7 != (__int128)(((unsigned __int128)0x1fffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 8;
+    return 0;
+}
+
+struct layout123 {
+    int f0: 12;
+    __int128 f1: 126;
+    unsigned long long f2: 44;
+    _Bool f3: 1;
+};
+
+size_t layout123_size(void) {
+    return sizeof(struct layout123);
+}
+
+void layout123_fill(struct layout123 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (int)0xfffffffffffff909ULL;
+    s->f1 = (__int128)(((unsigned __int128)0x1fffffffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f2 = (unsigned long long)0xfffffffffffULL;
+    s->f3 = (_Bool)0x1ULL;
+}
+
+int layout123_check(struct layout123 const *s) {
+    if (s->f0 != (int)0xfffffffffffff909ULL) return 1;
+    if (s->f1 != (__int128)(((unsigned __int128)0x1fffffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 2;
+    if (s->f2 != (unsigned long long)0xfffffffffffULL) return 3;
+    if (s->f3 != (_Bool)0x1ULL) return 4;
+    return 0;
+}
+
+struct layout124 {
+    unsigned short f0: 1;
+    long long f1: 56;
+    short f2: 4;
+};
+
+size_t layout124_size(void) {
+    return sizeof(struct layout124);
+}
+
+void layout124_fill(struct layout124 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned short)0x1ULL;
+    s->f1 = (long long)0xff80000000000000ULL;
+    s->f2 = (short)0x7ULL;
+}
+
+int layout124_check(struct layout124 const *s) {
+    if (s->f0 != (unsigned short)0x1ULL) return 1;
+    if (s->f1 != (long long)0xff80000000000000ULL) return 2;
+    if (s->f2 != (short)0x7ULL) return 3;
+    return 0;
+}
+
+struct layout125 {
+    unsigned char f0: 1;
+    unsigned char f1: 1;
+};
+
+size_t layout125_size(void) {
+    return sizeof(struct layout125);
+}
+
+void layout125_fill(struct layout125 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned char)0x1ULL;
+    s->f1 = (unsigned char)0x1ULL;
+}
+
+int layout125_check(struct layout125 const *s) {
+    if (s->f0 != (unsigned char)0x1ULL) return 1;
+    if (s->f1 != (unsigned char)0x1ULL) return 2;
+    return 0;
+}
+
+struct layout126 {
+    unsigned __int128 f0: 69;
+    unsigned char f1: 3;
+    unsigned int f2: 31;
+    unsigned int f3: 23;
+    signed char f4: 3;
+    unsigned __int128 f5: 79;
+    unsigned long long f6: 22;
+};
+
+size_t layout126_size(void) {
+    return sizeof(struct layout126);
+}
+
+void layout126_fill(struct layout126 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f1 = (unsigned char)0x7ULL;
+    s->f2 = (unsigned int)0x2e21b0feULL;
+    s->f3 = (unsigned int)0x0ULL;
+    s->f4 = (signed char)0x3ULL;
+    s->f5 = (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL);
+    s->f6 = (unsigned long long)0x0ULL;
+}
+
+int layout126_check(struct layout126 const *s) {
+    if (s->f0 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 1;
+    if (s->f1 != (unsigned char)0x7ULL) return 2;
+    if (s->f2 != (unsigned int)0x2e21b0feULL) return 3;
+    if (s->f3 != (unsigned int)0x0ULL) return 4;
+    if (s->f4 != (signed char)0x3ULL) return 5;
+    if (s->f5 != (unsigned __int128)(((unsigned __int128)0x0ULL << 64) | 0x0ULL)) return 6;
+    if (s->f6 != (unsigned long long)0x0ULL) return 7;
+    return 0;
+}
+
+struct layout127 {
+    __int128 f0: 20;
+    unsigned __int128 f1: 113;
+    _Bool f2: 1;
+    signed char f3: 3;
+    unsigned short f4: 13;
+    __int128 f5: 40;
+    __int128 f6: 127;
+    unsigned int f7: 6;
+};
+
+size_t layout127_size(void) {
+    return sizeof(struct layout127);
+}
+
+void layout127_fill(struct layout127 *s) {
+    memset(s, 0, sizeof(*s));
+    s->f0 = (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xfffffffffffe92b0ULL);
+    s->f1 = (unsigned __int128)(((unsigned __int128)0x1ffffffffffffULL << 64) | 0xffffffffffffffffULL);
+    s->f2 = (_Bool)0x1ULL;
+    s->f3 = (signed char)0xfffffffffffffffcULL;
+    s->f4 = (unsigned short)0x1fffULL;
+    s->f5 = (__int128)(((unsigned __int128)0x0ULL << 64) | 0x7fffffffffULL);
+    s->f6 = (__int128)(((unsigned __int128)0xc000000000000000ULL << 64) | 0x0ULL);
+    s->f7 = (unsigned int)0x1ULL;
+}
+
+int layout127_check(struct layout127 const *s) {
+    if (s->f0 != (__int128)(((unsigned __int128)0xffffffffffffffffULL << 64) | 0xfffffffffffe92b0ULL)) return 1;
+    if (s->f1 != (unsigned __int128)(((unsigned __int128)0x1ffffffffffffULL << 64) | 0xffffffffffffffffULL)) return 2;
+    if (s->f2 != (_Bool)0x1ULL) return 3;
+    if (s->f3 != (signed char)0xfffffffffffffffcULL) return 4;
+    if (s->f4 != (unsigned short)0x1fffULL) return 5;
+    if (s->f5 != (__int128)(((unsigned __int128)0x0ULL << 64) | 0x7fffffffffULL)) return 6;
+    if (s->f6 != (__int128)(((unsigned __int128)0xc000000000000000ULL << 64) | 0x0ULL)) return 7;
+    if (s->f7 != (unsigned int)0x1ULL) return 8;
+    return 0;
+}
@@ -0,0 +1,2798 @@
+// Generated by gen_layouts.py, do not edit
+
+extern crate c2rust_bitfields;
+extern crate libc;
+
+use c2rust_bitfields::BitfieldStruct;
+use std::mem::size_of;
+
+#[link(name = "test")]
+extern "C" {}
+
+macro_rules! check_layout {
+    ($ty:ident, $size:ident, $fill:ident, $check:ident,
+     $($field:ident, $setter:ident = $value:expr;)+) => {
+        extern "C" {
+            fn $size() -> libc::size_t;
+            fn $fill(_: *mut $ty);
+            fn $check(_: *const $ty) -> libc::c_int;
+        }
+
+        unsafe {
+            assert_eq!(size_of::<$ty>(), $size());
+
+            let mut from_c: $ty = std::mem::zeroed();
+            $fill(&mut from_c);
+            $(assert_eq!(from_c.$field(), $value, stringify!($field));)+
+
+            let mut from_rust: $ty = std::mem::zeroed();
+            $(from_rust.$setter($value);)+
+            assert_eq!($check(&from_rust), 0);
+            assert_eq!(&from_rust.bits[..], &from_c.bits[..]);
+        }
+    };
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout0 {
+    #[bitfield(name = "f0", ty = "libc::c_longlong", bits = "0..=30")]
+    #[bitfield(name = "f1", ty = "i128", bits = "128..=237")]
+    #[bitfield(name = "f2", ty = "u128", bits = "256..=356")]
+    #[bitfield(name = "f3", ty = "u128", bits = "384..=444")]
+    bits: [u8; 64],
+}
+
+#[test]
+fn test_layout0() {
+    check_layout!(
+        Layout0, layout0_size, layout0_fill, layout0_check,
+        f0, set_f0 = 1073741823i64 as libc::c_longlong;
+        f1, set_f1 = 220150595953564364596551775905782i128;
+        f2, set_f2 = 1343317337867741034551423992913u128;
+        f3, set_f3 = 2305843009213693951u128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout1 {
+    #[bitfield(name = "f0", ty = "i32", bits = "0..=28")]
+    #[bitfield(name = "f1", ty = "libc::c_ushort", bits = "32..=46")]
+    #[bitfield(name = "f2", ty = "i128", bits = "47..=61")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout1() {
+    check_layout!(
+        Layout1, layout1_size, layout1_fill, layout1_check,
+        f0, set_f0 = 268435455i32;
+        f1, set_f1 = 32767u16 as libc::c_ushort;
+        f2, set_f2 = 16383i128;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout2 {
+    #[bitfield(name = "f0", ty = "libc::c_uchar", bits = "0..=2")]
+    #[bitfield(name = "f1", ty = "libc::c_short", bits = "3..=14")]
+    #[bitfield(name = "f2", ty = "u8", bits = "16..=19")]
+    #[bitfield(name = "f3", ty = "libc::c_uchar", bits = "20..=23")]
+    #[bitfield(name = "f4", ty = "i8", bits = "24..=28")]
+    #[bitfield(name = "f5", ty = "libc::c_longlong", bits = "29..=58")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout2() {
+    check_layout!(
+        Layout2, layout2_size, layout2_fill, layout2_check,
+        f0, set_f0 = 5u8 as libc::c_uchar;
+        f1, set_f1 = 2047i16 as libc::c_short;
+        f2, set_f2 = 0u8;
+        f3, set_f3 = 15u8 as libc::c_uchar;
+        f4, set_f4 = -16i8;
+        f5, set_f5 = -536870912i64 as libc::c_longlong;
+    );
+}
+
+#[repr(C, align(2))]
+#[derive(BitfieldStruct)]
+struct Layout3 {
+    #[bitfield(name = "f0", ty = "libc::c_ushort", bits = "0..=3")]
+    bits: [u8; 2],
+}
+
+#[test]
+fn test_layout3() {
+    check_layout!(
+        Layout3, layout3_size, layout3_fill, layout3_check,
+        f0, set_f0 = 15u16 as libc::c_ushort;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout4 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=89")]
+    #[bitfield(name = "f1", ty = "u64", bits = "128..=183")]
+    #[bitfield(name = "f2", ty = "u128", bits = "256..=355")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout4() {
+    check_layout!(
+        Layout4, layout4_size, layout4_fill, layout4_check,
+        f0, set_f0 = 1188050899918341014245584750u128;
+        f1, set_f1 = 0u64;
+        f2, set_f2 = 0u128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout5 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=8")]
+    #[bitfield(name = "f1", ty = "u64", bits = "64..=121")]
+    #[bitfield(name = "f2", ty = "u128", bits = "128..=236")]
+    #[bitfield(name = "f3", ty = "libc::c_longlong", bits = "237..=244")]
+    #[bitfield(name = "f4", ty = "u128", bits = "256..=339")]
+    #[bitfield(name = "f5", ty = "libc::c_uchar", bits = "344..=348")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout5() {
+    check_layout!(
+        Layout5, layout5_size, layout5_fill, layout5_check,
+        f0, set_f0 = 0u16;
+        f1, set_f1 = 0u64;
+        f2, set_f2 = 649037107316853453566312041152511u128;
+        f3, set_f3 = -37i64 as libc::c_longlong;
+        f4, set_f4 = 19342813113834066795298815u128;
+        f5, set_f5 = 0u8 as libc::c_uchar;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout6 {
+    #[bitfield(name = "f0", ty = "libc::c_schar", bits = "0..=7")]
+    #[bitfield(name = "f1", ty = "u32", bits = "32..=63")]
+    #[bitfield(name = "f2", ty = "i128", bits = "64..=125")]
+    #[bitfield(name = "f3", ty = "libc::c_short", bits = "126..=126")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout6() {
+    check_layout!(
+        Layout6, layout6_size, layout6_fill, layout6_check,
+        f0, set_f0 = -86i8 as libc::c_schar;
+        f1, set_f1 = 4294967295u32;
+        f2, set_f2 = -2305843009213693952i128;
+        f3, set_f3 = 0i16 as libc::c_short;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout7 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=97")]
+    #[bitfield(name = "f1", ty = "bool", bits = "98..=98")]
+    #[bitfield(name = "f2", ty = "u8", bits = "99..=100")]
+    #[bitfield(name = "f3", ty = "libc::c_int", bits = "101..=124")]
+    #[bitfield(name = "f4", ty = "i128", bits = "128..=243")]
+    #[bitfield(name = "f5", ty = "u128", bits = "256..=356")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout7() {
+    check_layout!(
+        Layout7, layout7_size, layout7_fill, layout7_check,
+        f0, set_f0 = -56403269252839200639090561309i128;
+        f1, set_f1 = false;
+        f2, set_f2 = 1u8;
+        f3, set_f3 = 8388607i32 as libc::c_int;
+        f4, set_f4 = -41538374868278621028243970633760768i128;
+        f5, set_f5 = 2535301200456458802993406410751u128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout8 {
+    #[bitfield(name = "f0", ty = "libc::c_uint", bits = "0..=15")]
+    #[bitfield(name = "f1", ty = "i16", bits = "16..=24")]
+    #[bitfield(name = "f2", ty = "i128", bits = "25..=83")]
+    #[bitfield(name = "f3", ty = "u64", bits = "84..=86")]
+    #[bitfield(name = "f4", ty = "i32", bits = "96..=126")]
+    #[bitfield(name = "f5", ty = "u128", bits = "128..=152")]
+    #[bitfield(name = "f6", ty = "libc::c_schar", bits = "160..=167")]
+    #[bitfield(name = "f7", ty = "u8", bits = "168..=174")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout8() {
+    check_layout!(
+        Layout8, layout8_size, layout8_fill, layout8_check,
+        f0, set_f0 = 0u32 as libc::c_uint;
+        f1, set_f1 = 255i16;
+        f2, set_f2 = 288230376151711743i128;
+        f3, set_f3 = 7u64;
+        f4, set_f4 = -1073741824i32;
+        f5, set_f5 = 17775387u128;
+        f6, set_f6 = -112i8 as libc::c_schar;
+        f7, set_f7 = 96u8;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout9 {
+    #[bitfield(name = "f0", ty = "libc::c_longlong", bits = "0..=32")]
+    #[bitfield(name = "f1", ty = "bool", bits = "33..=33")]
+    #[bitfield(name = "f2", ty = "u64", bits = "34..=36")]
+    #[bitfield(name = "f3", ty = "i8", bits = "37..=37")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout9() {
+    check_layout!(
+        Layout9, layout9_size, layout9_fill, layout9_check,
+        f0, set_f0 = -4294967296i64 as libc::c_longlong;
+        f1, set_f1 = true;
+        f2, set_f2 = 0u64;
+        f3, set_f3 = -1i8;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout10 {
+    #[bitfield(name = "f0", ty = "libc::c_ulonglong", bits = "0..=19")]
+    #[bitfield(name = "f1", ty = "libc::c_ushort", bits = "20..=28")]
+    #[bitfield(name = "f2", ty = "libc::c_ushort", bits = "32..=44")]
+    #[bitfield(name = "f3", ty = "i32", bits = "45..=63")]
+    #[bitfield(name = "f4", ty = "u8", bits = "64..=71")]
+    #[bitfield(name = "f5", ty = "bool", bits = "72..=72")]
+    #[bitfield(name = "f6", ty = "u32", bits = "73..=76")]
+    #[bitfield(name = "f7", ty = "bool", bits = "77..=77")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout10() {
+    check_layout!(
+        Layout10, layout10_size, layout10_fill, layout10_check,
+        f0, set_f0 = 395098u64 as libc::c_ulonglong;
+        f1, set_f1 = 511u16 as libc::c_ushort;
+        f2, set_f2 = 8191u16 as libc::c_ushort;
+        f3, set_f3 = 262143i32;
+        f4, set_f4 = 154u8;
+        f5, set_f5 = true;
+        f6, set_f6 = 15u32;
+        f7, set_f7 = true;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout11 {
+    #[bitfield(name = "f0", ty = "libc::c_ushort", bits = "0..=7")]
+    #[bitfield(name = "f1", ty = "u32", bits = "8..=25")]
+    #[bitfield(name = "f2", ty = "u32", bits = "32..=58")]
+    #[bitfield(name = "f3", ty = "libc::c_short", bits = "59..=60")]
+    #[bitfield(name = "f4", ty = "u16", bits = "64..=74")]
+    bits: [u8; 12],
+}
+
+#[test]
+fn test_layout11() {
+    check_layout!(
+        Layout11, layout11_size, layout11_fill, layout11_check,
+        f0, set_f0 = 255u16 as libc::c_ushort;
+        f1, set_f1 = 39706u32;
+        f2, set_f2 = 134217727u32;
+        f3, set_f3 = -2i16 as libc::c_short;
+        f4, set_f4 = 0u16;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout12 {
+    #[bitfield(name = "f0", ty = "u64", bits = "0..=7")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout12() {
+    check_layout!(
+        Layout12, layout12_size, layout12_fill, layout12_check,
+        f0, set_f0 = 255u64;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout13 {
+    #[bitfield(name = "f0", ty = "u8", bits = "0..=7")]
+    #[bitfield(name = "f1", ty = "u128", bits = "8..=92")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout13() {
+    check_layout!(
+        Layout13, layout13_size, layout13_fill, layout13_check,
+        f0, set_f0 = 255u8;
+        f1, set_f1 = 22400031095443361848382931u128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout14 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "u64", bits = "1..=16")]
+    #[bitfield(name = "f2", ty = "libc::c_uint", bits = "17..=17")]
+    #[bitfield(name = "f3", ty = "bool", bits = "18..=18")]
+    #[bitfield(name = "f4", ty = "libc::c_short", bits = "19..=19")]
+    #[bitfield(name = "f5", ty = "u128", bits = "20..=126")]
+    #[bitfield(name = "f6", ty = "bool", bits = "127..=127")]
+    #[bitfield(name = "f7", ty = "u64", bits = "128..=174")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout14() {
+    check_layout!(
+        Layout14, layout14_size, layout14_fill, layout14_check,
+        f0, set_f0 = false;
+        f1, set_f1 = 25958u64;
+        f2, set_f2 = 1u32 as libc::c_uint;
+        f3, set_f3 = false;
+        f4, set_f4 = 0i16 as libc::c_short;
+        f5, set_f5 = 0u128;
+        f6, set_f6 = true;
+        f7, set_f7 = 0u64;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout15 {
+    #[bitfield(name = "f0", ty = "libc::c_short", bits = "0..=6")]
+    #[bitfield(name = "f1", ty = "u32", bits = "7..=7")]
+    #[bitfield(name = "f2", ty = "i64", bits = "8..=36")]
+    #[bitfield(name = "f3", ty = "bool", bits = "37..=37")]
+    #[bitfield(name = "f4", ty = "libc::c_ushort", bits = "38..=44")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout15() {
+    check_layout!(
+        Layout15, layout15_size, layout15_fill, layout15_check,
+        f0, set_f0 = -58i16 as libc::c_short;
+        f1, set_f1 = 0u32;
+        f2, set_f2 = -63554406i64;
+        f3, set_f3 = true;
+        f4, set_f4 = 70u16 as libc::c_ushort;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout16 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=34")]
+    #[bitfield(name = "f1", ty = "i128", bits = "35..=90")]
+    #[bitfield(name = "f2", ty = "libc::c_longlong", bits = "91..=122")]
+    #[bitfield(name = "f3", ty = "libc::c_uchar", bits = "123..=125")]
+    #[bitfield(name = "f4", ty = "u128", bits = "128..=195")]
+    #[bitfield(name = "f5", ty = "i128", bits = "196..=227")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout16() {
+    check_layout!(
+        Layout16, layout16_size, layout16_fill, layout16_check,
+        f0, set_f0 = -17179869184i128;
+        f1, set_f1 = -36028797018963968i128;
+        f2, set_f2 = 532129782i64 as libc::c_longlong;
+        f3, set_f3 = 7u8 as libc::c_uchar;
+        f4, set_f4 = 0u128;
+        f5, set_f5 = 2147483647i128;
+    );
+}
+
+#[repr(C, align(2))]
+#[derive(BitfieldStruct)]
+struct Layout17 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "u16", bits = "1..=10")]
+    #[bitfield(name = "f2", ty = "libc::c_short", bits = "11..=12")]
+    bits: [u8; 2],
+}
+
+#[test]
+fn test_layout17() {
+    check_layout!(
+        Layout17, layout17_size, layout17_fill, layout17_check,
+        f0, set_f0 = true;
+        f1, set_f1 = 272u16;
+        f2, set_f2 = 1i16 as libc::c_short;
+    );
+}
+
+#[repr(C, align(2))]
+#[derive(BitfieldStruct)]
+struct Layout18 {
+    #[bitfield(name = "f0", ty = "i16", bits = "0..=13")]
+    bits: [u8; 2],
+}
+
+#[test]
+fn test_layout18() {
+    check_layout!(
+        Layout18, layout18_size, layout18_fill, layout18_check,
+        f0, set_f0 = 8191i16;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout19 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=108")]
+    #[bitfield(name = "f1", ty = "libc::c_ushort", bits = "112..=122")]
+    #[bitfield(name = "f2", ty = "u32", bits = "128..=143")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout19() {
+    check_layout!(
+        Layout19, layout19_size, layout19_fill, layout19_check,
+        f0, set_f0 = 0u128;
+        f1, set_f1 = 2047u16 as libc::c_ushort;
+        f2, set_f2 = 0u32;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout20 {
+    #[bitfield(name = "f0", ty = "i32", bits = "0..=1")]
+    #[bitfield(name = "f1", ty = "u128", bits = "2..=69")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout20() {
+    check_layout!(
+        Layout20, layout20_size, layout20_fill, layout20_check,
+        f0, set_f0 = -2i32;
+        f1, set_f1 = 0u128;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout21 {
+    #[bitfield(name = "f0", ty = "libc::c_uchar", bits = "0..=1")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout21() {
+    check_layout!(
+        Layout21, layout21_size, layout21_fill, layout21_check,
+        f0, set_f0 = 3u8 as libc::c_uchar;
+    );
+}
+
+#[repr(C, align(2))]
+#[derive(BitfieldStruct)]
+struct Layout22 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "u16", bits = "1..=3")]
+    bits: [u8; 2],
+}
+
+#[test]
+fn test_layout22() {
+    check_layout!(
+        Layout22, layout22_size, layout22_fill, layout22_check,
+        f0, set_f0 = false;
+        f1, set_f1 = 7u16;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout23 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=2")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout23() {
+    check_layout!(
+        Layout23, layout23_size, layout23_fill, layout23_check,
+        f0, set_f0 = 3i32 as libc::c_int;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout24 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=76")]
+    #[bitfield(name = "f1", ty = "libc::c_uint", bits = "77..=79")]
+    #[bitfield(name = "f2", ty = "u128", bits = "80..=117")]
+    #[bitfield(name = "f3", ty = "bool", bits = "118..=118")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout24() {
+    check_layout!(
+        Layout24, layout24_size, layout24_fill, layout24_check,
+        f0, set_f0 = 75557863725914323419135i128;
+        f1, set_f1 = 7u32 as libc::c_uint;
+        f2, set_f2 = 0u128;
+        f3, set_f3 = true;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout25 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=96")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout25() {
+    check_layout!(
+        Layout25, layout25_size, layout25_fill, layout25_check,
+        f0, set_f0 = 158456325028528675187087900671u128;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout26 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=30")]
+    #[bitfield(name = "f1", ty = "i32", bits = "32..=57")]
+    #[bitfield(name = "f2", ty = "i64", bits = "64..=123")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout26() {
+    check_layout!(
+        Layout26, layout26_size, layout26_fill, layout26_check,
+        f0, set_f0 = -1073741824i32 as libc::c_int;
+        f1, set_f1 = 27804563i32;
+        f2, set_f2 = 576460752303423487i64;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout27 {
+    #[bitfield(name = "f0", ty = "libc::c_ulonglong", bits = "0..=53")]
+    #[bitfield(name = "f1", ty = "i128", bits = "128..=236")]
+    #[bitfield(name = "f2", ty = "libc::c_longlong", bits = "256..=292")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout27() {
+    check_layout!(
+        Layout27, layout27_size, layout27_fill, layout27_check,
+        f0, set_f0 = 18014398509481983u64 as libc::c_ulonglong;
+        f1, set_f1 = 324518553658426726783156020576255i128;
+        f2, set_f2 = 68719476735i64 as libc::c_longlong;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout28 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=118")]
+    #[bitfield(name = "f1", ty = "i64", bits = "119..=123")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout28() {
+    check_layout!(
+        Layout28, layout28_size, layout28_fill, layout28_check,
+        f0, set_f0 = 664613997892457936451903530140172287u128;
+        f1, set_f1 = -4i64;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout29 {
+    #[bitfield(name = "f0", ty = "i8", bits = "0..=3")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout29() {
+    check_layout!(
+        Layout29, layout29_size, layout29_fill, layout29_check,
+        f0, set_f0 = -8i8;
+    );
+}
+
+#[repr(C, align(2))]
+#[derive(BitfieldStruct)]
+struct Layout30 {
+    #[bitfield(name = "f0", ty = "libc::c_ushort", bits = "0..=3")]
+    bits: [u8; 2],
+}
+
+#[test]
+fn test_layout30() {
+    check_layout!(
+        Layout30, layout30_size, layout30_fill, layout30_check,
+        f0, set_f0 = 0u16 as libc::c_ushort;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout31 {
+    #[bitfield(name = "f0", ty = "i16", bits = "0..=10")]
+    #[bitfield(name = "f1", ty = "libc::c_uchar", bits = "16..=21")]
+    #[bitfield(name = "f2", ty = "libc::c_ushort", bits = "22..=25")]
+    #[bitfield(name = "f3", ty = "bool", bits = "26..=26")]
+    #[bitfield(name = "f4", ty = "u8", bits = "27..=29")]
+    #[bitfield(name = "f5", ty = "libc::c_uint", bits = "32..=57")]
+    #[bitfield(name = "f6", ty = "u64", bits = "64..=121")]
+    #[bitfield(name = "f7", ty = "libc::c_schar", bits = "122..=127")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout31() {
+    check_layout!(
+        Layout31, layout31_size, layout31_fill, layout31_check,
+        f0, set_f0 = -1024i16;
+        f1, set_f1 = 63u8 as libc::c_uchar;
+        f2, set_f2 = 1u16 as libc::c_ushort;
+        f3, set_f3 = false;
+        f4, set_f4 = 1u8;
+        f5, set_f5 = 0u32 as libc::c_uint;
+        f6, set_f6 = 288230376151711743u64;
+        f7, set_f7 = -1i8 as libc::c_schar;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout32 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "u32", bits = "1..=12")]
+    #[bitfield(name = "f2", ty = "i8", bits = "16..=21")]
+    #[bitfield(name = "f3", ty = "i128", bits = "22..=22")]
+    #[bitfield(name = "f4", ty = "libc::c_int", bits = "23..=26")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout32() {
+    check_layout!(
+        Layout32, layout32_size, layout32_fill, layout32_check,
+        f0, set_f0 = true;
+        f1, set_f1 = 0u32;
+        f2, set_f2 = 31i8;
+        f3, set_f3 = -1i128;
+        f4, set_f4 = 7i32 as libc::c_int;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout33 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=4")]
+    #[bitfield(name = "f1", ty = "libc::c_uchar", bits = "5..=6")]
+    #[bitfield(name = "f2", ty = "libc::c_uchar", bits = "8..=10")]
+    #[bitfield(name = "f3", ty = "libc::c_schar", bits = "16..=22")]
+    #[bitfield(name = "f4", ty = "u128", bits = "23..=62")]
+    #[bitfield(name = "f5", ty = "u128", bits = "128..=211")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout33() {
+    check_layout!(
+        Layout33, layout33_size, layout33_fill, layout33_check,
+        f0, set_f0 = 15i128;
+        f1, set_f1 = 0u8 as libc::c_uchar;
+        f2, set_f2 = 5u8 as libc::c_uchar;
+        f3, set_f3 = -42i8 as libc::c_schar;
+        f4, set_f4 = 1099511627775u128;
+        f5, set_f5 = 0u128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout34 {
+    #[bitfield(name = "f0", ty = "u64", bits = "0..=59")]
+    #[bitfield(name = "f1", ty = "i8", bits = "60..=62")]
+    #[bitfield(name = "f2", ty = "i128", bits = "63..=85")]
+    #[bitfield(name = "f3", ty = "libc::c_schar", bits = "88..=95")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout34() {
+    check_layout!(
+        Layout34, layout34_size, layout34_fill, layout34_check,
+        f0, set_f0 = 1152921504606846975u64;
+        f1, set_f1 = 3i8;
+        f2, set_f2 = 607626i128;
+        f3, set_f3 = -128i8 as libc::c_schar;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout35 {
+    #[bitfield(name = "f0", ty = "u8", bits = "0..=1")]
+    #[bitfield(name = "f1", ty = "libc::c_ulonglong", bits = "2..=35")]
+    #[bitfield(name = "f2", ty = "i128", bits = "128..=223")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout35() {
+    check_layout!(
+        Layout35, layout35_size, layout35_fill, layout35_check,
+        f0, set_f0 = 0u8;
+        f1, set_f1 = 10488305669u64 as libc::c_ulonglong;
+        f2, set_f2 = -39614081257132168796771975168i128;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout36 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout36() {
+    check_layout!(
+        Layout36, layout36_size, layout36_fill, layout36_check,
+        f0, set_f0 = false;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout37 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=29")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout37() {
+    check_layout!(
+        Layout37, layout37_size, layout37_fill, layout37_check,
+        f0, set_f0 = 518181739i32 as libc::c_int;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout38 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=5")]
+    #[bitfield(name = "f1", ty = "libc::c_ushort", bits = "16..=26")]
+    #[bitfield(name = "f2", ty = "u32", bits = "32..=53")]
+    #[bitfield(name = "f3", ty = "libc::c_uint", bits = "64..=94")]
+    bits: [u8; 12],
+}
+
+#[test]
+fn test_layout38() {
+    check_layout!(
+        Layout38, layout38_size, layout38_fill, layout38_check,
+        f0, set_f0 = 63u16;
+        f1, set_f1 = 1681u16 as libc::c_ushort;
+        f2, set_f2 = 4194303u32;
+        f3, set_f3 = 0u32 as libc::c_uint;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout39 {
+    #[bitfield(name = "f0", ty = "i16", bits = "0..=11")]
+    #[bitfield(name = "f1", ty = "u128", bits = "12..=119")]
+    #[bitfield(name = "f2", ty = "i128", bits = "128..=229")]
+    #[bitfield(name = "f3", ty = "libc::c_longlong", bits = "256..=304")]
+    #[bitfield(name = "f4", ty = "libc::c_uchar", bits = "305..=309")]
+    #[bitfield(name = "f5", ty = "libc::c_schar", bits = "312..=316")]
+    #[bitfield(name = "f6", ty = "u16", bits = "320..=328")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout39() {
+    check_layout!(
+        Layout39, layout39_size, layout39_fill, layout39_check,
+        f0, set_f0 = -2048i16;
+        f1, set_f1 = 188731175078773810471101676351217u128;
+        f2, set_f2 = -2535301200456458802993406410752i128;
+        f3, set_f3 = 24546825082037i64 as libc::c_longlong;
+        f4, set_f4 = 31u8 as libc::c_uchar;
+        f5, set_f5 = -6i8 as libc::c_schar;
+        f6, set_f6 = 511u16;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout40 {
+    #[bitfield(name = "f0", ty = "u64", bits = "0..=45")]
+    #[bitfield(name = "f1", ty = "libc::c_schar", bits = "48..=54")]
+    #[bitfield(name = "f2", ty = "i16", bits = "64..=78")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout40() {
+    check_layout!(
+        Layout40, layout40_size, layout40_fill, layout40_check,
+        f0, set_f0 = 6176433970107u64;
+        f1, set_f1 = -15i8 as libc::c_schar;
+        f2, set_f2 = 16383i16;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout41 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=113")]
+    #[bitfield(name = "f1", ty = "libc::c_int", bits = "128..=143")]
+    #[bitfield(name = "f2", ty = "u64", bits = "144..=181")]
+    #[bitfield(name = "f3", ty = "i64", bits = "192..=215")]
+    #[bitfield(name = "f4", ty = "i8", bits = "216..=219")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout41() {
+    check_layout!(
+        Layout41, layout41_size, layout41_fill, layout41_check,
+        f0, set_f0 = 0u128;
+        f1, set_f1 = 32767i32 as libc::c_int;
+        f2, set_f2 = 274877906943u64;
+        f3, set_f3 = -8388608i64;
+        f4, set_f4 = -3i8;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout42 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=18")]
+    #[bitfield(name = "f1", ty = "u8", bits = "19..=19")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout42() {
+    check_layout!(
+        Layout42, layout42_size, layout42_fill, layout42_check,
+        f0, set_f0 = 111472i32 as libc::c_int;
+        f1, set_f1 = 0u8;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout43 {
+    #[bitfield(name = "f0", ty = "libc::c_longlong", bits = "0..=51")]
+    #[bitfield(name = "f1", ty = "u64", bits = "52..=57")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout43() {
+    check_layout!(
+        Layout43, layout43_size, layout43_fill, layout43_check,
+        f0, set_f0 = -2251799813685248i64 as libc::c_longlong;
+        f1, set_f1 = 0u64;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout44 {
+    #[bitfield(name = "f0", ty = "i32", bits = "0..=28")]
+    #[bitfield(name = "f1", ty = "bool", bits = "29..=29")]
+    #[bitfield(name = "f2", ty = "bool", bits = "30..=30")]
+    #[bitfield(name = "f3", ty = "i32", bits = "32..=52")]
+    #[bitfield(name = "f4", ty = "libc::c_longlong", bits = "53..=63")]
+    #[bitfield(name = "f5", ty = "u8", bits = "64..=70")]
+    #[bitfield(name = "f6", ty = "libc::c_short", bits = "71..=75")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout44() {
+    check_layout!(
+        Layout44, layout44_size, layout44_fill, layout44_check,
+        f0, set_f0 = -268435456i32;
+        f1, set_f1 = true;
+        f2, set_f2 = false;
+        f3, set_f3 = 1048575i32;
+        f4, set_f4 = -1024i64 as libc::c_longlong;
+        f5, set_f5 = 22u8;
+        f6, set_f6 = 15i16 as libc::c_short;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout45 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=7")]
+    #[bitfield(name = "f1", ty = "i8", bits = "8..=10")]
+    #[bitfield(name = "f2", ty = "u8", bits = "16..=21")]
+    #[bitfield(name = "f3", ty = "libc::c_uchar", bits = "24..=26")]
+    #[bitfield(name = "f4", ty = "libc::c_int", bits = "32..=52")]
+    #[bitfield(name = "f5", ty = "bool", bits = "53..=53")]
+    #[bitfield(name = "f6", ty = "libc::c_ushort", bits = "54..=57")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout45() {
+    check_layout!(
+        Layout45, layout45_size, layout45_fill, layout45_check,
+        f0, set_f0 = 255u16;
+        f1, set_f1 = 3i8;
+        f2, set_f2 = 51u8;
+        f3, set_f3 = 6u8 as libc::c_uchar;
+        f4, set_f4 = -1048576i32 as libc::c_int;
+        f5, set_f5 = true;
+        f6, set_f6 = 0u16 as libc::c_ushort;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout46 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=70")]
+    #[bitfield(name = "f1", ty = "libc::c_schar", bits = "72..=77")]
+    #[bitfield(name = "f2", ty = "libc::c_longlong", bits = "78..=82")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout46() {
+    check_layout!(
+        Layout46, layout46_size, layout46_fill, layout46_check,
+        f0, set_f0 = 225386219536880741256u128;
+        f1, set_f1 = -32i8 as libc::c_schar;
+        f2, set_f2 = -9i64 as libc::c_longlong;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout47 {
+    #[bitfield(name = "f0", ty = "libc::c_longlong", bits = "0..=34")]
+    #[bitfield(name = "f1", ty = "libc::c_ulonglong", bits = "35..=37")]
+    #[bitfield(name = "f2", ty = "i8", bits = "38..=38")]
+    #[bitfield(name = "f3", ty = "u128", bits = "39..=80")]
+    #[bitfield(name = "f4", ty = "bool", bits = "81..=81")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout47() {
+    check_layout!(
+        Layout47, layout47_size, layout47_fill, layout47_check,
+        f0, set_f0 = -17179869184i64 as libc::c_longlong;
+        f1, set_f1 = 4u64 as libc::c_ulonglong;
+        f2, set_f2 = 0i8;
+        f3, set_f3 = 673886636288u128;
+        f4, set_f4 = true;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout48 {
+    #[bitfield(name = "f0", ty = "i32", bits = "0..=21")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout48() {
+    check_layout!(
+        Layout48, layout48_size, layout48_fill, layout48_check,
+        f0, set_f0 = -1094449i32;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout49 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "libc::c_uint", bits = "1..=19")]
+    #[bitfield(name = "f2", ty = "u8", bits = "20..=20")]
+    #[bitfield(name = "f3", ty = "u64", bits = "21..=41")]
+    #[bitfield(name = "f4", ty = "libc::c_int", bits = "42..=62")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout49() {
+    check_layout!(
+        Layout49, layout49_size, layout49_fill, layout49_check,
+        f0, set_f0 = true;
+        f1, set_f1 = 0u32 as libc::c_uint;
+        f2, set_f2 = 0u8;
+        f3, set_f3 = 2097151u64;
+        f4, set_f4 = 1048575i32 as libc::c_int;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout50 {
+    #[bitfield(name = "f0", ty = "libc::c_ulonglong", bits = "0..=63")]
+    #[bitfield(name = "f1", ty = "libc::c_uint", bits = "64..=90")]
+    #[bitfield(name = "f2", ty = "libc::c_short", bits = "91..=93")]
+    #[bitfield(name = "f3", ty = "u128", bits = "128..=242")]
+    #[bitfield(name = "f4", ty = "i32", bits = "243..=251")]
+    #[bitfield(name = "f5", ty = "bool", bits = "252..=252")]
+    #[bitfield(name = "f6", ty = "u16", bits = "256..=271")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout50() {
+    check_layout!(
+        Layout50, layout50_size, layout50_fill, layout50_check,
+        f0, set_f0 = 710985642967127617u64 as libc::c_ulonglong;
+        f1, set_f1 = 0u32 as libc::c_uint;
+        f2, set_f2 = -4i16 as libc::c_short;
+        f3, set_f3 = 0u128;
+        f4, set_f4 = 116i32;
+        f5, set_f5 = true;
+        f6, set_f6 = 0u16;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout51 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=12")]
+    #[bitfield(name = "f1", ty = "i8", bits = "13..=14")]
+    #[bitfield(name = "f2", ty = "u32", bits = "32..=49")]
+    #[bitfield(name = "f3", ty = "i16", bits = "50..=61")]
+    #[bitfield(name = "f4", ty = "u8", bits = "64..=71")]
+    bits: [u8; 12],
+}
+
+#[test]
+fn test_layout51() {
+    check_layout!(
+        Layout51, layout51_size, layout51_fill, layout51_check,
+        f0, set_f0 = 8191u16;
+        f1, set_f1 = -2i8;
+        f2, set_f2 = 221655u32;
+        f3, set_f3 = -1415i16;
+        f4, set_f4 = 255u8;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout52 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "i64", bits = "1..=41")]
+    #[bitfield(name = "f2", ty = "bool", bits = "42..=42")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout52() {
+    check_layout!(
+        Layout52, layout52_size, layout52_fill, layout52_check,
+        f0, set_f0 = false;
+        f1, set_f1 = 1099511627775i64;
+        f2, set_f2 = true;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout53 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=2")]
+    #[bitfield(name = "f1", ty = "u16", bits = "16..=30")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout53() {
+    check_layout!(
+        Layout53, layout53_size, layout53_fill, layout53_check,
+        f0, set_f0 = 3i32 as libc::c_int;
+        f1, set_f1 = 0u16;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout54 {
+    #[bitfield(name = "f0", ty = "i8", bits = "0..=5")]
+    #[bitfield(name = "f1", ty = "u64", bits = "6..=24")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout54() {
+    check_layout!(
+        Layout54, layout54_size, layout54_fill, layout54_check,
+        f0, set_f0 = 31i8;
+        f1, set_f1 = 173731u64;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout55 {
+    #[bitfield(name = "f0", ty = "libc::c_ulonglong", bits = "0..=2")]
+    #[bitfield(name = "f1", ty = "libc::c_ushort", bits = "3..=9")]
+    #[bitfield(name = "f2", ty = "u128", bits = "10..=64")]
+    #[bitfield(name = "f3", ty = "u128", bits = "128..=242")]
+    #[bitfield(name = "f4", ty = "libc::c_schar", bits = "248..=255")]
+    #[bitfield(name = "f5", ty = "u64", bits = "256..=264")]
+    #[bitfield(name = "f6", ty = "libc::c_uint", bits = "265..=287")]
+    #[bitfield(name = "f7", ty = "u8", bits = "288..=292")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout55() {
+    check_layout!(
+        Layout55, layout55_size, layout55_fill, layout55_check,
+        f0, set_f0 = 7u64 as libc::c_ulonglong;
+        f1, set_f1 = 82u16 as libc::c_ushort;
+        f2, set_f2 = 36028797018963967u128;
+        f3, set_f3 = 0u128;
+        f4, set_f4 = -57i8 as libc::c_schar;
+        f5, set_f5 = 511u64;
+        f6, set_f6 = 8388607u32 as libc::c_uint;
+        f7, set_f7 = 31u8;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout56 {
+    #[bitfield(name = "f0", ty = "i16", bits = "0..=8")]
+    #[bitfield(name = "f1", ty = "libc::c_uint", bits = "9..=22")]
+    #[bitfield(name = "f2", ty = "bool", bits = "23..=23")]
+    #[bitfield(name = "f3", ty = "bool", bits = "24..=24")]
+    #[bitfield(name = "f4", ty = "libc::c_short", bits = "32..=39")]
+    #[bitfield(name = "f5", ty = "libc::c_uint", bits = "64..=89")]
+    bits: [u8; 12],
+}
+
+#[test]
+fn test_layout56() {
+    check_layout!(
+        Layout56, layout56_size, layout56_fill, layout56_check,
+        f0, set_f0 = 255i16;
+        f1, set_f1 = 16383u32 as libc::c_uint;
+        f2, set_f2 = true;
+        f3, set_f3 = false;
+        f4, set_f4 = -128i16 as libc::c_short;
+        f5, set_f5 = 0u32 as libc::c_uint;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout57 {
+    #[bitfield(name = "f0", ty = "i64", bits = "0..=61")]
+    #[bitfield(name = "f1", ty = "libc::c_longlong", bits = "64..=81")]
+    #[bitfield(name = "f2", ty = "libc::c_ushort", bits = "82..=91")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout57() {
+    check_layout!(
+        Layout57, layout57_size, layout57_fill, layout57_check,
+        f0, set_f0 = 2305843009213693951i64;
+        f1, set_f1 = 131071i64 as libc::c_longlong;
+        f2, set_f2 = 0u16 as libc::c_ushort;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout58 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=20")]
+    #[bitfield(name = "f1", ty = "libc::c_longlong", bits = "21..=38")]
+    #[bitfield(name = "f2", ty = "i32", bits = "39..=59")]
+    #[bitfield(name = "f3", ty = "libc::c_int", bits = "64..=76")]
+    #[bitfield(name = "f4", ty = "u32", bits = "77..=89")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout58() {
+    check_layout!(
+        Layout58, layout58_size, layout58_fill, layout58_check,
+        f0, set_f0 = 1048575i32 as libc::c_int;
+        f1, set_f1 = 131071i64 as libc::c_longlong;
+        f2, set_f2 = 541603i32;
+        f3, set_f3 = 1024i32 as libc::c_int;
+        f4, set_f4 = 0u32;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout59 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=103")]
+    #[bitfield(name = "f1", ty = "u16", bits = "104..=108")]
+    #[bitfield(name = "f2", ty = "bool", bits = "109..=109")]
+    #[bitfield(name = "f3", ty = "libc::c_int", bits = "110..=124")]
+    #[bitfield(name = "f4", ty = "i128", bits = "128..=226")]
+    #[bitfield(name = "f5", ty = "libc::c_uint", bits = "227..=248")]
+    #[bitfield(name = "f6", ty = "libc::c_schar", bits = "249..=252")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout59() {
+    check_layout!(
+        Layout59, layout59_size, layout59_fill, layout59_check,
+        f0, set_f0 = -10141204801825835211973625643008i128;
+        f1, set_f1 = 31u16;
+        f2, set_f2 = true;
+        f3, set_f3 = 16383i32 as libc::c_int;
+        f4, set_f4 = -316912650057057350374175801344i128;
+        f5, set_f5 = 4194303u32 as libc::c_uint;
+        f6, set_f6 = -8i8 as libc::c_schar;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout60 {
+    #[bitfield(name = "f0", ty = "libc::c_longlong", bits = "0..=58")]
+    #[bitfield(name = "f1", ty = "i128", bits = "59..=107")]
+    #[bitfield(name = "f2", ty = "libc::c_uchar", bits = "108..=108")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout60() {
+    check_layout!(
+        Layout60, layout60_size, layout60_fill, layout60_check,
+        f0, set_f0 = 288230376151711743i64 as libc::c_longlong;
+        f1, set_f1 = 281474976710655i128;
+        f2, set_f2 = 0u8 as libc::c_uchar;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout61 {
+    #[bitfield(name = "f0", ty = "u8", bits = "0..=2")]
+    #[bitfield(name = "f1", ty = "libc::c_longlong", bits = "3..=28")]
+    #[bitfield(name = "f2", ty = "libc::c_uchar", bits = "32..=39")]
+    #[bitfield(name = "f3", ty = "u64", bits = "40..=50")]
+    #[bitfield(name = "f4", ty = "libc::c_uchar", bits = "56..=62")]
+    #[bitfield(name = "f5", ty = "u32", bits = "64..=75")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout61() {
+    check_layout!(
+        Layout61, layout61_size, layout61_fill, layout61_check,
+        f0, set_f0 = 7u8;
+        f1, set_f1 = 33554431i64 as libc::c_longlong;
+        f2, set_f2 = 178u8 as libc::c_uchar;
+        f3, set_f3 = 2047u64;
+        f4, set_f4 = 0u8 as libc::c_uchar;
+        f5, set_f5 = 0u32;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout62 {
+    #[bitfield(name = "f0", ty = "i32", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "u128", bits = "1..=87")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout62() {
+    check_layout!(
+        Layout62, layout62_size, layout62_fill, layout62_check,
+        f0, set_f0 = -1i32;
+        f1, set_f1 = 0u128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout63 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=83")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout63() {
+    check_layout!(
+        Layout63, layout63_size, layout63_fill, layout63_check,
+        f0, set_f0 = -2997740112633862811962944i128;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout64 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "libc::c_uint", bits = "1..=31")]
+    #[bitfield(name = "f2", ty = "libc::c_ushort", bits = "32..=35")]
+    #[bitfield(name = "f3", ty = "libc::c_ulonglong", bits = "64..=118")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout64() {
+    check_layout!(
+        Layout64, layout64_size, layout64_fill, layout64_check,
+        f0, set_f0 = true;
+        f1, set_f1 = 2147483647u32 as libc::c_uint;
+        f2, set_f2 = 0u16 as libc::c_ushort;
+        f3, set_f3 = 5026117017619202u64 as libc::c_ulonglong;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout65 {
+    #[bitfield(name = "f0", ty = "libc::c_short", bits = "0..=13")]
+    #[bitfield(name = "f1", ty = "libc::c_int", bits = "32..=57")]
+    #[bitfield(name = "f2", ty = "i64", bits = "64..=93")]
+    #[bitfield(name = "f3", ty = "u16", bits = "94..=94")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout65() {
+    check_layout!(
+        Layout65, layout65_size, layout65_fill, layout65_check,
+        f0, set_f0 = 8191i16 as libc::c_short;
+        f1, set_f1 = 16429309i32 as libc::c_int;
+        f2, set_f2 = -536870912i64;
+        f3, set_f3 = 0u16;
+    );
+}
+
+#[repr(C, align(2))]
+#[derive(BitfieldStruct)]
+struct Layout66 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=6")]
+    #[bitfield(name = "f1", ty = "i8", bits = "7..=7")]
+    bits: [u8; 2],
+}
+
+#[test]
+fn test_layout66() {
+    check_layout!(
+        Layout66, layout66_size, layout66_fill, layout66_check,
+        f0, set_f0 = 0u16;
+        f1, set_f1 = 0i8;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout67 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=23")]
+    #[bitfield(name = "f1", ty = "i32", bits = "24..=31")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout67() {
+    check_layout!(
+        Layout67, layout67_size, layout67_fill, layout67_check,
+        f0, set_f0 = 8388607i32 as libc::c_int;
+        f1, set_f1 = -97i32;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout68 {
+    #[bitfield(name = "f0", ty = "u64", bits = "0..=48")]
+    #[bitfield(name = "f1", ty = "i16", bits = "49..=55")]
+    #[bitfield(name = "f2", ty = "u128", bits = "56..=86")]
+    #[bitfield(name = "f3", ty = "u64", bits = "128..=172")]
+    #[bitfield(name = "f4", ty = "libc::c_uchar", bits = "176..=181")]
+    #[bitfield(name = "f5", ty = "libc::c_uchar", bits = "182..=183")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout68() {
+    check_layout!(
+        Layout68, layout68_size, layout68_fill, layout68_check,
+        f0, set_f0 = 0u64;
+        f1, set_f1 = 63i16;
+        f2, set_f2 = 2094065646u128;
+        f3, set_f3 = 0u64;
+        f4, set_f4 = 63u8 as libc::c_uchar;
+        f5, set_f5 = 2u8 as libc::c_uchar;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout69 {
+    #[bitfield(name = "f0", ty = "libc::c_ulonglong", bits = "0..=37")]
+    #[bitfield(name = "f1", ty = "i32", bits = "38..=59")]
+    #[bitfield(name = "f2", ty = "u16", bits = "64..=70")]
+    #[bitfield(name = "f3", ty = "i32", bits = "71..=75")]
+    #[bitfield(name = "f4", ty = "u16", bits = "80..=91")]
+    #[bitfield(name = "f5", ty = "u16", bits = "92..=95")]
+    #[bitfield(name = "f6", ty = "bool", bits = "96..=96")]
+    #[bitfield(name = "f7", ty = "libc::c_uint", bits = "97..=99")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout69() {
+    check_layout!(
+        Layout69, layout69_size, layout69_fill, layout69_check,
+        f0, set_f0 = 0u64 as libc::c_ulonglong;
+        f1, set_f1 = -2097152i32;
+        f2, set_f2 = 73u16;
+        f3, set_f3 = 15i32;
+        f4, set_f4 = 2061u16;
+        f5, set_f5 = 15u16;
+        f6, set_f6 = true;
+        f7, set_f7 = 5u32 as libc::c_uint;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout70 {
+    #[bitfield(name = "f0", ty = "i64", bits = "0..=33")]
+    #[bitfield(name = "f1", ty = "i8", bits = "40..=47")]
+    #[bitfield(name = "f2", ty = "i64", bits = "64..=114")]
+    #[bitfield(name = "f3", ty = "u64", bits = "128..=169")]
+    #[bitfield(name = "f4", ty = "i128", bits = "170..=186")]
+    #[bitfield(name = "f5", ty = "libc::c_uchar", bits = "187..=191")]
+    #[bitfield(name = "f6", ty = "i16", bits = "192..=204")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout70() {
+    check_layout!(
+        Layout70, layout70_size, layout70_fill, layout70_check,
+        f0, set_f0 = -8589934592i64;
+        f1, set_f1 = 79i8;
+        f2, set_f2 = 1125899906842623i64;
+        f3, set_f3 = 4398046511103u64;
+        f4, set_f4 = 65535i128;
+        f5, set_f5 = 0u8 as libc::c_uchar;
+        f6, set_f6 = -4096i16;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout71 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=2")]
+    #[bitfield(name = "f1", ty = "i8", bits = "3..=6")]
+    #[bitfield(name = "f2", ty = "u32", bits = "7..=31")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout71() {
+    check_layout!(
+        Layout71, layout71_size, layout71_fill, layout71_check,
+        f0, set_f0 = 0u16;
+        f1, set_f1 = 7i8;
+        f2, set_f2 = 0u32;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout72 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=68")]
+    #[bitfield(name = "f1", ty = "u128", bits = "69..=98")]
+    #[bitfield(name = "f2", ty = "libc::c_longlong", bits = "128..=167")]
+    #[bitfield(name = "f3", ty = "libc::c_uchar", bits = "168..=175")]
+    #[bitfield(name = "f4", ty = "libc::c_ushort", bits = "176..=187")]
+    #[bitfield(name = "f5", ty = "libc::c_schar", bits = "192..=199")]
+    #[bitfield(name = "f6", ty = "i128", bits = "256..=371")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout72() {
+    check_layout!(
+        Layout72, layout72_size, layout72_fill, layout72_check,
+        f0, set_f0 = 563316949466501833360u128;
+        f1, set_f1 = 0u128;
+        f2, set_f2 = 549755813887i64 as libc::c_longlong;
+        f3, set_f3 = 172u8 as libc::c_uchar;
+        f4, set_f4 = 4095u16 as libc::c_ushort;
+        f5, set_f5 = -38i8 as libc::c_schar;
+        f6, set_f6 = 41538374868278621028243970633760767i128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout73 {
+    #[bitfield(name = "f0", ty = "libc::c_ulonglong", bits = "0..=21")]
+    #[bitfield(name = "f1", ty = "i128", bits = "128..=243")]
+    #[bitfield(name = "f2", ty = "u64", bits = "244..=254")]
+    #[bitfield(name = "f3", ty = "libc::c_uchar", bits = "256..=263")]
+    #[bitfield(name = "f4", ty = "u16", bits = "264..=271")]
+    #[bitfield(name = "f5", ty = "i8", bits = "272..=278")]
+    #[bitfield(name = "f6", ty = "i8", bits = "279..=279")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout73() {
+    check_layout!(
+        Layout73, layout73_size, layout73_fill, layout73_check,
+        f0, set_f0 = 4194303u64 as libc::c_ulonglong;
+        f1, set_f1 = -41538374868278621028243970633760768i128;
+        f2, set_f2 = 0u64;
+        f3, set_f3 = 255u8 as libc::c_uchar;
+        f4, set_f4 = 0u16;
+        f5, set_f5 = 63i8;
+        f6, set_f6 = -1i8;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout74 {
+    #[bitfield(name = "f0", ty = "i64", bits = "0..=51")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout74() {
+    check_layout!(
+        Layout74, layout74_size, layout74_fill, layout74_check,
+        f0, set_f0 = -1324069929577012i64;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout75 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=70")]
+    #[bitfield(name = "f1", ty = "i64", bits = "71..=76")]
+    #[bitfield(name = "f2", ty = "libc::c_ushort", bits = "77..=77")]
+    #[bitfield(name = "f3", ty = "u64", bits = "78..=88")]
+    #[bitfield(name = "f4", ty = "i16", bits = "89..=93")]
+    #[bitfield(name = "f5", ty = "u8", bits = "96..=100")]
+    #[bitfield(name = "f6", ty = "libc::c_uchar", bits = "104..=108")]
+    #[bitfield(name = "f7", ty = "i8", bits = "109..=109")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout75() {
+    check_layout!(
+        Layout75, layout75_size, layout75_fill, layout75_check,
+        f0, set_f0 = 1916573958011986290686u128;
+        f1, set_f1 = -32i64;
+        f2, set_f2 = 1u16 as libc::c_ushort;
+        f3, set_f3 = 0u64;
+        f4, set_f4 = -9i16;
+        f5, set_f5 = 4u8;
+        f6, set_f6 = 31u8 as libc::c_uchar;
+        f7, set_f7 = -1i8;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout76 {
+    #[bitfield(name = "f0", ty = "i64", bits = "0..=28")]
+    #[bitfield(name = "f1", ty = "u64", bits = "29..=54")]
+    #[bitfield(name = "f2", ty = "libc::c_ushort", bits = "55..=58")]
+    #[bitfield(name = "f3", ty = "libc::c_schar", bits = "59..=62")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout76() {
+    check_layout!(
+        Layout76, layout76_size, layout76_fill, layout76_check,
+        f0, set_f0 = -268435456i64;
+        f1, set_f1 = 67108863u64;
+        f2, set_f2 = 0u16 as libc::c_ushort;
+        f3, set_f3 = 7i8 as libc::c_schar;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout77 {
+    #[bitfield(name = "f0", ty = "i8", bits = "0..=6")]
+    #[bitfield(name = "f1", ty = "i8", bits = "8..=14")]
+    #[bitfield(name = "f2", ty = "u128", bits = "15..=120")]
+    #[bitfield(name = "f3", ty = "i128", bits = "128..=253")]
+    #[bitfield(name = "f4", ty = "libc::c_ulonglong", bits = "254..=255")]
+    #[bitfield(name = "f5", ty = "u64", bits = "256..=310")]
+    #[bitfield(name = "f6", ty = "libc::c_uchar", bits = "312..=315")]
+    #[bitfield(name = "f7", ty = "u128", bits = "316..=353")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout77() {
+    check_layout!(
+        Layout77, layout77_size, layout77_fill, layout77_check,
+        f0, set_f0 = 61i8;
+        f1, set_f1 = 63i8;
+        f2, set_f2 = 32679661441114653660640074695013u128;
+        f3, set_f3 = -28662115423041173373913152979136831508i128;
+        f4, set_f4 = 3u64 as libc::c_ulonglong;
+        f5, set_f5 = 0u64;
+        f6, set_f6 = 3u8 as libc::c_uchar;
+        f7, set_f7 = 0u128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout78 {
+    #[bitfield(name = "f0", ty = "u32", bits = "0..=23")]
+    #[bitfield(name = "f1", ty = "libc::c_ulonglong", bits = "24..=36")]
+    #[bitfield(name = "f2", ty = "libc::c_int", bits = "37..=55")]
+    #[bitfield(name = "f3", ty = "libc::c_longlong", bits = "64..=91")]
+    #[bitfield(name = "f4", ty = "i128", bits = "128..=242")]
+    #[bitfield(name = "f5", ty = "i16", bits = "243..=247")]
+    #[bitfield(name = "f6", ty = "u32", bits = "256..=271")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout78() {
+    check_layout!(
+        Layout78, layout78_size, layout78_fill, layout78_check,
+        f0, set_f0 = 16777215u32;
+        f1, set_f1 = 8191u64 as libc::c_ulonglong;
+        f2, set_f2 = -262144i32 as libc::c_int;
+        f3, set_f3 = -134217728i64 as libc::c_longlong;
+        f4, set_f4 = -20769187434139310514121985316880384i128;
+        f5, set_f5 = 15i16;
+        f6, set_f6 = 65535u32;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout79 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "i8", bits = "1..=1")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout79() {
+    check_layout!(
+        Layout79, layout79_size, layout79_fill, layout79_check,
+        f0, set_f0 = true;
+        f1, set_f1 = 0i8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout80 {
+    #[bitfield(name = "f0", ty = "libc::c_ushort", bits = "0..=6")]
+    #[bitfield(name = "f1", ty = "u128", bits = "7..=106")]
+    #[bitfield(name = "f2", ty = "u16", bits = "107..=107")]
+    #[bitfield(name = "f3", ty = "i16", bits = "112..=127")]
+    #[bitfield(name = "f4", ty = "libc::c_int", bits = "128..=158")]
+    #[bitfield(name = "f5", ty = "libc::c_ulonglong", bits = "159..=188")]
+    #[bitfield(name = "f6", ty = "u64", bits = "189..=191")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout80() {
+    check_layout!(
+        Layout80, layout80_size, layout80_fill, layout80_check,
+        f0, set_f0 = 127u16 as libc::c_ushort;
+        f1, set_f1 = 1267650600228229401496703205375u128;
+        f2, set_f2 = 0u16;
+        f3, set_f3 = -1927i16;
+        f4, set_f4 = 1052193803i32 as libc::c_int;
+        f5, set_f5 = 0u64 as libc::c_ulonglong;
+        f6, set_f6 = 7u64;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout81 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=54")]
+    #[bitfield(name = "f1", ty = "u8", bits = "56..=63")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout81() {
+    check_layout!(
+        Layout81, layout81_size, layout81_fill, layout81_check,
+        f0, set_f0 = 0u128;
+        f1, set_f1 = 0u8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout82 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=67")]
+    #[bitfield(name = "f1", ty = "u32", bits = "68..=91")]
+    #[bitfield(name = "f2", ty = "i8", bits = "96..=102")]
+    #[bitfield(name = "f3", ty = "libc::c_schar", bits = "104..=110")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout82() {
+    check_layout!(
+        Layout82, layout82_size, layout82_fill, layout82_check,
+        f0, set_f0 = -147573952589676412928i128;
+        f1, set_f1 = 7290666u32;
+        f2, set_f2 = 63i8;
+        f3, set_f3 = 12i8 as libc::c_schar;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout83 {
+    #[bitfield(name = "f0", ty = "libc::c_short", bits = "0..=12")]
+    #[bitfield(name = "f1", ty = "u64", bits = "13..=52")]
+    #[bitfield(name = "f2", ty = "u64", bits = "64..=80")]
+    #[bitfield(name = "f3", ty = "libc::c_ushort", bits = "81..=93")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout83() {
+    check_layout!(
+        Layout83, layout83_size, layout83_fill, layout83_check,
+        f0, set_f0 = -1865i16 as libc::c_short;
+        f1, set_f1 = 0u64;
+        f2, set_f2 = 127798u64;
+        f3, set_f3 = 7643u16 as libc::c_ushort;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout84 {
+    #[bitfield(name = "f0", ty = "u8", bits = "0..=1")]
+    #[bitfield(name = "f1", ty = "libc::c_uchar", bits = "8..=14")]
+    #[bitfield(name = "f2", ty = "u128", bits = "128..=242")]
+    #[bitfield(name = "f3", ty = "i128", bits = "256..=273")]
+    #[bitfield(name = "f4", ty = "libc::c_uchar", bits = "280..=286")]
+    #[bitfield(name = "f5", ty = "bool", bits = "287..=287")]
+    #[bitfield(name = "f6", ty = "i64", bits = "320..=370")]
+    #[bitfield(name = "f7", ty = "i128", bits = "384..=501")]
+    bits: [u8; 64],
+}
+
+#[test]
+fn test_layout84() {
+    check_layout!(
+        Layout84, layout84_size, layout84_fill, layout84_check,
+        f0, set_f0 = 0u8;
+        f1, set_f1 = 0u8 as libc::c_uchar;
+        f2, set_f2 = 0u128;
+        f3, set_f3 = 15612i128;
+        f4, set_f4 = 0u8 as libc::c_uchar;
+        f5, set_f5 = false;
+        f6, set_f6 = -1125899906842624i64;
+        f7, set_f7 = 166153499473114484112975882535043071i128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout85 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=5")]
+    #[bitfield(name = "f1", ty = "libc::c_short", bits = "16..=28")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout85() {
+    check_layout!(
+        Layout85, layout85_size, layout85_fill, layout85_check,
+        f0, set_f0 = -32i128;
+        f1, set_f1 = 4095i16 as libc::c_short;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout86 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=9")]
+    #[bitfield(name = "f1", ty = "libc::c_longlong", bits = "10..=63")]
+    #[bitfield(name = "f2", ty = "libc::c_longlong", bits = "64..=120")]
+    #[bitfield(name = "f3", ty = "libc::c_ushort", bits = "121..=124")]
+    #[bitfield(name = "f4", ty = "u64", bits = "128..=167")]
+    #[bitfield(name = "f5", ty = "libc::c_short", bits = "176..=190")]
+    #[bitfield(name = "f6", ty = "i32", bits = "192..=199")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout86() {
+    check_layout!(
+        Layout86, layout86_size, layout86_fill, layout86_check,
+        f0, set_f0 = 319u16;
+        f1, set_f1 = 2312156351441946i64 as libc::c_longlong;
+        f2, set_f2 = -72057594037927936i64 as libc::c_longlong;
+        f3, set_f3 = 15u16 as libc::c_ushort;
+        f4, set_f4 = 1099511627775u64;
+        f5, set_f5 = -16384i16 as libc::c_short;
+        f6, set_f6 = 127i32;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout87 {
+    #[bitfield(name = "f0", ty = "u8", bits = "0..=6")]
+    #[bitfield(name = "f1", ty = "i128", bits = "7..=51")]
+    #[bitfield(name = "f2", ty = "libc::c_ulonglong", bits = "64..=111")]
+    #[bitfield(name = "f3", ty = "i16", bits = "112..=117")]
+    #[bitfield(name = "f4", ty = "bool", bits = "118..=118")]
+    #[bitfield(name = "f5", ty = "libc::c_schar", bits = "120..=127")]
+    #[bitfield(name = "f6", ty = "u32", bits = "128..=136")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout87() {
+    check_layout!(
+        Layout87, layout87_size, layout87_fill, layout87_check,
+        f0, set_f0 = 127u8;
+        f1, set_f1 = 17592186044415i128;
+        f2, set_f2 = 281474976710655u64 as libc::c_ulonglong;
+        f3, set_f3 = 31i16;
+        f4, set_f4 = false;
+        f5, set_f5 = -128i8 as libc::c_schar;
+        f6, set_f6 = 511u32;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout88 {
+    #[bitfield(name = "f0", ty = "libc::c_longlong", bits = "0..=49")]
+    #[bitfield(name = "f1", ty = "bool", bits = "50..=50")]
+    #[bitfield(name = "f2", ty = "libc::c_ushort", bits = "51..=53")]
+    #[bitfield(name = "f3", ty = "libc::c_ushort", bits = "54..=60")]
+    #[bitfield(name = "f4", ty = "bool", bits = "61..=61")]
+    #[bitfield(name = "f5", ty = "libc::c_ushort", bits = "64..=67")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout88() {
+    check_layout!(
+        Layout88, layout88_size, layout88_fill, layout88_check,
+        f0, set_f0 = 533845437616157i64 as libc::c_longlong;
+        f1, set_f1 = false;
+        f2, set_f2 = 4u16 as libc::c_ushort;
+        f3, set_f3 = 127u16 as libc::c_ushort;
+        f4, set_f4 = false;
+        f5, set_f5 = 0u16 as libc::c_ushort;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout89 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout89() {
+    check_layout!(
+        Layout89, layout89_size, layout89_fill, layout89_check,
+        f0, set_f0 = false;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout90 {
+    #[bitfield(name = "f0", ty = "i8", bits = "0..=6")]
+    #[bitfield(name = "f1", ty = "libc::c_ushort", bits = "16..=25")]
+    #[bitfield(name = "f2", ty = "u64", bits = "64..=116")]
+    #[bitfield(name = "f3", ty = "i8", bits = "117..=117")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout90() {
+    check_layout!(
+        Layout90, layout90_size, layout90_fill, layout90_check,
+        f0, set_f0 = -64i8;
+        f1, set_f1 = 1023u16 as libc::c_ushort;
+        f2, set_f2 = 6637990788676942u64;
+        f3, set_f3 = 0i8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout91 {
+    #[bitfield(name = "f0", ty = "i8", bits = "0..=1")]
+    #[bitfield(name = "f1", ty = "libc::c_ulonglong", bits = "2..=22")]
+    #[bitfield(name = "f2", ty = "i64", bits = "23..=35")]
+    #[bitfield(name = "f3", ty = "libc::c_short", bits = "36..=41")]
+    #[bitfield(name = "f4", ty = "libc::c_uint", bits = "42..=63")]
+    #[bitfield(name = "f5", ty = "libc::c_int", bits = "64..=66")]
+    #[bitfield(name = "f6", ty = "i128", bits = "67..=67")]
+    #[bitfield(name = "f7", ty = "libc::c_ulonglong", bits = "68..=111")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout91() {
+    check_layout!(
+        Layout91, layout91_size, layout91_fill, layout91_check,
+        f0, set_f0 = 1i8;
+        f1, set_f1 = 2097151u64 as libc::c_ulonglong;
+        f2, set_f2 = -4096i64;
+        f3, set_f3 = 17i16 as libc::c_short;
+        f4, set_f4 = 3405698u32 as libc::c_uint;
+        f5, set_f5 = -4i32 as libc::c_int;
+        f6, set_f6 = 0i128;
+        f7, set_f7 = 12640865764653u64 as libc::c_ulonglong;
+    );
+}
+
+#[repr(C, align(2))]
+#[derive(BitfieldStruct)]
+struct Layout92 {
+    #[bitfield(name = "f0", ty = "libc::c_short", bits = "0..=2")]
+    #[bitfield(name = "f1", ty = "libc::c_short", bits = "16..=31")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout92() {
+    check_layout!(
+        Layout92, layout92_size, layout92_fill, layout92_check,
+        f0, set_f0 = -4i16 as libc::c_short;
+        f1, set_f1 = -32768i16 as libc::c_short;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout93 {
+    #[bitfield(name = "f0", ty = "libc::c_short", bits = "0..=11")]
+    #[bitfield(name = "f1", ty = "libc::c_longlong", bits = "64..=124")]
+    #[bitfield(name = "f2", ty = "i128", bits = "128..=242")]
+    #[bitfield(name = "f3", ty = "i32", bits = "243..=250")]
+    #[bitfield(name = "f4", ty = "libc::c_schar", bits = "256..=261")]
+    #[bitfield(name = "f5", ty = "u128", bits = "262..=381")]
+    #[bitfield(name = "f6", ty = "u8", bits = "384..=390")]
+    bits: [u8; 64],
+}
+
+#[test]
+fn test_layout93() {
+    check_layout!(
+        Layout93, layout93_size, layout93_fill, layout93_check,
+        f0, set_f0 = -1895i16 as libc::c_short;
+        f1, set_f1 = -1152921504606846976i64 as libc::c_longlong;
+        f2, set_f2 = 10600332371029427820863152385370567i128;
+        f3, set_f3 = 127i32;
+        f4, set_f4 = 31i8 as libc::c_schar;
+        f5, set_f5 = 1329227995784915872903807060280344575u128;
+        f6, set_f6 = 20u8;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout94 {
+    #[bitfield(name = "f0", ty = "libc::c_short", bits = "0..=11")]
+    #[bitfield(name = "f1", ty = "libc::c_uint", bits = "32..=55")]
+    #[bitfield(name = "f2", ty = "u16", bits = "56..=60")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout94() {
+    check_layout!(
+        Layout94, layout94_size, layout94_fill, layout94_check,
+        f0, set_f0 = 1804i16 as libc::c_short;
+        f1, set_f1 = 16777215u32 as libc::c_uint;
+        f2, set_f2 = 5u16;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout95 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=24")]
+    #[bitfield(name = "f1", ty = "u128", bits = "25..=122")]
+    #[bitfield(name = "f2", ty = "u128", bits = "128..=193")]
+    #[bitfield(name = "f3", ty = "libc::c_uchar", bits = "194..=194")]
+    #[bitfield(name = "f4", ty = "u32", bits = "195..=219")]
+    #[bitfield(name = "f5", ty = "u8", bits = "224..=228")]
+    #[bitfield(name = "f6", ty = "i128", bits = "256..=305")]
+    #[bitfield(name = "f7", ty = "u32", bits = "320..=334")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout95() {
+    check_layout!(
+        Layout95, layout95_size, layout95_fill, layout95_check,
+        f0, set_f0 = -14300946i32 as libc::c_int;
+        f1, set_f1 = 316912650057057350374175801343u128;
+        f2, set_f2 = 0u128;
+        f3, set_f3 = 0u8 as libc::c_uchar;
+        f4, set_f4 = 33554431u32;
+        f5, set_f5 = 22u8;
+        f6, set_f6 = -562949953421312i128;
+        f7, set_f7 = 0u32;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout96 {
+    #[bitfield(name = "f0", ty = "libc::c_ushort", bits = "0..=8")]
+    #[bitfield(name = "f1", ty = "u32", bits = "32..=58")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout96() {
+    check_layout!(
+        Layout96, layout96_size, layout96_fill, layout96_check,
+        f0, set_f0 = 0u16 as libc::c_ushort;
+        f1, set_f1 = 134217727u32;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout97 {
+    #[bitfield(name = "f0", ty = "u32", bits = "0..=17")]
+    #[bitfield(name = "f1", ty = "libc::c_short", bits = "18..=31")]
+    #[bitfield(name = "f2", ty = "u16", bits = "32..=43")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout97() {
+    check_layout!(
+        Layout97, layout97_size, layout97_fill, layout97_check,
+        f0, set_f0 = 21396u32;
+        f1, set_f1 = -7170i16 as libc::c_short;
+        f2, set_f2 = 4095u16;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout98 {
+    #[bitfield(name = "f0", ty = "libc::c_uchar", bits = "0..=1")]
+    #[bitfield(name = "f1", ty = "bool", bits = "2..=2")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout98() {
+    check_layout!(
+        Layout98, layout98_size, layout98_fill, layout98_check,
+        f0, set_f0 = 3u8 as libc::c_uchar;
+        f1, set_f1 = true;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout99 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "i128", bits = "1..=114")]
+    #[bitfield(name = "f2", ty = "libc::c_ulonglong", bits = "128..=162")]
+    #[bitfield(name = "f3", ty = "i128", bits = "256..=351")]
+    #[bitfield(name = "f4", ty = "u64", bits = "384..=439")]
+    bits: [u8; 64],
+}
+
+#[test]
+fn test_layout99() {
+    check_layout!(
+        Layout99, layout99_size, layout99_fill, layout99_check,
+        f0, set_f0 = true;
+        f1, set_f1 = -10384593717069655257060992658440192i128;
+        f2, set_f2 = 21549865098u64 as libc::c_ulonglong;
+        f3, set_f3 = -39614081257132168796771975168i128;
+        f4, set_f4 = 0u64;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout100 {
+    #[bitfield(name = "f0", ty = "i8", bits = "0..=5")]
+    #[bitfield(name = "f1", ty = "libc::c_short", bits = "6..=10")]
+    #[bitfield(name = "f2", ty = "libc::c_ushort", bits = "11..=14")]
+    #[bitfield(name = "f3", ty = "i32", bits = "32..=62")]
+    #[bitfield(name = "f4", ty = "i8", bits = "64..=67")]
+    bits: [u8; 12],
+}
+
+#[test]
+fn test_layout100() {
+    check_layout!(
+        Layout100, layout100_size, layout100_fill, layout100_check,
+        f0, set_f0 = 31i8;
+        f1, set_f1 = -16i16 as libc::c_short;
+        f2, set_f2 = 15u16 as libc::c_ushort;
+        f3, set_f3 = -252464193i32;
+        f4, set_f4 = 7i8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout101 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=3")]
+    #[bitfield(name = "f1", ty = "libc::c_uchar", bits = "8..=15")]
+    #[bitfield(name = "f2", ty = "u128", bits = "16..=61")]
+    #[bitfield(name = "f3", ty = "i128", bits = "128..=239")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout101() {
+    check_layout!(
+        Layout101, layout101_size, layout101_fill, layout101_check,
+        f0, set_f0 = -8i32 as libc::c_int;
+        f1, set_f1 = 255u8 as libc::c_uchar;
+        f2, set_f2 = 70368744177663u128;
+        f3, set_f3 = -1650775300119703824831744966003642i128;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout102 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=9")]
+    #[bitfield(name = "f1", ty = "i64", bits = "10..=17")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout102() {
+    check_layout!(
+        Layout102, layout102_size, layout102_fill, layout102_check,
+        f0, set_f0 = 1023u16;
+        f1, set_f1 = 57i64;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout103 {
+    #[bitfield(name = "f0", ty = "u32", bits = "0..=18")]
+    #[bitfield(name = "f1", ty = "libc::c_longlong", bits = "19..=53")]
+    #[bitfield(name = "f2", ty = "bool", bits = "54..=54")]
+    #[bitfield(name = "f3", ty = "u64", bits = "55..=62")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout103() {
+    check_layout!(
+        Layout103, layout103_size, layout103_fill, layout103_check,
+        f0, set_f0 = 524287u32;
+        f1, set_f1 = -17179869184i64 as libc::c_longlong;
+        f2, set_f2 = false;
+        f3, set_f3 = 255u64;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout104 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=16")]
+    #[bitfield(name = "f1", ty = "u64", bits = "17..=35")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout104() {
+    check_layout!(
+        Layout104, layout104_size, layout104_fill, layout104_check,
+        f0, set_f0 = 38335i32 as libc::c_int;
+        f1, set_f1 = 524287u64;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout105 {
+    #[bitfield(name = "f0", ty = "i16", bits = "0..=10")]
+    #[bitfield(name = "f1", ty = "libc::c_longlong", bits = "11..=53")]
+    #[bitfield(name = "f2", ty = "libc::c_uchar", bits = "56..=62")]
+    #[bitfield(name = "f3", ty = "u16", bits = "64..=70")]
+    #[bitfield(name = "f4", ty = "bool", bits = "71..=71")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout105() {
+    check_layout!(
+        Layout105, layout105_size, layout105_fill, layout105_check,
+        f0, set_f0 = -1024i16;
+        f1, set_f1 = -486051733234i64 as libc::c_longlong;
+        f2, set_f2 = 127u8 as libc::c_uchar;
+        f3, set_f3 = 114u16;
+        f4, set_f4 = true;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout106 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=124")]
+    #[bitfield(name = "f1", ty = "u16", bits = "128..=135")]
+    #[bitfield(name = "f2", ty = "bool", bits = "136..=136")]
+    #[bitfield(name = "f3", ty = "libc::c_schar", bits = "137..=140")]
+    #[bitfield(name = "f4", ty = "i32", bits = "141..=151")]
+    #[bitfield(name = "f5", ty = "libc::c_int", bits = "160..=191")]
+    #[bitfield(name = "f6", ty = "u128", bits = "256..=321")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout106() {
+    check_layout!(
+        Layout106, layout106_size, layout106_fill, layout106_check,
+        f0, set_f0 = 21267647932558653966460912964485513215i128;
+        f1, set_f1 = 0u16;
+        f2, set_f2 = false;
+        f3, set_f3 = -8i8 as libc::c_schar;
+        f4, set_f4 = -1024i32;
+        f5, set_f5 = -439356217i32 as libc::c_int;
+        f6, set_f6 = 73786976294838206463u128;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout107 {
+    #[bitfield(name = "f0", ty = "i8", bits = "0..=1")]
+    #[bitfield(name = "f1", ty = "bool", bits = "2..=2")]
+    #[bitfield(name = "f2", ty = "u64", bits = "3..=46")]
+    #[bitfield(name = "f3", ty = "libc::c_short", bits = "48..=62")]
+    #[bitfield(name = "f4", ty = "libc::c_ushort", bits = "64..=67")]
+    #[bitfield(name = "f5", ty = "bool", bits = "68..=68")]
+    #[bitfield(name = "f6", ty = "libc::c_longlong", bits = "69..=114")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout107() {
+    check_layout!(
+        Layout107, layout107_size, layout107_fill, layout107_check,
+        f0, set_f0 = -2i8;
+        f1, set_f1 = false;
+        f2, set_f2 = 17592186044415u64;
+        f3, set_f3 = 16383i16 as libc::c_short;
+        f4, set_f4 = 15u16 as libc::c_ushort;
+        f5, set_f5 = true;
+        f6, set_f6 = -35184372088832i64 as libc::c_longlong;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout108 {
+    #[bitfield(name = "f0", ty = "libc::c_short", bits = "0..=3")]
+    #[bitfield(name = "f1", ty = "u8", bits = "8..=13")]
+    #[bitfield(name = "f2", ty = "bool", bits = "14..=14")]
+    #[bitfield(name = "f3", ty = "u8", bits = "16..=20")]
+    #[bitfield(name = "f4", ty = "libc::c_ulonglong", bits = "64..=124")]
+    #[bitfield(name = "f5", ty = "u32", bits = "128..=144")]
+    #[bitfield(name = "f6", ty = "libc::c_schar", bits = "145..=149")]
+    bits: [u8; 24],
+}
+
+#[test]
+fn test_layout108() {
+    check_layout!(
+        Layout108, layout108_size, layout108_fill, layout108_check,
+        f0, set_f0 = 7i16 as libc::c_short;
+        f1, set_f1 = 0u8;
+        f2, set_f2 = true;
+        f3, set_f3 = 0u8;
+        f4, set_f4 = 0u64 as libc::c_ulonglong;
+        f5, set_f5 = 0u32;
+        f6, set_f6 = 15i8 as libc::c_schar;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout109 {
+    #[bitfield(name = "f0", ty = "u32", bits = "0..=23")]
+    #[bitfield(name = "f1", ty = "u32", bits = "24..=28")]
+    #[bitfield(name = "f2", ty = "bool", bits = "29..=29")]
+    #[bitfield(name = "f3", ty = "u128", bits = "30..=70")]
+    #[bitfield(name = "f4", ty = "bool", bits = "71..=71")]
+    #[bitfield(name = "f5", ty = "i128", bits = "72..=124")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout109() {
+    check_layout!(
+        Layout109, layout109_size, layout109_fill, layout109_check,
+        f0, set_f0 = 16777215u32;
+        f1, set_f1 = 30u32;
+        f2, set_f2 = true;
+        f3, set_f3 = 0u128;
+        f4, set_f4 = false;
+        f5, set_f5 = -4503599627370496i128;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout110 {
+    #[bitfield(name = "f0", ty = "libc::c_uchar", bits = "0..=7")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout110() {
+    check_layout!(
+        Layout110, layout110_size, layout110_fill, layout110_check,
+        f0, set_f0 = 255u8 as libc::c_uchar;
+    );
+}
+
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Layout111 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "i32", bits = "1..=21")]
+    #[bitfield(name = "f2", ty = "i32", bits = "32..=60")]
+    #[bitfield(name = "f3", ty = "libc::c_uchar", bits = "64..=69")]
+    #[bitfield(name = "f4", ty = "u8", bits = "70..=70")]
+    bits: [u8; 12],
+}
+
+#[test]
+fn test_layout111() {
+    check_layout!(
+        Layout111, layout111_size, layout111_fill, layout111_check,
+        f0, set_f0 = true;
+        f1, set_f1 = 219658i32;
+        f2, set_f2 = 268435455i32;
+        f3, set_f3 = 62u8 as libc::c_uchar;
+        f4, set_f4 = 0u8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout112 {
+    #[bitfield(name = "f0", ty = "libc::c_short", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "i8", bits = "1..=3")]
+    #[bitfield(name = "f2", ty = "u128", bits = "4..=44")]
+    #[bitfield(name = "f3", ty = "u128", bits = "45..=116")]
+    #[bitfield(name = "f4", ty = "u128", bits = "128..=239")]
+    #[bitfield(name = "f5", ty = "libc::c_longlong", bits = "256..=291")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout112() {
+    check_layout!(
+        Layout112, layout112_size, layout112_fill, layout112_check,
+        f0, set_f0 = 0i16 as libc::c_short;
+        f1, set_f1 = 3i8;
+        f2, set_f2 = 0u128;
+        f3, set_f3 = 0u128;
+        f4, set_f4 = 5192296858534827628530496329220095u128;
+        f5, set_f5 = -29787718846i64 as libc::c_longlong;
+    );
+}
+
+#[repr(C, align(2))]
+#[derive(BitfieldStruct)]
+struct Layout113 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=15")]
+    #[bitfield(name = "f1", ty = "bool", bits = "16..=16")]
+    bits: [u8; 4],
+}
+
+#[test]
+fn test_layout113() {
+    check_layout!(
+        Layout113, layout113_size, layout113_fill, layout113_check,
+        f0, set_f0 = 16647u16;
+        f1, set_f1 = false;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout114 {
+    #[bitfield(name = "f0", ty = "bool", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "u128", bits = "1..=12")]
+    #[bitfield(name = "f2", ty = "u16", bits = "16..=25")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout114() {
+    check_layout!(
+        Layout114, layout114_size, layout114_fill, layout114_check,
+        f0, set_f0 = true;
+        f1, set_f1 = 4093u128;
+        f2, set_f2 = 574u16;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout115 {
+    #[bitfield(name = "f0", ty = "i16", bits = "0..=8")]
+    #[bitfield(name = "f1", ty = "i128", bits = "9..=94")]
+    #[bitfield(name = "f2", ty = "libc::c_ulonglong", bits = "95..=102")]
+    #[bitfield(name = "f3", ty = "i8", bits = "104..=109")]
+    #[bitfield(name = "f4", ty = "libc::c_longlong", bits = "110..=118")]
+    #[bitfield(name = "f5", ty = "libc::c_short", bits = "119..=123")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout115() {
+    check_layout!(
+        Layout115, layout115_size, layout115_fill, layout115_check,
+        f0, set_f0 = 255i16;
+        f1, set_f1 = -27768998237156443062074554i128;
+        f2, set_f2 = 20u64 as libc::c_ulonglong;
+        f3, set_f3 = 7i8;
+        f4, set_f4 = 177i64 as libc::c_longlong;
+        f5, set_f5 = 2i16 as libc::c_short;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout116 {
+    #[bitfield(name = "f0", ty = "u8", bits = "0..=2")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout116() {
+    check_layout!(
+        Layout116, layout116_size, layout116_fill, layout116_check,
+        f0, set_f0 = 0u8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout117 {
+    #[bitfield(name = "f0", ty = "libc::c_int", bits = "0..=8")]
+    #[bitfield(name = "f1", ty = "libc::c_short", bits = "16..=23")]
+    #[bitfield(name = "f2", ty = "u16", bits = "24..=24")]
+    #[bitfield(name = "f3", ty = "i32", bits = "25..=30")]
+    #[bitfield(name = "f4", ty = "libc::c_uint", bits = "32..=58")]
+    #[bitfield(name = "f5", ty = "i128", bits = "59..=100")]
+    #[bitfield(name = "f6", ty = "libc::c_ushort", bits = "112..=124")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout117() {
+    check_layout!(
+        Layout117, layout117_size, layout117_fill, layout117_check,
+        f0, set_f0 = 216i32 as libc::c_int;
+        f1, set_f1 = -128i16 as libc::c_short;
+        f2, set_f2 = 1u16;
+        f3, set_f3 = 31i32;
+        f4, set_f4 = 0u32 as libc::c_uint;
+        f5, set_f5 = 2199023255551i128;
+        f6, set_f6 = 8191u16 as libc::c_ushort;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout118 {
+    #[bitfield(name = "f0", ty = "libc::c_ulonglong", bits = "0..=20")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout118() {
+    check_layout!(
+        Layout118, layout118_size, layout118_fill, layout118_check,
+        f0, set_f0 = 1190439u64 as libc::c_ulonglong;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout119 {
+    #[bitfield(name = "f0", ty = "libc::c_uchar", bits = "0..=3")]
+    #[bitfield(name = "f1", ty = "i64", bits = "4..=59")]
+    #[bitfield(name = "f2", ty = "libc::c_short", bits = "64..=75")]
+    #[bitfield(name = "f3", ty = "bool", bits = "76..=76")]
+    #[bitfield(name = "f4", ty = "i8", bits = "80..=87")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout119() {
+    check_layout!(
+        Layout119, layout119_size, layout119_fill, layout119_check,
+        f0, set_f0 = 0u8 as libc::c_uchar;
+        f1, set_f1 = -30451283032785291i64;
+        f2, set_f2 = 44i16 as libc::c_short;
+        f3, set_f3 = false;
+        f4, set_f4 = -128i8;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout120 {
+    #[bitfield(name = "f0", ty = "libc::c_ulonglong", bits = "0..=51")]
+    #[bitfield(name = "f1", ty = "libc::c_short", bits = "52..=52")]
+    #[bitfield(name = "f2", ty = "libc::c_longlong", bits = "64..=107")]
+    #[bitfield(name = "f3", ty = "libc::c_schar", bits = "108..=111")]
+    #[bitfield(name = "f4", ty = "libc::c_schar", bits = "112..=117")]
+    #[bitfield(name = "f5", ty = "u8", bits = "120..=122")]
+    bits: [u8; 16],
+}
+
+#[test]
+fn test_layout120() {
+    check_layout!(
+        Layout120, layout120_size, layout120_fill, layout120_check,
+        f0, set_f0 = 2639781319255951u64 as libc::c_ulonglong;
+        f1, set_f1 = 0i16 as libc::c_short;
+        f2, set_f2 = 8796093022207i64 as libc::c_longlong;
+        f3, set_f3 = 7i8 as libc::c_schar;
+        f4, set_f4 = -32i8 as libc::c_schar;
+        f5, set_f5 = 5u8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout121 {
+    #[bitfield(name = "f0", ty = "i8", bits = "0..=4")]
+    #[bitfield(name = "f1", ty = "u128", bits = "5..=97")]
+    #[bitfield(name = "f2", ty = "i64", bits = "128..=174")]
+    #[bitfield(name = "f3", ty = "libc::c_longlong", bits = "192..=247")]
+    #[bitfield(name = "f4", ty = "bool", bits = "248..=248")]
+    #[bitfield(name = "f5", ty = "libc::c_int", bits = "256..=287")]
+    #[bitfield(name = "f6", ty = "i8", bits = "288..=291")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout121() {
+    check_layout!(
+        Layout121, layout121_size, layout121_fill, layout121_check,
+        f0, set_f0 = 15i8;
+        f1, set_f1 = 9598527671441348941650378730u128;
+        f2, set_f2 = 70368744177663i64;
+        f3, set_f3 = -36028797018963968i64 as libc::c_longlong;
+        f4, set_f4 = false;
+        f5, set_f5 = 1949457520i32 as libc::c_int;
+        f6, set_f6 = -4i8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout122 {
+    #[bitfield(name = "f0", ty = "u16", bits = "0..=3")]
+    #[bitfield(name = "f1", ty = "libc::c_ulonglong", bits = "4..=12")]
+    #[bitfield(name = "f2", ty = "u128", bits = "13..=34")]
+    #[bitfield(name = "f3", ty = "i128", bits = "128..=224")]
+    #[bitfield(name = "f4", ty = "libc::c_uchar", bits = "225..=227")]
+    #[bitfield(name = "f5", ty = "u128", bits = "256..=306")]
+    #[bitfield(name = "f6", ty = "libc::c_ulonglong", bits = "320..=367")]
+    #[bitfield(name = "f7", ty = "i128", bits = "384..=501")]
+    bits: [u8; 64],
+}
+
+#[test]
+fn test_layout122() {
+    check_layout!(
+        Layout122, layout122_size, layout122_fill, layout122_check,
+        f0, set_f0 = 0u16;
+        f1, set_f1 = 511u64 as libc::c_ulonglong;
+        f2, set_f2 = 4194303u128;
+        f3, set_f3 = -79228162514264337593543950336i128;
+        f4, set_f4 = 2u8 as libc::c_uchar;
+        f5, set_f5 = 0u128;
+        f6, set_f6 = 281474976710655u64 as libc::c_ulonglong;
+        f7, set_f7 = 166153499473114484112975882535043071i128;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout123 {
+    #[bitfield(name = "f0", ty = "i32", bits = "0..=11")]
+    #[bitfield(name = "f1", ty = "i128", bits = "128..=253")]
+    #[bitfield(name = "f2", ty = "libc::c_ulonglong", bits = "256..=299")]
+    #[bitfield(name = "f3", ty = "bool", bits = "300..=300")]
+    bits: [u8; 48],
+}
+
+#[test]
+fn test_layout123() {
+    check_layout!(
+        Layout123, layout123_size, layout123_fill, layout123_check,
+        f0, set_f0 = -1783i32;
+        f1, set_f1 = 42535295865117307932921825928971026431i128;
+        f2, set_f2 = 17592186044415u64 as libc::c_ulonglong;
+        f3, set_f3 = true;
+    );
+}
+
+#[repr(C, align(8))]
+#[derive(BitfieldStruct)]
+struct Layout124 {
+    #[bitfield(name = "f0", ty = "libc::c_ushort", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "i64", bits = "1..=56")]
+    #[bitfield(name = "f2", ty = "i16", bits = "57..=60")]
+    bits: [u8; 8],
+}
+
+#[test]
+fn test_layout124() {
+    check_layout!(
+        Layout124, layout124_size, layout124_fill, layout124_check,
+        f0, set_f0 = 1u16 as libc::c_ushort;
+        f1, set_f1 = -36028797018963968i64;
+        f2, set_f2 = 7i16;
+    );
+}
+
+#[repr(C, align(1))]
+#[derive(BitfieldStruct)]
+struct Layout125 {
+    #[bitfield(name = "f0", ty = "libc::c_uchar", bits = "0..=0")]
+    #[bitfield(name = "f1", ty = "u8", bits = "1..=1")]
+    bits: [u8; 1],
+}
+
+#[test]
+fn test_layout125() {
+    check_layout!(
+        Layout125, layout125_size, layout125_fill, layout125_check,
+        f0, set_f0 = 1u8 as libc::c_uchar;
+        f1, set_f1 = 1u8;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout126 {
+    #[bitfield(name = "f0", ty = "u128", bits = "0..=68")]
+    #[bitfield(name = "f1", ty = "libc::c_uchar", bits = "69..=71")]
+    #[bitfield(name = "f2", ty = "u32", bits = "96..=126")]
+    #[bitfield(name = "f3", ty = "u32", bits = "128..=150")]
+    #[bitfield(name = "f4", ty = "i8", bits = "152..=154")]
+    #[bitfield(name = "f5", ty = "u128", bits = "155..=233")]
+    #[bitfield(name = "f6", ty = "u64", bits = "234..=255")]
+    bits: [u8; 32],
+}
+
+#[test]
+fn test_layout126() {
+    check_layout!(
+        Layout126, layout126_size, layout126_fill, layout126_check,
+        f0, set_f0 = 0u128;
+        f1, set_f1 = 7u8 as libc::c_uchar;
+        f2, set_f2 = 773959934u32;
+        f3, set_f3 = 0u32;
+        f4, set_f4 = 3i8;
+        f5, set_f5 = 0u128;
+        f6, set_f6 = 0u64;
+    );
+}
+
+#[repr(C, align(16))]
+#[derive(BitfieldStruct)]
+struct Layout127 {
+    #[bitfield(name = "f0", ty = "i128", bits = "0..=19")]
+    #[bitfield(name = "f1", ty = "u128", bits = "128..=240")]
+    #[bitfield(name = "f2", ty = "bool", bits = "241..=241")]
+    #[bitfield(name = "f3", ty = "libc::c_schar", bits = "242..=244")]
+    #[bitfield(name = "f4", ty = "libc::c_ushort", bits = "256..=268")]
+    #[bitfield(name = "f5", ty = "i128", bits = "269..=308")]
+    #[bitfield(name = "f6", ty = "i128", bits = "384..=510")]
+    #[bitfield(name = "f7", ty = "libc::c_uint", bits = "512..=517")]
+    bits: [u8; 80],
+}
+
+#[test]
+fn test_layout127() {
+    check_layout!(
+        Layout127, layout127_size, layout127_fill, layout127_check,
+        f0, set_f0 = -93520i128;
+        f1, set_f1 = 10384593717069655257060992658440191u128;
+        f2, set_f2 = true;
+        f3, set_f3 = -4i8 as libc::c_schar;
+        f4, set_f4 = 8191u16 as libc::c_ushort;
+        f5, set_f5 = 549755813887i128;
+        f6, set_f6 = -85070591730234615865843651857942052864i128;
+        f7, set_f7 = 1u32 as libc::c_uint;
+    );
+}
@@ -1,7 +1,7 @@
 extern crate c2rust_bitfields;
 extern crate libc;
 
-use c2rust_bitfields::BitfieldStruct;
+use c2rust_bitfields::{BitfieldStruct, FieldType};
 use libc::{c_double, c_short, c_uchar, c_uint, c_ulong, c_ushort};
 use std::mem::{size_of, transmute};
 
@@ -35,6 +35,8 @@ extern "C" {
         _: c_ushort,
     ) -> c_uint;
     fn assign_three_byte_date(_: *mut ThreeByteDate, _: c_uchar, _: c_uchar, _: c_ushort);
+    fn check_colored(_: *const Colored, _: Color, _: c_uchar) -> c_uint;
+    fn assign_colored(_: *mut Colored, _: Color, _: c_uchar);
 }
 
 // *** Dumping AST Record Layout
@@ -84,6 +86,19 @@ fn test_compact_date() {
     }
 }
 
+const DATE: CompactDate = CompactDate {
+    d_m: [0b00011111, 0b00001100],
+    y: 2014,
+};
+const DAY: c_uchar = DATE.d();
+const MONTH: c_uchar = DATE.m();
+
+#[test]
+fn test_const_getters() {
+    assert_eq!(DAY, 31);
+    assert_eq!(MONTH, 12);
+}
+
 #[test]
 fn test_compact_date2() {
     let mut date = CompactDate {
@@ -623,3 +638,66 @@ fn test_bool_bits() {
     assert!(bool_bits.y());
     assert!(bool_bits.z());
 }
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl FieldType for Color {
+    fn from_bits(bits: u128) -> Self {
+        match bits {
+            0 => Color::Red,
+            1 => Color::Green,
+            2 => Color::Blue,
+            _ => panic!("Invalid color: {}", bits),
+        }
+    }
+
+    fn into_bits(self) -> u128 {
+        self as u128
+    }
+}
+
+// *** Dumping AST Record Layout
+//          0 | struct colored
+//      0:0-1 |   color c
+//      0:2-7 |   unsigned char n
+//            | [sizeof=4, align=4]
+#[repr(C, align(4))]
+#[derive(BitfieldStruct)]
+struct Colored {
+    #[bitfield(name = "c", ty = "Color", bits = "0..=1", enum_type)]
+    #[bitfield(name = "n", ty = "libc::c_uchar", bits = "2..=7")]
+    c_n: [u8; 1],
+    #[bitfield(padding)]
+    _pad: [u8; 3],
+}
+
+#[test]
+fn test_enum_bits() {
+    assert_eq!(size_of::<Colored>(), 4);
+
+    let mut colored = Colored {
+        c_n: [0; 1],
+        _pad: [0; 3],
+    };
+
+    colored.set_c(Color::Blue);
+    colored.set_n(42);
+
+    assert_eq!(colored.c(), Color::Blue);
+    assert_eq!(colored.n(), 42);
+    assert_eq!(colored.c_n, [0b10101010]);
+
+    unsafe {
+        assert_eq!(check_colored(&colored, Color::Blue, 42), 1);
+        assign_colored(&mut colored, Color::Green, 63);
+    }
+
+    assert_eq!(colored.c(), Color::Green);
+    assert_eq!(colored.n(), 63);
+}
@@ -0,0 +1,415 @@
+#![no_std]
+//! Portable fixed-size vectors, used by C2Rust to translate the vector types
+//! of the GCC vector extensions (`__attribute__((vector_size(N)))`) and the
+//! operations on them on every architecture.
+//!
+//! A C vector of `N` elements of type `T` is translated to `Vector<[T; N]>`,
+//! which has the size and alignment of the C vector. Arithmetic and bitwise
+//! operators apply to each lane, with the wrapping semantics of the
+//! translated scalar operators. Comparisons produce vectors of signed
+//! integers of the width of the lanes, all ones for true and zero for false,
+//! as in C.
+//!
+//! Vectors are passed to and returned from functions as structs rather than
+//! in vector registers, so they cannot be passed by value to C functions.
+
+use core::fmt;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+    DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr,
+    ShrAssign, Sub, SubAssign,
+};
+
+/// Type of the elements of a vector
+pub trait Lane: Copy + Default + PartialEq + PartialOrd {
+    /// Signed integer of the same width, for the results of comparisons
+    type Mask: IntLane;
+
+    fn add(self, rhs: Self) -> Self;
+    fn sub(self, rhs: Self) -> Self;
+    fn mul(self, rhs: Self) -> Self;
+    fn div(self, rhs: Self) -> Self;
+    fn rem(self, rhs: Self) -> Self;
+    fn neg(self) -> Self;
+
+    /// The result of a comparison: all ones if `true`, zero otherwise
+    fn mask(b: bool) -> Self::Mask;
+}
+
+/// Type of the elements of an integer vector
+pub trait IntLane: Lane {
+    fn and(self, rhs: Self) -> Self;
+    fn or(self, rhs: Self) -> Self;
+    fn xor(self, rhs: Self) -> Self;
+    fn not(self) -> Self;
+    fn shl(self, rhs: Self) -> Self;
+    fn shr(self, rhs: Self) -> Self;
+}
+
+macro_rules! int_lanes {
+    ($($typ:ty => $mask:ty),+) => {
+        $(
+            impl Lane for $typ {
+                type Mask = $mask;
+
+                fn add(self, rhs: Self) -> Self { self.wrapping_add(rhs) }
+                fn sub(self, rhs: Self) -> Self { self.wrapping_sub(rhs) }
+                fn mul(self, rhs: Self) -> Self { self.wrapping_mul(rhs) }
+                fn div(self, rhs: Self) -> Self { self.wrapping_div(rhs) }
+                fn rem(self, rhs: Self) -> Self { self.wrapping_rem(rhs) }
+                fn neg(self) -> Self { self.wrapping_neg() }
+
+                fn mask(b: bool) -> $mask {
+                    if b { -1 } else { 0 }
+                }
+            }
+
+            impl IntLane for $typ {
+                fn and(self, rhs: Self) -> Self { self & rhs }
+                fn or(self, rhs: Self) -> Self { self | rhs }
+                fn xor(self, rhs: Self) -> Self { self ^ rhs }
+                fn not(self) -> Self { !self }
+                fn shl(self, rhs: Self) -> Self { self.wrapping_shl(rhs as u32) }
+                fn shr(self, rhs: Self) -> Self { self.wrapping_shr(rhs as u32) }
+            }
+        )+
+    }
+}
+
+int_lanes! {
+    i8 => i8, u8 => i8,
+    i16 => i16, u16 => i16,
+    i32 => i32, u32 => i32,
+    i64 => i64, u64 => i64,
+    isize => isize, usize => isize
+}
+
+macro_rules! float_lanes {
+    ($($typ:ty => $mask:ty),+) => {
+        $(
+            impl Lane for $typ {
+                type Mask = $mask;
+
+                fn add(self, rhs: Self) -> Self { self + rhs }
+                fn sub(self, rhs: Self) -> Self { self - rhs }
+                fn mul(self, rhs: Self) -> Self { self * rhs }
+                fn div(self, rhs: Self) -> Self { self / rhs }
+                fn rem(self, rhs: Self) -> Self { self % rhs }
+                fn neg(self) -> Self { -self }
+
+                fn mask(b: bool) -> $mask {
+                    if b { -1 } else { 0 }
+                }
+            }
+        )+
+    }
+}
+
+float_lanes! { f32 => i32, f64 => i64 }
+
+/// Arrays of lanes that can be held by a vector
+pub trait Lanes: Copy {
+    type Lane: Lane;
+    /// The lanes of the results of comparisons
+    type Mask: Lanes<Lane = <Self::Lane as Lane>::Mask>;
+    /// A zero-sized type with the alignment of the vector
+    #[doc(hidden)]
+    type Align: Copy;
+
+    fn splat(lane: Self::Lane) -> Self;
+    fn as_slice(&self) -> &[Self::Lane];
+    fn as_mut_slice(&mut self) -> &mut [Self::Lane];
+}
+
+macro_rules! align_types {
+    ($($name:ident = $align:expr),+) => {
+        $(
+            #[doc(hidden)]
+            #[derive(Clone, Copy)]
+            #[repr(align($align))]
+            pub struct $name;
+        )+
+    }
+}
+
+align_types! {
+    Align1 = 1, Align2 = 2, Align4 = 4, Align8 = 8, Align16 = 16,
+    Align32 = 32, Align64 = 64, Align128 = 128, Align256 = 256, Align512 = 512
+}
+
+macro_rules! lanes {
+    ($typ:ty: $($len:expr => $align:ident),+) => {
+        $(
+            impl Lanes for [$typ; $len] {
+                type Lane = $typ;
+                type Mask = [<$typ as Lane>::Mask; $len];
+                type Align = $align;
+
+                fn splat(lane: $typ) -> Self {
+                    [lane; $len]
+                }
+
+                fn as_slice(&self) -> &[$typ] {
+                    self
+                }
+
+                fn as_mut_slice(&mut self) -> &mut [$typ] {
+                    self
+                }
+            }
+        )+
+    }
+}
+
+macro_rules! lanes_8 {
+    ($($typ:ty),+) => {
+        $(
+            lanes!($typ: 1 => Align1, 2 => Align2, 4 => Align4, 8 => Align8,
+                   16 => Align16, 32 => Align32, 64 => Align64);
+        )+
+    }
+}
+
+macro_rules! lanes_16 {
+    ($($typ:ty),+) => {
+        $(
+            lanes!($typ: 1 => Align2, 2 => Align4, 4 => Align8, 8 => Align16,
+                   16 => Align32, 32 => Align64, 64 => Align128);
+        )+
+    }
+}
+
+macro_rules! lanes_32 {
+    ($($typ:ty),+) => {
+        $(
+            lanes!($typ: 1 => Align4, 2 => Align8, 4 => Align16, 8 => Align32,
+                   16 => Align64, 32 => Align128, 64 => Align256);
+        )+
+    }
+}
+
+macro_rules! lanes_64 {
+    ($($typ:ty),+) => {
+        $(
+            lanes!($typ: 1 => Align8, 2 => Align16, 4 => Align32, 8 => Align64,
+                   16 => Align128, 32 => Align256, 64 => Align512);
+        )+
+    }
+}
+
+lanes_8!(i8, u8);
+lanes_16!(i16, u16);
+lanes_32!(i32, u32, f32);
+lanes_64!(i64, u64, f64);
+#[cfg(target_pointer_width = "32")]
+lanes_32!(isize, usize);
+#[cfg(target_pointer_width = "64")]
+lanes_64!(isize, usize);
+
+/// Indices of the lanes selected by a shuffle of vectors of `T`, which
+/// produces a vector of as many lanes as there are indices
+pub trait ShuffleIndices<T: Lane> {
+    type Output: Lanes<Lane = T>;
+
+    fn as_slice(&self) -> &[usize];
+}
+
+macro_rules! shuffle_indices {
+    ($($len:expr),+) => {
+        $(
+            impl<T: Lane> ShuffleIndices<T> for [usize; $len]
+            where
+                [T; $len]: Lanes<Lane = T>,
+            {
+                type Output = [T; $len];
+
+                fn as_slice(&self) -> &[usize] {
+                    self
+                }
+            }
+        )+
+    }
+}
+
+shuffle_indices!(1, 2, 4, 8, 16, 32, 64);
+
+/// A vector of lanes `A`, such as `[i32; 4]`, with the size and alignment of
+/// the equivalent C vector
+#[repr(C)]
+pub struct Vector<A: Lanes> {
+    lanes: A,
+    align: [A::Align; 0],
+}
+
+impl<A: Lanes> Vector<A> {
+    /// Builds a vector from its lanes
+    pub fn new(lanes: A) -> Self {
+        Vector { lanes, align: [] }
+    }
+
+    /// Builds a vector with all its lanes set to `lane`
+    pub fn splat(lane: A::Lane) -> Self {
+        Self::new(A::splat(lane))
+    }
+
+    /// The lanes of the vector
+    pub fn lanes(self) -> A {
+        self.lanes
+    }
+
+    /// Selects lanes of `self` and `other` by index, those of `other`
+    /// following those of `self`, as `__builtin_shufflevector` does
+    pub fn shuffle<I: ShuffleIndices<A::Lane>>(self, other: Self, indices: I) -> Vector<I::Output> {
+        let (lanes, other_lanes) = (self.lanes.as_slice(), other.lanes.as_slice());
+        let mut out = I::Output::splat(Default::default());
+        for (lane, &i) in out.as_mut_slice().iter_mut().zip(indices.as_slice()) {
+            *lane = if i < lanes.len() {
+                lanes[i]
+            } else {
+                other_lanes[i - lanes.len()]
+            };
+        }
+        Vector::new(out)
+    }
+
+    pub fn simd_eq(self, rhs: Self) -> Vector<A::Mask> {
+        self.compare(rhs, |a, b| a == b)
+    }
+
+    pub fn simd_ne(self, rhs: Self) -> Vector<A::Mask> {
+        self.compare(rhs, |a, b| a != b)
+    }
+
+    pub fn simd_lt(self, rhs: Self) -> Vector<A::Mask> {
+        self.compare(rhs, |a, b| a < b)
+    }
+
+    pub fn simd_le(self, rhs: Self) -> Vector<A::Mask> {
+        self.compare(rhs, |a, b| a <= b)
+    }
+
+    pub fn simd_gt(self, rhs: Self) -> Vector<A::Mask> {
+        self.compare(rhs, |a, b| a > b)
+    }
+
+    pub fn simd_ge(self, rhs: Self) -> Vector<A::Mask> {
+        self.compare(rhs, |a, b| a >= b)
+    }
+
+    fn map(mut self, f: impl Fn(A::Lane) -> A::Lane) -> Self {
+        for lane in self.lanes.as_mut_slice() {
+            *lane = f(*lane);
+        }
+        self
+    }
+
+    fn zip(mut self, rhs: Self, f: impl Fn(A::Lane, A::Lane) -> A::Lane) -> Self {
+        for (lane, &rhs) in self.lanes.as_mut_slice().iter_mut().zip(rhs.lanes.as_slice()) {
+            *lane = f(*lane, rhs);
+        }
+        self
+    }
+
+    fn compare(self, rhs: Self, f: impl Fn(A::Lane, A::Lane) -> bool) -> Vector<A::Mask> {
+        let mut out = A::Mask::splat(Default::default());
+        let lanes = self.lanes.as_slice().iter().zip(rhs.lanes.as_slice());
+        for (mask, (&a, &b)) in out.as_mut_slice().iter_mut().zip(lanes) {
+            *mask = A::Lane::mask(f(a, b));
+        }
+        Vector::new(out)
+    }
+}
+
+impl<A: Lanes> Clone for Vector<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Lanes> Copy for Vector<A> {}
+
+impl<A: Lanes> Default for Vector<A> {
+    fn default() -> Self {
+        Self::splat(Default::default())
+    }
+}
+
+impl<A: Lanes> fmt::Debug for Vector<A>
+where
+    A::Lane: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.lanes.as_slice()).finish()
+    }
+}
+
+impl<A: Lanes> Index<usize> for Vector<A> {
+    type Output = A::Lane;
+
+    fn index(&self, index: usize) -> &A::Lane {
+        &self.lanes.as_slice()[index]
+    }
+}
+
+impl<A: Lanes> IndexMut<usize> for Vector<A> {
+    fn index_mut(&mut self, index: usize) -> &mut A::Lane {
+        &mut self.lanes.as_mut_slice()[index]
+    }
+}
+
+macro_rules! binary_ops {
+    ($bound:ident: $($op:ident::$method:ident, $assign_op:ident::$assign_method:ident => $lane_method:ident);+) => {
+        $(
+            impl<A: Lanes> $op for Vector<A> where A::Lane: $bound {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    self.zip(rhs, $bound::$lane_method)
+                }
+            }
+
+            impl<A: Lanes> $assign_op for Vector<A> where A::Lane: $bound {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = self.zip(rhs, $bound::$lane_method);
+                }
+            }
+        )+
+    }
+}
+
+binary_ops! {
+    Lane:
+    Add::add, AddAssign::add_assign => add;
+    Sub::sub, SubAssign::sub_assign => sub;
+    Mul::mul, MulAssign::mul_assign => mul;
+    Div::div, DivAssign::div_assign => div;
+    Rem::rem, RemAssign::rem_assign => rem
+}
+
+binary_ops! {
+    IntLane:
+    BitAnd::bitand, BitAndAssign::bitand_assign => and;
+    BitOr::bitor, BitOrAssign::bitor_assign => or;
+    BitXor::bitxor, BitXorAssign::bitxor_assign => xor;
+    Shl::shl, ShlAssign::shl_assign => shl;
+    Shr::shr, ShrAssign::shr_assign => shr
+}
+
+impl<A: Lanes> Neg for Vector<A> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(Lane::neg)
+    }
+}
+
+impl<A: Lanes> Not for Vector<A>
+where
+    A::Lane: IntLane,
+{
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.map(IntLane::not)
+    }
+}
@@ -0,0 +1,52 @@
+use c2rust_simd::Vector;
+use core::mem::{align_of, size_of};
+
+#[test]
+fn test_layout() {
+    assert_eq!(size_of::<Vector<[i32; 4]>>(), 16);
+    assert_eq!(align_of::<Vector<[i32; 4]>>(), 16);
+    assert_eq!(size_of::<Vector<[u8; 8]>>(), 8);
+    assert_eq!(align_of::<Vector<[u8; 8]>>(), 8);
+    assert_eq!(size_of::<Vector<[f64; 64]>>(), 512);
+    assert_eq!(align_of::<Vector<[f64; 64]>>(), 512);
+}
+
+#[test]
+fn test_operators() {
+    let a = Vector::new([1i32, 2, 3, i32::max_value()]);
+    let b = Vector::splat(2);
+
+    assert_eq!((a + b).lanes(), [3, 4, 5, i32::min_value() + 1]);
+    assert_eq!((a * b).lanes(), [2, 4, 6, -2]);
+    assert_eq!((a << b).lanes(), [4, 8, 12, -4]);
+    assert_eq!((!a).lanes(), [-2, -3, -4, i32::min_value()]);
+
+    let mut c = a;
+    c -= b;
+    c[0] = 7;
+    assert_eq!(c.lanes(), [7, 0, 1, i32::max_value() - 2]);
+
+    let f = Vector::new([1.5f32, -2.0]);
+    assert_eq!((f / Vector::splat(0.5)).lanes(), [3.0, -4.0]);
+}
+
+#[test]
+fn test_comparisons() {
+    let a = Vector::new([1u8, 2, 3, 4]);
+    let b = Vector::new([4u8, 3, 3, 1]);
+    assert_eq!(a.simd_lt(b).lanes(), [-1i8, -1, 0, 0]);
+    assert_eq!(a.simd_eq(b).lanes(), [0i8, 0, -1, 0]);
+
+    let f = Vector::new([1.0f64, 2.0]);
+    assert_eq!(f.simd_ge(Vector::splat(2.0)).lanes(), [0i64, -1]);
+}
+
+#[test]
+fn test_shuffle() {
+    let a = Vector::new([0i16, 1, 2, 3]);
+    let b = Vector::new([4i16, 5, 6, 7]);
+
+    assert_eq!(a.shuffle(b, [3usize, 4]).lanes(), [3, 4]);
+    assert_eq!(a.shuffle(b, [7usize, 6, 5, 4, 3, 2, 1, 0]).lanes(), [7, 6, 5, 4, 3, 2, 1, 0]);
+    assert_eq!(a.shuffle(a, [0usize]).lanes(), [0]);
+}
@@ -17,6 +17,7 @@ use crate::CrateSet;
 use crate::PragmaSet;
 use crate::get_module_name;
 use crate::ExternCrateDetails;
+use crate::header_modules::{HeaderModules, CTYPES_MODULE, HEADERS_MODULE};
 
 #[derive(Debug, Copy, Clone)]
 pub enum BuildDirectoryContents {
@@ -102,6 +103,40 @@ pub fn emit_build_files<'lcmd>(
     })
 }
 
+/// Emit the modules of the headers shared by the translation units of a crate
+/// into `c2rust_headers/`, and their `ctypes` module if any. Returns the paths
+/// to the modules, to be declared in `lib.rs` along with the translation units.
+pub fn emit_header_modules(
+    tcfg: &TranspilerConfig,
+    build_dir: &Path,
+    header_modules: &HeaderModules,
+) -> Vec<PathBuf> {
+    if header_modules.is_empty() {
+        return vec![];
+    }
+
+    let headers_dir = build_dir.join(HEADERS_MODULE);
+    if !headers_dir.exists() {
+        fs::create_dir_all(&headers_dir).expect(&format!(
+            "couldn't create header module directory: {}",
+            headers_dir.display()
+        ));
+    }
+
+    let mut paths = vec![];
+    if let Some(source) = header_modules.ctypes() {
+        let output_path = build_dir.join(format!("{}.rs", CTYPES_MODULE));
+        maybe_write_to_file(&output_path, source.to_string(), tcfg.overwrite_existing);
+        paths.push(output_path);
+    }
+    for (name, module) in header_modules.iter() {
+        let output_path = headers_dir.join(format!("{}.rs", name));
+        maybe_write_to_file(&output_path, module.to_source(), tcfg.overwrite_existing);
+        paths.push(output_path);
+    }
+    paths
+}
+
 #[derive(Serialize)]
 struct Module {
     path: Option<String>,
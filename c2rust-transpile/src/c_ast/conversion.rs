@@ -472,6 +472,7 @@ impl ConversionContext {
         }
 
         self.typed_context.va_list_kind = untyped_context.va_list_kind;
+        self.typed_context.target_info = untyped_context.target_info;
     }
 
     /// Visit one node.
@@ -961,6 +962,10 @@ impl ConversionContext {
 
                     let label_stmt = CStmtKind::Label(substmt);
 
+                    let name = from_value::<String>(node.extras[0].clone())
+                        .expect("Expected label name");
+                    self.typed_context.label_names.insert(CStmtId(new_id), name);
+
                     self.add_stmt(new_id, located(node, label_stmt));
                     self.processed_nodes.insert(new_id, LABEL_STMT);
                 }
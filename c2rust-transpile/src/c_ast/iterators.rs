@@ -301,7 +301,7 @@ fn immediate_type_children(kind: &CTypeKind) -> Vec<SomeId> {
     }
 }
 
-fn immediate_children(context: &TypedAstContext, s_or_e: SomeId) -> Vec<SomeId> {
+pub fn immediate_children(context: &TypedAstContext, s_or_e: SomeId) -> Vec<SomeId> {
     match s_or_e {
         SomeId::Stmt(stmt_id) => immediate_stmt_children(&context[stmt_id].kind),
         SomeId::Expr(expr_id) => immediate_expr_children(&context[expr_id].kind),
@@ -8,7 +8,7 @@ use std::mem;
 use std::ops::Index;
 use std::path::{Path, PathBuf};
 
-pub use c2rust_ast_exporter::clang_ast::{SrcFile, SrcLoc, SrcSpan, BuiltinVaListKind};
+pub use c2rust_ast_exporter::clang_ast::{SrcFile, SrcLoc, SrcSpan, BuiltinVaListKind, TargetInfo};
 
 #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Copy, Clone)]
 pub struct CTypeId(pub u64);
@@ -76,6 +76,9 @@ pub struct TypedAstContext {
     // if any
     pub macro_expansion_text: HashMap<CExprId, String>,
 
+    // map label statements to the name of the label they declare
+    pub label_names: HashMap<CLabelId, String>,
+
     pub comments: Vec<Located<String>>,
 
     // The key is the typedef decl being squashed away,
@@ -83,6 +86,7 @@ pub struct TypedAstContext {
     pub prenamed_decls: IndexMap<CDeclId, CDeclId>,
 
     pub va_list_kind: BuiltinVaListKind,
+    pub target_info: TargetInfo,
 }
 
 /// Comments associated with a typed AST context
@@ -171,10 +175,20 @@ impl TypedAstContext {
             macro_invocations: HashMap::new(),
             macro_expansions: HashMap::new(),
             macro_expansion_text: HashMap::new(),
+            label_names: HashMap::new(),
 
             comments: vec![],
             prenamed_decls: IndexMap::new(),
             va_list_kind: BuiltinVaListKind::CharPtrBuiltinVaList,
+            target_info: TargetInfo {
+                char_signed: true,
+                char_width: 8,
+                short_width: 16,
+                int_width: 32,
+                long_width: 64,
+                long_long_width: 64,
+                pointer_width: 64,
+            },
         }
     }
 
@@ -0,0 +1,259 @@
+//! This module moves the declarations the relooper lifts to the top of a function down to the
+//! innermost block covering all of their uses.
+//!
+//! A lifted declaration is made with a placeholder value (`let mut x: T = 0;`), and the C
+//! declaration it stands for is only an assignment (`x = f();`). Once moved next to its first use,
+//! the declaration absorbs that assignment when it is one, giving back `let mut x: T = f();`.
+//!
+//! The placeholder value is what a variable holds until it is first assigned, so a declaration is
+//! only moved into a loop if it absorbs an assignment there: otherwise the value a variable keeps
+//! from one iteration to the next would be lost. Variables whose address may be taken are left
+//! where they are, since pointers to them may be used outside of the block. So are variables
+//! mentioned in macros, such as the operands of inline assembly.
+
+use super::*;
+use syntax::ast::{Block, Local, Mac, PatKind};
+use syntax::token::{self, Nonterminal, Token};
+use syntax::tokenstream::{TokenStream, TokenTree};
+use syntax::visit::{self, Visitor};
+
+/// Move the lifted declarations down into `body`, merging them with their first assignment where
+/// possible. Returns the declarations which could not be moved.
+pub fn place_lifted_decls(lifted: Vec<Stmt>, body: &mut Vec<Stmt>) -> Vec<Stmt> {
+    let mut kept = vec![];
+    for stmt in lifted {
+        let local = match stmt.kind {
+            StmtKind::Local(ref local) => local.clone(),
+            _ => {
+                kept.push(stmt);
+                continue;
+            }
+        };
+        let name = match local.pat.kind {
+            PatKind::Ident(_, ident, None) => ident.to_string(),
+            _ => {
+                kept.push(stmt);
+                continue;
+            }
+        };
+
+        let uses = Uses::of(&name, body.iter());
+        if uses.count == 0 || uses.other_bindings || uses.by_reference {
+            kept.push(stmt);
+            continue;
+        }
+
+        if !place(body, &name, &local, stmt.span, 0) {
+            kept.push(stmt);
+        }
+    }
+    kept
+}
+
+/// Place the declaration `local` of `name` in `stmts`, or in the innermost block nested in them
+/// which contains all of the variable's uses. `loops` counts the loops the statements are nested in
+/// relative to the original placement of the declaration.
+fn place(stmts: &mut Vec<Stmt>, name: &str, local: &P<Local>, span: Span, loops: usize) -> bool {
+    let users: Vec<usize> = stmts
+        .iter()
+        .enumerate()
+        .filter(|(_, stmt)| Uses::of(name, Some(*stmt)).count > 0)
+        .map(|(i, _)| i)
+        .collect();
+    let first = match users.first() {
+        Some(&first) => first,
+        None => return false,
+    };
+
+    if users.len() == 1 {
+        let total = Uses::of(name, Some(&stmts[first])).count;
+        let mut blocks = vec![];
+        child_blocks_of_stmt(&mut stmts[first], false, &mut blocks);
+        for (block, in_loop) in blocks {
+            if Uses::of(name, block.stmts.iter()).count == total {
+                let loops = if in_loop { loops + 1 } else { loops };
+                if place(&mut block.stmts, name, local, span, loops) {
+                    return true;
+                }
+                break;
+            }
+        }
+    }
+
+    // Merge the declaration with the assignment it precedes
+    if let Some(init) = leading_assignment(&stmts[first], name) {
+        let mut merged = local.clone().into_inner();
+        merged.init = Some(init);
+        merged.span = stmts[first].span;
+        stmts[first] = mk().span(stmts[first].span).local_stmt(P(merged));
+        return true;
+    }
+
+    if loops > 0 {
+        return false;
+    }
+    stmts.insert(first, mk().span(span).local_stmt(local.clone()));
+    true
+}
+
+/// The value assigned to `name` by a statement of the form `name = value;`
+fn leading_assignment(stmt: &Stmt, name: &str) -> Option<P<Expr>> {
+    let expr = match stmt.kind {
+        StmtKind::Semi(ref expr) | StmtKind::Expr(ref expr) => expr,
+        _ => return None,
+    };
+    match expr.kind {
+        ExprKind::Assign(ref lhs, ref rhs) if is_var(lhs, name) => {
+            if Uses::of_expr(name, rhs).count == 0 {
+                Some(rhs.clone())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+pub(super) fn is_var(expr: &Expr, name: &str) -> bool {
+    match expr.kind {
+        ExprKind::Path(None, ref path) => {
+            path.segments.len() == 1 && path.segments[0].ident.as_str() == name
+        }
+        _ => false,
+    }
+}
+
+/// Whether an expression is a variable or a part of it
+fn is_part_of_var(expr: &Expr, name: &str) -> bool {
+    match expr.kind {
+        ExprKind::Paren(ref e) | ExprKind::Field(ref e, _) | ExprKind::Index(ref e, _) => {
+            is_part_of_var(e, name)
+        }
+        _ => is_var(expr, name),
+    }
+}
+
+/// Collect the blocks directly nested in a statement, along with whether they are loop bodies
+fn child_blocks_of_stmt<'a>(
+    stmt: &'a mut Stmt,
+    in_loop: bool,
+    out: &mut Vec<(&'a mut Block, bool)>,
+) {
+    match stmt.kind {
+        StmtKind::Expr(ref mut expr) | StmtKind::Semi(ref mut expr) => {
+            child_blocks(expr, in_loop, out)
+        }
+        _ => {}
+    }
+}
+
+fn child_blocks<'a>(expr: &'a mut Expr, in_loop: bool, out: &mut Vec<(&'a mut Block, bool)>) {
+    match expr.kind {
+        ExprKind::If(_, ref mut then, ref mut els) => {
+            out.push((then, in_loop));
+            if let Some(ref mut els) = *els {
+                child_blocks(els, in_loop, out);
+            }
+        }
+        ExprKind::While(_, ref mut body, _)
+        | ExprKind::Loop(ref mut body, _)
+        | ExprKind::ForLoop(_, _, ref mut body, _) => out.push((body, true)),
+        ExprKind::Block(ref mut block, _) => out.push((block, in_loop)),
+        ExprKind::Match(_, ref mut arms) => {
+            for arm in arms {
+                child_blocks(&mut arm.body, in_loop, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// How a variable is used in some statements
+#[derive(Default)]
+pub(super) struct Uses<'a> {
+    name: &'a str,
+    /// Number of references to the variable
+    pub(super) count: usize,
+    /// Whether a reference may be to the variable's storage rather than its value
+    pub(super) by_reference: bool,
+    /// Whether another variable with the same name is declared
+    other_bindings: bool,
+}
+
+impl<'a> Uses<'a> {
+    fn of<'s>(name: &'a str, stmts: impl IntoIterator<Item = &'s Stmt>) -> Self {
+        let mut uses = Uses {
+            name,
+            ..Uses::default()
+        };
+        for stmt in stmts {
+            uses.visit_stmt(stmt);
+        }
+        uses
+    }
+
+    pub(super) fn of_expr(name: &'a str, expr: &Expr) -> Self {
+        let mut uses = Uses {
+            name,
+            ..Uses::default()
+        };
+        uses.visit_expr(expr);
+        uses
+    }
+
+    /// Count the mentions of the variable in the arguments of a macro, which may be to its storage
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        for tree in tokens.into_trees() {
+            let count = match tree {
+                TokenTree::Token(Token {
+                    kind: token::Ident(name, _),
+                    ..
+                }) => (name.as_str() == self.name) as usize,
+                TokenTree::Token(Token {
+                    kind: token::Interpolated(ref nt),
+                    ..
+                }) => match **nt {
+                    Nonterminal::NtExpr(ref expr) => Uses::of_expr(self.name, expr).count,
+                    _ => 0,
+                },
+                TokenTree::Token(_) => 0,
+                TokenTree::Delimited(_, _, tokens) => {
+                    self.visit_tokens(tokens);
+                    0
+                }
+            };
+            if count > 0 {
+                self.count += count;
+                self.by_reference = true;
+            }
+        }
+    }
+}
+
+impl<'a, 'ast> Visitor<'ast> for Uses<'a> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        let referenced = match expr.kind {
+            ExprKind::AddrOf(_, _, ref e) => Some(e),
+            ExprKind::MethodCall(_, ref args) => args.first(),
+            _ => None,
+        };
+        if referenced.map_or(false, |e| is_part_of_var(e, self.name)) {
+            self.by_reference = true;
+        }
+        if is_var(expr, self.name) {
+            self.count += 1;
+        }
+        visit::walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'ast Pat) {
+        if let PatKind::Ident(_, ident, _) = pat.kind {
+            self.other_bindings |= ident.as_str() == self.name;
+        }
+        visit::walk_pat(self, pat);
+    }
+
+    fn visit_mac(&mut self, mac: &'ast Mac) {
+        self.visit_tokens(mac.args.inner_tokens());
+    }
+}
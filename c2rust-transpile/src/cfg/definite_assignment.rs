@@ -0,0 +1,369 @@
+//! This module drops the placeholder values of local variables which are always assigned before
+//! being read.
+//!
+//! A C local declared without an initializer is translated with a zero value (`let mut x: T = 0;`),
+//! and so is a declaration lifted to the top of a function by the relooper. When every path from
+//! the declaration writes the variable before reading it, the placeholder is never observed and
+//! the declaration becomes `let mut x: T;`, which Rust accepts as long as it can see the variable
+//! is definitely assigned too.
+//!
+//! The analysis is done on the translated statements and is deliberately more conservative than
+//! the one of the Rust compiler: a variable only counts as assigned after an `if` or a `match` if
+//! every branch assigns it, and never after a loop or a labelled block that can be broken out of.
+
+use super::decl_placement::{is_var, Uses};
+use super::*;
+use syntax::ast::{BindingMode, Block, Local, Mutability, PatKind, UnOp};
+use syntax::visit::{self, Visitor};
+
+/// Drop the placeholder initializers of the declarations of `candidates` in `stmts` wherever the
+/// variable is definitely assigned before being read. Candidates are pairs of a variable name and
+/// its printed placeholder value. Returns the variables whose placeholder was kept, along with the
+/// reason why.
+pub fn elide_dead_inits(
+    stmts: &mut Vec<Stmt>,
+    candidates: &IndexSet<(String, String)>,
+) -> Vec<(String, &'static str)> {
+    let mut kept = vec![];
+    if !candidates.is_empty() {
+        elide_in_stmts(stmts, candidates, &mut kept);
+    }
+    kept
+}
+
+fn elide_in_stmts(
+    stmts: &mut Vec<Stmt>,
+    candidates: &IndexSet<(String, String)>,
+    kept: &mut Vec<(String, &'static str)>,
+) {
+    for i in 0..stmts.len() {
+        let (head, rest) = stmts.split_at_mut(i + 1);
+        match head[i].kind {
+            StmtKind::Local(ref mut local) => {
+                if let Some(name) = placeholder_of(local, candidates) {
+                    match assigned_before_read(rest, &name, false) {
+                        Ok(_) => local.init = None,
+                        Err(reason) => kept.push((name, reason)),
+                    }
+                }
+            }
+            StmtKind::Expr(ref mut expr) | StmtKind::Semi(ref mut expr) => {
+                elide_in_expr(expr, candidates, kept)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn elide_in_expr(
+    expr: &mut Expr,
+    candidates: &IndexSet<(String, String)>,
+    kept: &mut Vec<(String, &'static str)>,
+) {
+    match expr.kind {
+        ExprKind::If(_, ref mut then, ref mut els) => {
+            elide_in_stmts(&mut then.stmts, candidates, kept);
+            if let Some(ref mut els) = *els {
+                elide_in_expr(els, candidates, kept);
+            }
+        }
+        ExprKind::While(_, ref mut body, _)
+        | ExprKind::Loop(ref mut body, _)
+        | ExprKind::ForLoop(_, _, ref mut body, _)
+        | ExprKind::Block(ref mut body, _) => elide_in_stmts(&mut body.stmts, candidates, kept),
+        ExprKind::Match(_, ref mut arms) => {
+            for arm in arms {
+                elide_in_expr(&mut arm.body, candidates, kept);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The name of the variable declared by `local` if it is a candidate declared with its placeholder
+fn placeholder_of(local: &Local, candidates: &IndexSet<(String, String)>) -> Option<String> {
+    let name = match local.pat.kind {
+        PatKind::Ident(BindingMode::ByValue(Mutability::Mutable), ident, None) => ident.to_string(),
+        _ => return None,
+    };
+    let init = local.init.as_ref()?;
+    if local.ty.is_none() || !is_placeholder(init) {
+        return None;
+    }
+    if candidates.contains(&(name.clone(), pprust::expr_to_string(init))) {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Whether evaluating an expression has no effect other than producing a value
+fn is_placeholder(expr: &Expr) -> bool {
+    match expr.kind {
+        ExprKind::Lit(_) | ExprKind::Path(..) => true,
+        ExprKind::Paren(ref e)
+        | ExprKind::Cast(ref e, _)
+        | ExprKind::Unary(UnOp::Neg, ref e)
+        | ExprKind::Repeat(ref e, _) => is_placeholder(e),
+        ExprKind::Array(ref es) | ExprKind::Tup(ref es) => es.iter().all(|e| is_placeholder(e)),
+        ExprKind::Struct(_, ref fields, None) => fields.iter().all(|f| is_placeholder(&f.expr)),
+        ExprKind::Call(ref func, ref args) => {
+            let is_zero_fn = match func.kind {
+                ExprKind::Path(None, ref path) => path.segments.last().map_or(false, |seg| {
+                    let name = seg.ident.as_str();
+                    name == "zeroed" || name == "null" || name == "null_mut"
+                }),
+                _ => false,
+            };
+            is_zero_fn && args.iter().all(|e| is_placeholder(e))
+        }
+        ExprKind::Block(ref block, None) => match block.stmts.as_slice() {
+            [Stmt {
+                kind: StmtKind::Expr(ref e),
+                ..
+            }] => is_placeholder(e),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether `name` is assigned when control reaches the end of `stmts`, given whether it was
+/// assigned before them. Control not reaching the end counts as the variable being assigned. Fails
+/// with the reason why if the variable may be used before it is assigned.
+fn assigned_before_read(
+    stmts: &[Stmt],
+    name: &str,
+    mut assigned: bool,
+) -> Result<bool, &'static str> {
+    for stmt in stmts {
+        if assigned {
+            return Ok(true);
+        }
+        assigned = match stmt.kind {
+            StmtKind::Local(ref local) => {
+                if binds(&local.pat, name) {
+                    return Err("its name is reused by another variable");
+                }
+                if let Some(ref init) = local.init {
+                    assigned_in_expr(init, name)?;
+                }
+                false
+            }
+            StmtKind::Item(_) => false,
+            StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => assigned_in_expr(expr, name)?,
+            StmtKind::Mac(ref mac) => {
+                if mac
+                    .0
+                    .path
+                    .segments
+                    .last()
+                    .map_or(true, |seg| seg.ident.as_str() != "panic")
+                {
+                    return Err("it may be used in a macro");
+                }
+                true
+            }
+        };
+    }
+    Ok(assigned)
+}
+
+fn assigned_in_block(block: &Block, name: &str) -> Result<bool, &'static str> {
+    assigned_before_read(&block.stmts, name, false)
+}
+
+/// Whether `name` is assigned once `expr` is evaluated, starting from it being unassigned
+fn assigned_in_expr(expr: &Expr, name: &str) -> Result<bool, &'static str> {
+    match expr.kind {
+        ExprKind::Paren(ref e) => assigned_in_expr(e, name),
+        ExprKind::Assign(ref lhs, ref rhs) if is_var(lhs, name) => {
+            not_used(rhs, name)?;
+            Ok(true)
+        }
+        ExprKind::If(ref cond, ref then, ref els) => {
+            not_used(cond, name)?;
+            let in_then = assigned_in_block(then, name)?;
+            let in_els = match *els {
+                Some(ref els) => assigned_in_expr(els, name)?,
+                None => false,
+            };
+            Ok(in_then && in_els)
+        }
+        ExprKind::Match(ref scrutinee, ref arms) => {
+            not_used(scrutinee, name)?;
+            let mut all = !arms.is_empty();
+            for arm in arms {
+                if let Some(ref guard) = arm.guard {
+                    not_used(guard, name)?;
+                }
+                all &= assigned_in_expr(&arm.body, name)?;
+            }
+            Ok(all)
+        }
+        ExprKind::Block(ref block, None) => assigned_in_block(block, name),
+        ExprKind::Block(ref block, Some(label)) => {
+            let assigned = assigned_in_block(block, name)?;
+            Ok(assigned && !breaks_to(block, &label.ident.to_string()))
+        }
+        ExprKind::While(ref cond, ref body, _) => {
+            not_used(cond, name)?;
+            assigned_in_block(body, name)?;
+            Ok(false)
+        }
+        ExprKind::ForLoop(_, ref iter, ref body, _) => {
+            not_used(iter, name)?;
+            assigned_in_block(body, name)?;
+            Ok(false)
+        }
+        ExprKind::Loop(ref body, _) => {
+            assigned_in_block(body, name)?;
+            Ok(false)
+        }
+        ExprKind::Ret(ref e) | ExprKind::Break(_, ref e) => {
+            if let Some(ref e) = *e {
+                not_used(e, name)?;
+            }
+            Ok(true)
+        }
+        ExprKind::Continue(_) => Ok(true),
+        _ => {
+            not_used(expr, name)?;
+            Ok(false)
+        }
+    }
+}
+
+/// Fail if `expr` mentions `name`, with the reason why that prevents dropping the placeholder
+fn not_used(expr: &Expr, name: &str) -> Result<(), &'static str> {
+    let uses = Uses::of_expr(name, expr);
+    if uses.by_reference {
+        Err("it may be borrowed before it is assigned")
+    } else if uses.count > 0 {
+        Err("it may be read before it is assigned")
+    } else {
+        Ok(())
+    }
+}
+
+fn binds(pat: &Pat, name: &str) -> bool {
+    match pat.kind {
+        PatKind::Ident(_, ident, _) => ident.as_str() == name,
+        _ => false,
+    }
+}
+
+/// Whether a block contains a `break` out of the block labelled `label`
+fn breaks_to(block: &Block, label: &str) -> bool {
+    struct BreaksTo<'a> {
+        label: &'a str,
+        found: bool,
+    }
+
+    impl<'a, 'ast> Visitor<'ast> for BreaksTo<'a> {
+        fn visit_expr(&mut self, expr: &'ast Expr) {
+            if let ExprKind::Break(Some(label), _) = expr.kind {
+                self.found |= label.ident.as_str() == self.label;
+            }
+            visit::walk_expr(self, expr);
+        }
+
+        fn visit_mac(&mut self, _mac: &'ast syntax::ast::Mac) {}
+    }
+
+    let mut visitor = BreaksTo {
+        label,
+        found: false,
+    };
+    visitor.visit_block(block);
+    visitor.found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syntax::with_globals;
+    use syntax_pos::edition::Edition;
+
+    fn decl(name: &str) -> Stmt {
+        let pat = mk().set_mutbl("mut").ident_pat(name);
+        let zero = mk().lit_expr(mk().int_lit(0, ""));
+        mk().local_stmt(P(mk().local(pat, Some(mk().ident_ty("i32")), Some(zero))))
+    }
+
+    fn assign(name: &str, value: u128) -> Stmt {
+        mk().semi_stmt(mk().assign_expr(
+            mk().ident_expr(name),
+            mk().lit_expr(mk().int_lit(value, "")),
+        ))
+    }
+
+    fn read(name: &str) -> Stmt {
+        mk().semi_stmt(mk().call_expr(mk().ident_expr("f"), vec![mk().ident_expr(name)]))
+    }
+
+    fn if_stmt(then: Vec<Stmt>, els: Option<Vec<Stmt>>) -> Stmt {
+        let els = els.map(|els| mk().block_expr(mk().block(els)));
+        mk().expr_stmt(mk().ifte_expr(mk().ident_expr("c"), mk().block(then), els))
+    }
+
+    /// Run the analysis on `stmts`, whose first statement declares `x`, and return whether its
+    /// placeholder was dropped along with the reasons for keeping placeholders
+    fn elide(mut stmts: Vec<Stmt>) -> (bool, Vec<(String, &'static str)>) {
+        let candidates = indexset![("x".to_string(), "0".to_string())];
+        let kept = elide_dead_inits(&mut stmts, &candidates);
+        let elided = match stmts[0].kind {
+            StmtKind::Local(ref local) => local.init.is_none(),
+            _ => panic!("The first statement should declare `x`"),
+        };
+        (elided, kept)
+    }
+
+    #[test]
+    fn assigned_before_read() {
+        with_globals(Edition::Edition2018, || {
+            let (elided, kept) = elide(vec![decl("x"), assign("x", 1), read("x")]);
+            assert!(elided);
+            assert!(kept.is_empty());
+        })
+    }
+
+    #[test]
+    fn read_before_assigned() {
+        with_globals(Edition::Edition2018, || {
+            let (elided, kept) = elide(vec![decl("x"), read("x"), assign("x", 1)]);
+            assert!(!elided);
+            assert_eq!(
+                kept,
+                vec![("x".to_string(), "it may be read before it is assigned")]
+            );
+        })
+    }
+
+    #[test]
+    fn assigned_in_every_branch() {
+        with_globals(Edition::Edition2018, || {
+            let branches = if_stmt(vec![assign("x", 1)], Some(vec![assign("x", 2)]));
+            let (elided, _) = elide(vec![decl("x"), branches, read("x")]);
+            assert!(elided);
+        })
+    }
+
+    #[test]
+    fn assigned_in_some_branches() {
+        with_globals(Edition::Edition2018, || {
+            let branch = if_stmt(vec![assign("x", 1)], None);
+            let (elided, _) = elide(vec![decl("x"), branch, read("x")]);
+            assert!(!elided);
+        })
+    }
+
+    #[test]
+    fn not_a_candidate() {
+        with_globals(Edition::Edition2018, || {
+            let (elided, kept) = elide(vec![decl("y"), assign("y", 1), read("y")]);
+            assert!(!elided);
+            assert!(kept.is_empty());
+        })
+    }
+}
@@ -0,0 +1,365 @@
+//! This module writes control flow graphs and relooped structures out as HTML pages. Each page
+//! holds a Mermaid flowchart of the graph, followed by a table that puts the C source location of
+//! every block next to the Rust statements it was translated into.
+//!
+//! The flowchart is rendered by the Mermaid script when the page is opened in a browser.
+
+use super::*;
+
+const MERMAID_SCRIPT: &str = "https://cdn.jsdelivr.net/npm/mermaid/dist/mermaid.min.js";
+
+impl Cfg<Label, StmtOrDecl> {
+    /// Write out an HTML page with a Mermaid flowchart of the control flow graph
+    pub fn dump_html_graph(
+        &self,
+        ctx: &TypedAstContext,
+        store: &DeclStmtStore,
+        title: &str,
+        file_path: String,
+    ) -> io::Result<()> {
+        let mut flowchart = String::from("flowchart TD\n");
+        let mut rows: Vec<Row> = vec![];
+
+        flowchart.push_str(&format!(
+            "  entry((entry)) --> {}\n",
+            self.entries.debug_print()
+        ));
+
+        for (lbl, bb) in self.nodes.iter() {
+            let loc = display_loc(ctx, &bb.src_loc);
+            let mut rust: Vec<String> = bb
+                .body
+                .iter()
+                .flat_map(|sd: &StmtOrDecl| -> Vec<String> { sd.to_string(store) })
+                .collect();
+            rust.extend(terminator_text(&bb.terminator));
+
+            flowchart.push_str(&format!(
+                "  {}[\"{}\"]\n",
+                lbl.debug_print(),
+                node_text(&lbl.debug_print(), &loc, &rust),
+            ));
+
+            let edges: Vec<(String, Label)> = match bb.terminator {
+                End => vec![],
+                Jump(tgt) => vec![(String::new(), tgt)],
+                Branch(_, tru, fal) => {
+                    vec![(String::from("true"), tru), (String::from("false"), fal)]
+                }
+                Switch { ref cases, .. } => cases
+                    .iter()
+                    .map(|&(ref pat, tgt)| (pprust::pat_to_string(pat.deref()), tgt))
+                    .collect(),
+            };
+            for (desc, tgt) in edges {
+                flowchart.push_str(&edge(&lbl.debug_print(), &tgt.debug_print(), &desc, false));
+            }
+
+            rows.push(Row {
+                block: lbl.debug_print(),
+                loc,
+                rust,
+            });
+        }
+
+        write_page(title, &flowchart, &rows, file_path)
+    }
+
+    /// Locations in the C source of the blocks of the graph, for use once the graph has been
+    /// relooped
+    pub fn src_locs(&self) -> IndexMap<Label, SrcSpan> {
+        self.nodes
+            .iter()
+            .filter_map(|(lbl, bb)| bb.src_loc.map(|loc| (*lbl, loc)))
+            .collect()
+    }
+}
+
+/// Write out an HTML page with a Mermaid flowchart of the structures relooper produced. Loops and
+/// `current_block` dispatches are drawn as nested subgraphs. `src_locs` are the C source
+/// locations of the blocks of the graph that was relooped.
+pub fn dump_html_structures(
+    ctx: &TypedAstContext,
+    root: &Vec<Structure<Stmt>>,
+    src_locs: &IndexMap<Label, SrcSpan>,
+    title: &str,
+    file_path: String,
+) -> io::Result<()> {
+    let mut writer = StructuresWriter {
+        ctx,
+        src_locs,
+        flowchart: String::from("flowchart TD\n"),
+        rows: vec![],
+        next_id: 0,
+        label_ids: IndexMap::new(),
+        edges: vec![],
+    };
+
+    if let Some(first) = writer.write_structures(root, 1) {
+        writer
+            .flowchart
+            .push_str(&format!("  entry((entry)) --> {}\n", first));
+    }
+
+    for (from, to, desc, dashed) in writer.edges.clone() {
+        let to = match writer.label_ids.get(&to) {
+            Some(id) => id.clone(),
+            None => {
+                // The target lies outside of the structures, as the exit of a substatement does
+                let id = to.debug_print();
+                writer.flowchart.push_str(&format!("  {}(({}))\n", id, id));
+                id
+            }
+        };
+        writer.flowchart.push_str(&edge(&from, &to, &desc, dashed));
+    }
+
+    write_page(title, &writer.flowchart, &writer.rows, file_path)
+}
+
+/// One line of the table following the flowchart
+struct Row {
+    block: String,
+    loc: Option<String>,
+    rust: Vec<String>,
+}
+
+/// State kept while walking relooped structures
+struct StructuresWriter<'a> {
+    ctx: &'a TypedAstContext,
+    src_locs: &'a IndexMap<Label, SrcSpan>,
+    flowchart: String,
+    rows: Vec<Row>,
+    next_id: u64,
+
+    /// Flowchart node of the first `Simple` structure entered through each label
+    label_ids: IndexMap<Label, String>,
+
+    /// Edges are only written once every label has a node: source, target, description and
+    /// whether the edge exits a loop or a dispatch
+    edges: Vec<(String, Label, String, bool)>,
+}
+
+impl<'a> StructuresWriter<'a> {
+    fn fresh_id(&mut self) -> String {
+        self.next_id += 1;
+        format!("n{}", self.next_id)
+    }
+
+    /// Write out a sequence of structures, returning the node of the first one
+    fn write_structures(
+        &mut self,
+        structures: &Vec<Structure<Stmt>>,
+        depth: usize,
+    ) -> Option<String> {
+        let mut first = None;
+        for structure in structures {
+            let id = self.write_structure(structure, depth);
+            first = first.or(Some(id));
+        }
+        first
+    }
+
+    fn write_structure(&mut self, structure: &Structure<Stmt>, depth: usize) -> String {
+        let indent = "  ".repeat(depth);
+        let id = self.fresh_id();
+
+        match structure {
+            &Structure::Simple {
+                ref entries,
+                ref body,
+                ref terminator,
+                ..
+            } => {
+                let block = entries
+                    .iter()
+                    .map(Label::debug_print)
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                let src_loc = entries
+                    .iter()
+                    .filter_map(|lbl| self.src_locs.get(lbl))
+                    .next();
+                let loc = display_loc(self.ctx, &src_loc.cloned());
+                let mut rust: Vec<String> = body.iter().map(pprust::stmt_to_string).collect();
+                rust.extend(terminator_text(terminator));
+
+                self.flowchart.push_str(&format!(
+                    "{}{}[\"{}\"]\n",
+                    indent,
+                    id,
+                    node_text(&block, &loc, &rust),
+                ));
+                for lbl in entries {
+                    self.label_ids.entry(*lbl).or_insert(id.clone());
+                }
+                self.rows.push(Row { block, loc, rust });
+
+                let targets: Vec<(String, &StructureLabel<Stmt>)> = match terminator {
+                    &End => vec![],
+                    &Jump(ref tgt) => vec![(String::new(), tgt)],
+                    &Branch(_, ref tru, ref fal) => {
+                        vec![(String::from("true"), tru), (String::from("false"), fal)]
+                    }
+                    &Switch { ref cases, .. } => cases
+                        .iter()
+                        .map(|&(ref pat, ref tgt)| (pprust::pat_to_string(pat.deref()), tgt))
+                        .collect(),
+                };
+                for (desc, tgt) in targets {
+                    match tgt {
+                        &StructureLabel::GoTo(lbl) => {
+                            self.edges.push((id.clone(), lbl, desc, false))
+                        }
+                        &StructureLabel::ExitTo(lbl) => {
+                            self.edges.push((id.clone(), lbl, desc, true))
+                        }
+                        &StructureLabel::Nested(ref nested) => {
+                            if let Some(nested_id) = self.write_structures(nested, depth) {
+                                self.flowchart
+                                    .push_str(&edge(&id, &nested_id, &desc, false));
+                            }
+                        }
+                    }
+                }
+            }
+            &Structure::Loop { ref body, .. } => {
+                self.flowchart
+                    .push_str(&format!("{}subgraph {}[\"loop\"]\n", indent, id));
+                self.write_structures(body, depth + 1);
+                self.flowchart.push_str(&format!("{}end\n", indent));
+            }
+            &Structure::Multiple {
+                ref branches,
+                ref then,
+                ..
+            } => {
+                self.flowchart.push_str(&format!(
+                    "{}subgraph {}[\"match current_block\"]\n",
+                    indent, id
+                ));
+                for (lbl, branch) in branches {
+                    let branch_id = self.fresh_id();
+                    self.flowchart.push_str(&format!(
+                        "{}  subgraph {}[\"{}\"]\n",
+                        indent,
+                        branch_id,
+                        lbl.debug_print(),
+                    ));
+                    self.write_structures(branch, depth + 2);
+                    self.flowchart.push_str(&format!("{}  end\n", indent));
+                }
+                if !then.is_empty() {
+                    let then_id = self.fresh_id();
+                    self.flowchart
+                        .push_str(&format!("{}  subgraph {}[\"_\"]\n", indent, then_id));
+                    self.write_structures(then, depth + 2);
+                    self.flowchart.push_str(&format!("{}  end\n", indent));
+                }
+                self.flowchart.push_str(&format!("{}end\n", indent));
+            }
+        }
+
+        id
+    }
+}
+
+fn display_loc(ctx: &TypedAstContext, loc: &Option<SrcSpan>) -> Option<String> {
+    ctx.display_loc(loc).map(|loc| loc.to_string())
+}
+
+/// What a terminator decides on, if anything
+fn terminator_text<L>(terminator: &GenTerminator<L>) -> Option<String> {
+    match terminator {
+        &End | &Jump(_) => None,
+        &Branch(ref cond, _, _) => Some(format!("if {}", pprust::expr_to_string(cond.deref()))),
+        &Switch { ref expr, .. } => Some(format!("match {}", pprust::expr_to_string(expr.deref()))),
+    }
+}
+
+/// Text of a flowchart node: the block, where it comes from and the Rust code it holds
+fn node_text(block: &str, loc: &Option<String>, rust: &[String]) -> String {
+    let mut lines: Vec<String> = vec![match loc {
+        Some(loc) => format!("{} @ {}", block, loc),
+        None => block.to_owned(),
+    }];
+    lines.extend(
+        rust.iter()
+            .flat_map(|s| s.lines().map(String::from).collect::<Vec<_>>()),
+    );
+    lines
+        .iter()
+        .map(|line| mermaid_escape(line))
+        .collect::<Vec<String>>()
+        .join("<br/>")
+}
+
+fn edge(from: &str, to: &str, desc: &str, dashed: bool) -> String {
+    let arrow = if dashed { "-.->" } else { "-->" };
+    if desc.is_empty() {
+        format!("  {} {} {}\n", from, arrow, to)
+    } else {
+        format!(
+            "  {} {}|\"{}\"| {}\n",
+            from,
+            arrow,
+            mermaid_escape(desc),
+            to
+        )
+    }
+}
+
+/// Escape text for use inside a quoted Mermaid label, using Mermaid's entity codes
+fn mermaid_escape(text: &str) -> String {
+    text.replace("#", "#35;")
+        .replace("\"", "#quot;")
+        .replace("&", "#amp;")
+        .replace("<", "#lt;")
+        .replace(">", "#gt;")
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace("&", "&amp;")
+        .replace("<", "&lt;")
+        .replace(">", "&gt;")
+        .replace("\"", "&quot;")
+}
+
+fn write_page(title: &str, flowchart: &str, rows: &[Row], file_path: String) -> io::Result<()> {
+    let mut file = File::create(file_path)?;
+
+    file.write_all(b"<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n")?;
+    file.write_fmt(format_args!("<title>{}</title>\n", html_escape(title)))?;
+    file.write_fmt(format_args!(
+        "<script src=\"{}\"></script>\n",
+        MERMAID_SCRIPT
+    ))?;
+    file.write_all(
+        b"<script>mermaid.initialize({ startOnLoad: true, flowchart: { useMaxWidth: false } });</script>\n",
+    )?;
+    file.write_all(
+        b"<style>table { border-collapse: collapse; } \
+          td, th { border: 1px solid #ccc; padding: 4px; text-align: left; vertical-align: top; } \
+          pre { margin: 0; }</style>\n",
+    )?;
+    file.write_all(b"</head>\n<body>\n")?;
+    file.write_fmt(format_args!("<h1>{}</h1>\n", html_escape(title)))?;
+
+    file.write_fmt(format_args!(
+        "<pre class=\"mermaid\">\n{}</pre>\n",
+        html_escape(flowchart)
+    ))?;
+
+    file.write_all(b"<table>\n<tr><th>Block</th><th>C source</th><th>Rust</th></tr>\n")?;
+    for row in rows {
+        file.write_fmt(format_args!(
+            "<tr><td><code>{}</code></td><td><code>{}</code></td><td><pre>{}</pre></td></tr>\n",
+            html_escape(&row.block),
+            html_escape(row.loc.as_ref().map_or("", String::as_str)),
+            html_escape(&row.rust.join("\n")),
+        ))?;
+    }
+    file.write_all(b"</table>\n</body>\n</html>\n")?;
+
+    Ok(())
+}
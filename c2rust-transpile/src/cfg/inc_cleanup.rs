@@ -1,14 +1,17 @@
 //! This module provides support for removing the extraneous break statements
 //! generated by the incremental relooper.
 use super::*;
+use syntax::ast::{Block, Ident, Mac};
+use syntax::mut_visit::{self, MutVisitor};
+use syntax::visit::{self, Visitor};
 
 pub struct IncCleanup {
     in_tail: Option<ImplicitReturnType>,
-    brk_lbl: Label,
+    brk_lbl: String,
 }
 
 impl IncCleanup {
-    pub fn new(in_tail: Option<ImplicitReturnType>, brk_lbl: Label) -> Self {
+    pub fn new(in_tail: Option<ImplicitReturnType>, brk_lbl: String) -> Self {
         IncCleanup { in_tail, brk_lbl }
     }
 
@@ -114,20 +117,125 @@ impl IncCleanup {
                 false
             }
 
-            _ => {
-                if let Expr {
-                    kind: ExprKind::Break(Some(ref blbl), None),
-                    ..
-                } = **tail_expr
-                {
-                    if blbl.ident == mk().label(self.brk_lbl.pretty_print()).ident {
-                        return true;
+            _ => match tail_expr.kind {
+                ExprKind::Break(Some(ref blbl), None) | ExprKind::Continue(Some(ref blbl)) => {
+                    blbl.ident == mk().label(&self.brk_lbl).ident
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Turn a trailing `if c { ...; break 'a; } else { rest }` into `if c { ...; break 'a; } rest`,
+/// for as long as there is one, so that jumps out of a block read as early exits.
+pub fn flatten_early_exits(stmts: &mut Vec<Stmt>) {
+    fn exits(blk: &Block) -> bool {
+        match blk.stmts.last() {
+            Some(&Stmt {
+                kind: StmtKind::Semi(ref expr),
+                ..
+            })
+            | Some(&Stmt {
+                kind: StmtKind::Expr(ref expr),
+                ..
+            }) => match expr.kind {
+                ExprKind::Break(..) | ExprKind::Continue(..) | ExprKind::Ret(..) => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    loop {
+        let els = match stmts.last_mut() {
+            Some(&mut Stmt {
+                kind: StmtKind::Expr(ref mut expr),
+                ..
+            }) => match expr.kind {
+                ExprKind::If(_, ref body, ref mut els) if exits(body) => els.take(),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match els.map(|els| els.into_inner()) {
+            Some(Expr {
+                kind: ExprKind::Block(blk, None),
+                ..
+            }) => stmts.extend(blk.into_inner().stmts),
+            // An `else if`
+            Some(els) => stmts.push(mk().expr_stmt(P(els))),
+            None => return,
+        }
+    }
+}
+
+/// Whether any of the statements contains a `break` or `continue` to the label `brk_lbl`
+pub fn jumps_to(stmts: &[Stmt], brk_lbl: &str) -> bool {
+    struct JumpFinder {
+        ident: Ident,
+        found: bool,
+    }
+
+    impl<'a> Visitor<'a> for JumpFinder {
+        fn visit_expr(&mut self, expr: &'a Expr) {
+            match expr.kind {
+                ExprKind::Break(Some(ref lbl), _) | ExprKind::Continue(Some(ref lbl)) => {
+                    self.found |= lbl.ident == self.ident
+                }
+                _ => {}
+            }
+            visit::walk_expr(self, expr);
+        }
+
+        fn visit_mac(&mut self, _mac: &'a Mac) {}
+    }
+
+    let mut finder = JumpFinder {
+        ident: mk().label(brk_lbl).ident,
+        found: false,
+    };
+    for stmt in stmts {
+        finder.visit_stmt(stmt);
+    }
+    finder.found
+}
+
+/// Drop the label of the `break`s and `continue`s to the loop labelled `lbl` which aren't nested
+/// in another loop or labelled block, and so don't need it
+pub fn unlabel_loop_jumps(stmts: &mut Vec<Stmt>, lbl: &str) {
+    struct Unlabeller {
+        ident: Ident,
+    }
+
+    impl MutVisitor for Unlabeller {
+        fn visit_expr(&mut self, expr: &mut P<Expr>) {
+            match expr.kind {
+                ExprKind::Break(ref mut lbl, _) | ExprKind::Continue(ref mut lbl) => {
+                    if lbl.map_or(false, |lbl| lbl.ident == self.ident) {
+                        *lbl = None;
                     }
                 }
-                false
+                ExprKind::While(..)
+                | ExprKind::Loop(..)
+                | ExprKind::ForLoop(..)
+                | ExprKind::Block(_, Some(_)) => return,
+                _ => {}
             }
+            mut_visit::noop_visit_expr(expr, self)
         }
+
+        fn visit_mac(&mut self, _mac: &mut Mac) {}
     }
+
+    let mut unlabeller = Unlabeller {
+        ident: mk().label(lbl).ident,
+    };
+    *stmts = stmts
+        .drain(..)
+        .flat_map(|stmt| unlabeller.flat_map_stmt(stmt))
+        .collect();
 }
 
 /// Remove empty else clauses from if expressions that can arise from
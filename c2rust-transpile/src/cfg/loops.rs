@@ -232,6 +232,25 @@ impl<Lbl: Hash + Eq + Clone> LoopInfo<Lbl> {
         self.loops.insert(id, (contents, outer_id));
     }
 
+    /// Record that `copy` is a duplicate of `original`, and so belongs to the same loops
+    pub fn add_copy(&mut self, original: &Lbl, copy: Lbl) -> () {
+        for loop_id in self.enclosing_loops(original) {
+            if let Some(&mut (ref mut set, _)) = self.loops.get_mut(&loop_id) {
+                set.insert(copy.clone());
+            }
+        }
+        if let Some(&loop_id) = self.node_loops.get(original) {
+            self.node_loops.insert(copy, loop_id);
+        }
+    }
+
+    /// Every label mentioned in the loop information
+    pub fn labels(&self) -> impl Iterator<Item = &Lbl> {
+        self.node_loops
+            .keys()
+            .chain(self.loops.values().flat_map(|&(ref set, _)| set.iter()))
+    }
+
     /// Get all of the `LoopId`'s corresponding to loops of increasing size around the given label
     pub fn enclosing_loops(&self, lbl: &Lbl) -> Vec<LoopId> {
         let mut loop_id_opt: Option<LoopId> = self.node_loops.get(lbl).cloned();
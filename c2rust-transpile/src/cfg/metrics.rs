@@ -0,0 +1,100 @@
+//! This module computes per-function metrics on the quality of the control flow we emit, to help
+//! decide which translated functions are most in need of manual refactoring.
+//!
+//! The metrics are written as JSON next to the `--json-function-cfgs` output.
+
+use super::*;
+
+/// Control-flow metrics of a single translated function
+#[derive(Debug, Default, Serialize)]
+pub struct FunctionMetrics {
+    /// Name of the function
+    pub function: String,
+
+    /// Number of `Multiple` structures, each of which is a `match` on `current_block`
+    pub multiples: usize,
+
+    /// Number of loops that relooper could not match to a loop of the C source
+    pub unmatched_loops: usize,
+
+    /// Number of loops that keep a label because they are exited from an inner loop
+    pub loop_labels: usize,
+
+    /// Number of blocks duplicated by node splitting, including `switch` fallthrough copies
+    pub duplicated_blocks: usize,
+
+    /// Cyclomatic complexity of the CFG built from the C source
+    pub complexity_before: usize,
+
+    /// Cyclomatic complexity of the emitted structures, including `current_block` dispatches
+    pub complexity_after: usize,
+}
+
+impl FunctionMetrics {
+    /// Write the metrics out as JSON
+    pub fn dump_json(&self, file_path: String) -> io::Result<()> {
+        let file = File::create(file_path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+}
+
+impl<S> Cfg<Label, S> {
+    /// Number of basic blocks in the graph
+    pub fn num_blocks(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Cyclomatic complexity of the graph, counted as one plus the number of additional ways each
+    /// block can be left.
+    pub fn cyclomatic_complexity(&self) -> usize {
+        1 + self
+            .nodes
+            .values()
+            .map(|bb| terminator_decisions(&bb.terminator))
+            .sum::<usize>()
+    }
+}
+
+/// Cyclomatic complexity of a sequence of structures, counted like that of a CFG. A `Multiple`
+/// adds one decision per branch besides the default one.
+pub fn structures_complexity<S>(root: &Vec<Structure<S>>) -> usize {
+    1 + structures_decisions(root)
+}
+
+fn structures_decisions<S>(root: &Vec<Structure<S>>) -> usize {
+    root.iter()
+        .map(|structure| match structure {
+            &Structure::Simple { ref terminator, .. } => {
+                let nested: usize = terminator
+                    .get_labels()
+                    .into_iter()
+                    .map(|structure_label| match structure_label {
+                        &StructureLabel::Nested(ref nested) => structures_decisions(nested),
+                        _ => 0,
+                    })
+                    .sum();
+                terminator_decisions(terminator) + nested
+            }
+            &Structure::Multiple {
+                ref branches,
+                ref then,
+                ..
+            } => {
+                branches.len()
+                    + branches.values().map(structures_decisions).sum::<usize>()
+                    + structures_decisions(then)
+            }
+            &Structure::Loop { ref body, .. } => structures_decisions(body),
+        })
+        .sum()
+}
+
+/// Number of additional ways a terminator can leave its block
+fn terminator_decisions<L>(terminator: &GenTerminator<L>) -> usize {
+    match terminator {
+        &End | &Jump(_) => 0,
+        &Branch(..) => 1,
+        &Switch { ref cases, .. } => cases.len().saturating_sub(1),
+    }
+}
@@ -9,19 +9,20 @@
 //!
 //!   - given an entry point C statement, translate it into a CFG consisting of `BasicBlock<Label>`
 //!   - simplify this CFG (by eliminating empty blocks that jump unconditionally to the next block)
+//!   - optionally, split nodes of this CFG to reduce the branching relooper can't structure
 //!   - use the _Relooper algorithm_ to convert this CFG into a sequence of `Structure<StmtOrDecl>`s
 //!   - place the declarations in the right place and produce a sequence of `Structure<Stmt>`s
 //!   - simplify that sequence of `Structure<Stmt>`s into another such sequence
 //!   - convert the `Vec<Structure<Stmt>>` back into a `Vec<Stmt>`
+//!   - move the declarations lifted to the top of the `Vec<Stmt>` down next to their uses
+//!   - drop the placeholder values of variables which are always assigned before being read
 //!
 
 use crate::c_ast::iterators::{DFExpr, SomeId};
 use crate::c_ast::CLabelId;
-use std::collections::hash_map::DefaultHasher;
 use std::collections::BTreeSet;
 use std::fs::File;
 use std::hash::Hash;
-use std::hash::Hasher;
 use std::io;
 use std::io::Write;
 use std::ops::Deref;
@@ -45,8 +46,13 @@ use crate::with_stmts::WithStmts;
 use c2rust_ast_builder::mk;
 
 mod inc_cleanup;
+pub mod decl_placement;
+pub mod definite_assignment;
+pub mod html;
 pub mod loops;
+pub mod metrics;
 pub mod multiples;
+pub mod node_splitting;
 pub mod relooper;
 pub mod structures;
 
@@ -78,14 +84,6 @@ impl Label {
         String::from(self.pretty_print().trim_start_matches('\''))
     }
 
-    fn to_num_expr(&self) -> P<Expr> {
-        let mut s = DefaultHasher::new();
-        self.hash(&mut s);
-        let as_num = s.finish();
-
-        mk().lit_expr(as_num as u128)
-    }
-
     fn to_string_expr(&self) -> P<Expr> {
         mk().lit_expr(self.debug_print())
     }
@@ -227,6 +225,71 @@ impl Structure<StmtOrDecl> {
     }
 }
 
+impl Serialize for StructureLabel<Stmt> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            StructureLabel::GoTo(ref l) => {
+                let mut tv = serializer.serialize_tuple_variant("StructureLabel", 0, "GoTo", 1)?;
+                tv.serialize_field(l)?;
+                tv.end()
+            }
+            StructureLabel::ExitTo(ref l) => {
+                let mut tv =
+                    serializer.serialize_tuple_variant("StructureLabel", 1, "ExitTo", 1)?;
+                tv.serialize_field(l)?;
+                tv.end()
+            }
+            StructureLabel::Nested(ref structures) => {
+                let mut tv =
+                    serializer.serialize_tuple_variant("StructureLabel", 2, "Nested", 1)?;
+                tv.serialize_field(structures)?;
+                tv.end()
+            }
+        }
+    }
+}
+
+impl Serialize for Structure<Stmt> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Structure::Simple {
+                ref entries,
+                ref body,
+                ref terminator,
+                ..
+            } => {
+                let body: Vec<String> = body.iter().map(pprust::stmt_to_string).collect();
+
+                let mut sv = serializer.serialize_struct_variant("Structure", 0, "Simple", 3)?;
+                sv.serialize_field("entries", entries)?;
+                sv.serialize_field("body", &body)?;
+                sv.serialize_field("terminator", terminator)?;
+                sv.end()
+            }
+            Structure::Loop {
+                ref entries,
+                ref body,
+            } => {
+                let mut sv = serializer.serialize_struct_variant("Structure", 1, "Loop", 2)?;
+                sv.serialize_field("entries", entries)?;
+                sv.serialize_field("body", body)?;
+                sv.end()
+            }
+            Structure::Multiple {
+                ref entries,
+                ref branches,
+                ref then,
+            } => {
+                let mut sv = serializer.serialize_struct_variant("Structure", 2, "Multiple", 3)?;
+                sv.serialize_field("entries", entries)?;
+                sv.serialize_field("branches", branches)?;
+                sv.serialize_field("then", then)?;
+                sv.end()
+            }
+        }
+    }
+}
+
 /// Generalized basic block.
 #[derive(Clone, Debug)]
 pub struct BasicBlock<L, S> {
@@ -244,6 +307,9 @@ pub struct BasicBlock<L, S> {
 
     /// Span of this block
     span: Span,
+
+    /// Location in the C source of the statement this block was built from
+    src_loc: Option<SrcSpan>,
 }
 
 impl<L: Clone, S1> BasicBlock<L, S1> {
@@ -256,6 +322,7 @@ impl<L: Clone, S1> BasicBlock<L, S1> {
             live: self.live.clone(),
             defined: self.defined.clone(),
             span: self.span,
+            src_loc: self.src_loc,
         }
     }
 }
@@ -277,6 +344,7 @@ impl<L, S> BasicBlock<L, S> {
             live: IndexSet::new(),
             defined: IndexSet::new(),
             span: DUMMY_SP,
+            src_loc: None,
         }
     }
 
@@ -435,7 +503,12 @@ impl GenTerminator<StructureLabel<StmtOrDecl>> {
 #[derive(Clone, Debug, Default)]
 pub struct SwitchCases {
     cases: Vec<(P<Pat>, Label)>,
-    default: Option<Label>,
+    /// The `default` label, with the span carrying the comments before it
+    default: Option<(Label, Span)>,
+    /// The enum switched over, when it is translated into a Rust enum
+    rust_enum: Option<CEnumId>,
+    /// The variants of that enum which have a case
+    variants: IndexSet<CEnumConstantId>,
 }
 
 /// A Rust statement, or a C declaration, or a comment
@@ -498,6 +571,10 @@ pub struct Cfg<Lbl: Ord + Hash, Stmt> {
 
     /// Branching in the graph
     multiples: MultipleInfo<Lbl>,
+
+    /// What some of the synthetic labels stand for in the C control flow (loop body, `else`
+    /// branch, ...)
+    label_roles: IndexMap<Lbl, &'static str>,
 }
 
 impl<L: Clone + Ord + Hash, S1> Cfg<L, S1> {
@@ -512,12 +589,14 @@ impl<L: Clone + Ord + Hash, S1> Cfg<L, S1> {
             .collect();
         let loops = self.loops.clone();
         let multiples = self.multiples.clone();
+        let label_roles = self.label_roles.clone();
 
         Cfg {
             entries,
             nodes,
             loops,
             multiples,
+            label_roles,
         }
     }
 }
@@ -531,6 +610,18 @@ impl<L: Serialize + Ord + Hash, St: Serialize> Serialize for Cfg<L, St> {
     }
 }
 
+/// The point of the translation of a function at which `--ddump-function-cfgs`,
+/// `--json-function-cfgs` and `--html-function-cfgs` take their snapshot
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CfgDumpStage {
+    /// The CFG as built from the C statements
+    Raw,
+    /// The CFG once empty and unreachable blocks have been pruned
+    Pruned,
+    /// The structures produced by relooper
+    Relooped,
+}
+
 /// Reaching the end of a body without encountering a `return` means different things depending on
 /// the function we are in.
 #[derive(Copy, Clone, Debug)]
@@ -581,6 +672,13 @@ impl Cfg<Label, StmtOrDecl> {
         }
 
         let mut cfg_builder = CfgBuilder::new(c_label_to_goto);
+        cfg_builder.local_uses = match ret {
+            ImplicitReturnType::StmtExpr(..) => None,
+            _ if translator.tcfg.incremental_relooper => {
+                Some(LocalUses::new(&translator.ast_context, stmt_ids))
+            }
+            _ => None,
+        };
         let entry = cfg_builder.entry;
         cfg_builder.per_stmt_stack.push(PerStmt::new(
             stmt_ids.get(0).cloned(),
@@ -652,7 +750,7 @@ impl Cfg<Label, StmtOrDecl> {
 
         // Make a CFG from the PerStmt.
 
-        let (graph, decls_seen, live_in) = last_per_stmt.into_cfg();
+        let (graph, decls_seen, live_in) = last_per_stmt.into_cfg(&cfg_builder.label_roles);
         assert!(live_in.is_empty(), "non-empty live_in");
 
         Ok((graph, decls_seen))
@@ -664,33 +762,41 @@ use std::fmt::Debug;
 /// The polymorphism here is only to make it clear exactly how little these functions need to know
 /// about the actual contents of the CFG - we only actual call these on one monomorphic CFG type.
 impl<Lbl: Copy + Ord + Hash + Debug, Stmt> Cfg<Lbl, Stmt> {
-    /// Removes blocks that cannot be reached from the CFG
-    pub fn prune_unreachable_blocks_mut(&mut self) -> () {
-        let visited: IndexSet<Lbl> = {
-            let mut visited: IndexSet<Lbl> = IndexSet::new();
-            let mut to_visit: Vec<Lbl> = vec![self.entries];
+    /// What some of the synthetic labels of the graph stand for in the C control flow
+    pub fn label_roles(&self) -> &IndexMap<Lbl, &'static str> {
+        &self.label_roles
+    }
 
-            while let Some(lbl) = to_visit.pop() {
-                if visited.contains(&lbl) {
-                    continue;
-                }
+    /// Labels of the blocks that can be reached from the entry of the CFG
+    pub fn reachable_labels(&self) -> IndexSet<Lbl> {
+        let mut visited: IndexSet<Lbl> = IndexSet::new();
+        let mut to_visit: Vec<Lbl> = vec![self.entries];
 
-                let blk = self.nodes.get(&lbl).expect(&format!(
-                    "prune_unreachable_blocks: block not found\n{:?}\n{:?}",
-                    lbl,
-                    self.nodes.keys().cloned().collect::<Vec<Lbl>>()
-                ));
-                visited.insert(lbl);
+        while let Some(lbl) = to_visit.pop() {
+            if visited.contains(&lbl) {
+                continue;
+            }
 
-                for lbl in blk.terminator.get_labels() {
-                    if !visited.contains(lbl) {
-                        to_visit.push(*lbl);
-                    }
+            let blk = self.nodes.get(&lbl).expect(&format!(
+                "reachable_labels: block not found\n{:?}\n{:?}",
+                lbl,
+                self.nodes.keys().cloned().collect::<Vec<Lbl>>()
+            ));
+            visited.insert(lbl);
+
+            for lbl in blk.terminator.get_labels() {
+                if !visited.contains(lbl) {
+                    to_visit.push(*lbl);
                 }
             }
+        }
 
-            visited
-        };
+        visited
+    }
+
+    /// Removes blocks that cannot be reached from the CFG
+    pub fn prune_unreachable_blocks_mut(&mut self) -> () {
+        let visited: IndexSet<Lbl> = self.reachable_labels();
 
         self.nodes.retain(|lbl, _| visited.contains(lbl));
         self.loops.filter_unreachable(&visited);
@@ -736,14 +842,21 @@ impl<Lbl: Copy + Ord + Hash + Debug, Stmt> Cfg<Lbl, Stmt> {
             for from in from_any {
                 if from != to_final {
                     let span = self.nodes[&from].span;
-                    let tgt_span = &mut self.nodes[&to_final].span;
-                    if tgt_span.is_dummy() {
-                        *tgt_span = span;
+                    let src_loc = self.nodes[&from].src_loc;
+                    let tgt = &mut self.nodes[&to_final];
+                    if tgt.span.is_dummy() {
+                        tgt.span = span;
                     } else if !span.is_dummy() {
                         // If we can't transfer this basic block's span to the
                         // target, don't delete it
                         continue;
                     }
+                    if tgt.src_loc.is_none() {
+                        tgt.src_loc = src_loc;
+                    }
+                    if let Some(role) = self.label_roles.swap_remove(&from) {
+                        self.label_roles.entry(to_final).or_insert(role);
+                    }
                     actual_rewrites.insert(from, to_final);
                 }
             }
@@ -801,16 +914,24 @@ struct CfgBuilder {
     /// Accumulates information for the 'case'/'default' encountered so far while translating the
     /// body of a 'switch'.
     switch_expr_cases: Vec<SwitchCases>,
+    /// Span of the statement whose translation started last, which precedes or encloses the
+    /// current one. It bounds the span of the blocks started for 'case'/'default' labels.
+    prev_stmt_span: Span,
 
     // Fresh ID sources
     /// Source for generating fresh synthetic labels
     prev_label: u64,
     /// Source for generating fresh loop IDs
     prev_loop_id: u64,
+    /// Roles of the synthetic labels generated for the parts of C control-flow constructs
+    label_roles: IndexMap<Label, &'static str>,
 
     /// Global (immutable) mapping of `CLabelId` -> ID of pointing gotos (basically, reverse the dir
     /// of the goto)
     c_label_to_goto: IndexMap<CLabelId, IndexSet<CStmtId>>,
+    /// Uses of the function's variables, used to recognize `for` loops over ranges. Missing when
+    /// the statements are only part of a function.
+    local_uses: Option<LocalUses>,
 
     // Book-keeping information to build up the `loops` and `multiples` fields in `graph`.
     /// Loops we are currently in. Every time we enter a loop, we push a new vector onto this field.
@@ -853,6 +974,16 @@ struct PerStmt {
     live_in: IndexSet<CDeclId>,
 }
 
+/// Statements relooped on their own by `convert_region_help`
+struct ConvertedRegion {
+    stmts: Vec<Stmt>,
+    /// Declarations live going into the statements
+    live_in: IndexSet<CDeclId>,
+    /// Declarations to make before the statements
+    hoisted: Vec<(CDeclId, DeclStmtInfo)>,
+    stmt_id: CStmtId,
+}
+
 impl PerStmt {
     /// Create a fresh `PerStmt`
     pub fn new(stmt_id: Option<CStmtId>, entry: Label, live_in: IndexSet<CDeclId>) -> PerStmt {
@@ -941,18 +1072,25 @@ impl PerStmt {
     }
 
     /// Flatten the current `PerStmt` into
-    pub fn into_cfg(self) -> (Cfg<Label, StmtOrDecl>, DeclStmtStore, IndexSet<CDeclId>) {
-        // Synthesize a CFG from the current `PerStmt`
-        let mut graph = Cfg {
+    pub fn into_cfg(
+        self,
+        label_roles: &IndexMap<Label, &'static str>,
+    ) -> (Cfg<Label, StmtOrDecl>, DeclStmtStore, IndexSet<CDeclId>) {
+        // Synthesize a CFG from the current `PerStmt`. It is only pruned once it reaches
+        // `convert_cfg`, so that the raw graph can still be dumped.
+        let label_roles = label_roles
+            .iter()
+            .filter(|(lbl, _)| self.nodes.contains_key(*lbl))
+            .map(|(lbl, role)| (*lbl, *role))
+            .collect();
+        let graph = Cfg {
             entries: self.entry,
             nodes: self.nodes,
             loops: self.loop_info,
             multiples: self.multiple_info,
+            label_roles,
         };
 
-        graph.prune_empty_blocks_mut();
-        graph.prune_unreachable_blocks_mut();
-
         (graph, self.decls_seen, self.live_in)
     }
 }
@@ -1126,6 +1264,9 @@ struct WipBlock {
 
     /// Span of this block
     span: Span,
+
+    /// Location in the C source of the statement this block was built from
+    src_loc: Option<SrcSpan>,
 }
 
 impl Extend<Stmt> for WipBlock {
@@ -1193,6 +1334,7 @@ impl CfgBuilder {
             defined,
             live,
             span,
+            src_loc,
         } = wip;
         self.add_block(
             label,
@@ -1202,6 +1344,7 @@ impl CfgBuilder {
                 defined,
                 live,
                 span,
+                src_loc,
             },
         );
     }
@@ -1291,6 +1434,7 @@ impl CfgBuilder {
             defined: IndexSet::new(),
             live: self.current_variables(),
             span: DUMMY_SP,
+            src_loc: None,
         }
     }
 
@@ -1300,7 +1444,14 @@ impl CfgBuilder {
         Label::Synthetic(self.prev_label)
     }
 
-    /// Generate a fresh (synthetic) label.
+    /// Generate a fresh (synthetic) label standing for a given part of a control-flow construct.
+    fn fresh_label_for(&mut self, role: &'static str) -> Label {
+        let label = self.fresh_label();
+        self.label_roles.insert(label, role);
+        label
+    }
+
+    /// Generate a fresh loop ID.
     fn fresh_loop_id(&mut self) -> LoopId {
         self.prev_loop_id += 1;
         LoopId::new(self.prev_loop_id)
@@ -1317,12 +1468,15 @@ impl CfgBuilder {
 
             prev_label: 0,
             prev_loop_id: 0,
+            label_roles: IndexMap::new(),
 
             c_label_to_goto,
+            local_uses: None,
 
             break_labels: vec![],
             continue_labels: vec![],
             switch_expr_cases: vec![],
+            prev_stmt_span: DUMMY_SP,
 
             currently_live: vec![IndexSet::new()],
 
@@ -1340,22 +1494,316 @@ impl CfgBuilder {
         in_tail: Option<ImplicitReturnType>, // Are we in tail position (is there anything to fallthrough to)?
         entry: Label,                        // Current WIP block
     ) -> Result<Option<Label>, TranslationError> {
-        self.with_scope(
-            translator,
-            |slf| -> Result<Option<Label>, TranslationError> {
-                let mut lbl = Some(entry);
-                let last = stmt_ids.last();
-
-                // We feed the optional output label into the entry label of the next block
-                for stmt in stmt_ids {
-                    let new_label: Label = lbl.unwrap_or(slf.fresh_label());
-                    let sub_in_tail = in_tail.filter(|_| Some(stmt) == last);
-                    lbl = slf.convert_stmt_help(translator, ctx, *stmt, sub_in_tail, new_label)?;
+        self.with_scope(translator, |slf| {
+            slf.convert_stmt_seq_help(translator, ctx, stmt_ids, in_tail, entry)
+        })
+    }
+
+    /// Same as `convert_stmts_help`, but without opening a scope: declarations made by the
+    /// statements stay in scope afterwards.
+    fn convert_stmt_seq_help(
+        &mut self,
+        translator: &Translation,
+        ctx: ExprContext,
+        stmt_ids: &[CStmtId],
+        in_tail: Option<ImplicitReturnType>,
+        entry: Label,
+    ) -> Result<Option<Label>, TranslationError> {
+        let mut lbl = Some(entry);
+        let last = stmt_ids.last();
+        let cleanup_regions = if translator.tcfg.incremental_relooper {
+            self.cleanup_regions(translator, stmt_ids)
+        } else {
+            IndexMap::new()
+        };
+
+        // We feed the optional output label into the entry label of the next block
+        let mut i = 0;
+        while i < stmt_ids.len() {
+            let stmt = &stmt_ids[i];
+            let new_label: Label = lbl.unwrap_or(self.fresh_label());
+
+            // Statements followed by the cleanup label they `goto` go into a labelled
+            // block, unless something else jumps in or out of them
+            if let Some(&label_idx) = cleanup_regions.get(&i) {
+                let label_stmt = stmt_ids[label_idx];
+                let exit = self.fresh_label();
+                let exits = indexset![exit, Label::FromC(label_stmt)];
+                let brk_name = cleanup_label_name(translator, label_stmt);
+                let region = &stmt_ids[i..label_idx];
+                let used_after: IndexSet<CDeclId> = stmt_ids[label_idx..]
+                    .iter()
+                    .flat_map(|&stmt_id| DFExpr::new(&translator.ast_context, stmt_id.into()))
+                    .flat_map(SomeId::expr)
+                    .filter_map(|expr_id| match translator.ast_context[expr_id].kind {
+                        CExprKind::DeclRef(_, decl_id, _) => Some(decl_id),
+                        _ => None,
+                    })
+                    .collect();
+                let in_block = self.convert_breakable_help(
+                    translator, ctx, region, &exits, brk_name, &used_after, new_label,
+                )?;
+
+                let sub_in_tail = in_tail.filter(|_| Some(&label_stmt) == last);
+                lbl = match translator.ast_context[label_stmt].kind {
+                    // No `goto` is left for the label, so only its statement is needed
+                    CStmtKind::Label(sub_stmt) if in_block => self.convert_stmt_help(
+                        translator, ctx, sub_stmt, sub_in_tail, exit,
+                    )?,
+                    _ => self.convert_stmt_help(
+                        translator, ctx, label_stmt, sub_in_tail, exit,
+                    )?,
+                };
+                i = label_idx + 1;
+                continue;
+            }
+
+            let sub_in_tail = in_tail.filter(|_| Some(stmt) == last);
+            lbl = self.convert_stmt_help(translator, ctx, *stmt, sub_in_tail, new_label)?;
+            i += 1;
+        }
+
+        Ok(lbl)
+    }
+
+    /// Find the statements of a sequence that are followed by a cleanup label, that is a label
+    /// that is only the target of `goto`s in statements of the sequence coming before it. Keys are
+    /// the index of the first statement with such a `goto`, values the index of the label.
+    fn cleanup_regions(
+        &self,
+        translator: &Translation,
+        stmt_ids: &[CStmtId],
+    ) -> IndexMap<usize, usize> {
+        let mut regions: IndexMap<usize, usize> = IndexMap::new();
+
+        // Index of the statement of the sequence each `goto` is in
+        let mut goto_idxs: IndexMap<CStmtId, usize> = IndexMap::new();
+        for (idx, &stmt_id) in stmt_ids.iter().enumerate() {
+            for id in DFExpr::new(&translator.ast_context, stmt_id.into()).flat_map(SomeId::stmt) {
+                if let CStmtKind::Goto(_) = translator.ast_context[id].kind {
+                    goto_idxs.insert(id, idx);
                 }
+            }
+        }
 
-                Ok(lbl)
-            },
-        )
+        for (label_idx, &label_stmt) in stmt_ids.iter().enumerate() {
+            match translator.ast_context[label_stmt].kind {
+                CStmtKind::Label(_) => {}
+                _ => continue,
+            }
+            let gotos = match self.c_label_to_goto.get(&label_stmt) {
+                Some(gotos) => gotos,
+                None => continue,
+            };
+
+            let idxs: Option<Vec<usize>> = gotos
+                .iter()
+                .map(|goto| goto_idxs.get(goto).cloned().filter(|&idx| idx < label_idx))
+                .collect();
+            let first = match idxs.and_then(|idxs| idxs.into_iter().min()) {
+                Some(first) => first,
+                None => continue,
+            };
+
+            // A region containing earlier ones replaces them, since they are found again when
+            // converting its statements. Regions that overlap without nesting are skipped.
+            let overlapping: Vec<usize> = regions
+                .iter()
+                .filter(|&(_, &other_label_idx)| other_label_idx >= first)
+                .map(|(&other_first, _)| other_first)
+                .collect();
+            if overlapping.iter().all(|&other_first| other_first >= first) {
+                for other_first in overlapping {
+                    regions.swap_remove(&other_first);
+                }
+                regions.insert(first, label_idx);
+            }
+        }
+
+        regions
+    }
+
+    /// Translate statements that can only be left by falling through or by jumping to one of
+    /// `exits` into a labelled block, whose `break`s stand for these jumps. This is how `goto`s to
+    /// a cleanup label and `break`s out of `do { ... } while (0)` are translated. For instance,
+    ///
+    /// ```c
+    /// if (!(buf = malloc(n))) goto out;
+    /// if (read(fd, buf, n) < 0) goto out;
+    /// process(buf);
+    /// out:
+    /// free(buf);
+    /// ```
+    ///
+    /// becomes `'out: { ... if read(fd, buf, n) < 0 { break 'out; } process(buf); } free(buf);`.
+    /// Declarations made by the statements that are still in scope and in `used_after` are placed
+    /// before the block.
+    ///
+    /// The statements are followed by the block labelled with the first of `exits`. Returns
+    /// whether they could be turned into a labelled block: if something else jumps in or out of
+    /// them, they are left in the CFG as usual.
+    fn convert_breakable_help(
+        &mut self,
+        translator: &Translation,
+        ctx: ExprContext,
+        stmt_ids: &[CStmtId],
+        exits: &IndexSet<Label>,
+        brk_lbl: String,
+        used_after: &IndexSet<CDeclId>,
+        entry: Label,
+    ) -> Result<bool, TranslationError> {
+        let exit = *exits.iter().next().expect("There should be a block following the statements");
+        let brk = mk().semi_stmt(mk().break_expr_value(Some(&brk_lbl), None as Option<P<Expr>>));
+        let exits = exits.iter().map(|&lbl| (lbl, brk.clone())).collect();
+
+        let region = match self.convert_region_help(translator, ctx, stmt_ids, &exits, used_after, entry)? {
+            Some(region) => region,
+            None => return Ok(false),
+        };
+        let mut stmts = region.stmts;
+
+        IncCleanup::new(None, brk_lbl.clone()).remove_tail_expr(&mut stmts);
+        if inc_cleanup::jumps_to(&stmts, &brk_lbl) {
+            translator.use_feature("label_break_value");
+            let block = mk().labelled_block_expr(mk().block(stmts), brk_lbl);
+            stmts = vec![mk().expr_stmt(block)];
+        }
+
+        let mut wip = self.new_wip_block(entry);
+        wip.live = region.live_in;
+        wip.src_loc = translator.ast_context.get_src_loc(SomeId::Stmt(region.stmt_id));
+        for (decl, info) in region.hoisted {
+            self.last_per_stmt_mut().decls_seen.store.insert(decl, info);
+            wip.push_decl(decl);
+            wip.defined.insert(decl);
+        }
+        wip.extend(stmts);
+        self.add_wip_block(wip, Jump(exit));
+
+        Ok(true)
+    }
+
+    /// Translate statements that can only be left by falling through or by jumping to one of the
+    /// keys of `exits` into Rust statements of their own, in which these jumps are replaced by the
+    /// matching statements (labelled `break`s or `continue`s). Falling through jumps to the first
+    /// of `exits`. Declarations made by the statements that are still in scope and in
+    /// `used_after` are split out of them, for the caller to place before the statements.
+    ///
+    /// Returns `None` if something else jumps in or out of the statements, in which case they are
+    /// left in the CFG as usual.
+    fn convert_region_help(
+        &mut self,
+        translator: &Translation,
+        ctx: ExprContext,
+        stmt_ids: &[CStmtId],
+        exits: &IndexMap<Label, Stmt>,
+        used_after: &IndexSet<CDeclId>,
+        entry: Label,
+    ) -> Result<Option<ConvertedRegion>, TranslationError> {
+        let exit = *exits.keys().next().expect("There should be a block following the statements");
+
+        let live_in: IndexSet<CDeclId> = self.current_variables();
+        self.per_stmt_stack
+            .push(PerStmt::new(stmt_ids.first().cloned(), entry, live_in.clone()));
+
+        let body_exit = self.convert_stmt_seq_help(translator, ctx, stmt_ids, None, entry)?;
+        if let Some(body_exit) = body_exit {
+            let wip = self.new_wip_block(body_exit);
+            self.add_wip_block(wip, Jump(exit));
+        }
+
+        let mut per_stmt = self.per_stmt_stack.pop().unwrap();
+
+        // The jumps to the exits are the ones the statements replace
+        let exit_gotos: Vec<(CLabelId, IndexSet<CStmtId>)> = exits
+            .keys()
+            .filter_map(|lbl| match *lbl {
+                Label::FromC(label_id) => per_stmt
+                    .c_labels_used
+                    .swap_remove(&label_id)
+                    .map(|gotos| (label_id, gotos)),
+                Label::Synthetic(_) => None,
+            })
+            .collect();
+        let saw_unmatched = (per_stmt.saw_unmatched_break, per_stmt.saw_unmatched_continue);
+        if self.break_labels.last().map_or(false, |lbl| exits.contains_key(lbl)) {
+            per_stmt.saw_unmatched_break = false;
+        }
+        if self.continue_labels.last().map_or(false, |lbl| exits.contains_key(lbl)) {
+            per_stmt.saw_unmatched_continue = false;
+        }
+
+        let branches_out = per_stmt.nodes.values().any(|bb| match bb.terminator {
+            Jump(_) => false,
+            ref term => term.get_labels().into_iter().any(|lbl| exits.contains_key(lbl)),
+        });
+        if branches_out || !per_stmt.is_contained(&self.c_label_to_goto, &live_in) {
+            per_stmt.c_labels_used.extend(exit_gotos);
+            per_stmt.saw_unmatched_break = saw_unmatched.0;
+            per_stmt.saw_unmatched_continue = saw_unmatched.1;
+            self.last_per_stmt_mut().absorb(per_stmt);
+            return Ok(None);
+        }
+
+        for bb in per_stmt.nodes.values_mut() {
+            let exit_stmt = match bb.terminator {
+                Jump(ref lbl) => exits.get(lbl),
+                _ => None,
+            };
+            if let Some(exit_stmt) = exit_stmt {
+                bb.body.push(StmtOrDecl::Stmt(exit_stmt.clone()));
+                bb.terminator = End;
+            }
+        }
+
+        // Declarations still in scope are made before the statements, and only assigned in them,
+        // unless they aren't used after them anyway
+        let (hoisted, scoped): (Vec<CDeclId>, Vec<CDeclId>) = self
+            .current_variables()
+            .into_iter()
+            .filter(|decl| !live_in.contains(decl))
+            .partition(|decl| used_after.contains(decl));
+        for decl in scoped {
+            self.currently_live.last_mut().unwrap().remove(&decl);
+        }
+        let mut hoisted_infos = vec![];
+        for &decl in &hoisted {
+            let decl_stmts = per_stmt.decls_seen.extract_decl(decl)?;
+            let assign_stmts = per_stmt.decls_seen.extract_assign(decl)?;
+            for bb in per_stmt.nodes.values_mut() {
+                bb.defined.remove(&decl);
+                let body = std::mem::replace(&mut bb.body, vec![]);
+                for s in body {
+                    match s {
+                        StmtOrDecl::Decl(d) if d == decl => bb
+                            .body
+                            .extend(assign_stmts.iter().cloned().map(StmtOrDecl::Stmt)),
+                        s => bb.body.push(s),
+                    }
+                }
+            }
+            hoisted_infos.push((decl, DeclStmtInfo::new(decl_stmts.clone(), vec![], decl_stmts)));
+        }
+
+        let stmt_id = per_stmt.stmt_id.unwrap_or(CStmtId(0));
+        let (graph, store, mut graph_live_in) = per_stmt.into_cfg(&self.label_roles);
+        graph_live_in.extend(hoisted.iter().cloned());
+
+        let mut stmts = translator.convert_cfg(
+            &format!("<substmt_{:?}>", stmt_id),
+            graph,
+            store,
+            graph_live_in,
+            false,
+        )?;
+        inc_cleanup::flatten_early_exits(&mut stmts);
+
+        Ok(Some(ConvertedRegion {
+            stmts,
+            live_in,
+            hoisted: hoisted_infos,
+            stmt_id,
+        }))
     }
 
     /// Translate a C statement, inserting it into the CFG under the label key passed in.
@@ -1388,6 +1836,8 @@ impl CfgBuilder {
         let mut wip = self.new_wip_block(entry);
 
         wip.span = translator.get_span(SomeId::Stmt(stmt_id)).unwrap_or(DUMMY_SP);
+        let prev_stmt_span = std::mem::replace(&mut self.prev_stmt_span, wip.span);
+        wip.src_loc = translator.ast_context.get_src_loc(SomeId::Stmt(stmt_id));
 
         let out_wip: Result<Option<WipBlock>, TranslationError> =
             match translator.ast_context.index(stmt_id).kind {
@@ -1408,7 +1858,16 @@ impl CfgBuilder {
                 }
 
                 CStmtKind::Return(expr) => {
-                    let val = match expr.map(|i| translator.convert_expr(ctx.used(), i)) {
+                    // Returned values are always rvalues in C, so an lvalue
+                    // here is a C++ function returning a reference.
+                    let convert = |i: CExprId| {
+                        if translator.ast_context[i].kind.lrvalue().is_lvalue() {
+                            translator.convert_reference_binding(ctx.used(), i)
+                        } else {
+                            translator.convert_expr(ctx.used(), i)
+                        }
+                    };
+                    let val = match expr.map(convert) {
                         Some(r) => Some(r?),
                         None => None,
                     };
@@ -1427,12 +1886,12 @@ impl CfgBuilder {
                     true_variant,
                     false_variant,
                 } => {
-                    let next_entry = self.fresh_label();
-                    let then_entry = self.fresh_label();
+                    let next_entry = self.fresh_label_for("AfterIf");
+                    let then_entry = self.fresh_label_for("Then");
                     let else_entry = if false_variant.is_none() {
                         next_entry
                     } else {
-                        self.fresh_label()
+                        self.fresh_label_for("Else")
                     };
 
                     // Condition
@@ -1483,9 +1942,9 @@ impl CfgBuilder {
                     condition,
                     body: body_stmt,
                 } => {
-                    let cond_entry = self.fresh_label();
-                    let body_entry = self.fresh_label();
-                    let next_entry = self.fresh_label();
+                    let cond_entry = self.fresh_label_for("LoopCond");
+                    let body_entry = self.fresh_label_for("LoopBody");
+                    let next_entry = self.fresh_label_for("AfterLoop");
 
                     self.add_wip_block(wip, Jump(cond_entry));
                     self.open_loop();
@@ -1531,10 +1990,46 @@ impl CfgBuilder {
                 CStmtKind::DoWhile {
                     body: body_stmt,
                     condition,
-                } => {
+                } if translator.tcfg.incremental_relooper
+                    && translator.ast_context[condition].kind.get_bool() == Some(false) =>
+                {
+                    // `do { ... } while (0)` doesn't loop: its body becomes a block, labelled if
+                    // it is left with `break` or `continue`
                     let body_entry = self.fresh_label();
-                    let cond_entry = self.fresh_label();
-                    let next_entry = self.fresh_label();
+                    let next_entry = self.fresh_label_for("AfterLoop");
+
+                    self.add_wip_block(wip, Jump(body_entry));
+
+                    let saw_unmatched_break = self.last_per_stmt_mut().saw_unmatched_break;
+                    let saw_unmatched_continue = self.last_per_stmt_mut().saw_unmatched_continue;
+                    self.break_labels.push(next_entry);
+                    self.continue_labels.push(next_entry);
+
+                    self.convert_breakable_help(
+                        translator,
+                        ctx,
+                        &[body_stmt],
+                        &indexset![next_entry],
+                        next_entry.pretty_print(),
+                        &IndexSet::new(),
+                        body_entry,
+                    )?;
+
+                    self.last_per_stmt_mut().saw_unmatched_break = saw_unmatched_break;
+                    self.last_per_stmt_mut().saw_unmatched_continue = saw_unmatched_continue;
+                    self.break_labels.pop();
+                    self.continue_labels.pop();
+
+                    Ok(Some(self.new_wip_block(next_entry)))
+                }
+
+                CStmtKind::DoWhile {
+                    body: body_stmt,
+                    condition,
+                } => {
+                    let body_entry = self.fresh_label_for("LoopBody");
+                    let cond_entry = self.fresh_label_for("LoopCond");
+                    let next_entry = self.fresh_label_for("AfterLoop");
 
                     self.add_wip_block(wip, Jump(body_entry));
                     self.open_loop();
@@ -1583,15 +2078,25 @@ impl CfgBuilder {
                     increment,
                     body,
                 } => {
-                    let init_entry = self.fresh_label();
-                    let cond_entry = self.fresh_label();
-                    let body_entry = self.fresh_label();
-                    let incr_entry = self.fresh_label();
-                    let next_label = self.fresh_label();
+                    let init_entry = self.fresh_label_for("LoopInit");
+                    let cond_entry = self.fresh_label_for("LoopCond");
+                    let body_entry = self.fresh_label_for("LoopBody");
+                    let incr_entry = self.fresh_label_for("LoopIncrement");
+                    let next_label = self.fresh_label_for("AfterLoop");
+
+                    // A loop over a range of integers becomes a Rust `for` loop, as long as its
+                    // body can be relooped on its own
+                    let for_range = match self.local_uses {
+                        Some(ref uses) => translator.recognize_for_range(stmt_id, uses),
+                        None => None,
+                    };
 
                     self.with_scope(translator, |slf| -> Result<(), TranslationError> {
+                        let live_in = slf.current_variables();
+
                         // Init
                         slf.add_wip_block(wip, Jump(init_entry));
+                        let blocks_before_init = slf.last_per_stmt_mut().nodes.len();
                         let init_stuff: Option<Label> = match init {
                             None => Some(init_entry),
                             Some(init) => {
@@ -1631,12 +2136,67 @@ impl CfgBuilder {
                         slf.break_labels.push(next_label);
                         slf.continue_labels.push(incr_entry);
 
-                        let body_stuff =
-                            slf.convert_stmt_help(translator, ctx, body, None, body_entry)?;
+                        let for_range = match for_range {
+                            Some(range) => translator.convert_for_range(ctx, &range)?,
+                            None => None,
+                        };
+                        let mut body_converted = false;
+                        if let Some((pat, range)) = for_range {
+                            let lbl = next_label.pretty_print();
+                            let exits = indexmap! {
+                                incr_entry => mk().semi_stmt(mk().continue_expr(Some(&lbl))),
+                                next_label => mk().semi_stmt(
+                                    mk().break_expr_value(Some(&lbl), None as Option<P<Expr>>),
+                                ),
+                            };
+                            let region = slf.convert_region_help(
+                                translator,
+                                ctx,
+                                &[body],
+                                &exits,
+                                &IndexSet::new(),
+                                body_entry,
+                            )?;
+                            body_converted = true;
+
+                            if let Some(region) = region {
+                                slf.break_labels.pop();
+                                slf.continue_labels.pop();
+                                slf.loops.pop();
+
+                                // The `for` loop takes care of the initialization and the condition
+                                let nodes = &mut slf.last_per_stmt_mut().nodes;
+                                while nodes.len() > blocks_before_init {
+                                    nodes.pop();
+                                }
+
+                                let mut stmts = region.stmts;
+                                IncCleanup::new(None, lbl.clone()).remove_tail_expr(&mut stmts);
+                                inc_cleanup::unlabel_loop_jumps(&mut stmts, &lbl);
+                                let lbl = if inc_cleanup::jumps_to(&stmts, &lbl) {
+                                    Some(lbl)
+                                } else {
+                                    None
+                                };
+                                let for_expr = mk().for_expr(pat, range, mk().block(stmts), lbl);
+
+                                let mut wip_for = slf.new_wip_block(init_entry);
+                                wip_for.live = live_in;
+                                wip_for.src_loc = translator.ast_context.get_src_loc(SomeId::Stmt(stmt_id));
+                                wip_for.push_stmt(mk().expr_stmt(for_expr));
+                                slf.add_wip_block(wip_for, Jump(next_label));
+                                return Ok(());
+                            }
+                        }
+
+                        if !body_converted {
+                            let body_stuff =
+                                slf.convert_stmt_help(translator, ctx, body, None, body_entry)?;
 
-                        if let Some(body_end) = body_stuff {
-                            let wip_body = slf.new_wip_block(body_end);
-                            slf.add_wip_block(wip_body, Jump(incr_entry));
+                            if let Some(body_end) = body_stuff {
+                                let wip_body = slf.new_wip_block(body_end);
+                                slf.add_wip_block(wip_body, Jump(incr_entry));
+                            }
                         }
 
                         slf.last_per_stmt_mut().saw_unmatched_break = saw_unmatched_break;
@@ -1758,37 +2318,46 @@ impl CfgBuilder {
                 CStmtKind::Case(case_expr, sub_stmt, cie) => {
                     self.last_per_stmt_mut().saw_unmatched_case = true;
                     let this_label = Label::FromC(stmt_id);
+                    let case_span = take_label_comments(&mut wip, prev_stmt_span);
                     self.add_wip_block(wip, Jump(this_label));
 
                     // Case
-                    let resolved = translator.ast_context.resolve_expr(case_expr);
-                    let branch = match resolved.1 {
-                        CExprKind::Literal(..) | CExprKind::ConstantExpr(_, _, Some(_))  => {
-                            match translator
-                                .convert_expr(ctx.used(), resolved.0)?
-                                .to_pure_expr()
-                            {
-                                Some(expr) => match expr.kind {
-                                    ExprKind::Lit(..) | ExprKind::Path(..) => Some(expr),
+                    let switch_cases = self.switch_expr_cases.last_mut().ok_or(format_err!(
+                        "Cannot find the 'switch' wrapping this ({:?}) 'case' statement",
+                        stmt_id,
+                    ))?;
+                    if let Some(enum_id) = switch_cases.rust_enum {
+                        let variant_id = translator
+                            .enum_variant_for(enum_id, cie)
+                            .ok_or(format_err!("Case {:?} is not a variant of its enum", stmt_id))?;
+                        let path = translator
+                            .enum_variant_path(variant_id)
+                            .ok_or(format_err!("Enum constant not named"))?;
+                        switch_cases.variants.insert(variant_id);
+                        switch_cases.cases.push((mk().span(case_span).lit_pat(path), this_label));
+                    } else {
+                        let resolved = translator.ast_context.resolve_expr(case_expr);
+                        let branch = match resolved.1 {
+                            CExprKind::Literal(..) | CExprKind::ConstantExpr(_, _, Some(_))  => {
+                                match translator
+                                    .convert_expr(ctx.used(), resolved.0)?
+                                    .to_pure_expr()
+                                {
+                                    Some(expr) => match expr.kind {
+                                        ExprKind::Lit(..) | ExprKind::Path(..) => Some(expr),
+                                        _ => None,
+                                    }
                                     _ => None,
                                 }
-                                _ => None,
                             }
-                        }
-                        _ => None,
-                    };
-                    let branch = match branch {
-                        Some(expr) => expr,
-                        None => translator.convert_constant(cie)?,
-                    };
-                    self.switch_expr_cases
-                        .last_mut()
-                        .ok_or(format_err!(
-                            "Cannot find the 'switch' wrapping this ({:?}) 'case' statement",
-                            stmt_id,
-                        ))?
-                        .cases
-                        .push((mk().lit_pat(branch), this_label));
+                            _ => None,
+                        };
+                        let branch = match branch {
+                            Some(expr) => expr,
+                            None => translator.convert_constant(cie)?,
+                        };
+                        switch_cases.cases.push((mk().span(case_span).lit_pat(branch), this_label));
+                    }
 
                     // Sub stmt
                     let sub_stmt_next =
@@ -1799,6 +2368,7 @@ impl CfgBuilder {
                 CStmtKind::Default(sub_stmt) => {
                     self.last_per_stmt_mut().saw_unmatched_default = true;
                     let this_label = Label::FromC(stmt_id);
+                    let default_span = take_label_comments(&mut wip, prev_stmt_span);
                     self.add_wip_block(wip, Jump(this_label));
 
                     // Default case
@@ -1806,7 +2376,7 @@ impl CfgBuilder {
                         .last_mut()
                         .expect("'default' outside of 'switch'")
                         .default
-                        .get_or_insert(this_label);
+                        .get_or_insert((this_label, default_span));
 
                     // Sub stmt
                     let sub_stmt_next =
@@ -1818,12 +2388,13 @@ impl CfgBuilder {
                     scrutinee,
                     body: switch_body,
                 } => {
-                    let next_label = self.fresh_label();
-                    let body_label = self.fresh_label();
+                    let next_label = self.fresh_label_for("AfterSwitch");
+                    let body_label = self.fresh_label_for("SwitchBody");
 
-                    // Convert the condition
+                    // Convert the condition, matching on the variants of Rust enums directly
+                    let rust_enum = translator.enum_switch(scrutinee);
                     let (stmts, val) = translator
-                        .convert_expr(ctx.used(), scrutinee)?
+                        .convert_expr(ctx.used(), rust_enum.map_or(scrutinee, |(_, e)| e))?
                         .discard_unsafe();
                     wip.extend(stmts);
 
@@ -1835,7 +2406,10 @@ impl CfgBuilder {
                     let saw_unmatched_case = self.last_per_stmt_mut().saw_unmatched_case;
                     let saw_unmatched_default = self.last_per_stmt_mut().saw_unmatched_default;
                     self.break_labels.push(next_label);
-                    self.switch_expr_cases.push(SwitchCases::default());
+                    self.switch_expr_cases.push(SwitchCases {
+                        rust_enum: rust_enum.map(|(enum_id, _)| enum_id),
+                        ..SwitchCases::default()
+                    });
 
                     let body_stuff =
                         self.convert_stmt_help(translator, ctx, switch_body, in_tail, body_label)?;
@@ -1853,11 +2427,16 @@ impl CfgBuilder {
                         .pop()
                         .expect("No 'SwitchCases' to pop");
 
+                    // A `match` with a case for every variant of a Rust enum is exhaustive
+                    let exhaustive = switch_case.rust_enum.map_or(false, |enum_id| {
+                        switch_case.variants.len() == translator.enum_variant_count(enum_id)
+                    });
                     let mut cases: Vec<_> = switch_case.cases.clone();
-                    cases.push((
-                        mk().wild_pat(),
-                        switch_case.default.unwrap_or(next_label),
-                    ));
+                    if !exhaustive {
+                        let (default_label, default_span) =
+                            switch_case.default.unwrap_or((next_label, DUMMY_SP));
+                        cases.push((mk().span(default_span).wild_pat(), default_label));
+                    }
 
                     // Add the condition basic block terminator (we need the information built up during
                     // the conversion of the body to make the right terminator)
@@ -1970,9 +2549,9 @@ impl CfgBuilder {
         let stmt_id = last_per_stmt.stmt_id.unwrap_or(CStmtId(0));
 
         // Make a CFG from the PerStmt.
-        let (graph, store, live_in) = last_per_stmt.into_cfg();
+        let (graph, store, live_in) = last_per_stmt.into_cfg(&self.label_roles);
         let has_fallthrough: bool = if let Some(fid) = fallthrough_id {
-            graph.nodes.contains_key(&fid)
+            graph.reachable_labels().contains(&fid)
         } else {
             false
         };
@@ -1995,8 +2574,8 @@ impl CfgBuilder {
 
         // Remove unnecessary break statements. We only need a break statement if we failed to
         // remove the tail expr.
-        let need_block =
-            stmts.is_empty() || !IncCleanup::new(in_tail, brk_lbl).remove_tail_expr(&mut stmts);
+        let need_block = stmts.is_empty()
+            || !IncCleanup::new(in_tail, brk_lbl.pretty_print()).remove_tail_expr(&mut stmts);
 
         if has_fallthrough && need_block && use_brk_lbl {
             translator.use_feature("label_break_value");
@@ -2006,6 +2585,7 @@ impl CfgBuilder {
         }
 
         let mut flattened_wip = self.new_wip_block(entry);
+        flattened_wip.src_loc = translator.ast_context.get_src_loc(SomeId::Stmt(stmt_id));
         // Copy span from removed statement if there was only one.
         if stmts.is_empty() {
             if let Some(span) = inner_span {
@@ -2029,6 +2609,42 @@ impl CfgBuilder {
     }
 }
 
+/// Take the span of the block started for a `case` or `default` label, whose comments are those
+/// before the label and go on its match arm. Comments at the end of the labelled statement stay
+/// with the block, which starts where `prev_stmt`, the statement preceding or enclosing the label,
+/// has no comments left to give.
+fn take_label_comments(wip: &mut WipBlock, prev_stmt: Span) -> Span {
+    let span = wip.span;
+    wip.span = if span.lo() == span.hi() {
+        DUMMY_SP
+    } else if prev_stmt.hi() <= span.lo() {
+        span.with_lo(prev_stmt.hi())
+    } else {
+        span.with_lo(prev_stmt.lo())
+    };
+    span.shrink_to_lo()
+}
+
+/// Block label standing for a cleanup label: the name of the C label when it can be used as a Rust
+/// label (and can't be confused with the labels generated from `Label`s), the generated one
+/// otherwise.
+fn cleanup_label_name(translator: &Translation, label_id: CLabelId) -> String {
+    let is_generated = |name: &str| {
+        (name.starts_with("c_") || name.starts_with("s_"))
+            && name[2..].chars().all(|c| c.is_ascii_digit())
+    };
+    match translator.ast_context.label_names.get(&label_id) {
+        Some(name)
+            if name != "_"
+                && !is_generated(name)
+                && !crate::convert_type::RESERVED_NAMES.contains(&name.as_str()) =>
+        {
+            format!("'{}", name)
+        }
+        _ => Label::FromC(label_id).pretty_print(),
+    }
+}
+
 /// This impl block deals with pretty-printing control flow graphs into a format that `dot` can
 /// consume. Compiling these files into images means running something like:
 ///
@@ -2199,3 +2815,11 @@ impl Cfg<Label, StmtOrDecl> {
         Ok(())
     }
 }
+
+/// Write out a JSON representation of the structures relooper produced
+pub fn dump_json_structures(root: &Vec<Structure<Stmt>>, file_path: String) -> io::Result<()> {
+    let file = File::create(file_path)?;
+    serde_json::to_writer(file, root)?;
+
+    Ok(())
+}
@@ -118,6 +118,18 @@ impl<Lbl: Hash + Ord + Clone> MultipleInfo<Lbl> {
         }
     }
 
+    /// Every label mentioned in the multiple information
+    pub fn labels(&self) -> impl Iterator<Item = &Lbl> {
+        self.multiples.iter().flat_map(|(entries, &(ref join_lbl, ref arms))| {
+            entries
+                .iter()
+                .chain(std::iter::once(join_lbl))
+                .chain(arms.iter().flat_map(|(arm_lbl, arm_body)| {
+                    std::iter::once(arm_lbl).chain(arm_body.iter())
+                }))
+        })
+    }
+
     /// Look up the multiple, if there is one, which corresponds to the given set of entry labels.
     pub fn get_multiple<'a>(
         &'a self,
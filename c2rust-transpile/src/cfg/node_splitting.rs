@@ -0,0 +1,480 @@
+//! This module implements node splitting, an optional pass run on a `Cfg` before relooping in
+//! order to reduce the number of `Multiple` structures (and so of `current_block` dispatches)
+//! relooper has to produce.
+//!
+//! Relooper falls back on `Multiple` when several paths through the graph merge again in a way
+//! that doesn't nest, or when a loop can be entered at several points. Giving each incoming path
+//! its own copy of the shared block (classic node splitting) or of the whole shared tail removes
+//! the merge. For instance, in
+//!
+//! ```c
+//! if (a) { x(); goto out; }
+//! if (b) { y(); goto err; }
+//! z();
+//! err: cleanup();
+//! out: return;
+//! ```
+//!
+//! the blocks after `err` can be duplicated so that the `goto err` path and the fall-through path
+//! each end with their own `cleanup(); return;`.
+//!
+//! Duplicating code has a cost, so splitting is bounded by a budget counted in statements. A
+//! split is only kept if it actually lowers the number of `current_block` dispatches. Telling
+//! whether it does means relooping the whole function, so the number of splits tried is bounded
+//! too, which keeps the pass linear in the size of the function.
+//!
+//! The same duplication is used, within the same budget, for the common case of a `switch` arm
+//! falling through into the next one (see `split_switch_fallthrough`).
+
+use super::*;
+
+/// Largest number of splits `split_nodes` evaluates, each of which reloops the function
+const MAX_TRIALS: usize = 32;
+
+/// Duplicate shared blocks or tails of the CFG for as long as this lowers the number of
+/// dispatches counted by `count_dispatches` and the number of duplicated statements stays within
+/// `budget`. At most `MAX_TRIALS` splits are evaluated.
+pub fn split_nodes<F>(
+    cfg: Cfg<Label, StmtOrDecl>,
+    budget: usize,
+    count_dispatches: F,
+) -> Cfg<Label, StmtOrDecl>
+where
+    F: Fn(&Cfg<Label, StmtOrDecl>) -> usize,
+{
+    let mut best_count = count_dispatches(&cfg);
+    let mut best = cfg;
+    let mut remaining = budget;
+    let mut tried: IndexSet<Label> = IndexSet::new();
+    let mut trials = 0;
+
+    while best_count > 0 && trials < MAX_TRIALS {
+        let predecessors = predecessor_map(&best);
+
+        // Candidates are declaration-free blocks with several predecessors, on their own or
+        // together with the acyclic tail they head. Cheaper regions get tried first.
+        let mut candidates: Vec<(usize, Label, IndexSet<Label>)> = vec![];
+        for (&lbl, preds) in &predecessors {
+            if preds.len() < 2 || tried.contains(&lbl) || lbl == best.entries {
+                continue;
+            }
+            let node = indexset![lbl];
+            if !is_splittable(&best, &node) {
+                continue;
+            }
+            if let Some(tail) = acyclic_tail(&best, lbl) {
+                if tail.len() > 1 && is_splittable(&best, &tail) {
+                    let cost = region_cost(&best, &tail) * (preds.len() - 1);
+                    candidates.push((cost, lbl, tail));
+                }
+            }
+            let cost = region_cost(&best, &node) * (preds.len() - 1);
+            candidates.push((cost, lbl, node));
+        }
+        candidates.sort_by_key(|&(cost, _, _)| cost);
+
+        let mut improved = false;
+        for (cost, lbl, region) in candidates {
+            if cost > remaining || trials == MAX_TRIALS {
+                break;
+            }
+            tried.insert(lbl);
+            trials += 1;
+
+            let split = duplicate_region(&best, lbl, &region, &predecessors[&lbl]);
+            let count = count_dispatches(&split);
+            if count < best_count {
+                best = split;
+                best_count = count;
+                remaining -= cost;
+                improved = true;
+                break;
+            }
+        }
+
+        if !improved {
+            break;
+        }
+    }
+
+    best
+}
+
+/// Give every `case` of a `switch` that falls through into another one its own copy of the code it
+/// falls into, so that each arm of the resulting `match` ends on its own and relooper doesn't need
+/// `current_block` to share the rest of the arm. For example
+///
+/// ```c
+/// switch (x) {
+///     case 1: case 2: a();
+///     case 3: b(); break;
+///     default: c();
+/// }
+/// ```
+///
+/// becomes `match x { 1 | 2 => { a(); b(); } 3 => b(), _ => c() }`. A case with no code of its
+/// own just shares the arm of the case it falls into, as `1 | 2` does. Only the code that belongs to
+/// the case being fallen into (that can't be reached without going through its label) is copied,
+/// up to the end of the `switch`.
+/// Irregular fallthrough is left to relooper: a case label that is also the target of a `goto`
+/// from outside the `switch`, or a case whose code has loops, declarations or exceeds `budget`
+/// statements.
+///
+/// Like `split_nodes`, this only runs with `--split-nodes`. Without it, only the empty cases are
+/// merged (see `merge_switch_empty_cases`) and the rest of the fallthrough is left to relooper.
+pub fn split_switch_fallthrough(
+    cfg: Cfg<Label, StmtOrDecl>,
+    budget: usize,
+) -> Cfg<Label, StmtOrDecl> {
+    let switches: Vec<(Label, IndexSet<Label>)> = cfg
+        .nodes
+        .iter()
+        .filter_map(|(&lbl, bb)| match bb.terminator {
+            Switch { ref cases, .. } => Some((lbl, cases.iter().map(|&(_, tgt)| tgt).collect())),
+            _ => None,
+        })
+        .collect();
+
+    let mut cfg = cfg;
+    merge_switch_empty_cases(&mut cfg);
+
+    for (head, targets) in switches {
+        let targets: IndexSet<Label> = match cfg.nodes.get(&head) {
+            Some(&BasicBlock {
+                terminator: Switch { ref cases, .. },
+                ..
+            }) => targets
+                .into_iter()
+                .filter(|target| cases.iter().any(|&(_, tgt)| tgt == *target))
+                .collect(),
+            _ => continue,
+        };
+        // Later cases first, so that a chain of fallthroughs only copies each case once per arm
+        for &target in targets.iter().rev() {
+            if target == head {
+                continue;
+            }
+            let predecessors = predecessor_map(&cfg);
+            let preds = match predecessors.get(&target) {
+                Some(preds) if preds.len() > 1 && preds.contains(&head) => preds,
+                _ => continue,
+            };
+            if cfg.label_roles.get(&target) == Some(&"AfterSwitch") {
+                // A case that just breaks out of the `switch`
+                continue;
+            }
+
+            let in_switch = dominated_by(&cfg, head);
+            if !preds.iter().all(|pred| in_switch.contains(pred)) {
+                continue;
+            }
+
+            let mut region = dominated_by(&cfg, target);
+            let is_switch = |lbl: &Label| match cfg.nodes[lbl].terminator {
+                Switch { .. } => true,
+                _ => false,
+            };
+            if !region.iter().any(is_switch) {
+                // Don't copy the code following the `switch`. With a nested `switch`, its own
+                // exit can't be told apart from this one's, so the whole region is kept.
+                let exits: IndexSet<Label> = region
+                    .iter()
+                    .filter(|lbl| cfg.label_roles.get(*lbl) == Some(&"AfterSwitch"))
+                    .cloned()
+                    .collect();
+                let within = reachable_avoiding(&cfg, target, &exits);
+                region.retain(|lbl| within.contains(lbl));
+            }
+            if !is_splittable(&cfg, &region)
+                || !is_acyclic(&cfg, &region)
+                || region_cost(&cfg, &region) > budget
+            {
+                continue;
+            }
+
+            // The `switch` keeps the original blocks, the cases falling through get the copies
+            let mut ordered: IndexSet<Label> = indexset![head];
+            ordered.extend(preds.iter().cloned());
+            cfg = duplicate_region(&cfg, target, &region, &ordered);
+        }
+    }
+
+    cfg
+}
+
+/// Merge the empty cases of every `switch` into the case they fall into, as `merge_empty_cases`
+/// does, and drop the blocks that are left unreachable. `split_switch_fallthrough` starts with this,
+/// so doing it beforehand only removes blocks, which the copies it makes can then be counted
+/// against.
+pub fn merge_switch_empty_cases(cfg: &mut Cfg<Label, StmtOrDecl>) {
+    let heads: Vec<Label> = cfg
+        .nodes
+        .iter()
+        .filter_map(|(&lbl, bb)| match bb.terminator {
+            Switch { .. } => Some(lbl),
+            _ => None,
+        })
+        .collect();
+
+    let mut merged = false;
+    for head in heads {
+        merged |= merge_empty_cases(cfg, head);
+    }
+    if merged {
+        cfg.prune_unreachable_blocks_mut();
+    }
+}
+
+/// Make the cases of a `switch` that go to an empty block falling through into another case go to
+/// that case directly, so that relooper merges their patterns into a single `a | b` arm. Returns
+/// whether any case changed.
+fn merge_empty_cases(cfg: &mut Cfg<Label, StmtOrDecl>, head: Label) -> bool {
+    let predecessors = predecessor_map(cfg);
+    let mut cases = match cfg.nodes[&head].terminator {
+        Switch { ref cases, .. } => cases.clone(),
+        _ => return false,
+    };
+
+    let mut merged = false;
+    for case in cases.iter_mut() {
+        // The comments before a `case` label are on its pattern, so the empty block itself can go
+        while let Some(bb) = cfg.nodes.get(&case.1) {
+            let next = match bb.terminator {
+                Jump(next) if bb.body.is_empty() && case.1 != head && next != case.1 => next,
+                _ => break,
+            };
+            if predecessors.get(&case.1) != Some(&indexset![head]) {
+                break;
+            }
+            case.1 = next;
+            merged = true;
+        }
+    }
+
+    if merged {
+        if let Some(bb) = cfg.nodes.get_mut(&head) {
+            if let Switch {
+                cases: ref mut old_cases,
+                ..
+            } = bb.terminator
+            {
+                *old_cases = cases;
+            }
+        }
+    }
+    merged
+}
+
+/// The blocks that can only be reached from the entry of the CFG by going through `dominator`,
+/// including `dominator` itself
+fn dominated_by(cfg: &Cfg<Label, StmtOrDecl>, dominator: Label) -> IndexSet<Label> {
+    let avoiding = reachable_avoiding(cfg, cfg.entries, &indexset![dominator]);
+    reachable_avoiding(cfg, dominator, &IndexSet::new())
+        .into_iter()
+        .filter(|lbl| !avoiding.contains(lbl))
+        .collect()
+}
+
+/// The blocks reachable from `start` without going through any of the blocks in `avoid`
+fn reachable_avoiding(
+    cfg: &Cfg<Label, StmtOrDecl>,
+    start: Label,
+    avoid: &IndexSet<Label>,
+) -> IndexSet<Label> {
+    let mut seen: IndexSet<Label> = IndexSet::new();
+    let mut to_visit: Vec<Label> = vec![start];
+    while let Some(lbl) = to_visit.pop() {
+        if avoid.contains(&lbl) || !seen.insert(lbl) {
+            continue;
+        }
+        if let Some(bb) = cfg.nodes.get(&lbl) {
+            to_visit.extend(bb.terminator.get_labels().into_iter().cloned());
+        }
+    }
+    seen
+}
+
+/// Whether the jumps between blocks of `region` never form a cycle
+fn is_acyclic(cfg: &Cfg<Label, StmtOrDecl>, region: &IndexSet<Label>) -> bool {
+    // Repeatedly remove the blocks that no other remaining block of the region jumps to
+    let mut remaining = region.clone();
+    loop {
+        let targeted: IndexSet<Label> = remaining
+            .iter()
+            .flat_map(|lbl| cfg.nodes[lbl].terminator.get_labels().into_iter().cloned())
+            .filter(|succ| remaining.contains(succ))
+            .collect();
+        if targeted.len() == remaining.len() {
+            return remaining.is_empty();
+        }
+        remaining.retain(|lbl| targeted.contains(lbl));
+    }
+}
+
+/// Map every block to the blocks that jump to it
+fn predecessor_map(cfg: &Cfg<Label, StmtOrDecl>) -> IndexMap<Label, IndexSet<Label>> {
+    let mut predecessors: IndexMap<Label, IndexSet<Label>> = IndexMap::new();
+    for (&lbl, bb) in &cfg.nodes {
+        for &succ in bb.terminator.get_labels() {
+            predecessors
+                .entry(succ)
+                .or_insert(IndexSet::new())
+                .insert(lbl);
+        }
+    }
+    predecessors
+}
+
+/// Whether the blocks of a region exist and can be copied. A block declaring a variable can't,
+/// since the variable would then be declared twice.
+fn is_splittable(cfg: &Cfg<Label, StmtOrDecl>, region: &IndexSet<Label>) -> bool {
+    region.iter().all(|lbl| match cfg.nodes.get(lbl) {
+        Some(bb) => {
+            bb.defined.is_empty()
+                && bb.body.iter().all(|s| match s {
+                    StmtOrDecl::Decl(_) => false,
+                    StmtOrDecl::Stmt(_) => true,
+                })
+        }
+        None => false,
+    })
+}
+
+/// The blocks reachable from `head`, provided that they don't form any cycle. Copying a tail
+/// with a loop would copy the loop, and copying one that loops back to `head` would copy the
+/// predecessors of `head` too.
+fn acyclic_tail(cfg: &Cfg<Label, StmtOrDecl>, head: Label) -> Option<IndexSet<Label>> {
+    let mut tail: IndexSet<Label> = IndexSet::new();
+    let mut to_visit: Vec<Label> = vec![head];
+
+    while let Some(lbl) = to_visit.pop() {
+        if !tail.insert(lbl) {
+            continue;
+        }
+
+        let bb = cfg.nodes.get(&lbl)?;
+        for &succ in bb.terminator.get_labels() {
+            if succ == head {
+                return None;
+            }
+            to_visit.push(succ);
+        }
+    }
+
+    if is_acyclic(cfg, &tail) {
+        Some(tail)
+    } else {
+        None
+    }
+}
+
+/// Number of statements a copy of `region` adds, counting at least one per block
+fn region_cost(cfg: &Cfg<Label, StmtOrDecl>, region: &IndexSet<Label>) -> usize {
+    region
+        .iter()
+        .map(|lbl| std::cmp::max(cfg.nodes[lbl].body.len(), 1))
+        .sum()
+}
+
+/// Give every predecessor of `head` but the first its own copy of `region`. Jumps within the
+/// region go to the copy, jumps out of it go to the original blocks.
+fn duplicate_region(
+    cfg: &Cfg<Label, StmtOrDecl>,
+    head: Label,
+    region: &IndexSet<Label>,
+    predecessors: &IndexSet<Label>,
+) -> Cfg<Label, StmtOrDecl> {
+    let mut split = cfg.clone();
+
+    // Pruned labels can linger in the roles, loops and multiples, so fresh labels must be
+    // fresh with respect to those too
+    let mut next_label = cfg
+        .nodes
+        .keys()
+        .chain(cfg.label_roles.keys())
+        .chain(cfg.loops.labels())
+        .chain(cfg.multiples.labels())
+        .filter_map(|lbl| match lbl {
+            Label::Synthetic(id) => Some(*id),
+            Label::FromC(_) => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    for pred in predecessors.iter().skip(1) {
+        let renaming: IndexMap<Label, Label> = region
+            .iter()
+            .map(|&lbl| {
+                next_label += 1;
+                (lbl, Label::Synthetic(next_label))
+            })
+            .collect();
+        let rename = |lbl: &Label| *renaming.get(lbl).unwrap_or(lbl);
+
+        for (lbl, new_lbl) in &renaming {
+            let bb = &cfg.nodes[lbl];
+            let copy = BasicBlock {
+                body: bb.body.clone(),
+                terminator: bb.terminator.map_labels(&rename),
+                live: bb.live.clone(),
+                defined: bb.defined.clone(),
+                span: bb.span,
+                src_loc: bb.src_loc,
+            };
+            split.nodes.insert(*new_lbl, copy);
+            if let Some(role) = cfg.label_roles.get(lbl) {
+                split.label_roles.insert(*new_lbl, role);
+            }
+            split.loops.add_copy(lbl, *new_lbl);
+        }
+
+        let pred_bb = split
+            .nodes
+            .get_mut(pred)
+            .expect("predecessor should be in the CFG");
+        for lbl in pred_bb.terminator.get_labels_mut() {
+            if *lbl == head {
+                *lbl = renaming[&head];
+            }
+        }
+    }
+
+    split
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_labels_skip_stale_roles() {
+        let head = Label::Synthetic(3);
+        // `'s_4` was pruned, but its role is still recorded
+        let cfg: Cfg<Label, StmtOrDecl> = Cfg {
+            entries: Label::Synthetic(0),
+            nodes: indexmap! {
+                Label::Synthetic(0) => BasicBlock::new_jump(Label::Synthetic(1)),
+                Label::Synthetic(1) => BasicBlock::new_jump(head),
+                Label::Synthetic(2) => BasicBlock::new_jump(head),
+                head => BasicBlock::new(End),
+            },
+            loops: LoopInfo::new(),
+            multiples: MultipleInfo::new(),
+            label_roles: indexmap! { Label::Synthetic(4) => "Loop body" },
+        };
+
+        let split = duplicate_region(
+            &cfg,
+            head,
+            &indexset![head],
+            &indexset![Label::Synthetic(1), Label::Synthetic(2)],
+        );
+        let copy = match split.nodes[&Label::Synthetic(2)].terminator {
+            Jump(lbl) => lbl,
+            _ => panic!("The second predecessor should jump to the copy"),
+        };
+        assert_eq!(copy, Label::Synthetic(5));
+        assert!(split.nodes.contains_key(&copy));
+        assert!(!split.label_roles.contains_key(&copy));
+    }
+}
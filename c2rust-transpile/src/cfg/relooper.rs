@@ -3,7 +3,8 @@
 
 use super::*;
 
-/// Convert the CFG into a sequence of structures
+/// Convert the CFG into a sequence of structures. Also returns how many loops could not be matched
+/// to a loop of the C source.
 pub fn reloop(
     cfg: Cfg<Label, StmtOrDecl>, // the control flow graph to reloop
     mut store: DeclStmtStore,    // store of what to do with declarations
@@ -11,7 +12,7 @@ pub fn reloop(
     use_c_loop_info: bool,       // use the loop information in the CFG (slower, but better)
     use_c_multiple_info: bool,   // use the multiple information in the CFG (slower, but better)
     live_in: IndexSet<CDeclId>,  // declarations we assume are live going into this graph
-) -> (Vec<Stmt>, Vec<Structure<Stmt>>) {
+) -> (Vec<Stmt>, Vec<Structure<Stmt>>, usize) {
     let entries: IndexSet<Label> = vec![cfg.entries].into_iter().collect();
     let blocks = cfg
         .nodes
@@ -26,6 +27,7 @@ pub fn reloop(
                     defined: bb.defined,
                     live: bb.live,
                     span: bb.span,
+                    src_loc: bb.src_loc,
                 },
             )
         })
@@ -64,7 +66,7 @@ pub fn reloop(
         relooped = simplify_structure(relooped)
     }
 
-    (lifted_stmts, relooped)
+    (lifted_stmts, relooped, state.unmatched_loops)
 }
 
 /// This is the state we close over while relooping. It accumulates information about which
@@ -81,6 +83,9 @@ struct RelooperState {
 
     /// Information about multiples
     multiple_info: Option<MultipleInfo<Label>>,
+
+    /// Number of loops built heuristically rather than from a C loop
+    unmatched_loops: usize,
 }
 
 impl RelooperState {
@@ -94,6 +99,7 @@ impl RelooperState {
             lifted: IndexSet::new(),
             loop_info,
             multiple_info,
+            unmatched_loops: 0,
         }
     }
 
@@ -196,6 +202,7 @@ impl RelooperState {
                     live,
                     defined,
                     span,
+                    ..
                 } = bb;
 
                 // Flag declarations for everything that is live going in but not already in scope.
@@ -390,6 +397,7 @@ impl RelooperState {
 
             // If matching an existing loop didn't work, fall back on a heuristic
             if !matched_existing_loop {
+                self.unmatched_loops += 1;
                 loops::heuristic_loop_body(
                     &predecessor_map,
                     &mut body_blocks,
@@ -1,5 +1,7 @@
 //! This modules handles converting `Vec<Structure>` into `Vec<Stmt>`.
 
+use syntax::ast::{BinOpKind, Item, Mac, Ty, TyKind};
+use syntax::mut_visit::{self, MutVisitor};
 use syntax::source_map::{dummy_spanned, Spanned};
 use syntax_pos::BytePos;
 
@@ -12,18 +14,24 @@ pub fn structured_cfg(
     root: &Vec<Structure<Stmt>>,
     comment_store: &mut comment_store::CommentStore,
     current_block: P<Expr>,
-    debug_labels: bool,
+    block_labels: &BlockLabels,
     cut_out_trailing_ret: bool,
+    simplify_structures: bool,
+    locals: &IndexSet<String>,
 ) -> Result<Vec<Stmt>, TranslationError> {
     let ast: StructuredAST<P<Expr>, P<Pat>, Label, Stmt> =
         structured_cfg_help(vec![], &IndexSet::new(), root, &mut IndexSet::new())?;
 
     let s = StructureState {
-        debug_labels,
+        block_labels,
         current_block,
     };
     let (mut stmts, _span) = s.into_stmt(ast, comment_store);
 
+    if simplify_structures {
+        if_chains_to_matches(&mut stmts, locals);
+    }
+
     // If the very last statement in the vector is a `return`, we can either cut it out or replace
     // it with the returned value.
     if cut_out_trailing_ret {
@@ -365,6 +373,38 @@ fn structured_cfg_help<
     Ok(rest)
 }
 
+/// Counts the loops that will need a label because they are exited from within an inner loop
+pub fn count_loop_labels(root: &Vec<Structure<Stmt>>) -> Result<usize, TranslationError> {
+    let mut used_loop_labels = IndexSet::new();
+    let _: StructuredAST<P<Expr>, P<Pat>, Label, Stmt> =
+        structured_cfg_help(vec![], &IndexSet::new(), root, &mut used_loop_labels)?;
+    Ok(used_loop_labels.len())
+}
+
+/// Counts the `Multiple` structures anywhere, each of which becomes a `match` on `current_block`.
+pub fn count_multiples<Stmt>(root: &Vec<Structure<Stmt>>) -> usize {
+    root.iter()
+        .map(|structure| match structure {
+            &Structure::Simple { ref terminator, .. } => terminator
+                .get_labels()
+                .into_iter()
+                .map(|structure_label| match structure_label {
+                    &StructureLabel::Nested(ref nested) => count_multiples(nested),
+                    _ => 0,
+                })
+                .sum(),
+            &Structure::Multiple {
+                ref branches,
+                ref then,
+                ..
+            } => {
+                1 + branches.values().map(count_multiples).sum::<usize>() + count_multiples(then)
+            }
+            &Structure::Loop { ref body, .. } => count_multiples(body),
+        })
+        .sum()
+}
+
 /// Checks if there are any `Multiple` structures anywhere. Only if so will there be any need for a
 /// `current_block` variable.
 pub fn has_multiple<Stmt>(root: &Vec<Structure<Stmt>>) -> bool {
@@ -383,8 +423,198 @@ pub fn has_multiple<Stmt>(root: &Vec<Structure<Stmt>>) -> bool {
     })
 }
 
-struct StructureState {
-    debug_labels: bool,
+/// How the labels `current_block` is set to are written out
+pub enum BlockLabels {
+    /// String literals naming the labels, as asked for by `--ddebug-labels`
+    Strings,
+
+    /// Variants of a local enum, given its name and the variant of each label
+    Enum(String, IndexMap<Label, String>),
+}
+
+impl BlockLabels {
+    /// Make an enum whose variants are named after the C labels, `case`s and `default`s, or else
+    /// after the role of the block in the C control flow (loop body, `else` branch, ...).
+    pub fn new_enum(
+        name: String,
+        labels: &IndexSet<Label>,
+        label_roles: &IndexMap<Label, &'static str>,
+        ast_context: &TypedAstContext,
+    ) -> BlockLabels {
+        let bases: Vec<(Label, String)> = labels
+            .iter()
+            .map(|lbl| (*lbl, variant_base_name(lbl, label_roles, ast_context)))
+            .collect();
+
+        let mut counts: IndexMap<&str, usize> = IndexMap::new();
+        for (_, base) in &bases {
+            *counts.entry(base.as_str()).or_insert(0) += 1;
+        }
+
+        // Labels sharing a base name get numbered
+        let mut used: IndexSet<String> = IndexSet::new();
+        let mut next_index: IndexMap<&str, usize> = IndexMap::new();
+        let mut variants: IndexMap<Label, String> = IndexMap::new();
+        for (lbl, base) in &bases {
+            let mut variant = base.clone();
+            if counts[base.as_str()] > 1 || used.contains(&variant) {
+                let index = next_index.entry(base.as_str()).or_insert(0);
+                loop {
+                    *index += 1;
+                    variant = format!("{}{}", base, index);
+                    if !used.contains(&variant) && !counts.contains_key(variant.as_str()) {
+                        break;
+                    }
+                }
+            }
+            used.insert(variant.clone());
+            variants.insert(*lbl, variant);
+        }
+
+        BlockLabels::Enum(name, variants)
+    }
+
+    /// Declaration of the enum, if labels are written as its variants
+    pub fn enum_item(&self) -> Option<P<Item>> {
+        match self {
+            &BlockLabels::Strings => None,
+            &BlockLabels::Enum(ref name, ref variants) => {
+                let variants = variants
+                    .values()
+                    .map(|variant| mk().unit_variant(variant, None as Option<P<Expr>>))
+                    .collect();
+                Some(
+                    mk().call_attr("derive", vec!["Copy", "Clone"])
+                        .enum_item(name, variants),
+                )
+            }
+        }
+    }
+
+    /// Type of the `current_block` variable
+    pub fn block_ty(&self) -> P<Ty> {
+        match self {
+            &BlockLabels::Strings => mk().ref_lt_ty("'static", mk().path_ty(vec!["str"])),
+            &BlockLabels::Enum(ref name, _) => mk().path_ty(vec![name]),
+        }
+    }
+
+    fn variant_path(&self, lbl: &Label) -> Option<Vec<String>> {
+        match self {
+            &BlockLabels::Strings => None,
+            &BlockLabels::Enum(ref name, ref variants) => {
+                let variant = variants
+                    .get(lbl)
+                    .expect("Label missing from the current_block enum");
+                Some(vec![name.clone(), variant.clone()])
+            }
+        }
+    }
+
+    fn label_expr(&self, lbl: &Label) -> P<Expr> {
+        match self.variant_path(lbl) {
+            Some(path) => mk().path_expr(path),
+            None => lbl.to_string_expr(),
+        }
+    }
+
+    fn label_pat(&self, lbl: &Label) -> P<Pat> {
+        match self.variant_path(lbl) {
+            Some(path) => mk().qpath_pat(None, path),
+            None => mk().lit_pat(lbl.to_string_expr()),
+        }
+    }
+}
+
+/// Name of the variant standing for a label, before disambiguation
+fn variant_base_name(
+    lbl: &Label,
+    label_roles: &IndexMap<Label, &'static str>,
+    ast_context: &TypedAstContext,
+) -> String {
+    match lbl {
+        &Label::FromC(stmt_id) => match ast_context[stmt_id].kind {
+            CStmtKind::Label(_) => match ast_context.label_names.get(&stmt_id) {
+                Some(name) => camel_case(name),
+                None => String::from("Label"),
+            },
+            CStmtKind::Case(_, _, ConstIntExpr::U(n)) => format!("Case{}", n),
+            CStmtKind::Case(_, _, ConstIntExpr::I(n)) if n < 0 => {
+                // `-n` overflows on `case LLONG_MIN:`
+                format!("CaseMinus{}", n.wrapping_neg() as u64)
+            }
+            CStmtKind::Case(_, _, ConstIntExpr::I(n)) => format!("Case{}", n),
+            CStmtKind::Default(_) => String::from("Default"),
+            _ => String::from("Block"),
+        },
+        &Label::Synthetic(_) => label_roles.get(lbl).unwrap_or(&"Block").to_string(),
+    }
+}
+
+/// Turn a C label such as `out_of_memory` into a variant name such as `OutOfMemory`
+fn camel_case(name: &str) -> String {
+    let camel: String = name
+        .split('_')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+                .collect::<Vec<char>>()
+        })
+        .collect();
+    match camel.chars().next() {
+        None => String::from("Label"),
+        Some(c) if c.is_ascii_digit() => format!("Label{}", camel),
+        Some(_) if camel == "Self" => String::from("SelfLabel"),
+        Some(_) => camel,
+    }
+}
+
+/// Collects the labels `current_block` is set to or dispatched on
+pub fn dispatch_labels(root: &Vec<Structure<Stmt>>) -> Result<IndexSet<Label>, TranslationError> {
+    let ast: StructuredAST<P<Expr>, P<Pat>, Label, Stmt> =
+        structured_cfg_help(vec![], &IndexSet::new(), root, &mut IndexSet::new())?;
+    let mut labels = IndexSet::new();
+    collect_dispatch_labels(&ast, &mut labels);
+    Ok(labels)
+}
+
+fn collect_dispatch_labels<E, Pa, S>(
+    ast: &StructuredAST<E, Pa, Label, S>,
+    labels: &mut IndexSet<Label>,
+) {
+    use crate::cfg::structures::StructuredASTKind::*;
+
+    match ast.node {
+        Empty | Singleton(_) | Exit(..) => {}
+        Append(ref lhs, ref rhs) | If(_, ref lhs, ref rhs) => {
+            collect_dispatch_labels(lhs, labels);
+            collect_dispatch_labels(rhs, labels);
+        }
+        Goto(to) => {
+            labels.insert(to);
+        }
+        Match(_, ref cases) => {
+            for (_, case) in cases {
+                collect_dispatch_labels(case, labels);
+            }
+        }
+        GotoTable(ref cases, ref then) => {
+            for (lbl, case) in cases {
+                labels.insert(*lbl);
+                collect_dispatch_labels(case, labels);
+            }
+            collect_dispatch_labels(then, labels);
+        }
+        Loop(_, ref body) => collect_dispatch_labels(body, labels),
+    }
+}
+
+struct StructureState<'a> {
+    block_labels: &'a BlockLabels,
     current_block: P<Expr>,
 }
 
@@ -416,7 +646,7 @@ fn span_subst_hi(span: Span, other: Span) -> Option<Span> {
     Some(span)
 }
 
-impl StructureState {
+impl<'a> StructureState<'a> {
     pub fn into_stmt(
         &self,
         ast: StructuredAST<P<Expr>, P<Pat>, Label, Stmt>,
@@ -486,11 +716,7 @@ impl StructureState {
             Goto(to) => {
                 // Assign to `current_block` the next label we want to go to.
 
-                let lbl_expr = if self.debug_labels {
-                    to.to_string_expr()
-                } else {
-                    to.to_num_expr()
-                };
+                let lbl_expr = self.block_labels.label_expr(&to);
                 mk().span(span)
                     .semi_stmt(mk().assign_expr(self.current_block.clone(), lbl_expr))
             }
@@ -584,12 +810,7 @@ impl StructureState {
                     .map(|(lbl, stmts)| -> Arm {
                         let (stmts, stmts_span) = self.into_stmt(stmts, comment_store);
 
-                        let lbl_expr = if self.debug_labels {
-                            lbl.to_string_expr()
-                        } else {
-                            lbl.to_num_expr()
-                        };
-                        let pat = mk().lit_pat(lbl_expr);
+                        let pat = self.block_labels.label_pat(&lbl);
                         let body = mk().block_expr(mk().span(stmts_span).block(stmts));
                         mk().arm(pat, None as Option<P<Expr>>, body)
                     })
@@ -683,3 +904,197 @@ fn not(bool_expr: &P<Expr>) -> P<Expr> {
         _ => mk().unary_expr("!", bool_expr.clone()),
     }
 }
+
+/// `if` chains comparing a value to at least this many constants become `match`es
+const MIN_IF_CHAIN_ARMS: usize = 3;
+
+/// Turn chains of `if`s which compare the same local variable (or a field of it) to constants
+/// into `match`es, keeping the order of the comparisons. `locals` holds the names of the
+/// variables that can be compared. For instance,
+///
+/// ```ignore
+/// if c == 'a' as i32 { .. } else if c == 'b' as i32 || c == 'c' as i32 { .. } else if c == 0 { .. } else { .. }
+/// ```
+///
+/// becomes `match c { 97 => { .. } 98 | 99 => { .. } 0 => { .. } _ => { .. } }`. Like `case`
+/// values, characters are matched by their codes.
+pub fn if_chains_to_matches(stmts: &mut Vec<Stmt>, locals: &IndexSet<String>) {
+    struct IfChains<'a> {
+        locals: &'a IndexSet<String>,
+    }
+
+    impl<'a> MutVisitor for IfChains<'a> {
+        fn visit_expr(&mut self, expr: &mut P<Expr>) {
+            if let Some(mut match_expr) = if_chain_match(expr, self.locals) {
+                match_expr.span = expr.span;
+                *expr = match_expr;
+            }
+            mut_visit::noop_visit_expr(expr, self)
+        }
+
+        fn visit_mac(&mut self, _mac: &mut Mac) {}
+    }
+
+    let mut if_chains = IfChains { locals };
+    *stmts = stmts
+        .drain(..)
+        .flat_map(|stmt| if_chains.flat_map_stmt(stmt))
+        .collect();
+}
+
+/// The `match` equivalent to an `if` chain, if it is long enough
+fn if_chain_match(expr: &Expr, locals: &IndexSet<String>) -> Option<P<Expr>> {
+    let mut scrutinee: Option<(String, P<Expr>)> = None;
+    let mut arms = vec![];
+    let mut rest = expr;
+    let default = loop {
+        let (cond, then, els) = match rest.kind {
+            ExprKind::If(ref cond, ref then, ref els) => (cond, then, els),
+            ExprKind::Block(ref blk, None) => break blk.clone(),
+            _ => return None,
+        };
+        let (place, pats) = match compared_place(cond, locals) {
+            Some(compared) => compared,
+            // The rest of the chain tests something else
+            None => break mk().block(vec![mk().expr_stmt(P(rest.clone()))]),
+        };
+        let place_str = pprust::expr_to_string(&place);
+        match scrutinee {
+            None => scrutinee = Some((place_str, place)),
+            Some((ref scrutinee_str, _)) if *scrutinee_str == place_str => {}
+            Some(_) => break mk().block(vec![mk().expr_stmt(P(rest.clone()))]),
+        }
+
+        let pat = if pats.len() == 1 {
+            pats.into_iter().next().unwrap()
+        } else {
+            mk().or_pat(pats)
+        };
+        arms.push(mk().arm(pat, None as Option<P<Expr>>, mk().block_expr(then.clone())));
+
+        match *els {
+            Some(ref els) => rest = els,
+            None => break mk().block(Vec::<Stmt>::new()),
+        }
+    };
+
+    if arms.len() < MIN_IF_CHAIN_ARMS {
+        return None;
+    }
+    arms.push(mk().arm(mk().wild_pat(), None as Option<P<Expr>>, mk().block_expr(default)));
+    Some(mk().match_expr(scrutinee?.1, arms))
+}
+
+/// The place a condition compares to constants, and the patterns matching these constants
+fn compared_place(cond: &Expr, locals: &IndexSet<String>) -> Option<(P<Expr>, Vec<P<Pat>>)> {
+    match cond.kind {
+        ExprKind::Paren(ref e) => compared_place(e, locals),
+        ExprKind::Binary(op, ref lhs, ref rhs) => match op.node {
+            BinOpKind::Eq => {
+                if is_pure_place(lhs, locals) {
+                    Some((lhs.clone(), vec![const_pat(rhs)?]))
+                } else if is_pure_place(rhs, locals) {
+                    Some((rhs.clone(), vec![const_pat(lhs)?]))
+                } else {
+                    None
+                }
+            }
+            BinOpKind::Or => {
+                let (place, mut pats) = compared_place(lhs, locals)?;
+                let (rhs_place, rhs_pats) = compared_place(rhs, locals)?;
+                if pprust::expr_to_string(&place) != pprust::expr_to_string(&rhs_place) {
+                    return None;
+                }
+                pats.extend(rhs_pats);
+                Some((place, pats))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether an expression reads one of `locals` or one of its fields (or a cast of it). Statics
+/// and places behind pointers are left alone, since what they hold could change.
+fn is_pure_place(expr: &Expr, locals: &IndexSet<String>) -> bool {
+    match expr.kind {
+        ExprKind::Path(None, ref path) => match *path.segments {
+            [ref segment] => locals.contains(&*segment.ident.as_str()),
+            _ => false,
+        },
+        ExprKind::Paren(ref e) | ExprKind::Field(ref e, _) | ExprKind::Cast(ref e, _) => {
+            is_pure_place(e, locals)
+        }
+        _ => false,
+    }
+}
+
+/// The largest value of the integer types casts to which are looked through in constants
+fn int_type_max(ty: &Ty) -> Option<u128> {
+    let name = match ty.kind {
+        TyKind::Path(None, ref path) => path.segments.last()?.ident.name.as_str(),
+        _ => return None,
+    };
+    let max = match &*name {
+        "i8" | "c_char" | "c_schar" => i8::max_value() as u128,
+        "u8" | "c_uchar" => u8::max_value() as u128,
+        "i16" | "c_short" => i16::max_value() as u128,
+        "u16" | "c_ushort" => u16::max_value() as u128,
+        "i32" | "u32" | "i64" | "u64" | "i128" | "u128" | "isize" | "usize" | "c_int"
+        | "c_uint" | "c_long" | "c_ulong" | "c_longlong" | "c_ulonglong" => {
+            i32::max_value() as u128
+        }
+        _ => return None,
+    };
+    Some(max)
+}
+
+/// The pattern matching a constant. Casts of literals are only looked through when they are casts
+/// to integer types that keep the value, so that the pattern has the type of the place compared
+/// to it: `x == 0 as libc::c_double` compares a float and isn't turned into a pattern.
+fn const_pat(expr: &Expr) -> Option<P<Pat>> {
+    fn int_value(expr: &Expr) -> Option<u128> {
+        let value = match expr.kind {
+            ExprKind::Paren(ref e) => return int_value(e),
+            ExprKind::Cast(ref e, ref ty) => {
+                let value = int_value(e)?;
+                return if value <= int_type_max(ty)? {
+                    Some(value)
+                } else {
+                    None
+                };
+            }
+            ExprKind::Lit(ref lit) => match lit.kind {
+                LitKind::Int(n, _) => n,
+                LitKind::Char(c) => c as u128,
+                LitKind::Byte(b) => b as u128,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if value <= i32::max_value() as u128 {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    match expr.kind {
+        ExprKind::Lit(ref lit) => match lit.kind {
+            LitKind::Int(..) | LitKind::Char(_) | LitKind::Byte(_) | LitKind::Bool(_) => {
+                Some(mk().lit_pat(P(expr.clone())))
+            }
+            _ => None,
+        },
+        ExprKind::Paren(ref e) => const_pat(e),
+        ExprKind::Cast(..) => {
+            let value = int_value(expr)?;
+            Some(mk().lit_pat(mk().lit_expr(mk().int_lit(value, ""))))
+        }
+        ExprKind::Unary(syntax::ast::UnOp::Neg, ref e) => {
+            let value = int_value(e)?;
+            Some(mk().lit_pat(mk().unary_expr("-", mk().lit_expr(mk().int_lit(value, "")))))
+        }
+        _ => None,
+    }
+}
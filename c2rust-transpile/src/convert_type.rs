@@ -2,12 +2,74 @@ use crate::c_ast::CDeclId;
 use crate::c_ast::*;
 use crate::renamer::*;
 use crate::diagnostics::TranslationError;
+use crate::ExternCrate;
 use c2rust_ast_builder::mk;
 use std::collections::{HashMap, HashSet};
 use std::ops::Index;
 use syntax::ast::*;
 use syntax::ptr::P;
 
+/// Where the translated code takes its C types (`c_int`, `size_t`, ...) from
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CTypeProvider {
+    /// The `libc` crate
+    Libc,
+    /// `core::ffi::c_void` and the primitive types matching the target's C types
+    CoreFfi,
+    /// A `ctypes` module generated into the translated crate
+    Ctypes,
+}
+
+impl CTypeProvider {
+    /// Path to the named C type
+    pub fn path(self, name: &str, target: &TargetInfo) -> Vec<&str> {
+        match self {
+            CTypeProvider::Libc => vec!["libc", name],
+            CTypeProvider::CoreFfi => match name {
+                "c_void" => vec!["", "core", "ffi", name],
+                _ => vec![primitive_c_type(name, target)],
+            },
+            CTypeProvider::Ctypes => vec!["ctypes", name],
+        }
+    }
+}
+
+/// The primitive Rust type with the target layout of the named C type
+pub fn primitive_c_type(name: &str, target: &TargetInfo) -> &'static str {
+    fn int_ty(signed: bool, width: u64) -> &'static str {
+        match (signed, width) {
+            (true, 8) => "i8",
+            (true, 16) => "i16",
+            (true, 32) => "i32",
+            (true, 64) => "i64",
+            (false, 8) => "u8",
+            (false, 16) => "u16",
+            (false, 32) => "u32",
+            (false, 64) => "u64",
+            _ => panic!("Unexpected C integer width {}", width),
+        }
+    }
+
+    match name {
+        "c_char" => int_ty(target.char_signed, target.char_width),
+        "c_schar" => int_ty(true, target.char_width),
+        "c_uchar" => int_ty(false, target.char_width),
+        "c_short" => int_ty(true, target.short_width),
+        "c_ushort" => int_ty(false, target.short_width),
+        "c_int" => int_ty(true, target.int_width),
+        "c_uint" => int_ty(false, target.int_width),
+        "c_long" => int_ty(true, target.long_width),
+        "c_ulong" => int_ty(false, target.long_width),
+        "c_longlong" => int_ty(true, target.long_long_width),
+        "c_ulonglong" => int_ty(false, target.long_long_width),
+        "c_float" => "f32",
+        "c_double" => "f64",
+        "size_t" | "uintptr_t" => "usize",
+        "ssize_t" | "intptr_t" | "ptrdiff_t" => "isize",
+        _ => panic!("Unexpected C type {}", name),
+    }
+}
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 enum FieldKey {
     Field(CFieldId),
@@ -20,7 +82,10 @@ pub struct TypeConverter {
     fields: HashMap<CDeclId, Renamer<FieldKey>>,
     suffix_names: HashMap<(CDeclId, &'static str), String>,
     features: HashSet<&'static str>,
+    crates: HashSet<ExternCrate>,
     emit_no_std: bool,
+    c_types: CTypeProvider,
+    target_info: TargetInfo,
 }
 
 pub const RESERVED_NAMES: [&str; 103] = [
@@ -133,27 +198,50 @@ pub const RESERVED_NAMES: [&str; 103] = [
 ];
 
 impl TypeConverter {
-    pub fn new(emit_no_std: bool) -> TypeConverter {
+    pub fn new(
+        emit_no_std: bool,
+        c_types: CTypeProvider,
+        target_info: TargetInfo,
+    ) -> TypeConverter {
         TypeConverter {
             translate_valist: false,
             renamer: Renamer::new(&RESERVED_NAMES),
             fields: HashMap::new(),
             suffix_names: HashMap::new(),
             features: HashSet::new(),
+            crates: HashSet::new(),
             emit_no_std,
+            c_types,
+            target_info,
         }
     }
 
+    /// The named C type from the configured provider
+    pub fn c_type(&self, name: &str) -> P<Ty> {
+        mk().path_ty(self.c_types.path(name, &self.target_info))
+    }
+
     pub fn features_used(&self) -> &HashSet<&'static str> {
         &self.features
     }
 
+    /// The crates the converted types refer to
+    pub fn crates_used(&self) -> &HashSet<ExternCrate> {
+        &self.crates
+    }
+
     pub fn declare_decl_name(&mut self, decl_id: CDeclId, name: &str) -> String {
         self.renamer
             .insert(decl_id, name)
             .expect("Name already assigned")
     }
 
+    /// A name for a type local to a function body that doesn't collide with the types of the
+    /// translation unit. It isn't reserved, so every function can use the same name.
+    pub fn local_type_name(&self, basename: &str) -> String {
+        self.renamer.unused_name(basename)
+    }
+
     pub fn alias_decl_name(&mut self, new_decl_id: CDeclId, old_decl_id: CDeclId) {
         self.renamer.alias(new_decl_id, &old_decl_id)
     }
@@ -275,7 +363,7 @@ impl TypeConverter {
             CTypeKind::Void => {
                 Ok(mk()
                     .set_mutbl(mutbl)
-                    .ptr_ty(mk().path_ty(vec!["libc", "c_void"])))
+                    .ptr_ty(self.c_type("c_void")))
             }
 
             CTypeKind::VariableArray(mut elt, _len) => {
@@ -318,25 +406,28 @@ impl TypeConverter {
         match ctxt.index(ctype).kind {
             CTypeKind::Void => Ok(mk().tuple_ty(vec![] as Vec<P<Ty>>)),
             CTypeKind::Bool => Ok(mk().path_ty(mk().path(vec!["bool"]))),
-            CTypeKind::Short => Ok(mk().path_ty(mk().path(vec!["libc", "c_short"]))),
-            CTypeKind::Int => Ok(mk().path_ty(mk().path(vec!["libc", "c_int"]))),
-            CTypeKind::Long => Ok(mk().path_ty(mk().path(vec!["libc", "c_long"]))),
-            CTypeKind::LongLong => Ok(mk().path_ty(mk().path(vec!["libc", "c_longlong"]))),
-            CTypeKind::UShort => Ok(mk().path_ty(mk().path(vec!["libc", "c_ushort"]))),
-            CTypeKind::UInt => Ok(mk().path_ty(mk().path(vec!["libc", "c_uint"]))),
-            CTypeKind::ULong => Ok(mk().path_ty(mk().path(vec!["libc", "c_ulong"]))),
-            CTypeKind::ULongLong => Ok(mk().path_ty(mk().path(vec!["libc", "c_ulonglong"]))),
-            CTypeKind::SChar => Ok(mk().path_ty(mk().path(vec!["libc", "c_schar"]))),
-            CTypeKind::UChar => Ok(mk().path_ty(mk().path(vec!["libc", "c_uchar"]))),
-            CTypeKind::Char => Ok(mk().path_ty(mk().path(vec!["libc", "c_char"]))),
-            CTypeKind::Double => Ok(mk().path_ty(mk().path(vec!["libc", "c_double"]))),
+            CTypeKind::Short => Ok(self.c_type("c_short")),
+            CTypeKind::Int => Ok(self.c_type("c_int")),
+            CTypeKind::Long => Ok(self.c_type("c_long")),
+            CTypeKind::LongLong => Ok(self.c_type("c_longlong")),
+            CTypeKind::UShort => Ok(self.c_type("c_ushort")),
+            CTypeKind::UInt => Ok(self.c_type("c_uint")),
+            CTypeKind::ULong => Ok(self.c_type("c_ulong")),
+            CTypeKind::ULongLong => Ok(self.c_type("c_ulonglong")),
+            CTypeKind::SChar => Ok(self.c_type("c_schar")),
+            CTypeKind::UChar => Ok(self.c_type("c_uchar")),
+            CTypeKind::Char => Ok(self.c_type("c_char")),
+            CTypeKind::Double => Ok(self.c_type("c_double")),
             CTypeKind::LongDouble => Ok(mk().path_ty(mk().path(vec!["f128", "f128"]))),
-            CTypeKind::Float => Ok(mk().path_ty(mk().path(vec!["libc", "c_float"]))),
+            CTypeKind::Float => Ok(self.c_type("c_float")),
             CTypeKind::Int128 => Ok(mk().path_ty(mk().path(vec!["i128"]))),
             CTypeKind::UInt128 => Ok(mk().path_ty(mk().path(vec!["u128"]))),
 
             CTypeKind::Pointer(qtype) => self.convert_pointer(ctxt, qtype),
 
+            // C++ references are lowered to the pointer they are implemented as
+            CTypeKind::Reference(qtype) => self.convert_pointer(ctxt, qtype),
+
             CTypeKind::Elaborated(ref ctype) => self.convert(ctxt, *ctype),
             CTypeKind::Decayed(ref ctype) => self.convert(ctxt, *ctype),
             CTypeKind::Paren(ref ctype) => self.convert(ctxt, *ctype),
@@ -388,6 +479,20 @@ impl TypeConverter {
 
             CTypeKind::Attributed(ty, _) => self.convert(ctxt, ty.ctype),
 
+            // Vector types without an x86 SIMD typedef are translated to portable vectors
+            CTypeKind::Vector(element, count) => {
+                self.crates.insert(ExternCrate::C2RustSimd);
+                let ty = self.convert(ctxt, element.ctype)?;
+                let lanes = mk().array_ty(
+                    ty,
+                    mk().lit_expr(mk().int_lit(count as u128, LitIntType::Unsuffixed)),
+                );
+                Ok(mk().path_ty(vec![
+                    mk().path_segment("c2rust_simd"),
+                    mk().path_segment_with_args("Vector", mk().angle_bracketed_args(vec![lanes])),
+                ]))
+            }
+
             // ANSI/ISO C-style function
             CTypeKind::Function(ret, ref params, is_var, is_noreturn, true) => {
                 let opt_ret = if is_noreturn { None } else { Some(ret) };
@@ -19,6 +19,7 @@ pub enum Diagnostic {
     All,
     Comments,
     ClangAst,
+    Idioms,
 }
 
 #[allow(unused_macros)]
@@ -0,0 +1,274 @@
+//! Modules shared by the translation units of a crate for the declarations
+//! of the headers they include.
+//!
+//! Translation units group the declarations of their headers by header, as
+//! with `--reorganize-definitions`. Instead of emitting a submodule per header
+//! in every translation unit, they add the declarations to a module per
+//! header shared by the whole crate, and import them from there. Declarations
+//! several translation units agree on are only emitted once. A translation
+//! unit whose version of a declaration differs from the shared one, because
+//! it was compiled with different macros for instance, keeps all of its
+//! headers in submodules of its own.
+//!
+//! Every translation unit has its own copy of the `static` variables of a
+//! header, so their definitions, and the items referring to them, are moved
+//! into the module of the translation unit instead of being shared.
+//!
+//! With `--c-types ctypes`, the generated `ctypes` module is shared the same
+//! way, as a module of the crate that all of them import.
+
+use std::path::PathBuf;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The module of the crate holding the modules of the headers
+pub const HEADERS_MODULE: &str = "c2rust_headers";
+
+/// The module of the crate defining the C types with `--c-types ctypes`
+pub const CTYPES_MODULE: &str = "ctypes";
+
+/// A declaration of a header module, as printed Rust
+#[derive(Debug, Clone)]
+pub struct HeaderItem {
+    /// The declaration without its comments, to compare it with the ones of
+    /// other translation units
+    pub plain: String,
+    /// The declaration with its comments
+    pub text: String,
+}
+
+/// The translated declarations of a header
+#[derive(Debug, Clone, Default)]
+pub struct HeaderModule {
+    /// Path to the header
+    pub path: PathBuf,
+    /// Its `use` items importing the declarations of other headers
+    pub uses: IndexSet<String>,
+    /// Its items, by name, or by their text if they have none
+    pub items: IndexMap<String, HeaderItem>,
+    /// The items of its `extern "C"` block, by name
+    pub foreign_items: IndexMap<String, HeaderItem>,
+}
+
+impl HeaderModule {
+    /// Does the module declare an item differently from another one
+    fn conflicts_with(&self, other: &HeaderModule) -> bool {
+        fn conflict(a: &IndexMap<String, HeaderItem>, b: &IndexMap<String, HeaderItem>) -> bool {
+            a.iter()
+                .any(|(key, item)| b.get(key).map_or(false, |other| other.plain != item.plain))
+        }
+
+        self.path != other.path
+            || conflict(&self.items, &other.items)
+            || conflict(&self.foreign_items, &other.foreign_items)
+    }
+
+    /// The source of the module
+    pub fn to_source(&self) -> String {
+        let mut source = format!("//! Declarations of `{}`\n", self.path.display());
+        for item in &self.uses {
+            source += item;
+            source += "\n";
+        }
+        if !self.foreign_items.is_empty() {
+            source += "extern \"C\" {\n";
+            for item in self.foreign_items.values() {
+                for line in item.text.lines() {
+                    if !line.is_empty() {
+                        source += "    ";
+                    }
+                    source += line;
+                    source += "\n";
+                }
+            }
+            source += "}\n";
+        }
+        for item in self.items.values() {
+            source += &item.text;
+            source += "\n";
+        }
+        source
+    }
+}
+
+/// The header modules shared by the translation units of a crate, by module
+/// name
+#[derive(Debug, Default)]
+pub struct HeaderModules {
+    modules: IndexMap<String, HeaderModule>,
+    /// The source of the `ctypes` module, once a translation unit defined it
+    ctypes: Option<String>,
+}
+
+impl HeaderModules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Can the header modules of a translation unit be merged into the shared
+    /// ones
+    pub fn can_share(&self, headers: &IndexMap<String, HeaderModule>) -> bool {
+        headers.iter().all(|(name, header)| {
+            self.modules
+                .get(name)
+                .map_or(true, |shared| !header.conflicts_with(shared))
+        })
+    }
+
+    /// Add the declarations of the header modules of a translation unit that
+    /// are not shared yet
+    pub fn share(&mut self, headers: IndexMap<String, HeaderModule>) {
+        for (name, header) in headers {
+            let shared = self.modules.entry(name).or_insert_with(|| HeaderModule {
+                path: header.path.clone(),
+                ..HeaderModule::default()
+            });
+            shared.uses.extend(header.uses);
+            for (key, item) in header.items {
+                shared.items.entry(key).or_insert(item);
+            }
+            for (key, item) in header.foreign_items {
+                shared.foreign_items.entry(key).or_insert(item);
+            }
+        }
+    }
+
+    /// Define the `ctypes` module of the crate, unless a translation unit
+    /// already did
+    pub fn share_ctypes(&mut self, source: String) {
+        self.ctypes.get_or_insert(source);
+    }
+
+    pub fn ctypes(&self) -> Option<&str> {
+        self.ctypes.as_ref().map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &HeaderModule)> {
+        self.modules.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty() && self.ctypes.is_none()
+    }
+}
+
+/// The keys of the items that must stay in their translation unit: those of
+/// `statics`, and of the items whose text refers to another one that must
+pub fn local_items(items: &[(String, String)], statics: IndexSet<String>) -> IndexSet<String> {
+    let mut local = statics;
+    loop {
+        let referring: Vec<String> = items
+            .iter()
+            .filter(|(key, text)| {
+                !local.contains(key) && idents(text).any(|ident| local.contains(ident))
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        if referring.is_empty() {
+            return local;
+        }
+        local.extend(referring);
+    }
+}
+
+/// The identifiers of printed Rust
+fn idents(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| !word.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(items: &[(&str, &str)]) -> HeaderModule {
+        let mut module = HeaderModule {
+            path: PathBuf::from("/usr/include/point.h"),
+            ..HeaderModule::default()
+        };
+        for &(name, text) in items {
+            let item = HeaderItem {
+                plain: text.to_string(),
+                text: text.to_string(),
+            };
+            module.items.insert(name.to_string(), item);
+        }
+        module
+    }
+
+    fn headers(module: HeaderModule) -> IndexMap<String, HeaderModule> {
+        let mut headers = IndexMap::new();
+        headers.insert("point_h".to_string(), module);
+        headers
+    }
+
+    #[test]
+    fn share_once() {
+        let point = "pub struct point { pub x: libc::c_int, }";
+        let point_x = "pub unsafe extern \"C\" fn point_x(p: point) -> libc::c_int { return p.x; }";
+
+        let mut shared = HeaderModules::new();
+        assert!(shared.is_empty());
+        shared.share(headers(header(&[("point", point)])));
+        assert!(shared.can_share(&headers(header(&[("point", point), ("point_x", point_x)]))));
+        shared.share(headers(header(&[("point", point), ("point_x", point_x)])));
+
+        let (name, module) = shared.iter().next().unwrap();
+        assert_eq!(name, "point_h");
+        assert_eq!(
+            module.to_source(),
+            format!("//! Declarations of `/usr/include/point.h`\n{}\n{}\n", point, point_x)
+        );
+    }
+
+    #[test]
+    fn conflicting_declarations() {
+        let mut shared = HeaderModules::new();
+        shared.share(headers(header(&[("point", "pub struct point { pub x: libc::c_int, }")])));
+
+        let other = header(&[("point", "pub struct point { pub x: libc::c_long, }")]);
+        assert!(!shared.can_share(&headers(other)));
+
+        let mut moved = header(&[]);
+        moved.path = PathBuf::from("/usr/local/include/point.h");
+        assert!(!shared.can_share(&headers(moved)));
+    }
+
+    #[test]
+    fn foreign_items() {
+        let mut module = header(&[]);
+        module.uses.insert("use super::stddef_h::size_t;".to_string());
+        module.foreign_items.insert(
+            "point_len".to_string(),
+            HeaderItem {
+                plain: "pub fn point_len() -> size_t;".to_string(),
+                text: "/* The length */\npub fn point_len() -> size_t;".to_string(),
+            },
+        );
+        assert_eq!(
+            module.to_source(),
+            "//! Declarations of `/usr/include/point.h`\n\
+             use super::stddef_h::size_t;\n\
+             extern \"C\" {\n    \
+             /* The length */\n    \
+             pub fn point_len() -> size_t;\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn local_statics() {
+        let item = |key: &str, text: &str| (key.to_string(), text.to_string());
+        let items = vec![
+            item("point", "pub struct point { pub x: libc::c_int, }"),
+            item("origin", "pub static mut origin: point = point{x: 0,};"),
+            item("origin_x", "pub unsafe extern \"C\" fn origin_x() -> libc::c_int { origin.x }"),
+            item("twice_x", "pub unsafe extern \"C\" fn twice_x() -> libc::c_int { 2 * origin_x() }"),
+            item("point_x", "pub unsafe extern \"C\" fn point_x(p: point) -> libc::c_int { p.x }"),
+        ];
+        let statics = vec!["origin".to_string()].into_iter().collect();
+
+        let local: Vec<String> = local_items(&items, statics).into_iter().collect();
+        assert_eq!(local, vec!["origin", "origin_x", "twice_x"]);
+    }
+}
@@ -19,6 +19,7 @@ extern crate itertools;
 extern crate libc;
 extern crate regex;
 extern crate serde_json;
+extern crate toml;
 #[macro_use]
 extern crate log;
 extern crate fern;
@@ -36,6 +37,7 @@ pub mod c_ast;
 pub mod cfg;
 mod compile_cmds;
 pub mod convert_type;
+pub mod header_modules;
 pub mod renamer;
 pub mod rust_ast;
 pub mod translator;
@@ -56,10 +58,13 @@ use crate::c_ast::*;
 pub use crate::diagnostics::Diagnostic;
 use c2rust_ast_exporter as ast_exporter;
 
-use crate::build_files::{emit_build_files, get_build_dir, CrateConfig};
+use crate::build_files::{emit_build_files, emit_header_modules, get_build_dir, CrateConfig};
 use crate::compile_cmds::get_compile_commands;
+use crate::header_modules::HeaderModules;
 use crate::convert_type::RESERVED_NAMES;
-pub use crate::translator::ReplaceMode;
+pub use crate::cfg::CfgDumpStage;
+pub use crate::convert_type::CTypeProvider;
+pub use crate::translator::{RenameMap, ReplaceMode};
 use std::prelude::v1::Vec;
 use syntax_pos::edition::Edition;
 
@@ -77,6 +82,9 @@ pub struct TranspilerConfig {
     pub pretty_typed_context: bool,
     pub dump_function_cfgs: bool,
     pub json_function_cfgs: bool,
+    pub html_function_cfgs: bool,
+    pub cfg_dump_filter: Option<Regex>,
+    pub cfg_dump_stage: CfgDumpStage,
     pub dump_cfg_liveness: bool,
     pub dump_structures: bool,
     pub verbose: bool,
@@ -85,6 +93,7 @@ pub struct TranspilerConfig {
     // Options that control translation
     pub incremental_relooper: bool,
     pub fail_on_multiple: bool,
+    pub split_nodes: Option<usize>,
     pub filter: Option<Regex>,
     pub debug_relooper_labels: bool,
     pub prefix_function_names: Option<String>,
@@ -100,11 +109,20 @@ pub struct TranspilerConfig {
     pub overwrite_existing: bool,
     pub reduce_type_annotations: bool,
     pub reorganize_definitions: bool,
+    pub header_modules: bool,
     pub enabled_warnings: HashSet<Diagnostic>,
     pub emit_no_std: bool,
     pub output_dir: Option<PathBuf>,
     pub translate_const_macros: bool,
     pub translate_fn_macros: bool,
+    pub translate_restrict_refs: bool,
+    pub translate_slice_params: bool,
+    pub translate_c_strings: bool,
+    pub translate_enums: bool,
+    pub translate_tagged_unions: bool,
+    pub rust_naming: bool,
+    pub rename_map: RenameMap,
+    pub c_types: CTypeProvider,
     pub disable_refactoring: bool,
     pub preserve_unused_functions: bool,
     pub log_level: log::LevelFilter,
@@ -135,10 +153,12 @@ impl TranspilerConfig {
 pub enum ExternCrate {
     C2RustBitfields,
     C2RustAsmCasts,
+    C2RustSimd,
     F128,
     NumTraits,
     Memoffset,
     Libc,
+    Cstr,
 }
 
 #[derive(Serialize)]
@@ -163,12 +183,14 @@ impl ExternCrateDetails {
 impl From<ExternCrate> for ExternCrateDetails {
     fn from(extern_crate: ExternCrate) -> Self {
         match extern_crate {
-            ExternCrate::C2RustBitfields => Self::new("c2rust-bitfields", "0.3", true),
+            ExternCrate::C2RustBitfields => Self::new("c2rust-bitfields", "0.4", true),
             ExternCrate::C2RustAsmCasts => Self::new("c2rust-asm-casts", "0.2", true),
+            ExternCrate::C2RustSimd => Self::new("c2rust-simd", "0.1", false),
             ExternCrate::F128 => Self::new("f128", "0.2", false),
             ExternCrate::NumTraits => Self::new("num-traits", "0.2", true),
             ExternCrate::Memoffset => Self::new("memoffset", "0.5", true),
             ExternCrate::Libc => Self::new("libc", "0.2", false),
+            ExternCrate::Cstr => Self::new("cstr", "0.1", true),
         }
     }
 }
@@ -281,15 +303,17 @@ pub fn transpile(tcfg: TranspilerConfig, cc_db: &Path, extra_clang_args: &[&str]
             }
         }
 
+        let mut header_modules = HeaderModules::new();
         let results = cmds
             .iter()
             .map(|cmd| transpile_single(&tcfg, cmd.abs_file(),
                                         &ancestor_path,
                                         &build_dir,
                                         cc_db,
-                                        &clang_args))
+                                        &clang_args,
+                                        &mut header_modules))
             .collect::<Vec<TranspileResult>>();
-        let mut modules = vec![];
+        let mut modules = emit_header_modules(&tcfg, &build_dir, &header_modules);
         let mut modules_skipped = false;
         let mut pragmas = PragmaSet::new();
         let mut crates = CrateSet::new();
@@ -427,8 +451,13 @@ fn reorganize_definitions(
     build_dir: &PathBuf,
     crate_file: Option<PathBuf>,
 ) -> Result<(), Error> {
-    // We only run the reorganization refactoring if we emitted a fresh crate file
-    if crate_file.is_none() || tcfg.disable_refactoring || !tcfg.reorganize_definitions {
+    // We only run the reorganization refactoring if we emitted a fresh crate file, and
+    // headers are not shared by the translator itself
+    if crate_file.is_none()
+        || tcfg.disable_refactoring
+        || !tcfg.reorganize_definitions
+        || tcfg.header_modules
+    {
         return Ok(());
     }
 
@@ -451,6 +480,7 @@ fn transpile_single(
     build_dir: &Path,
     cc_db: &Path,
     extra_clang_args: &[&str],
+    header_modules: &mut HeaderModules,
 ) -> TranspileResult {
     let output_path = get_output_path(tcfg, &input_path, ancestor_path, build_dir);
     if output_path.exists() && !tcfg.overwrite_existing {
@@ -518,7 +548,7 @@ fn transpile_single(
     // Perform the translation
     let (translated_string, pragmas, crates) =
         syntax::with_globals(Edition::Edition2018, move || {
-            translator::translate(typed_context, &tcfg, input_path)
+            translator::translate(typed_context, &tcfg, input_path, header_modules)
         });
 
     let mut file = match File::create(&output_path) {
@@ -86,6 +86,18 @@ impl<T: Clone + Eq + Hash> Renamer<T> {
     /// Assigns a name that doesn't collide with anything in the context of a particular
     /// scope, defaulting to the current scope if None is provided
     fn pick_name_in_scope(&mut self, basename: &str, scope: Option<usize>) -> String {
+        let target = self.unused_name(basename);
+
+        match scope {
+            Some(scope_index) => self.scopes[scope_index].reserve(target.clone()),
+            None => self.current_scope_mut().reserve(target.clone()),
+        }
+
+        target
+    }
+
+    /// A name that doesn't collide with anything currently in use, without reserving it
+    pub fn unused_name(&self, basename: &str) -> String {
         let mut target = basename.to_string();
 
         for i in 0.. {
@@ -96,11 +108,6 @@ impl<T: Clone + Eq + Hash> Renamer<T> {
             }
         }
 
-        match scope {
-            Some(scope_index) => self.scopes[scope_index].reserve(target.clone()),
-            None => self.current_scope_mut().reserve(target.clone()),
-        }
-
         target
     }
 
@@ -171,6 +178,13 @@ impl<T: Clone + Eq + Hash> Renamer<T> {
         None
     }
 
+    /// The keys bound in all but the outermost scope, together with their mangled names
+    pub fn scoped_names(&self) -> impl Iterator<Item = (&T, &str)> {
+        self.scopes[1..]
+            .iter()
+            .flat_map(|scope| scope.name_map.iter().map(|(key, name)| (key, name.as_str())))
+    }
+
     pub fn fresh(&mut self) -> String {
         let fresh = self.next_fresh;
         self.next_fresh += 1;
@@ -178,6 +192,81 @@ impl<T: Clone + Eq + Hash> Renamer<T> {
     }
 }
 
+/// Splits an identifier into its words, breaking at underscores, at lowercase
+/// letters or digits followed by an uppercase letter, and before the last
+/// letter of a run of uppercase letters that is followed by a lowercase one.
+fn words(name: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = name.char_indices().collect();
+    let mut words = vec![];
+    let mut start = None;
+    for (i, &(pos, c)) in chars.iter().enumerate() {
+        if c == '_' {
+            if let Some(start) = start.take() {
+                words.push(&name[start..pos]);
+            }
+            continue;
+        }
+        if let Some(word_start) = start {
+            let prev = chars[i - 1].1;
+            let next_lower = chars.get(i + 1).map_or(false, |&(_, n)| n.is_lowercase());
+            if c.is_uppercase()
+                && (prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower))
+            {
+                words.push(&name[word_start..pos]);
+                start = Some(pos);
+            }
+        } else {
+            start = Some(pos);
+        }
+    }
+    if let Some(start) = start {
+        words.push(&name[start..]);
+    }
+    words
+}
+
+/// Converts a C identifier into `snake_case`, keeping its leading underscores
+pub fn snake_case(name: &str) -> String {
+    let prefix_len = name.len() - name.trim_start_matches('_').len();
+    let words: Vec<String> = words(name).iter().map(|w| w.to_lowercase()).collect();
+    if words.is_empty() {
+        return name.to_string();
+    }
+    format!("{}{}", &name[..prefix_len], words.join("_"))
+}
+
+/// Converts a C identifier into `SCREAMING_SNAKE_CASE`, keeping its leading
+/// underscores
+pub fn screaming_snake_case(name: &str) -> String {
+    let prefix_len = name.len() - name.trim_start_matches('_').len();
+    let words: Vec<String> = words(name).iter().map(|w| w.to_uppercase()).collect();
+    if words.is_empty() {
+        return name.to_string();
+    }
+    format!("{}{}", &name[..prefix_len], words.join("_"))
+}
+
+/// Converts a C type name into `CamelCase`, dropping the `_t` suffix of
+/// typedef names. Names that would not be identifiers anymore are kept.
+pub fn camel_case(name: &str) -> String {
+    let mut words = words(name);
+    if words.len() > 1 && words.last() == Some(&"t") {
+        words.pop();
+    }
+    let camel: String = words
+        .iter()
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().into_iter().flat_map(char::to_uppercase);
+            first.chain(chars.flat_map(char::to_lowercase))
+        })
+        .collect();
+    match camel.chars().next() {
+        Some(c) if !c.is_ascii_digit() => camel,
+        _ => name.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,4 +315,17 @@ mod tests {
         renamer.drop_scope();
         assert_eq!(renamer.get(&1), None);
     }
+
+    #[test]
+    fn cases() {
+        assert_eq!(snake_case("fooBar"), "foo_bar");
+        assert_eq!(snake_case("HTTPServer_init"), "http_server_init");
+        assert_eq!(snake_case("__vec3Add"), "__vec3_add");
+        assert_eq!(screaming_snake_case("maxLen"), "MAX_LEN");
+        assert_eq!(screaming_snake_case("FOO_BAR"), "FOO_BAR");
+        assert_eq!(camel_case("struct_foo_t"), "StructFoo");
+        assert_eq!(camel_case("FOO_BAR"), "FooBar");
+        assert_eq!(camel_case("t"), "T");
+        assert_eq!(camel_case("_1st"), "_1st");
+    }
 }
@@ -44,6 +44,8 @@
 //! Trailing comments can be printed after the following elements hi pos, but on
 //! the same line:
 //! - Stmt
+//! - StructField
+//! - Variant
 //! - Comma separated Expr (struct initializer, tuples literals, etc.)
 //!
 //! Before the close of a Block
@@ -52,6 +54,7 @@ use crate::rust_ast::{pos_to_span, traverse};
 use itertools::Itertools;
 use smallvec::{smallvec, SmallVec};
 use std::collections::BTreeMap;
+use std::mem;
 use syntax::ast::*;
 use syntax::util::comments;
 use syntax_pos::{BytePos, Span};
@@ -162,7 +165,27 @@ impl CommentStore {
         }
 
         let lines: Vec<String> = lines.into_iter().map(translate_comment).collect();
+        self.insert_lines(lines, pos, style)
+    }
+
+    /// Add doc comments before whatever is associated with the returned
+    /// `BytePos`. Unlike other comments, their `///` markers are kept, so they
+    /// must only be attached to items. If pos is None, use the current
+    /// position.
+    pub fn extend_existing_doc_comments(
+        &mut self,
+        lines: Vec<String>,
+        pos: Option<BytePos>,
+    ) -> Option<BytePos> {
+        self.insert_lines(lines, pos, comments::CommentStyle::Isolated)
+    }
 
+    fn insert_lines(
+        &mut self,
+        lines: Vec<String>,
+        pos: Option<BytePos>,
+        style: comments::CommentStyle,
+    ) -> Option<BytePos> {
         if lines.is_empty() {
             None
         } else {
@@ -175,6 +198,17 @@ impl CommentStore {
         }
     }
 
+    /// Rewrite each line of the doc comments added by `extend_existing_doc_comments`.
+    pub fn map_doc_comment_lines<F: FnMut(&str) -> String>(&mut self, mut f: F) {
+        for comment in self.output_comments.values_mut().flatten() {
+            for line in &mut comment.lines {
+                if line.starts_with("///") {
+                    *line = f(line);
+                }
+            }
+        }
+    }
+
     /// Move comments associated with `old` to `new`.
     pub fn move_comments(&mut self, old: BytePos, new: BytePos) {
         if old == new {
@@ -230,6 +264,23 @@ impl CommentTraverser {
         }
     }
 
+    /// Reinsert the comments of a pattern, and of the alternatives of an or-pattern, which are
+    /// printed in order before each of them.
+    fn reinsert_pat(&mut self, mut p: Pat) -> Pat {
+        p.span = pos_to_span(self.reinsert_comment_at(p.span.lo()).unwrap_or(BytePos(0)));
+        if let PatKind::Or(pats) = p.kind {
+            p.kind = PatKind::Or(pats.into_iter().map(|p| p.map(|p| self.reinsert_pat(p))).collect());
+        }
+        p
+    }
+
+    /// Take the comments reinserted so far, to print the nodes traversed until
+    /// now on their own. Later nodes keep getting new positions.
+    pub fn take_comments(&mut self) -> Vec<comments::Comment> {
+        let comments = mem::replace(&mut self.store.output_comments, BTreeMap::new());
+        comments.into_iter().map(|(_, v)| v).flatten().collect()
+    }
+
     /// Turn the traverser back into a `CommentStore`.
     pub fn into_comment_store(self) -> CommentStore {
         //        assert!(old_comments.is_empty());
@@ -261,8 +312,16 @@ impl traverse::Traversal for CommentTraverser {
     reinsert_and_traverse!(traverse_block, Block, traverse::traverse_block_def);
     reinsert_and_traverse!(traverse_local, Local, traverse::traverse_local_def);
     reinsert_and_traverse!(traverse_field, Field, traverse::traverse_field_def);
+    reinsert_and_traverse!(traverse_struct_field, StructField, traverse::traverse_struct_field_def);
+    reinsert_and_traverse!(traverse_variant, Variant, traverse::traverse_variant_def);
     reinsert_and_traverse!(traverse_item, Item, traverse::traverse_item_def);
 
+    fn traverse_arm(&mut self, mut a: Arm) -> Arm {
+        // Comments on an arm are printed before its pattern
+        a.pat = a.pat.map(|pat| self.reinsert_pat(pat));
+        traverse::traverse_arm_def(self, a)
+    }
+
     fn traverse_foreign_item(&mut self, mut i: ForeignItem) -> ForeignItem {
         i.span = pos_to_span(self.reinsert_comment_at(i.span.lo()).unwrap_or(BytePos(0)));
         i
@@ -66,6 +66,21 @@ impl PathedMultiImports {
 
         self.0.into_iter().map(build_items).collect()
     }
+
+    /// An import of each leaf on its own, along with its path
+    pub fn simple_items(&self) -> Vec<(Vec<String>, P<Item>)> {
+        let mut items = vec![];
+        for (path, imports) in &self.0 {
+            let attrs = imports.attrs.clone().unwrap_or_else(|| mk());
+            for leaf in &imports.leaves {
+                let mut path = path.clone();
+                path.push(leaf.clone());
+                let item = attrs.clone().use_simple_item(path.clone(), None as Option<Ident>);
+                items.push((path, item));
+            }
+        }
+        items
+    }
 }
 
 #[derive(Debug)]
@@ -100,6 +115,18 @@ impl ItemStore {
         self.uses.get_mut(path).insert_with_attr(ident, attrs)
     }
 
+    pub fn items(&self) -> &[P<Item>] {
+        &self.items
+    }
+
+    pub fn foreign_items(&self) -> &[ForeignItem] {
+        &self.foreign_items
+    }
+
+    pub fn uses(&self) -> &PathedMultiImports {
+        &self.uses
+    }
+
     pub fn drain(&mut self) -> (Vec<P<Item>>, Vec<ForeignItem>, PathedMultiImports) {
         let mut items = Vec::new();
         let mut foreign_items = Vec::new();
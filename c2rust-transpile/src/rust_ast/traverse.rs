@@ -36,6 +36,14 @@ pub trait Traversal: Sized {
         traverse_field_def(self, f)
     }
 
+    fn traverse_struct_field(&mut self, f: StructField) -> StructField {
+        traverse_struct_field_def(self, f)
+    }
+
+    fn traverse_variant(&mut self, v: Variant) -> Variant {
+        traverse_variant_def(self, v)
+    }
+
     fn traverse_mod(&mut self, m: Mod) -> Mod {
         traverse_mod_def(self, m)
     }
@@ -76,6 +84,8 @@ traversable_impl!(Block, traverse_block);
 traversable_impl!(Local, traverse_local);
 traversable_impl!(Arm, traverse_arm);
 traversable_impl!(Field, traverse_field);
+traversable_impl!(StructField, traverse_struct_field);
+traversable_impl!(Variant, traverse_variant);
 traversable_impl!(Mod, traverse_mod);
 traversable_impl!(ForeignMod, traverse_foreign_mod);
 traversable_impl!(Item, traverse_item);
@@ -208,6 +218,23 @@ pub fn traverse_field_def<W: Traversal>(walk: &mut W, mut f: Field) -> Field {
     f
 }
 
+pub fn traverse_struct_field_def<W: Traversal>(_walk: &mut W, f: StructField) -> StructField {
+    f
+}
+
+pub fn traverse_variant_def<W: Traversal>(walk: &mut W, mut v: Variant) -> Variant {
+    v.data = traverse_variant_data(walk, v.data);
+    v
+}
+
+fn traverse_variant_data<W: Traversal>(walk: &mut W, data: VariantData) -> VariantData {
+    match data {
+        VariantData::Struct(fields, recovered) => VariantData::Struct(fields.traverse(walk), recovered),
+        VariantData::Tuple(fields, id) => VariantData::Tuple(fields.traverse(walk), id),
+        VariantData::Unit(id) => VariantData::Unit(id),
+    }
+}
+
 pub fn traverse_mod_def<W: Traversal>(walk: &mut W, mut m: Mod) -> Mod {
     m.items = m.items.traverse(walk);
     m
@@ -233,9 +260,9 @@ pub fn traverse_item_def<W: Traversal>(walk: &mut W, mut i: Item) -> Item {
         ItemKind::ExternCrate(u) => ItemKind::ExternCrate(u),
         ItemKind::GlobalAsm(u) => ItemKind::GlobalAsm(u),
         ItemKind::TyAlias(l, r) => ItemKind::TyAlias(l, r),
-        ItemKind::Enum(l, r) => ItemKind::Enum(l, r),
-        ItemKind::Struct(l, r) => ItemKind::Struct(l, r),
-        ItemKind::Union(l, r) => ItemKind::Union(l, r),
+        ItemKind::Enum(l, r) => ItemKind::Enum(EnumDef { variants: l.variants.traverse(walk) }, r),
+        ItemKind::Struct(l, r) => ItemKind::Struct(traverse_variant_data(walk, l), r),
+        ItemKind::Union(l, r) => ItemKind::Union(traverse_variant_data(walk, l), r),
         ItemKind::TraitAlias(l, r) => ItemKind::TraitAlias(l, r),
         ItemKind::Mac(m) => ItemKind::Mac(m),
         ItemKind::MacroDef(m) => ItemKind::MacroDef(m),
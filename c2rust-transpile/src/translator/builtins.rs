@@ -93,7 +93,7 @@ impl<'c> Translation<'c> {
                 Ok(val.map(|v| {
                     let val = mk().method_call_expr(v, "is_sign_negative", vec![] as Vec<P<Expr>>);
 
-                    mk().cast_expr(val, mk().path_ty(vec!["libc", "c_int"]))
+                    mk().cast_expr(val, self.c_type("c_int"))
                 }))
             },
             "__builtin_ffs" | "__builtin_ffsl" | "__builtin_ffsll" => {
@@ -261,7 +261,7 @@ impl<'c> Translation<'c> {
                         let if_expr = mk().ifte_expr(if_cond,
                                        mk().block(vec![mk().expr_stmt(minus_one)]),
                                        Some(mk().lit_expr(mk().int_lit(0, "isize"))));
-                        let size_t = mk().path_ty(vec!["libc", "size_t"]);
+                        let size_t = self.c_type("size_t");
                         mk().cast_expr(if_expr, size_t)
                     }))
                 })
@@ -671,6 +671,7 @@ impl<'c> Translation<'c> {
         args: &[CExprId],
     ) -> Result<WithStmts<P<Expr>>, TranslationError> {
         let name = &builtin_name[10..];
+        self.use_crate(ExternCrate::Libc);
         let mem = mk().path_expr(vec!["libc", name]);
         let args = self.convert_exprs(ctx.used(), args)?;
         args.and_then(|args| {
@@ -678,7 +679,7 @@ impl<'c> Translation<'c> {
             let dst = args.next().ok_or("Missing dst argument to convert_libc_fns")?;
             let c = args.next().ok_or("Missing c argument to convert_libc_fns")?;
             let len = args.next().ok_or("Missing len argument to convert_libc_fns")?;
-            let size_t = mk().path_ty(vec!["libc", "size_t"]);
+            let size_t = self.c_type("size_t");
             let len1 = mk().cast_expr(len, size_t);
             let mem_expr = mk().call_expr(mem, vec![dst, c, len1]);
 
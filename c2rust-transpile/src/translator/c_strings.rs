@@ -0,0 +1,392 @@
+#![deny(missing_docs)]
+//! This module implements the opt-in translation of C strings into `CStr`.
+//!
+//! String literals that decay to `const char *` are emitted as `cstr!("...")`
+//! literals (from the `cstr` crate) instead of byte strings with pointer
+//! casts, as long as they are valid UTF-8 without interior nul bytes and are
+//! not part of a static initializer.
+//!
+//! `const char *` parameters of `static` functions additionally become
+//! `&CStr` when
+//!
+//!   * the function is prototyped, not variadic and only ever called directly;
+//!   * the body only passes the parameter on to known libc string functions,
+//!     declared in a system header, which take a `const char *`, or to another
+//!     `&CStr` parameter;
+//!   * every call site passes a string literal that can be turned into a
+//!     `cstr!` literal or another `&CStr` parameter of the caller.
+//!
+//! Uses of such a parameter in the body become `s.as_ptr()`.
+
+use super::*;
+use std::collections::HashSet;
+use std::str;
+
+/// libc functions that only read the C strings they are passed
+static C_STRING_FUNCTIONS: &[&str] = &[
+    "atof",
+    "atoi",
+    "atol",
+    "atoll",
+    "fopen",
+    "fprintf",
+    "fputs",
+    "getenv",
+    "perror",
+    "printf",
+    "puts",
+    "strcasecmp",
+    "strchr",
+    "strcmp",
+    "strcoll",
+    "strcspn",
+    "strdup",
+    "strlen",
+    "strncasecmp",
+    "strncmp",
+    "strndup",
+    "strnlen",
+    "strpbrk",
+    "strrchr",
+    "strspn",
+    "strstr",
+    "strtod",
+    "strtof",
+    "strtol",
+    "strtoll",
+    "strtoul",
+    "strtoull",
+];
+
+/// Directories system headers are found in
+static SYSTEM_HEADER_DIRS: &[&str] = &[
+    "/usr/include/",
+    "/usr/local/include/",
+    "/usr/lib/",
+    "/Library/Developer/",
+    "/Applications/Xcode.app/",
+];
+
+impl<'c> Translation<'c> {
+    /// Build a `cstr!("...")` literal for the contents of a C string literal,
+    /// if the option is enabled and the string can be expressed that way.
+    pub fn c_str_literal(&self, bytes: &[u8]) -> Option<P<Expr>> {
+        let s = self.c_str_literal_text(bytes)?;
+        self.use_crate(ExternCrate::Cstr);
+        let lit = Nonterminal::NtExpr(mk().lit_expr(s));
+        let macro_body = vec![TokenTree::token(
+            token::Interpolated(Rc::new(lit)),
+            DUMMY_SP,
+        )];
+        let mac = mk().mac(mk().path("cstr"), macro_body, MacDelimiter::Parenthesis);
+        Some(mk().mac_expr(mac))
+    }
+
+    /// Compute the set of `const char *` parameters that can be translated
+    /// into `&CStr`. Parameters that were considered but rejected are reported
+    /// through the `idioms` diagnostic.
+    pub fn compute_c_str_params(&self) -> IndexSet<CParamId> {
+        // All `const char *` parameters, mapped to their function
+        let mut candidates: IndexMap<CParamId, CDeclId> = IndexMap::new();
+        let mut rejected: IndexMap<CParamId, String> = IndexMap::new();
+
+        for &decl_id in &self.ast_context.c_decls_top {
+            let (is_global, typ, parameters) = match self.ast_context[decl_id].kind {
+                CDeclKind::Function {
+                    is_global,
+                    typ,
+                    ref parameters,
+                    body: Some(_),
+                    ..
+                } => (is_global, typ, parameters),
+                _ => continue,
+            };
+            let fn_error = self.pointer_params_fn_error(is_global, typ);
+
+            for &param_id in parameters {
+                let typ = match self.ast_context[param_id].kind {
+                    CDeclKind::Variable { typ, .. } if self.is_const_char_ptr(typ) => typ,
+                    _ => continue,
+                };
+                candidates.insert(param_id, decl_id);
+                let err = fn_error
+                    .or_else(|| Some("it is volatile").filter(|_| typ.qualifiers.is_volatile));
+                if let Some(err) = err {
+                    rejected.insert(param_id, err.to_string());
+                }
+            }
+        }
+
+        let functions: IndexSet<CDeclId> = candidates
+            .iter()
+            .filter(|&(param_id, _)| !rejected.contains_key(param_id))
+            .map(|(_, &fn_id)| fn_id)
+            .collect();
+        if functions.is_empty() {
+            self.report_rejected_params(&candidates, &rejected, "`&CStr`");
+            return IndexSet::new();
+        }
+
+        // Check that the parameters are only passed on to string functions
+        for &fn_id in &functions {
+            let body = match self.ast_context[fn_id].kind {
+                CDeclKind::Function {
+                    body: Some(body), ..
+                } => body,
+                _ => continue,
+            };
+
+            let mut passed: HashSet<CExprId> = HashSet::new();
+            for node in DFExpr::new(&self.ast_context, body.into()) {
+                let expr_id = match node {
+                    SomeId::Expr(expr_id) => expr_id,
+                    _ => continue,
+                };
+                match self.ast_context[expr_id].kind {
+                    CExprKind::Call(_, func, ref args) => {
+                        let callee = match self.direct_callee(func) {
+                            Some((_, callee)) => callee,
+                            None => continue,
+                        };
+                        for (i, &arg) in args.iter().enumerate() {
+                            let declref = match self.c_str_param_read(arg) {
+                                Some((declref, _)) => declref,
+                                None => continue,
+                            };
+                            if self.takes_c_string(callee, i, &candidates) {
+                                passed.insert(declref);
+                            }
+                        }
+                    }
+                    CExprKind::DeclRef(_, param_id, _)
+                        if candidates.contains_key(&param_id) && !passed.contains(&expr_id) =>
+                    {
+                        rejected.entry(param_id).or_insert_with(|| {
+                            "it is used other than as an argument to a string function".to_string()
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let (calls, address_taken) = self.direct_calls(&functions);
+
+        for (&param_id, fn_id) in &candidates {
+            if address_taken.contains(fn_id) {
+                rejected
+                    .entry(param_id)
+                    .or_insert_with(|| "the address of the function is taken".to_string());
+            }
+        }
+
+        // Check that every call site passes a string literal or another
+        // `&CStr`. Parameters passed on to each other stand or fall together.
+        let mut forwards: Vec<(CParamId, CParamId)> = vec![];
+        for (callee, call) in calls {
+            let args = match self.ast_context[call].kind {
+                CExprKind::Call(_, _, ref args) => args,
+                _ => continue,
+            };
+            let parameters = match self.ast_context[callee].kind {
+                CDeclKind::Function { ref parameters, .. } => parameters,
+                _ => continue,
+            };
+
+            for (&param_id, &arg) in parameters.iter().zip(args.iter()) {
+                if !candidates.contains_key(&param_id) || rejected.contains_key(&param_id) {
+                    continue;
+                }
+                if self.c_str_literal_arg(arg).is_some() {
+                    continue;
+                }
+                match self.c_str_param_read(arg) {
+                    Some((_, source)) if candidates.contains_key(&source) => {
+                        forwards.push((source, param_id));
+                    }
+                    _ => {
+                        let loc = self.ast_context.display_loc(&self.ast_context[arg].loc);
+                        let reason = match loc {
+                            Some(loc) => {
+                                format!("the call at {} does not pass a string literal", loc)
+                            }
+                            None => "a call site does not pass a string literal".to_string(),
+                        };
+                        rejected.insert(param_id, reason);
+                    }
+                }
+            }
+        }
+
+        self.reject_forwarded_params(&forwards, &mut rejected);
+
+        self.report_rejected_params(&candidates, &rejected, "`&CStr`");
+
+        candidates
+            .keys()
+            .filter(|param_id| !rejected.contains_key(*param_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Is the given parameter translated into a `&CStr`?
+    pub fn is_c_str_param(&self, param_id: CParamId) -> bool {
+        self.c_str_params.contains(&param_id)
+    }
+
+    /// If the expression reads a parameter translated into a `&CStr`, return
+    /// the `DeclRef` expression of that parameter.
+    pub fn c_str_param_ref(&self, expr_id: CExprId) -> Option<CExprId> {
+        if self.c_str_params.is_empty() {
+            return None;
+        }
+        match self.c_str_param_read(expr_id) {
+            Some((declref, param_id)) if self.is_c_str_param(param_id) => Some(declref),
+            _ => None,
+        }
+    }
+
+    /// The type of a parameter translated into a `&CStr`.
+    pub fn convert_c_str_param_type(&self) -> P<Ty> {
+        mk().ref_ty(mk().path_ty(vec!["", "std", "ffi", "CStr"]))
+    }
+
+    /// Convert a call argument passed to a parameter translated into a
+    /// `&CStr`. The analysis guarantees that the argument is either a string
+    /// literal or a `&CStr` parameter of the caller.
+    pub fn convert_c_str_arg(
+        &self,
+        ctx: ExprContext,
+        arg: CExprId,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        if let Some(lit) = self
+            .c_str_literal_arg(arg)
+            .and_then(|b| self.c_str_literal(b))
+        {
+            return Ok(WithStmts::new_val(lit));
+        }
+        match self.c_str_param_read(arg) {
+            Some((declref, _)) => self.convert_expr(ctx, declref),
+            None => Err(format_translation_err!(
+                self.ast_context.display_loc(&self.ast_context[arg].loc),
+                "Unexpected argument for `&CStr` parameter",
+            )),
+        }
+    }
+
+    /// Convert a `&CStr` parameter passed to a libc string function back into
+    /// a `*const c_char`.
+    pub fn convert_c_str_ptr(
+        &self,
+        ctx: ExprContext,
+        declref: CExprId,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        let param = self.convert_expr(ctx, declref)?;
+        Ok(param.map(|param| mk().method_call_expr(param, "as_ptr", vec![] as Vec<P<Expr>>)))
+    }
+
+    fn is_const_char_ptr(&self, typ: CQualTypeId) -> bool {
+        match self.ast_context.get_pointee_qual_type(typ.ctype) {
+            Some(pointee) if pointee.qualifiers.is_const => {
+                match self.ast_context.resolve_type(pointee.ctype).kind {
+                    CTypeKind::Char => true,
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// If the argument reads a parameter or variable, possibly adding a
+    /// `const` qualifier, return the `DeclRef` expression and the declaration.
+    fn c_str_param_read(&self, arg: CExprId) -> Option<(CExprId, CDeclId)> {
+        match *self.strip_noop_casts(arg) {
+            CExprKind::ImplicitCast(_, declref, CastKind::LValueToRValue, _, _) => {
+                match self.ast_context[declref].kind {
+                    CExprKind::DeclRef(_, decl_id, _) => Some((declref, decl_id)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn c_str_literal_text<'a>(&self, bytes: &'a [u8]) -> Option<&'a str> {
+        if !self.tcfg.translate_c_strings || self.tcfg.emit_no_std || bytes.contains(&0) {
+            return None;
+        }
+        str::from_utf8(bytes).ok()
+    }
+
+    /// If the argument is a string literal that can become a `cstr!` literal,
+    /// return its contents.
+    fn c_str_literal_arg(&self, arg: CExprId) -> Option<&[u8]> {
+        match *self.strip_noop_casts(arg) {
+            CExprKind::ImplicitCast(_, lit, CastKind::ArrayToPointerDecay, _, _) => {
+                match self.ast_context[lit].kind {
+                    CExprKind::Literal(_, CLiteral::String(ref bytes, 1))
+                        if self.c_str_literal_text(bytes).is_some() =>
+                    {
+                        Some(bytes)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Does the `i`th argument of a call to the given function only read a C
+    /// string? Passing a parameter on to another candidate is fine too, as
+    /// call sites are checked separately.
+    fn takes_c_string(
+        &self,
+        callee: CDeclId,
+        i: usize,
+        candidates: &IndexMap<CParamId, CDeclId>,
+    ) -> bool {
+        let (name, typ, parameters, is_libc_decl) = match self.ast_context[callee].kind {
+            CDeclKind::Function {
+                ref name,
+                typ,
+                ref parameters,
+                is_global,
+                body,
+                ..
+            } => (
+                name,
+                typ,
+                parameters,
+                is_global && body.is_none() && self.is_in_system_header(callee),
+            ),
+            _ => return false,
+        };
+        if let Some(param_id) = parameters.get(i) {
+            if candidates.contains_key(param_id) {
+                return true;
+            }
+        }
+        // A function of the translation unit can share its name with one of
+        // libc's without sharing its behavior
+        if !is_libc_decl || !C_STRING_FUNCTIONS.contains(&name.as_str()) {
+            return false;
+        }
+        match self.ast_context.resolve_type(typ).kind {
+            CTypeKind::Function(_, ref params, is_variadic, _, _) => match params.get(i) {
+                Some(&param_ty) => self.is_const_char_ptr(param_ty),
+                None => is_variadic,
+            },
+            _ => false,
+        }
+    }
+
+    /// Is the declaration located in a system header?
+    fn is_in_system_header(&self, decl_id: CDeclId) -> bool {
+        self.ast_context
+            .get_source_path(&self.ast_context[decl_id])
+            .and_then(|path| path.to_str())
+            .map_or(false, |path| {
+                SYSTEM_HEADER_DIRS.iter().any(|dir| path.starts_with(dir))
+            })
+    }
+}
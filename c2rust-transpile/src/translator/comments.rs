@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use syntax::util::comments::CommentStyle;
 use syntax::source_map::{DUMMY_SP, Span};
-use crate::c_ast::{CDeclId, CDeclKind, CommentContext, SrcLoc, TypedAstContext};
+use crate::c_ast::{CDeclId, CDeclKind, CStmtKind, CommentContext, SrcLoc, TypedAstContext};
 use crate::c_ast::iterators::{NodeVisitor, SomeId};
 use crate::rust_ast::pos_to_span;
 use crate::rust_ast::comment_store::CommentStore;
@@ -13,34 +13,413 @@ struct CommentLocator<'c> {
     comment_store: &'c mut CommentStore,
     spans: &'c mut HashMap<SomeId, Span>,
     top_decls: &'c HashSet<CDeclId>,
+    item_decls: &'c HashSet<CDeclId>,
     last_id: Option<SomeId>,
 }
 
+/// The text of a Doxygen or Javadoc comment (`/** ... */`, `/*! ... */`,
+/// `/// ...` or `//! ...`) as `///` doc comment lines, or `None` if `comment`
+/// isn't a doc comment. Member comments such as `///< ...` are doc comments
+/// only if `member` is set.
+fn doc_comment_lines(comment: &str, member: bool) -> Option<Vec<String>> {
+    let comment = comment.trim();
+    let (body, is_block) = if comment.starts_with("/**") || comment.starts_with("/*!") {
+        if comment.len() < 5 || comment[3..].starts_with('*') || comment[3..].starts_with('/') {
+            // `/**/` and `/*****` banners
+            return None;
+        }
+        (comment[3..comment.len() - 2].to_owned(), true)
+    } else if comment.starts_with("///") || comment.starts_with("//!") {
+        if comment[3..].starts_with('/') {
+            return None;
+        }
+        let body = comment
+            .lines()
+            .map(|line| {
+                let line = line.trim_start();
+                if line.starts_with("///") || line.starts_with("//!") {
+                    &line[3..]
+                } else {
+                    line
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        (body, false)
+    } else {
+        return None;
+    };
+
+    let body = if body.starts_with('<') {
+        if !member {
+            return None;
+        }
+        &body[1..]
+    } else if member {
+        return None;
+    } else {
+        &body[..]
+    };
+
+    let lines: Vec<&str> = body
+        .lines()
+        .map(|line| {
+            let line = line.trim_end();
+            let start = line.trim_start();
+            // Strip the decoration of lines continuing a block
+            if is_block && start.starts_with('*') {
+                &start[1..]
+            } else {
+                line
+            }
+        })
+        .collect();
+    let indent_of = |line: &str| line.len() - line.trim_start_matches(|c| c == ' ' || c == '\t').len();
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| indent_of(line))
+        .min()
+        .unwrap_or(0);
+    let mut lines: Vec<&str> = lines
+        .into_iter()
+        .map(|line| if line.trim().is_empty() { "" } else { &line[indent..] })
+        .collect();
+    while lines.first().map_or(false, |line| line.is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().map_or(false, |line| line.is_empty()) {
+        lines.pop();
+    }
+
+    Some(
+        rustdoc_lines(&lines)?
+            .into_iter()
+            .map(|line| {
+                if line.is_empty() {
+                    String::from("///")
+                } else {
+                    format!("/// {}", line)
+                }
+            })
+            .collect(),
+    )
+}
+
+/// A paragraph of a Doxygen comment
+enum DocTarget {
+    Body,
+    Param,
+    Returns,
+    Retval,
+}
+
+/// Convert the text of a Doxygen comment into Markdown for rustdoc. Parameters
+/// and return values are moved into `# Arguments` and `# Returns` sections, and
+/// `@see` and `@ref` references become intra-doc links written with the C name
+/// of their target, see `resolve_doc_links`. Comments documenting files or
+/// groups rather than declarations are not converted.
+fn rustdoc_lines(lines: &[&str]) -> Option<Vec<String>> {
+    let mut body: Vec<String> = vec![];
+    let mut params: Vec<(String, String)> = vec![];
+    let mut returns: Vec<String> = vec![];
+    let mut retvals: Vec<(String, String)> = vec![];
+    let mut see: Vec<String> = vec![];
+    let mut target = DocTarget::Body;
+    let mut in_code = false;
+
+    for &line in lines {
+        let trimmed = line.trim_start();
+        let (command, rest) = match split_command(trimmed) {
+            Some((command, rest)) if !in_code || command == "endcode" => (command, rest),
+            _ => ("", trimmed),
+        };
+        if in_code {
+            if command == "endcode" {
+                body.push(String::from("```"));
+                in_code = false;
+            } else {
+                body.push(line.to_owned());
+            }
+            continue;
+        }
+
+        let (param_name, param_desc) = split_word(rest);
+        match command {
+            "file" | "mainpage" | "page" | "defgroup" | "addtogroup" | "dir" => return None,
+            "{" | "}" | "ingroup" | "internal" => {}
+            "brief" | "short" | "details" => {
+                if command == "details" && !body.is_empty() {
+                    body.push(String::new());
+                }
+                target = DocTarget::Body;
+                body.push(convert_inline_commands(rest));
+            }
+            "param" | "tparam" => {
+                // Drop directions such as `@param[in]`
+                let (name, desc) = if rest.starts_with('[') {
+                    match rest.find(']') {
+                        Some(end) => split_word(&rest[end + 1..]),
+                        None => (param_name, param_desc),
+                    }
+                } else {
+                    (param_name, param_desc)
+                };
+                target = DocTarget::Param;
+                params.push((name.to_owned(), convert_inline_commands(desc)));
+            }
+            "return" | "returns" | "result" => {
+                target = DocTarget::Returns;
+                returns.push(convert_inline_commands(rest));
+            }
+            "retval" => {
+                target = DocTarget::Retval;
+                retvals.push((param_name.to_owned(), convert_inline_commands(param_desc)));
+            }
+            "see" | "sa" => {
+                target = DocTarget::Body;
+                see.extend(
+                    rest.split(|c: char| c == ',' || c.is_whitespace())
+                        .map(|name| name.trim_end_matches("()"))
+                        .filter(|name| !name.is_empty())
+                        .map(|name| format!("[`{}`]", name)),
+                );
+            }
+            "note" | "remark" | "remarks" | "warning" | "attention" => {
+                if !body.is_empty() {
+                    body.push(String::new());
+                }
+                let label = match command {
+                    "warning" | "attention" => "Warning",
+                    _ => "Note",
+                };
+                target = DocTarget::Body;
+                body.push(format!("**{}:** {}", label, convert_inline_commands(rest)));
+            }
+            "code" => {
+                if body.last().map_or(false, |line| !line.is_empty()) {
+                    body.push(String::new());
+                }
+                target = DocTarget::Body;
+                body.push(String::from("```c"));
+                in_code = true;
+            }
+            _ if trimmed.is_empty() => {
+                target = DocTarget::Body;
+                if body.last().map_or(false, |line| !line.is_empty()) {
+                    body.push(String::new());
+                }
+            }
+            _ => {
+                // Continuation lines, and commands we don't know about
+                let text = convert_inline_commands(trimmed);
+                let last = match target {
+                    DocTarget::Body => {
+                        body.push(convert_inline_commands(line));
+                        continue;
+                    }
+                    DocTarget::Param => params.last_mut().map(|param| &mut param.1),
+                    DocTarget::Returns => returns.last_mut(),
+                    DocTarget::Retval => retvals.last_mut().map(|retval| &mut retval.1),
+                };
+                if let Some(last) = last {
+                    if !last.is_empty() {
+                        last.push(' ');
+                    }
+                    last.push_str(&text);
+                }
+            }
+        }
+    }
+    if in_code {
+        body.push(String::from("```"));
+    }
+
+    while body.first().map_or(false, |line| line.is_empty()) {
+        body.remove(0);
+    }
+    while body.last().map_or(false, |line| line.is_empty()) {
+        body.pop();
+    }
+    let mut section = |title: &str, lines: Vec<String>| {
+        if !lines.is_empty() {
+            if !body.is_empty() {
+                body.push(String::new());
+            }
+            body.push(format!("# {}", title));
+            body.push(String::new());
+            body.extend(lines);
+        }
+    };
+    section(
+        "Arguments",
+        params
+            .into_iter()
+            .map(|(name, desc)| format!("* `{}` - {}", name, desc))
+            .collect(),
+    );
+    if !returns.is_empty() && !retvals.is_empty() {
+        returns.push(String::new());
+    }
+    returns.extend(
+        retvals
+            .into_iter()
+            .map(|(value, desc)| format!("* `{}` - {}", value, desc)),
+    );
+    section("Returns", returns);
+    section(
+        "See also",
+        see.into_iter().map(|link| format!("* {}", link)).collect(),
+    );
+    Some(body)
+}
+
+/// Split the Doxygen command (`@name` or `\name`) starting `line` from the
+/// rest of the line
+fn split_command(line: &str) -> Option<(&str, &str)> {
+    if !line.starts_with('@') && !line.starts_with('\\') {
+        return None;
+    }
+    let line = &line[1..];
+    let len = if line.starts_with('{') || line.starts_with('}') {
+        1
+    } else {
+        line.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(line.len())
+    };
+    if len == 0 {
+        return None;
+    }
+    Some((&line[..len], line[len..].trim_start()))
+}
+
+/// Split the first word of `text` from the rest
+fn split_word(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    let len = text.find(char::is_whitespace).unwrap_or(text.len());
+    (&text[..len], text[len..].trim_start())
+}
+
+/// Convert the Doxygen commands formatting a word (`@p`, `@c`, `@a`, `@e`,
+/// `@em`, `@b` and `@ref`) into Markdown
+fn convert_inline_commands(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(|c| c == '@' || c == '\\') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let len = after.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(after.len());
+        let (command, arg) = after.split_at(len);
+        let word = arg.trim_start();
+        let word_len = word
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(word.len());
+        if word_len == 0 || word.len() == arg.len() {
+            out.push_str(&rest[start..start + 1]);
+            rest = after;
+            continue;
+        }
+        let (word, after_word) = word.split_at(word_len);
+        match command {
+            "p" | "c" => out.push_str(&format!("`{}`", word)),
+            "a" | "e" | "em" => out.push_str(&format!("*{}*", word)),
+            "b" => out.push_str(&format!("**{}**", word)),
+            "ref" | "link" => {
+                out.push_str(&format!("[`{}`]", word));
+                rest = after_word.trim_start_matches("()");
+                continue;
+            }
+            _ => {
+                out.push_str(&rest[start..start + 1]);
+                rest = after;
+                continue;
+            }
+        }
+        rest = after_word;
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Point the intra-doc links of `line`, written with the C names of their
+/// targets, to the Rust names in `names`. Links to names we don't know are
+/// turned into plain code spans.
+fn link_to_rust_names(line: &str, names: &HashMap<&str, String>) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+    while let Some(start) = rest.find("[`") {
+        let after = &rest[start + 2..];
+        let end = match after.find("`]") {
+            Some(end) => end,
+            None => break,
+        };
+        let (name, tail) = (&after[..end], &after[end + 2..]);
+        out.push_str(&rest[..start]);
+        let is_ident = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+        if tail.starts_with('(') || !is_ident {
+            out.push_str(&rest[start..start + end + 4]);
+        } else {
+            match names.get(name) {
+                Some(rust_name) if rust_name == name => out.push_str(&format!("[`{}`]", name)),
+                Some(rust_name) => out.push_str(&format!("[`{}`]({})", name, rust_name)),
+                None => out.push_str(&format!("`{}`", name)),
+            }
+        }
+        rest = tail;
+    }
+    out.push_str(rest);
+    out
+}
+
 impl<'c> CommentLocator<'c> {
+    /// Whether `id` is translated into an item, which can have doc comments.
+    fn is_item(&self, id: SomeId) -> bool {
+        match id {
+            SomeId::Decl(id) => {
+                self.item_decls.contains(&id)
+                    || match self.ast_context[id].kind {
+                        CDeclKind::Field { .. } | CDeclKind::EnumConstant { .. } => true,
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+
     /// Check for comments starting on the same line but after the end of the
     /// last node and before the end of the current node.
     fn check_last_for_trailing(&mut self, cur_loc: SrcLoc) {
         let last_id = match self.last_id {
-            // We can only attach trailing comments to the end of statements
-            // currently. The pretty-printer only supports trailing comments on
-            // statements and comma-separated exprs, but we don't support
-            // comments after comma-separated exprs yet.
+            // We can only attach trailing comments to the end of statements,
+            // struct fields and enum constants currently. The pretty-printer
+            // also supports trailing comments on comma-separated exprs, but we
+            // don't support comments after comma-separated exprs yet.
             Some(SomeId::Stmt(id)) => SomeId::Stmt(id),
+            Some(SomeId::Decl(id)) => match self.ast_context[id].kind {
+                CDeclKind::Field { .. } | CDeclKind::EnumConstant { .. } => SomeId::Decl(id),
+                _ => return,
+            },
             _ => return,
         };
         if let Some(last_loc) = self.ast_context.get_src_loc(last_id) {
-            // TODO: handle Mixed comments (code before and after the
-            // comment on the same line
-            if cur_loc.line == last_loc.end_line {
-                return;
-            }
-
+            // Comments between two nodes on the same line stay after the first
+            // one, which ends the line in the translation.
             while let Some(comment) = self.comment_context
                 .peek_next_comment_on_line(last_loc.end(), &self.ast_context)
             {
                 if comment.loc.unwrap().end() < cur_loc {
                     let existing_pos = self.spans.get(&last_id).map(|span| span.lo());
-                    if let Some(pos) = self.comment_store.extend_existing_comments(
+                    let member_doc = match last_id {
+                        SomeId::Decl(_) => doc_comment_lines(&comment.kind, true),
+                        _ => None,
+                    };
+                    if let Some(lines) = member_doc {
+                        // Doxygen comments documenting the preceding member
+                        // become its doc comment
+                        if let Some(pos) = self.comment_store.extend_existing_doc_comments(lines, existing_pos) {
+                            debug!("Attaching member doc comment {:?} at pos {:?}", comment.kind, pos);
+                            self.spans.entry(last_id).or_insert_with(|| pos_to_span(pos));
+                        }
+                    } else if let Some(pos) = self.comment_store.extend_existing_comments(
                         &[comment.kind.clone()],
                         existing_pos,
                         CommentStyle::Trailing,
@@ -75,7 +454,7 @@ impl<'c> NodeVisitor for CommentLocator<'c> {
             // attach to the end of the last node.
             self.check_last_for_trailing(loc.begin());
 
-            let comments = self.comment_context
+            let mut comments = self.comment_context
                 .get_comments_before(loc.begin(), &self.ast_context);
             if let SomeId::Decl(decl_id) = id {
                 let decl_kind = &self.ast_context[decl_id].kind;
@@ -85,6 +464,18 @@ impl<'c> NodeVisitor for CommentLocator<'c> {
                     id = SomeId::Decl(*canonical_decl);
                 }
             }
+
+            // Doc comments are only allowed before items
+            let mut docs = vec![];
+            if self.is_item(id) {
+                comments.retain(|comment| match doc_comment_lines(comment, false) {
+                    Some(lines) => {
+                        docs.extend(lines);
+                        false
+                    }
+                    None => true,
+                });
+            }
             if let Some(existing) = self.spans.get(&id) {
                 let new_pos = self.comment_store.extend_existing_comments(
                     &comments,
@@ -97,6 +488,13 @@ impl<'c> NodeVisitor for CommentLocator<'c> {
                 let span = pos_to_span(pos);
                 self.spans.insert(id, span);
             }
+            if !docs.is_empty() {
+                let existing_pos = self.spans.get(&id).map(|span| span.lo());
+                if let Some(pos) = self.comment_store.extend_existing_doc_comments(docs, existing_pos) {
+                    debug!("Attaching doc comments to id {:?} at pos {:?}", id, pos);
+                    self.spans.entry(id).or_insert_with(|| pos_to_span(pos));
+                }
+            }
         }
 
         // Don't traverse into macro object replacement expressions, as they are
@@ -119,6 +517,11 @@ impl<'c> NodeVisitor for CommentLocator<'c> {
             }
         }
         if let Some(loc) = self.ast_context.get_src_loc(id) {
+            // Check if we have a comment before the end of this node that we
+            // need to attach to the end of the last node, before the remaining
+            // comments are attached to the end of this one.
+            self.check_last_for_trailing(loc.end());
+
             let comments = self.comment_context
                 .get_comments_before(loc.end(), &self.ast_context);
             if let Some(pos) = self.comment_store.add_comments(&comments) {
@@ -127,12 +530,16 @@ impl<'c> NodeVisitor for CommentLocator<'c> {
                     .or_insert(DUMMY_SP);
                 *span = span.with_hi(pos);
             }
-
-            // Check if we have a comment before the end of this node that we
-            // need to attach to the end of the last node.
-            self.check_last_for_trailing(loc.end());
         }
 
+        // Labels end with the statement they label, which is the one that ends
+        // the line in the translation.
+        if let SomeId::Stmt(id) = id {
+            match self.ast_context[id].kind {
+                CStmtKind::Label(_) | CStmtKind::Case(..) | CStmtKind::Default(_) => return,
+                _ => {}
+            }
+        }
         self.last_id = Some(id);
     }
 }
@@ -145,6 +552,7 @@ impl<'c> Translation<'c> {
             .iter()
             .copied()
             .collect();
+        let item_decls = top_decls.clone();
         let mut spans: HashMap<SomeId, Span> = HashMap::new();
         for decl_id in &self.ast_context.c_decls_top {
             top_decls.remove(decl_id);
@@ -154,6 +562,7 @@ impl<'c> Translation<'c> {
                 comment_store: &mut *self.comment_store.borrow_mut(),
                 spans: &mut spans,
                 top_decls: &top_decls,
+                item_decls: &item_decls,
                 last_id: None,
             };
             visitor.visit_tree(&self.ast_context, SomeId::Decl(*decl_id));
@@ -161,7 +570,122 @@ impl<'c> Translation<'c> {
         self.spans = spans;
     }
 
+    /// Resolve the intra-doc links of the doc comments we emit, which name C
+    /// declarations, to the items they are translated into. This must run
+    /// once all declarations have been named.
+    pub fn resolve_doc_links(&self) {
+        let mut names: HashMap<&str, String> = HashMap::new();
+        for (&decl_id, decl) in self.ast_context.iter_decls() {
+            let c_name = match decl.kind {
+                CDeclKind::Function { ref name, .. }
+                | CDeclKind::Typedef { ref name, .. }
+                | CDeclKind::EnumConstant { ref name, .. }
+                | CDeclKind::MacroObject { ref name, .. }
+                | CDeclKind::Struct { name: Some(ref name), .. }
+                | CDeclKind::Union { name: Some(ref name), .. }
+                | CDeclKind::Enum { name: Some(ref name), .. } => name,
+                CDeclKind::Variable { ref ident, .. }
+                    if self.ast_context.c_decls_top.contains(&decl_id) => ident,
+                _ => continue,
+            };
+            let rust_name = match decl.kind {
+                CDeclKind::EnumConstant { .. } => {
+                    let name = self.renamer.borrow().get(&decl_id);
+                    // Variants of Rust enums and associated constants of flags
+                    // types are named through their type
+                    match self.ast_context.parents.get(&decl_id) {
+                        Some(enum_id)
+                            if self.rust_enums.contains(enum_id)
+                                || self.flag_enums.contains(enum_id) =>
+                        {
+                            let enum_name = self.type_converter.borrow().resolve_decl_name(*enum_id);
+                            enum_name.and_then(|enum_name| {
+                                name.map(|name| format!("{}::{}", enum_name, name))
+                            })
+                        }
+                        _ => name,
+                    }
+                }
+                CDeclKind::Struct { .. }
+                | CDeclKind::Union { .. }
+                | CDeclKind::Enum { .. }
+                | CDeclKind::Typedef { .. } => {
+                    self.type_converter.borrow().resolve_decl_name(decl_id)
+                }
+                _ => self.renamer.borrow().get(&decl_id),
+            };
+            if let Some(rust_name) = rust_name {
+                names.entry(c_name).or_insert(rust_name);
+            }
+        }
+        self.comment_store
+            .borrow_mut()
+            .map_doc_comment_lines(|line| link_to_rust_names(line, &names));
+    }
+
     pub fn get_span(&self, id: SomeId) -> Option<Span> {
         self.spans.get(&id).copied()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doxygen_sections() {
+        let comment = "/**
+                        * @brief Scales a pair.
+                        *
+                        * @param[in] p the pair, see @ref pair
+                        * @param factor the factor,
+                        *        @p factor must not be zero
+                        * @return the scaled sum
+                        * @see pair_score()
+                        */";
+        let lines = doc_comment_lines(comment, false).unwrap();
+        assert_eq!(
+            lines,
+            vec![
+                "/// Scales a pair.",
+                "///",
+                "/// # Arguments",
+                "///",
+                "/// * `p` - the pair, see [`pair`]",
+                "/// * `factor` - the factor, `factor` must not be zero",
+                "///",
+                "/// # Returns",
+                "///",
+                "/// the scaled sum",
+                "///",
+                "/// # See also",
+                "///",
+                "/// * [`pair_score`]",
+            ]
+        );
+    }
+
+    #[test]
+    fn doc_comment_kinds() {
+        assert_eq!(
+            doc_comment_lines("///< the first member", true),
+            Some(vec![String::from("/// the first member")])
+        );
+        assert_eq!(doc_comment_lines("///< the first member", false), None);
+        assert_eq!(doc_comment_lines("/** a declaration */", true), None);
+        assert_eq!(doc_comment_lines("/* not documentation */", false), None);
+        assert_eq!(doc_comment_lines("/****************/", false), None);
+        assert_eq!(doc_comment_lines("/** @file pair.h */", false), None);
+    }
+
+    #[test]
+    fn links() {
+        let mut names = HashMap::new();
+        names.insert("pair", String::from("pair"));
+        names.insert("pair_score", String::from("rust_pair_score"));
+        assert_eq!(
+            link_to_rust_names("/// * [`pair_score`], [`pair`] or [`unknown`]", &names),
+            "/// * [`pair_score`](rust_pair_score), [`pair`] or `unknown`"
+        );
+    }
+}
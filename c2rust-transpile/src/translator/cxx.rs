@@ -0,0 +1,81 @@
+#![deny(missing_docs)]
+//! This module implements the translation of C++ references for the
+//! C-compatible subset of C++ accepted by the exporter.
+//!
+//! A reference `T&` is lowered to the raw pointer `*mut T` (or `*const T` for
+//! `const T&`) that implements it:
+//!
+//!   * binding a reference, whether by initializing a variable, passing an
+//!     argument or returning from a function, takes the address of the bound
+//!     lvalue;
+//!   * naming a reference variable, or calling a function that returns a
+//!     reference, dereferences that pointer.
+//!
+//! Clang gives reference-typed expressions the type of the referenced value,
+//! so everything else translates exactly as it would for C.
+
+use super::*;
+
+impl<'c> Translation<'c> {
+    /// Whether a type resolves to a C++ reference.
+    pub fn is_reference_type(&self, typ: CTypeId) -> bool {
+        match self.ast_context.resolve_type(typ).kind {
+            CTypeKind::Reference(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether a declaration is a variable or parameter of reference type.
+    pub fn is_reference_decl(&self, decl_id: CDeclId) -> bool {
+        match self.ast_context[decl_id].kind {
+            CDeclKind::Variable { typ, .. } => self.is_reference_type(typ.ctype),
+            _ => false,
+        }
+    }
+
+    /// Whether a function type returns a reference.
+    pub fn returns_reference(&self, fn_ty: Option<&CTypeKind>) -> bool {
+        match fn_ty {
+            Some(CTypeKind::Function(ret, ..)) => self.is_reference_type(ret.ctype),
+            _ => false,
+        }
+    }
+
+    /// Translate the lvalue a reference is bound to into the pointer that
+    /// stands for the reference.
+    pub fn convert_reference_binding(
+        &self,
+        ctx: ExprContext,
+        expr: CExprId,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        let is_const = self.ast_context[expr]
+            .kind
+            .get_qual_type()
+            .map_or(false, |ty| ty.qualifiers.is_const);
+        let val = self.convert_expr(ctx.used().set_needs_address(true), expr)?;
+
+        Ok(val.map(|val| {
+            if ctx.is_static {
+                // Statics cannot borrow mutably, so go through `*const` as
+                // `&`-expressions in static initializers do.
+                let ptr = mk().cast_expr(
+                    mk().addr_of_expr(val),
+                    mk().set_mutbl(Mutability::Immutable)
+                        .ptr_ty(mk().infer_ty()),
+                );
+                if is_const {
+                    ptr
+                } else {
+                    mk().cast_expr(
+                        ptr,
+                        mk().set_mutbl(Mutability::Mutable).ptr_ty(mk().infer_ty()),
+                    )
+                }
+            } else if is_const {
+                mk().addr_of_expr(val)
+            } else {
+                mk().mutbl().addr_of_expr(val)
+            }
+        }))
+    }
+}
@@ -0,0 +1,1085 @@
+//! This module implements the opt-in translation of C enums into `#[repr(C)]`
+//! Rust enums and flags types.
+//!
+//! By default, an enum becomes a type alias of its underlying integer type and
+//! its constants become loose `const` items, since a C enum variable may hold
+//! any value of that type. A Rust enum may only hold one of its variants, so
+//! the analysis runs over the whole translation unit and an enum is only
+//! translated into one when
+//!
+//!   * its constants have distinct values that fit in a C `int`, one of which
+//!     is zero (the value of zero-initialized variables);
+//!   * every value converted to it is one of its own constants;
+//!   * values of it are only converted to integers, never used in arithmetic,
+//!     as conditions, or accessed through pointers to other types;
+//!   * it is never stored in a union or a bit-field, passed through variadic
+//!     arguments, or shared with declarations that other translation units
+//!     can see, which may hold any value. Any enum that the type of such a
+//!     declaration mentions counts, even behind pointers, struct fields or
+//!     typedefs.
+//!
+//! Its constants are then referred to as `Enum::CONSTANT`, and a `switch`
+//! over it becomes a `match` on its variants, which has no catch-all arm when
+//! every variant has a case.
+//!
+//! Enums whose constants are bit masks, combined with `|` and tested with `&`,
+//! are translated into flags types instead: a `#[repr(transparent)]` newtype
+//! of the underlying integer type with an associated constant per enum
+//! constant, `BitOr` and `BitAnd` implementations and a `contains` method. An
+//! enum is translated into one when
+//!
+//!   * its constants are non-negative and made of the bits of at least two
+//!     single-bit constants;
+//!   * its values or constants are operands of bitwise operators;
+//!   * values of it are only converted to and from integers, never incremented
+//!     or used in arithmetic other than compound assignments;
+//!   * it is never stored in a bit-field, passed through variadic arguments or
+//!     accessed atomically.
+//!
+//! Since the newtype has the layout of its integer type, a flags type may be
+//! stored in unions and shared with undefined declarations.
+
+use super::*;
+use crate::c_ast::iterators::immediate_children_all_types;
+use crate::diagnostics::Diagnostic;
+use std::i32;
+
+impl<'c> Translation<'c> {
+    /// Compute the set of enums that can be translated into Rust enums. Enums
+    /// that are used as a type but were rejected are reported through the
+    /// `idioms` diagnostic.
+    pub fn compute_rust_enums(&self) -> IndexSet<CEnumId> {
+        let mut candidates: IndexSet<CEnumId> = IndexSet::new();
+        let mut rejected: IndexMap<CEnumId, &'static str> = IndexMap::new();
+        let mut reject = |enum_id: CEnumId, reason: &'static str| {
+            rejected.entry(enum_id).or_insert(reason);
+        };
+
+        for (&decl_id, decl) in self.ast_context.iter_decls() {
+            match decl.kind {
+                CDeclKind::Variable { typ, .. }
+                | CDeclKind::Field { typ, .. }
+                | CDeclKind::Typedef { typ, .. } => {
+                    if let Some(enum_id) = self.enum_of(typ.ctype) {
+                        if !self.flag_enums.contains(&enum_id) {
+                            candidates.insert(enum_id);
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            match decl.kind {
+                CDeclKind::Enum {
+                    integral_type: Some(_),
+                    ref variants,
+                    ..
+                } => {
+                    if let Some(reason) = self.enum_variants_error(variants) {
+                        reject(decl_id, reason);
+                    }
+                }
+                CDeclKind::Enum { .. } => reject(decl_id, "it is never defined"),
+                CDeclKind::Field {
+                    typ,
+                    bitfield_width: Some(_),
+                    ..
+                } => {
+                    if let Some(enum_id) = self.enum_of(typ.ctype) {
+                        reject(enum_id, "it is the type of a bit-field");
+                    }
+                }
+                CDeclKind::Union {
+                    fields: Some(ref fields),
+                    ..
+                } => {
+                    for &field_id in fields {
+                        if let CDeclKind::Field { typ, .. } = self.ast_context[field_id].kind {
+                            for enum_id in self.enums_mentioned(typ.ctype) {
+                                reject(enum_id, "it is stored in a union");
+                            }
+                        }
+                    }
+                }
+                CDeclKind::Function {
+                    typ,
+                    body,
+                    is_global,
+                    ..
+                } if body.is_none() || is_global => {
+                    let reason = if body.is_none() {
+                        "it is shared with undefined functions"
+                    } else {
+                        "it is shared with exported functions"
+                    };
+                    for enum_id in self.enums_mentioned(typ) {
+                        reject(enum_id, reason);
+                    }
+                }
+                CDeclKind::Variable {
+                    is_defn,
+                    is_externally_visible,
+                    typ,
+                    ..
+                } if !is_defn || is_externally_visible => {
+                    let reason = if !is_defn {
+                        "it is shared with undefined variables"
+                    } else {
+                        "it is shared with exported variables"
+                    };
+                    for enum_id in self.enums_mentioned(typ.ctype) {
+                        reject(enum_id, reason);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for &decl_id in &self.ast_context.c_decls_top {
+            for node in DFExpr::new(&self.ast_context, decl_id.into()) {
+                let stmt_id = match node {
+                    SomeId::Expr(expr_id) => {
+                        if let Some((enum_id, reason)) = self.enum_use_error(expr_id) {
+                            reject(enum_id, reason);
+                        }
+                        for enum_id in self.enums_punned(expr_id) {
+                            reject(enum_id, "it is accessed through pointers to other types");
+                        }
+                        continue;
+                    }
+                    SomeId::Stmt(stmt_id) => stmt_id,
+                    _ => continue,
+                };
+                let conditions = match self.ast_context[stmt_id].kind {
+                    CStmtKind::If { scrutinee, .. } => vec![scrutinee],
+                    CStmtKind::While { condition, .. } | CStmtKind::DoWhile { condition, .. } => {
+                        vec![condition]
+                    }
+                    CStmtKind::ForLoop { condition, .. } => condition.into_iter().collect(),
+                    CStmtKind::Asm { ref outputs, .. } => {
+                        for output in outputs {
+                            if let Some(enum_id) = self.expr_enum(output.expression) {
+                                reject(enum_id, "it is written by inline assembly");
+                            }
+                        }
+                        vec![]
+                    }
+                    CStmtKind::Switch { scrutinee, body } => {
+                        if let Some((enum_id, _)) = self.promoted_enum(scrutinee) {
+                            let variants = self.enum_variants(enum_id);
+                            let in_range = self.switch_case_values(body).iter().all(|&value| {
+                                variants.iter().any(|&v| self.enum_constant_is(v, value))
+                            });
+                            if !in_range {
+                                reject(enum_id, "a `switch` over it has other cases");
+                            }
+                        }
+                        vec![scrutinee]
+                    }
+                    _ => vec![],
+                };
+                for condition in conditions {
+                    if let Some(enum_id) = self.expr_enum(condition) {
+                        reject(enum_id, "it is used as a condition");
+                    }
+                }
+            }
+        }
+
+        self.report_rejected_enums(&candidates, &rejected, "a Rust enum");
+        candidates
+            .into_iter()
+            .filter(|enum_id| !rejected.contains_key(enum_id))
+            .collect()
+    }
+
+    /// Why the constants of an enum cannot become the variants of a Rust enum
+    fn enum_variants_error(&self, variants: &[CEnumConstantId]) -> Option<&'static str> {
+        let values: Vec<i64> = variants
+            .iter()
+            .filter_map(|&v| match self.ast_context[v].kind {
+                CDeclKind::EnumConstant { value, .. } => Some(value),
+                _ => None,
+            })
+            .filter_map(|value| match value {
+                ConstIntExpr::I(v) if v >= i32::MIN as i64 && v <= i32::MAX as i64 => Some(v),
+                ConstIntExpr::U(v) if v <= i32::MAX as u64 => Some(v as i64),
+                _ => None,
+            })
+            .collect();
+        if values.len() != variants.len() {
+            return Some("some of its constants do not fit in an `int`");
+        }
+        if !values.contains(&0) {
+            return Some("none of its constants is zero");
+        }
+        let distinct: IndexSet<i64> = values.iter().cloned().collect();
+        if distinct.len() != values.len() {
+            return Some("some of its constants have the same value");
+        }
+        None
+    }
+
+    /// Why an expression prevents one of the enums it uses from becoming a
+    /// Rust enum
+    fn enum_use_error(&self, expr_id: CExprId) -> Option<(CEnumId, &'static str)> {
+        let operand_enum = |e: CExprId| self.expr_enum(e);
+        match self.ast_context[expr_id].kind {
+            CExprKind::ImplicitCast(ty, src, cast_kind, _, _)
+            | CExprKind::ExplicitCast(ty, src, cast_kind, _, _) => {
+                let target = self.enum_of(ty.ctype);
+                let source = operand_enum(src);
+                if let Some(enum_id) = target.filter(|&e| source != Some(e)) {
+                    let is_constant = cast_kind == CastKind::IntegralCast
+                        && self.is_constant_of(enum_id, src);
+                    if !is_constant {
+                        return Some((enum_id, "it is assigned values that are not its constants"));
+                    }
+                }
+                if let Some(enum_id) = source.filter(|&e| target != Some(e)) {
+                    let to_integer = match cast_kind {
+                        CastKind::IntegralCast => self
+                            .ast_context
+                            .resolve_type(ty.ctype)
+                            .kind
+                            .is_integral_type(),
+                        CastKind::LValueToRValue | CastKind::NoOp | CastKind::ToVoid => true,
+                        _ => false,
+                    };
+                    if !to_integer {
+                        return Some((enum_id, "it is converted to a type other than an integer"));
+                    }
+                }
+                None
+            }
+            CExprKind::Unary(_, c_ast::UnOp::AddressOf, _, _) => None,
+            CExprKind::Unary(_, _, arg, _) => {
+                operand_enum(arg).map(|enum_id| (enum_id, "arithmetic is done on it"))
+            }
+            CExprKind::Binary(_, op, lhs, rhs, _, _) => match op {
+                c_ast::BinOp::Assign | c_ast::BinOp::Comma => None,
+                _ => operand_enum(lhs)
+                    .or_else(|| operand_enum(rhs))
+                    .map(|enum_id| (enum_id, "arithmetic is done on it")),
+            },
+            CExprKind::Conditional(_, cond, _, _) | CExprKind::BinaryConditional(_, cond, _) => {
+                operand_enum(cond).map(|enum_id| (enum_id, "it is used as a condition"))
+            }
+            _ => self.enum_passing_error(expr_id),
+        }
+    }
+
+    /// Compute the set of enums that can be translated into flags types.
+    /// Enums that look like flags but were rejected are reported through the
+    /// `idioms` diagnostic.
+    pub fn compute_flag_enums(&self) -> IndexSet<CEnumId> {
+        let mut typed: IndexSet<CEnumId> = IndexSet::new();
+        let mut combined: IndexSet<CEnumId> = IndexSet::new();
+        let mut rejected: IndexMap<CEnumId, &'static str> = IndexMap::new();
+        let mut reject = |enum_id: CEnumId, reason: &'static str| {
+            rejected.entry(enum_id).or_insert(reason);
+        };
+
+        for (_, decl) in self.ast_context.iter_decls() {
+            match decl.kind {
+                CDeclKind::Variable { typ, .. }
+                | CDeclKind::Field { typ, .. }
+                | CDeclKind::Typedef { typ, .. } => {
+                    if let Some(enum_id) = self.enum_of(typ.ctype) {
+                        typed.insert(enum_id);
+                    }
+                }
+                _ => {}
+            }
+            if let CDeclKind::Field {
+                typ,
+                bitfield_width: Some(_),
+                ..
+            } = decl.kind
+            {
+                if let Some(enum_id) = self.enum_of(typ.ctype) {
+                    reject(enum_id, "it is the type of a bit-field");
+                }
+            }
+        }
+
+        for &decl_id in &self.ast_context.c_decls_top {
+            for node in DFExpr::new(&self.ast_context, decl_id.into()) {
+                match node {
+                    SomeId::Expr(expr_id) => {
+                        if let CExprKind::Binary(_, op, lhs, rhs, _, _) =
+                            self.ast_context[expr_id].kind
+                        {
+                            if is_bitwise(op) {
+                                for &operand in &[lhs, rhs] {
+                                    if let Some(enum_id) = self.flags_operand(operand) {
+                                        combined.insert(enum_id);
+                                    }
+                                }
+                            }
+                        }
+                        if let Some((enum_id, reason)) = self.flags_use_error(expr_id) {
+                            reject(enum_id, reason);
+                        }
+                    }
+                    SomeId::Stmt(stmt_id) => {
+                        if let CStmtKind::Asm { ref outputs, .. } = self.ast_context[stmt_id].kind {
+                            for output in outputs {
+                                if let Some(enum_id) = self.expr_enum(output.expression) {
+                                    reject(enum_id, "it is written by inline assembly");
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let candidates: IndexSet<CEnumId> = typed
+            .into_iter()
+            .filter(|enum_id| combined.contains(enum_id) && self.has_flag_values(*enum_id))
+            .collect();
+        self.report_rejected_enums(&candidates, &rejected, "a flags type");
+        candidates
+            .into_iter()
+            .filter(|enum_id| !rejected.contains_key(enum_id))
+            .collect()
+    }
+
+    /// Whether the constants of an enum are bit masks: non-negative values
+    /// made of the bits of at least two single-bit constants
+    fn has_flag_values(&self, enum_id: CEnumId) -> bool {
+        match self.ast_context[enum_id].kind {
+            CDeclKind::Enum {
+                integral_type: Some(_),
+                ..
+            } => {}
+            _ => return false,
+        }
+        let values: Option<Vec<u64>> = self
+            .enum_variants(enum_id)
+            .iter()
+            .map(|&v| match self.ast_context[v].kind {
+                CDeclKind::EnumConstant {
+                    value: ConstIntExpr::U(value),
+                    ..
+                } => Some(value),
+                CDeclKind::EnumConstant {
+                    value: ConstIntExpr::I(value),
+                    ..
+                } if value >= 0 => Some(value as u64),
+                _ => None,
+            })
+            .collect();
+        let values = match values {
+            Some(values) => values,
+            None => return false,
+        };
+        let bits: Vec<u64> = values
+            .iter()
+            .cloned()
+            .filter(|value| value.is_power_of_two())
+            .collect();
+        let mask = bits.iter().fold(0, |mask, bit| mask | bit);
+        bits.len() >= 2 && values.iter().all(|value| value & !mask == 0)
+    }
+
+    /// Why an expression prevents one of the enums it uses from becoming a
+    /// flags type
+    fn flags_use_error(&self, expr_id: CExprId) -> Option<(CEnumId, &'static str)> {
+        let operand_enum = |e: CExprId| self.expr_enum(e);
+        match self.ast_context[expr_id].kind {
+            CExprKind::ImplicitCast(ty, src, cast_kind, _, _)
+            | CExprKind::ExplicitCast(ty, src, cast_kind, _, _) => {
+                let target = self.enum_of(ty.ctype);
+                let source = operand_enum(src);
+                if let Some(enum_id) = target.filter(|&e| source != Some(e)) {
+                    if cast_kind != CastKind::IntegralCast {
+                        return Some((enum_id, "it is assigned values that are not integers"));
+                    }
+                }
+                if let Some(enum_id) = source.filter(|&e| target != Some(e)) {
+                    let to_integer = match cast_kind {
+                        CastKind::IntegralCast => self
+                            .ast_context
+                            .resolve_type(ty.ctype)
+                            .kind
+                            .is_integral_type(),
+                        CastKind::IntegralToBoolean
+                        | CastKind::LValueToRValue
+                        | CastKind::NoOp
+                        | CastKind::ToVoid => true,
+                        _ => false,
+                    };
+                    if !to_integer {
+                        return Some((enum_id, "it is converted to a type other than an integer"));
+                    }
+                }
+                None
+            }
+            CExprKind::Unary(_, c_ast::UnOp::AddressOf, _, _) => None,
+            CExprKind::Unary(_, _, arg, _) => {
+                operand_enum(arg).map(|enum_id| (enum_id, "arithmetic is done on it"))
+            }
+            CExprKind::Binary(_, op, lhs, rhs, _, _) => match op {
+                c_ast::BinOp::Assign | c_ast::BinOp::Comma => None,
+                op if op.underlying_assignment().is_some() => None,
+                _ => operand_enum(lhs)
+                    .or_else(|| operand_enum(rhs))
+                    .map(|enum_id| (enum_id, "arithmetic is done on it")),
+            },
+            _ => self.enum_passing_error(expr_id),
+        }
+    }
+
+    /// Why an expression passes values of an enum in a way that prevents it
+    /// from being translated into a Rust type
+    fn enum_passing_error(&self, expr_id: CExprId) -> Option<(CEnumId, &'static str)> {
+        let operand_enum = |e: CExprId| self.expr_enum(e);
+        match self.ast_context[expr_id].kind {
+            CExprKind::Call(_, func, ref args) => {
+                let fn_ty = self
+                    .expr_type(func)
+                    .and_then(|t| self.ast_context.get_pointee_qual_type(t));
+                let params = match fn_ty.map(|t| &self.ast_context.resolve_type(t.ctype).kind) {
+                    Some(&CTypeKind::Function(_, ref params, true, ..)) => params.len(),
+                    _ => return None,
+                };
+                args.iter()
+                    .skip(params)
+                    .filter_map(|&arg| operand_enum(arg))
+                    .next()
+                    .map(|enum_id| (enum_id, "it is passed to a variadic function"))
+            }
+            CExprKind::VAArg(ty, _) => self
+                .enum_of(ty.ctype)
+                .map(|enum_id| (enum_id, "it is read from a variadic argument list")),
+            CExprKind::Atomic { typ, .. } => self
+                .enum_of(typ.ctype)
+                .map(|enum_id| (enum_id, "it is accessed atomically")),
+            _ => None,
+        }
+    }
+
+    fn report_rejected_enums(
+        &self,
+        candidates: &IndexSet<CEnumId>,
+        rejected: &IndexMap<CEnumId, &'static str>,
+        translation: &str,
+    ) {
+        for (enum_id, reason) in rejected {
+            if !candidates.contains(enum_id) {
+                continue;
+            }
+            let name = self.type_converter.borrow().resolve_decl_name(*enum_id);
+            diag!(
+                Diagnostic::Idioms,
+                "keeping enum `{}` as an integer type instead of {}: {}",
+                name.as_ref().map_or("", String::as_str),
+                translation,
+                reason,
+            );
+        }
+    }
+
+    /// The `#[repr(C)]` Rust enum an enum is translated into, if it is
+    /// translated into one
+    pub fn convert_rust_enum(
+        &self,
+        enum_id: CEnumId,
+        span: Span,
+    ) -> Result<Option<P<Item>>, TranslationError> {
+        if !self.rust_enums.contains(&enum_id) {
+            return Ok(None);
+        }
+        let enum_name = self
+            .type_converter
+            .borrow()
+            .resolve_decl_name(enum_id)
+            .expect("Enums should already be renamed");
+
+        let mut variants = vec![];
+        for &variant_id in self.enum_variants(enum_id) {
+            let name = self
+                .renamer
+                .borrow_mut()
+                .get(&variant_id)
+                .ok_or_else(|| format_err!("Enum constant not named"))?;
+            let value = match self.ast_context[variant_id].kind {
+                CDeclKind::EnumConstant { value, .. } => value,
+                _ => continue,
+            };
+            let value = match value {
+                ConstIntExpr::I(value) => signed_int_expr(value),
+                ConstIntExpr::U(value) => {
+                    mk().lit_expr(mk().int_lit(value as u128, LitIntType::Unsuffixed))
+                }
+            };
+            let variant_span = self
+                .get_span(SomeId::Decl(variant_id))
+                .unwrap_or(DUMMY_SP);
+            variants.push(mk().span(variant_span).unit_variant(name, Some(value)));
+        }
+
+        Ok(Some(
+            mk().span(span)
+                .pub_()
+                .call_attr("derive", vec!["Copy", "Clone"])
+                .call_attr("repr", vec!["C"])
+                .enum_item(enum_name, variants),
+        ))
+    }
+
+    /// The newtype and trait implementations a flag enum is translated into,
+    /// if it is translated into a flags type
+    pub fn convert_flag_enum(
+        &self,
+        enum_id: CEnumId,
+        span: Span,
+    ) -> Result<Option<Vec<P<Item>>>, TranslationError> {
+        if !self.flag_enums.contains(&enum_id) {
+            return Ok(None);
+        }
+        let enum_name = self
+            .type_converter
+            .borrow()
+            .resolve_decl_name(enum_id)
+            .expect("Enums should already be renamed");
+        let integral_type = match self.ast_context[enum_id].kind {
+            CDeclKind::Enum {
+                integral_type: Some(integral_type),
+                ..
+            } => integral_type,
+            _ => return Ok(None),
+        };
+        let bits_ty = self.convert_type(integral_type.ctype)?;
+        let self_ty = || mk().path_ty(vec![enum_name.as_str()]);
+        let wrap = |e: P<Expr>| mk().call_expr(mk().path_expr(vec![enum_name.as_str()]), vec![e]);
+        let bits = |name: &str| mk().field_expr(mk().path_expr(vec![name]), "0");
+        let by_value = || mk().self_arg(SelfKind::Value(Mutability::Immutable));
+
+        let mut items = vec![];
+        for &variant_id in self.enum_variants(enum_id) {
+            let name = self
+                .renamer
+                .borrow_mut()
+                .get(&variant_id)
+                .ok_or_else(|| format_err!("Enum constant not named"))?;
+            let value = match self.ast_context[variant_id].kind {
+                CDeclKind::EnumConstant { value, .. } => value,
+                _ => continue,
+            };
+            let value = match value {
+                ConstIntExpr::I(value) => signed_int_expr(value),
+                ConstIntExpr::U(value) => {
+                    mk().lit_expr(mk().int_lit(value as u128, LitIntType::Unsuffixed))
+                }
+            };
+            items.push(mk().pub_().const_impl_item(name, self_ty(), wrap(value)));
+        }
+        let contains = mk().fn_decl(
+            vec![by_value(), mk().arg(self_ty(), mk().ident_pat("other"))],
+            FunctionRetTy::Ty(mk().path_ty(vec!["bool"])),
+        );
+        let masked = mk().binary_expr(BinOpKind::BitAnd, bits("self"), bits("other"));
+        let body = mk().binary_expr(BinOpKind::Eq, masked, bits("other"));
+        items.push(mk().pub_().fn_impl_item(
+            "contains",
+            contains,
+            mk().block(vec![mk().expr_stmt(body)]),
+        ));
+
+        let mut converted = vec![
+            mk().span(span)
+                .pub_()
+                .call_attr("derive", vec!["Copy", "Clone", "PartialEq", "Eq"])
+                .call_attr("repr", vec!["transparent"])
+                .struct_item(enum_name.as_str(), vec![mk().pub_().enum_field(bits_ty)], true),
+            mk().span(span).impl_item(self_ty(), items),
+        ];
+
+        let std_or_core = if self.tcfg.emit_no_std { "core" } else { "std" };
+        let ops = [
+            ("BitOr", "bitor", BinOpKind::BitOr),
+            ("BitAnd", "bitand", BinOpKind::BitAnd),
+        ];
+        for &(trait_name, method, op) in &ops {
+            let decl = mk().fn_decl(
+                vec![by_value(), mk().arg(self_ty(), mk().ident_pat("rhs"))],
+                FunctionRetTy::Ty(self_ty()),
+            );
+            let body = wrap(mk().binary_expr(op, bits("self"), bits("rhs")));
+            converted.push(mk().span(span).trait_impl_item(
+                vec!["", std_or_core, "ops", trait_name],
+                self_ty(),
+                vec![
+                    mk().ty_impl_item("Output", self_ty()),
+                    mk().fn_impl_item(method, decl, mk().block(vec![mk().expr_stmt(body)])),
+                ],
+            ));
+
+            let decl = mk().fn_decl(
+                vec![
+                    mk().self_arg(SelfKind::Region(None, Mutability::Mutable)),
+                    mk().arg(self_ty(), mk().ident_pat("rhs")),
+                ],
+                FunctionRetTy::Default(DUMMY_SP),
+            );
+            let body = mk().assign_op_expr(op, bits("self"), bits("rhs"));
+            converted.push(mk().span(span).trait_impl_item(
+                vec!["", std_or_core, "ops", &format!("{}Assign", trait_name)],
+                self_ty(),
+                vec![mk().fn_impl_item(
+                    format!("{}_assign", method),
+                    decl,
+                    mk().block(vec![mk().semi_stmt(body)]),
+                )],
+            ));
+        }
+
+        Ok(Some(converted))
+    }
+
+    /// The flag enum a type resolves to, if it is translated into a flags type
+    pub fn flag_enum_of(&self, ty: CTypeId) -> Option<CEnumId> {
+        self.enum_of(ty)
+            .filter(|enum_id| self.flag_enums.contains(enum_id))
+    }
+
+    /// The integer held by a value of a type, which is the bits of a flags
+    /// type or the value itself
+    pub fn flags_to_int(&self, ty: CTypeId, val: P<Expr>) -> P<Expr> {
+        if self.flag_enum_of(ty).is_some() {
+            mk().field_expr(val, "0")
+        } else {
+            val
+        }
+    }
+
+    /// Convert an integer into a flags type
+    pub fn int_to_flags(
+        &self,
+        enum_id: CEnumId,
+        val: P<Expr>,
+    ) -> Result<P<Expr>, TranslationError> {
+        let integral_type = match self.ast_context[enum_id].kind {
+            CDeclKind::Enum {
+                integral_type: Some(integral_type),
+                ..
+            } => integral_type,
+            _ => return Err(format_err!("Flags type without an underlying type").into()),
+        };
+        let bits_ty = self.convert_type(integral_type.ctype)?;
+        let ctor = self
+            .flags_ctor(enum_id)
+            .ok_or_else(|| format_err!("Enum is not a flags type"))?;
+        Ok(mk().call_expr(ctor, vec![mk().cast_expr(val, bits_ty)]))
+    }
+
+    /// The constructor of a flags type
+    pub fn flags_ctor(&self, enum_id: CEnumId) -> Option<P<Expr>> {
+        if !self.flag_enums.contains(&enum_id) {
+            return None;
+        }
+        let enum_name = self.type_converter.borrow().resolve_decl_name(enum_id)?;
+        if let Some(cur_file) = *self.cur_file.borrow() {
+            self.add_import(cur_file, enum_id, &enum_name);
+        }
+        Some(mk().path_expr(vec![enum_name]))
+    }
+
+    /// Translate the conversion of an integer expression to a flags type. Its
+    /// own constants and combinations of its values with `|` and `&` are
+    /// translated into values of the flags type directly.
+    pub fn convert_flags_cast(
+        &self,
+        ctx: ExprContext,
+        enum_type: CTypeId,
+        enum_id: CEnumId,
+        expr: CExprId,
+        val: WithStmts<P<Expr>>,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        if self.is_flags_value(ctx, enum_id, expr) {
+            return self.convert_flags_value(ctx, enum_id, expr);
+        }
+        match self.ast_context[expr].kind {
+            CExprKind::Literal(_, CLiteral::Integer(i, _)) => {
+                return Ok(val.map(|_| self.enum_for_i64(enum_type, i as i64)));
+            }
+            CExprKind::Unary(_, c_ast::UnOp::Negate, sub, _) => {
+                if let CExprKind::Literal(_, CLiteral::Integer(i, _)) = self.ast_context[sub].kind {
+                    return Ok(val.map(|_| self.enum_for_i64(enum_type, -(i as i64))));
+                }
+            }
+            _ => {}
+        }
+        val.result_map(|val| self.int_to_flags(enum_id, val))
+    }
+
+    /// Translate a condition testing a single flag of a value of a flags type
+    /// into a call to `contains`
+    pub fn convert_flag_test(
+        &self,
+        ctx: ExprContext,
+        target: bool,
+        cond_id: CExprId,
+    ) -> Result<Option<WithStmts<P<Expr>>>, TranslationError> {
+        if ctx.is_const || ctx.is_static {
+            return Ok(None);
+        }
+        let (lhs, rhs) = match self.ast_context[cond_id].kind {
+            CExprKind::Binary(_, c_ast::BinOp::BitAnd, lhs, rhs, _, _) => (lhs, rhs),
+            _ => return Ok(None),
+        };
+        for &(value, flag) in &[(lhs, rhs), (rhs, lhs)] {
+            let enum_id = match self.single_flag(flag) {
+                Some(enum_id) => enum_id,
+                None => continue,
+            };
+            if !self.is_flags_value(ctx, enum_id, value) {
+                continue;
+            }
+            let value = self.convert_flags_value(ctx, enum_id, value)?;
+            let flag = self.convert_flags_value(ctx, enum_id, flag)?;
+            return value
+                .and_then(|value| {
+                    Ok(flag.map(|flag| {
+                        let test = mk().method_call_expr(value, "contains", vec![flag]);
+                        if target {
+                            test
+                        } else {
+                            mk().unary_expr(ast::UnOp::Not, test)
+                        }
+                    }))
+                })
+                .map(Some);
+        }
+        Ok(None)
+    }
+
+    /// Translate `|=` and `&=` assignments of values of a flags type to a
+    /// variable of that type with the operators of the flags type
+    pub fn convert_flags_assignment(
+        &self,
+        ctx: ExprContext,
+        op: c_ast::BinOp,
+        lhs: CExprId,
+        rhs: CExprId,
+    ) -> Result<Option<WithStmts<P<Expr>>>, TranslationError> {
+        let op = match op {
+            c_ast::BinOp::AssignBitOr => BinOpKind::BitOr,
+            c_ast::BinOp::AssignBitAnd => BinOpKind::BitAnd,
+            _ => return Ok(None),
+        };
+        let lhs_ty = match self.ast_context[lhs].kind.get_qual_type() {
+            Some(lhs_ty) => lhs_ty,
+            None => return Ok(None),
+        };
+        let enum_id = match self.flag_enum_of(lhs_ty.ctype) {
+            Some(enum_id) => enum_id,
+            None => return Ok(None),
+        };
+        if ctx.is_used() || lhs_ty.qualifiers.is_volatile || !self.is_flags_value(ctx, enum_id, rhs)
+        {
+            return Ok(None);
+        }
+        let rhs = self.convert_flags_value(ctx, enum_id, rhs)?;
+        let write = self.name_reference_write(ctx, lhs)?;
+        rhs.and_then(|rhs| {
+            write.and_then(|write| {
+                Ok(WithStmts::new(
+                    vec![mk().expr_stmt(mk().assign_op_expr(op, write, rhs))],
+                    self.panic_or_err("Assignment value is not supposed to be read"),
+                ))
+            })
+        })
+        .map(Some)
+    }
+
+    /// Whether an integer expression only combines values and constants of a
+    /// flags type with `|` and `&`, which is then translated with the
+    /// operators of the flags type
+    fn is_flags_value(&self, ctx: ExprContext, enum_id: CEnumId, expr: CExprId) -> bool {
+        match self.ast_context[expr].kind {
+            CExprKind::ImplicitCast(_, src, CastKind::IntegralCast, _, _) => {
+                self.expr_enum(src) == Some(enum_id)
+            }
+            CExprKind::DeclRef(_, decl_id, _) => self.enum_variants(enum_id).contains(&decl_id),
+            CExprKind::Paren(_, sub) => self.is_flags_value(ctx, enum_id, sub),
+            CExprKind::Binary(_, c_ast::BinOp::BitOr, lhs, rhs, _, _)
+            | CExprKind::Binary(_, c_ast::BinOp::BitAnd, lhs, rhs, _, _) => {
+                !ctx.is_const
+                    && !ctx.is_static
+                    && self.is_flags_value(ctx, enum_id, lhs)
+                    && self.is_flags_value(ctx, enum_id, rhs)
+            }
+            _ => false,
+        }
+    }
+
+    /// Translate an expression for which `is_flags_value` holds into a value
+    /// of the flags type
+    fn convert_flags_value(
+        &self,
+        ctx: ExprContext,
+        enum_id: CEnumId,
+        expr: CExprId,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        match self.ast_context[expr].kind {
+            CExprKind::ImplicitCast(_, src, _, _, _) => self.convert_expr(ctx.used(), src),
+            CExprKind::DeclRef(_, decl_id, _) => self
+                .enum_variant_path(decl_id)
+                .map(WithStmts::new_val)
+                .ok_or_else(|| format_err!("Enum constant not named").into()),
+            CExprKind::Paren(_, sub) => self.convert_flags_value(ctx, enum_id, sub),
+            CExprKind::Binary(_, op, lhs, rhs, _, _) => {
+                let op = match op {
+                    c_ast::BinOp::BitOr => BinOpKind::BitOr,
+                    _ => BinOpKind::BitAnd,
+                };
+                let lhs = self.convert_flags_value(ctx, enum_id, lhs)?;
+                let rhs = self.convert_flags_value(ctx, enum_id, rhs)?;
+                lhs.and_then(|lhs| Ok(rhs.map(|rhs| mk().binary_expr(op, lhs, rhs))))
+            }
+            _ => Err(format_err!("Expression is not a value of a flags type").into()),
+        }
+    }
+
+    /// The flags type of a reference to one of its single-bit constants
+    fn single_flag(&self, expr: CExprId) -> Option<CEnumId> {
+        let decl_id = match self.ast_context[expr].kind {
+            CExprKind::DeclRef(_, decl_id, _) => decl_id,
+            _ => return None,
+        };
+        let single_bit = match self.ast_context[decl_id].kind {
+            CDeclKind::EnumConstant {
+                value: ConstIntExpr::U(value),
+                ..
+            } => value.is_power_of_two(),
+            CDeclKind::EnumConstant {
+                value: ConstIntExpr::I(value),
+                ..
+            } => value > 0 && (value as u64).is_power_of_two(),
+            _ => false,
+        };
+        self.ast_context
+            .parents
+            .get(&decl_id)
+            .cloned()
+            .filter(|enum_id| single_bit && self.flag_enums.contains(enum_id))
+    }
+
+    /// The enum whose values or constants an operand of a bitwise operator
+    /// combines
+    fn flags_operand(&self, expr: CExprId) -> Option<CEnumId> {
+        match self.ast_context[expr].kind {
+            CExprKind::ImplicitCast(_, src, CastKind::IntegralCast, _, _) => self.expr_enum(src),
+            CExprKind::DeclRef(_, decl_id, _) => match self.ast_context[decl_id].kind {
+                CDeclKind::EnumConstant { .. } => self.ast_context.parents.get(&decl_id).cloned(),
+                _ => None,
+            },
+            CExprKind::Paren(_, sub) => self.flags_operand(sub),
+            _ => None,
+        }
+    }
+
+    /// The path to an enum constant, as a variant of a Rust enum or a constant
+    /// of a flags type, if its enum is translated into one
+    pub fn enum_variant_path(&self, variant_id: CEnumConstantId) -> Option<P<Expr>> {
+        let enum_id = *self.ast_context.parents.get(&variant_id)?;
+        if !self.rust_enums.contains(&enum_id) && !self.flag_enums.contains(&enum_id) {
+            return None;
+        }
+        let enum_name = self.type_converter.borrow().resolve_decl_name(enum_id)?;
+        let name = self.renamer.borrow().get(&variant_id)?;
+        if let Some(cur_file) = *self.cur_file.borrow() {
+            self.add_import(cur_file, enum_id, &enum_name);
+        }
+        Some(mk().path_expr(vec![enum_name, name]))
+    }
+
+    /// If `scrutinee` is the integer promotion of a value of an enum that is
+    /// translated into a Rust enum, that enum and the value
+    pub fn enum_switch(&self, scrutinee: CExprId) -> Option<(CEnumId, CExprId)> {
+        self.promoted_enum(scrutinee)
+            .filter(|(enum_id, _)| self.rust_enums.contains(enum_id))
+    }
+
+    /// The variant of an enum translated into a Rust enum that has a given
+    /// value
+    pub fn enum_variant_for(
+        &self,
+        enum_id: CEnumId,
+        value: ConstIntExpr,
+    ) -> Option<CEnumConstantId> {
+        self.enum_variants(enum_id)
+            .iter()
+            .cloned()
+            .find(|&v| self.enum_constant_is(v, value))
+    }
+
+    /// The number of variants of an enum
+    pub fn enum_variant_count(&self, enum_id: CEnumId) -> usize {
+        self.enum_variants(enum_id).len()
+    }
+
+    fn enum_variants(&self, enum_id: CEnumId) -> &[CEnumConstantId] {
+        match self.ast_context[enum_id].kind {
+            CDeclKind::Enum { ref variants, .. } => variants,
+            _ => &[],
+        }
+    }
+
+    fn enum_constant_is(&self, variant_id: CEnumConstantId, value: ConstIntExpr) -> bool {
+        let v = match self.ast_context[variant_id].kind {
+            CDeclKind::EnumConstant { value, .. } => value,
+            _ => return false,
+        };
+        let as_i64 = |c: ConstIntExpr| match c {
+            ConstIntExpr::I(i) => i,
+            ConstIntExpr::U(u) => u as i64,
+        };
+        as_i64(v) == as_i64(value)
+    }
+
+    /// Whether an expression converted to an enum is one of its constants, in
+    /// a form `enum_cast` turns into the variant itself
+    fn is_constant_of(&self, enum_id: CEnumId, expr: CExprId) -> bool {
+        let variants = self.enum_variants(enum_id);
+        let has_value = |value: i64| {
+            variants
+                .iter()
+                .any(|&v| self.enum_constant_is(v, ConstIntExpr::I(value)))
+        };
+        match self.ast_context[expr].kind {
+            CExprKind::DeclRef(_, decl_id, _) => variants.contains(&decl_id),
+            CExprKind::Literal(_, CLiteral::Integer(i, _)) => has_value(i as i64),
+            CExprKind::Unary(_, c_ast::UnOp::Negate, sub, _) => match self.ast_context[sub].kind {
+                CExprKind::Literal(_, CLiteral::Integer(i, _)) => has_value(-(i as i64)),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// The values of the cases of a `switch` body, leaving out nested `switch`es
+    fn switch_case_values(&self, stmt_id: CStmtId) -> Vec<ConstIntExpr> {
+        let mut values = vec![];
+        let mut stack = vec![stmt_id];
+        while let Some(stmt_id) = stack.pop() {
+            match self.ast_context[stmt_id].kind {
+                CStmtKind::Switch { .. } => continue,
+                CStmtKind::Case(_, _, value) => values.push(value),
+                _ => {}
+            }
+            for child in immediate_children_all_types(&self.ast_context, stmt_id.into()) {
+                if let SomeId::Stmt(child) = child {
+                    stack.push(child);
+                }
+            }
+        }
+        values
+    }
+
+    /// The enum and value an expression promotes to an integer
+    fn promoted_enum(&self, expr: CExprId) -> Option<(CEnumId, CExprId)> {
+        match self.ast_context[expr].kind {
+            CExprKind::ImplicitCast(_, src, CastKind::IntegralCast, _, _) => {
+                self.expr_enum(src).map(|enum_id| (enum_id, src))
+            }
+            _ => None,
+        }
+    }
+
+    fn expr_type(&self, expr: CExprId) -> Option<CTypeId> {
+        self.ast_context[expr].kind.get_type()
+    }
+
+    /// The enum an expression is a value of
+    fn expr_enum(&self, expr: CExprId) -> Option<CEnumId> {
+        self.expr_type(expr).and_then(|ty| self.enum_of(ty))
+    }
+
+    /// The enum a type resolves to
+    fn enum_of(&self, ty: CTypeId) -> Option<CEnumId> {
+        match self.ast_context.resolve_type(ty).kind {
+            CTypeKind::Enum(enum_id) => Some(enum_id),
+            _ => None,
+        }
+    }
+
+    /// The enums a type mentions, looking through pointers, arrays, functions,
+    /// typedefs and the fields of structs and unions
+    fn enums_mentioned(&self, ty: CTypeId) -> IndexSet<CEnumId> {
+        let mut enums = IndexSet::new();
+        let mut visited: IndexSet<SomeId> = IndexSet::new();
+        let mut stack: Vec<SomeId> = vec![ty.into()];
+        while let Some(node) = stack.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            match node {
+                SomeId::Type(ty) => match self.ast_context[ty].kind {
+                    CTypeKind::Enum(enum_id) => {
+                        enums.insert(enum_id);
+                    }
+                    // Not a child type, since it refers to an earlier definition
+                    CTypeKind::Elaborated(ty) => stack.push(ty.into()),
+                    _ => {}
+                },
+                SomeId::Decl(_) => {}
+                // Array sizes and `typeof` operands
+                SomeId::Expr(_) | SomeId::Stmt(_) => continue,
+            }
+            stack.extend(immediate_children_all_types(&self.ast_context, node));
+        }
+        enums
+    }
+
+    /// The enums a pointer type points to, including those in the fields of
+    /// structs it points to
+    fn pointee_enums(&self, ty: CTypeId) -> IndexSet<CEnumId> {
+        match self.ast_context.resolve_type(ty).kind {
+            CTypeKind::Pointer(pointee) => self.enums_mentioned(pointee.ctype),
+            _ => IndexSet::new(),
+        }
+    }
+
+    /// The enums a cast lets memory be accessed through a pointer to another
+    /// type, as `memset` does with a `struct figure *` cast to `void *`. Such
+    /// accesses may store any value.
+    fn enums_punned(&self, expr_id: CExprId) -> IndexSet<CEnumId> {
+        let (ty, src, cast_kind) = match self.ast_context[expr_id].kind {
+            CExprKind::ImplicitCast(ty, src, cast_kind, _, _)
+            | CExprKind::ExplicitCast(ty, src, cast_kind, _, _) => (ty, src, cast_kind),
+            _ => return IndexSet::new(),
+        };
+        match cast_kind {
+            // The pointee of a decayed array or function is the array element or the function
+            CastKind::NullToPointer
+            | CastKind::ArrayToPointerDecay
+            | CastKind::FunctionToPointerDecay => return IndexSet::new(),
+            _ => {}
+        }
+        let target = self.pointee_enums(ty.ctype);
+        let source = self
+            .expr_type(src)
+            .map(|t| self.pointee_enums(t))
+            .unwrap_or_default();
+        target.symmetric_difference(&source).cloned().collect()
+    }
+}
+
+/// Whether an operator combines the bits of its operands
+fn is_bitwise(op: c_ast::BinOp) -> bool {
+    match op {
+        c_ast::BinOp::BitOr
+        | c_ast::BinOp::BitAnd
+        | c_ast::BinOp::BitXor
+        | c_ast::BinOp::AssignBitOr
+        | c_ast::BinOp::AssignBitAnd
+        | c_ast::BinOp::AssignBitXor => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,381 @@
+#![deny(missing_docs)]
+//! This module recognizes C `for` loops that count over a range of integers, so that they can be
+//! translated to `for i in a..b` loops rather than to `while` loops with a manual increment.
+//!
+//! A loop is recognized when
+//!
+//!   * its initialization declares or assigns a local integer variable, the induction variable;
+//!   * its condition is `i < b` or `i <= b`, where `b` only reads local variables the loop never
+//!     modifies;
+//!   * its increment adds a positive constant to the induction variable;
+//!   * its body never modifies the induction variable, which isn't used outside of the loop;
+//!   * the increment can't overflow the induction variable's type, which would make the C loop
+//!     wrap around (and, with `i <= UINT_MAX`, never end) where a Rust range stops. Unless the
+//!     loop is `i < b` stepping by one, this takes `b` to be a constant far enough from the
+//!     largest value of the type.
+
+use super::*;
+
+use crate::c_ast::{BinOp, UnOp};
+
+/// References to the variables of a function body, which tell whether a loop's induction variable
+/// is used outside of it or whether a loop's bound can change behind its back.
+#[derive(Debug, Clone, Default)]
+pub struct LocalUses {
+    refs: IndexMap<CDeclId, usize>,
+    address_taken: IndexSet<CDeclId>,
+}
+
+impl LocalUses {
+    /// Collect the uses of the variables in a function body
+    pub fn new(ast_context: &TypedAstContext, stmt_ids: &[CStmtId]) -> Self {
+        let mut uses = LocalUses::default();
+        for expr_id in stmt_ids
+            .iter()
+            .flat_map(|&stmt_id| DFExpr::new(ast_context, stmt_id.into()))
+            .flat_map(SomeId::expr)
+        {
+            match ast_context[expr_id].kind {
+                CExprKind::DeclRef(_, decl_id, _) => *uses.refs.entry(decl_id).or_insert(0) += 1,
+                CExprKind::Unary(_, UnOp::AddressOf, arg, _)
+                | CExprKind::ImplicitCast(_, arg, CastKind::ArrayToPointerDecay, _, _) => {
+                    if let Some(decl_id) = referenced_var(ast_context, arg) {
+                        uses.address_taken.insert(decl_id);
+                    }
+                }
+                _ => {}
+            }
+        }
+        uses
+    }
+}
+
+/// A C `for` loop counting over a range of integers
+#[derive(Debug, Copy, Clone)]
+pub struct ForRange {
+    /// The induction variable
+    pub var: CDeclId,
+    /// First value of the induction variable
+    pub start: CExprId,
+    /// Bound of the induction variable
+    pub end: CExprId,
+    /// Whether the bound is part of the range (`i <= b`)
+    pub inclusive: bool,
+    /// Value added to the induction variable after each iteration
+    pub step: u64,
+}
+
+/// The variable an expression designates, if it is just a (parenthesized) variable
+fn referenced_var(ast_context: &TypedAstContext, expr_id: CExprId) -> Option<CDeclId> {
+    match ast_context[expr_id].kind {
+        CExprKind::DeclRef(_, decl_id, _) => Some(decl_id),
+        CExprKind::Paren(_, e) => referenced_var(ast_context, e),
+        _ => None,
+    }
+}
+
+/// The largest value of an integral type on every target, so the smallest one for `long`
+fn int_type_max(kind: &CTypeKind) -> Option<u64> {
+    let bits = match *kind {
+        CTypeKind::Bool => return Some(1),
+        CTypeKind::Char | CTypeKind::SChar => 7,
+        CTypeKind::UChar => 8,
+        CTypeKind::Short => 15,
+        CTypeKind::UShort => 16,
+        CTypeKind::Int | CTypeKind::Long => 31,
+        CTypeKind::UInt | CTypeKind::ULong => 32,
+        CTypeKind::LongLong | CTypeKind::Int128 => 63,
+        CTypeKind::ULongLong | CTypeKind::UInt128 => 64,
+        _ => return None,
+    };
+    Some(u64::max_value() >> (64 - bits))
+}
+
+impl<'c> Translation<'c> {
+    /// Recognize a C `for` loop counting over a range of integers.
+    pub fn recognize_for_range(&self, for_id: CStmtId, uses: &LocalUses) -> Option<ForRange> {
+        let (init, cond, incr, body) = match self.ast_context[for_id].kind {
+            CStmtKind::ForLoop {
+                init: Some(init),
+                condition: Some(cond),
+                increment: Some(incr),
+                body,
+            } => (init, cond, incr, body),
+            _ => return None,
+        };
+
+        let (var, start) = match self.ast_context[init].kind {
+            CStmtKind::Decls(ref decls) if decls.len() == 1 => match self.ast_context[decls[0]].kind {
+                CDeclKind::Variable {
+                    initializer: Some(start),
+                    ..
+                } => (decls[0], start),
+                _ => return None,
+            },
+            CStmtKind::Expr(e) => match self.ast_context[e].kind {
+                CExprKind::Binary(_, BinOp::Assign, lhs, start, _, _) => {
+                    (referenced_var(&self.ast_context, lhs)?, start)
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if !self.is_local_counter(var, uses) || !self.is_integral_var(var) {
+            return None;
+        }
+
+        let (inclusive, end) = match self.ast_context[cond].kind {
+            CExprKind::Binary(_, op @ BinOp::Less, lhs, end, _, _)
+            | CExprKind::Binary(_, op @ BinOp::LessEqual, lhs, end, _, _)
+                if self.read_var(lhs) == Some(var) =>
+            {
+                (op == BinOp::LessEqual, end)
+            }
+            _ => return None,
+        };
+
+        let step = self.counter_step(incr, var)?;
+        if (inclusive || step != 1) && !self.increment_fits(var, end, inclusive, step) {
+            return None;
+        }
+
+        // The induction variable only lives in the loop, and only the increment modifies it
+        let refs_in_loop = DFExpr::new(&self.ast_context, for_id.into())
+            .flat_map(SomeId::expr)
+            .filter(|&e| match self.ast_context[e].kind {
+                CExprKind::DeclRef(_, decl_id, _) => decl_id == var,
+                _ => false,
+            })
+            .count();
+        if uses.refs.get(&var) != Some(&refs_in_loop) {
+            return None;
+        }
+        let mut bound_vars = IndexSet::new();
+        if !self.is_invariant_bound(end, uses, &mut bound_vars) || bound_vars.contains(&var) {
+            return None;
+        }
+        let mut watched = bound_vars.clone();
+        watched.insert(var);
+        if self.modifies_any(body.into(), &watched) || self.modifies_any(incr.into(), &bound_vars) {
+            return None;
+        }
+
+        Some(ForRange {
+            var,
+            start,
+            end,
+            inclusive,
+            step,
+        })
+    }
+
+    /// Translate the range a recognized loop counts over into a pattern binding the induction
+    /// variable and a `a..b`, `a..=b` or `(a..b).step_by(n)` expression. Returns `None` when the
+    /// range can't be computed by a single expression.
+    pub fn convert_for_range(
+        &self,
+        ctx: ExprContext,
+        range: &ForRange,
+    ) -> Result<Option<(P<Pat>, P<Expr>)>, TranslationError> {
+        let start = match self.convert_expr(ctx.used(), range.start)?.to_unsafe_pure_expr() {
+            Some(start) => start,
+            None => return Ok(None),
+        };
+        let end = match self.convert_expr(ctx.used(), range.end)?.to_pure_expr() {
+            Some(end) => end,
+            None => return Ok(None),
+        };
+        let var_name = self
+            .renamer
+            .borrow()
+            .get(&range.var)
+            .ok_or_else(|| format_err!("Loop counter not named"))?;
+
+        let limits = if range.inclusive {
+            RangeLimits::Closed
+        } else {
+            RangeLimits::HalfOpen
+        };
+        let mut range_expr = mk().range_expr(Some(start), Some(end), limits);
+        if range.step != 1 {
+            range_expr = mk().method_call_expr(
+                mk().paren_expr(range_expr),
+                "step_by",
+                vec![mk().lit_expr(mk().int_lit(range.step.into(), ""))],
+            );
+        }
+
+        Ok(Some((mk().ident_pat(var_name), range_expr)))
+    }
+
+    /// The variable an rvalue reads, if it just reads one
+    pub fn read_var(&self, expr_id: CExprId) -> Option<CDeclId> {
+        match self.ast_context[expr_id].kind {
+            CExprKind::ImplicitCast(_, e, CastKind::LValueToRValue, _, _) | CExprKind::Paren(_, e) => {
+                self.read_var(e)
+            }
+            CExprKind::DeclRef(_, decl_id, _) => Some(decl_id),
+            _ => None,
+        }
+    }
+
+    /// Whether a variable is local to the function, without anything else having its address
+    fn is_local_counter(&self, decl_id: CDeclId, uses: &LocalUses) -> bool {
+        match self.ast_context[decl_id].kind {
+            CDeclKind::Variable {
+                has_static_duration: false,
+                has_thread_duration: false,
+                typ,
+                ..
+            } => !typ.qualifiers.is_volatile && !uses.address_taken.contains(&decl_id),
+            _ => false,
+        }
+    }
+
+    fn is_integral_var(&self, decl_id: CDeclId) -> bool {
+        match self.ast_context[decl_id].kind {
+            CDeclKind::Variable { typ, .. } => self
+                .ast_context
+                .resolve_type(typ.ctype)
+                .kind
+                .is_integral_type(),
+            _ => false,
+        }
+    }
+
+    /// Whether the induction variable `var` can be incremented by `step` past the last value of a
+    /// range ending at the constant `end` without overflowing its type
+    fn increment_fits(&self, var: CDeclId, end: CExprId, inclusive: bool, step: u64) -> bool {
+        let max = match self.ast_context[var].kind {
+            CDeclKind::Variable { typ, .. } => {
+                match int_type_max(&self.ast_context.resolve_type(typ.ctype).kind) {
+                    Some(max) => max,
+                    None => return false,
+                }
+            }
+            _ => return false,
+        };
+        let last_plus_step = self.int_constant(end).and_then(|end| {
+            if inclusive {
+                end.checked_add(step)
+            } else {
+                end.checked_add(step - 1)
+            }
+        });
+        last_plus_step.map_or(false, |n| n <= max)
+    }
+
+    /// The positive constant an increment adds to a variable: `i++`, `++i`, `i += n` or
+    /// `i = i + n`.
+    fn counter_step(&self, incr: CExprId, var: CDeclId) -> Option<u64> {
+        let step = match self.ast_context[incr].kind {
+            CExprKind::Unary(_, UnOp::PostIncrement, arg, _)
+            | CExprKind::Unary(_, UnOp::PreIncrement, arg, _)
+                if referenced_var(&self.ast_context, arg) == Some(var) =>
+            {
+                1
+            }
+            CExprKind::Binary(_, BinOp::AssignAdd, lhs, rhs, _, _)
+                if referenced_var(&self.ast_context, lhs) == Some(var) =>
+            {
+                self.int_constant(rhs)?
+            }
+            CExprKind::Binary(_, BinOp::Assign, lhs, rhs, _, _)
+                if referenced_var(&self.ast_context, lhs) == Some(var) =>
+            {
+                match self.ast_context[rhs].kind {
+                    CExprKind::Binary(_, BinOp::Add, l, r, _, _) if self.read_var(l) == Some(var) => {
+                        self.int_constant(r)?
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        if step > 0 {
+            Some(step)
+        } else {
+            None
+        }
+    }
+
+    /// The value of an integer literal, looking through integral casts
+    pub fn int_constant(&self, expr_id: CExprId) -> Option<u64> {
+        match self.ast_context[expr_id].kind {
+            CExprKind::Literal(_, CLiteral::Integer(n, _)) => Some(n),
+            CExprKind::ImplicitCast(_, e, CastKind::IntegralCast, _, _) | CExprKind::Paren(_, e) => {
+                self.int_constant(e)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a loop bound can only change if the loop assigns one of the variables it reads,
+    /// which get collected into `vars`.
+    fn is_invariant_bound(
+        &self,
+        expr_id: CExprId,
+        uses: &LocalUses,
+        vars: &mut IndexSet<CDeclId>,
+    ) -> bool {
+        match self.ast_context[expr_id].kind {
+            CExprKind::Literal(..)
+            | CExprKind::UnaryType(..)
+            | CExprKind::OffsetOf(..)
+            | CExprKind::ConstantExpr(..) => true,
+            CExprKind::DeclRef(_, decl_id, _) => match self.ast_context[decl_id].kind {
+                CDeclKind::EnumConstant { .. } => true,
+                CDeclKind::Variable { .. } if self.is_local_counter(decl_id, uses) => {
+                    vars.insert(decl_id);
+                    true
+                }
+                _ => false,
+            },
+            CExprKind::ImplicitCast(_, e, _, _, _)
+            | CExprKind::ExplicitCast(_, e, _, _, _)
+            | CExprKind::Paren(_, e)
+            | CExprKind::Unary(_, UnOp::Plus, e, _)
+            | CExprKind::Unary(_, UnOp::Negate, e, _)
+            | CExprKind::Unary(_, UnOp::Complement, e, _)
+            | CExprKind::Unary(_, UnOp::Not, e, _) => self.is_invariant_bound(e, uses, vars),
+            CExprKind::Binary(_, op, lhs, rhs, _, _) if !op.is_assignment() && op != BinOp::Comma => {
+                self.is_invariant_bound(lhs, uses, vars) && self.is_invariant_bound(rhs, uses, vars)
+            }
+            CExprKind::Conditional(_, c, lhs, rhs) => {
+                self.is_invariant_bound(c, uses, vars)
+                    && self.is_invariant_bound(lhs, uses, vars)
+                    && self.is_invariant_bound(rhs, uses, vars)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether some code might modify one of `vars`. Inline assembly is assumed to modify
+    /// anything.
+    pub fn modifies_any(&self, id: SomeId, vars: &IndexSet<CDeclId>) -> bool {
+        let assigns_var = |e: CExprId| {
+            referenced_var(&self.ast_context, e).map_or(false, |decl_id| vars.contains(&decl_id))
+        };
+        DFExpr::new(&self.ast_context, id).any(|id| match id {
+            SomeId::Stmt(stmt_id) => match self.ast_context[stmt_id].kind {
+                CStmtKind::Asm { .. } => true,
+                _ => false,
+            },
+            SomeId::Expr(expr_id) => match self.ast_context[expr_id].kind {
+                CExprKind::Binary(_, op, lhs, _, _, _) if op.is_assignment() => {
+                    assigns_var(lhs)
+                }
+                CExprKind::Unary(_, op, arg, _) => match op {
+                    UnOp::PreIncrement
+                    | UnOp::PostIncrement
+                    | UnOp::PreDecrement
+                    | UnOp::PostDecrement
+                    | UnOp::AddressOf => assigns_var(arg),
+                    _ => false,
+                },
+                _ => false,
+            },
+            _ => false,
+        })
+    }
+}
@@ -8,6 +8,11 @@ use std::iter;
 impl<'c> Translation<'c> {
     /// Generate an integer literal corresponding to the given type, value, and base.
     pub fn mk_int_lit(&self, ty: CQualTypeId, val: u64, base: IntBase) -> Result<P<Expr>, TranslationError> {
+        // C++ `true` and `false` are exported as integer literals of type `bool`
+        if let CTypeKind::Bool = self.ast_context.resolve_type(ty.ctype).kind {
+            return Ok(mk().lit_expr(mk().bool_lit(val != 0)));
+        }
+
         let lit = match base {
             IntBase::Dec => mk().int_lit(val.into(), LitIntType::Unsuffixed),
             IntBase::Hex => mk().float_unsuffixed_lit(format!("0x{:x}", val)),
@@ -39,6 +44,9 @@ impl<'c> Translation<'c> {
             match self.ast_context[variant_id].kind {
                 CDeclKind::EnumConstant { value: v, .. } => {
                     if v == ConstIntExpr::I(value) || v == ConstIntExpr::U(value as u64) {
+                        if let Some(path) = self.enum_variant_path(variant_id) {
+                            return path;
+                        }
                         let name = self.renamer.borrow().get(&variant_id).unwrap();
 
                         // Import the enum variant if needed
@@ -64,6 +72,10 @@ impl<'c> Translation<'c> {
             _ => signed_int_expr(value),
         };
 
+        if let Some(ctor) = self.flags_ctor(def_id) {
+            return mk().call_expr(ctor, vec![value]);
+        }
+
         let target_ty = self.convert_type(enum_type_id).unwrap();
 
         mk().cast_expr(value, target_ty)
@@ -257,7 +269,11 @@ impl<'c> Translation<'c> {
                 self.convert_expr(ctx.used(), *id)
             }
             CTypeKind::Vector(CQualTypeId { ctype, .. }, len) => {
-                self.vector_list_initializer(ctx, ids, ctype, len)
+                if self.is_portable_vector(ty.ctype) {
+                    self.portable_vector_list_initializer(ctx, ids, ctype, len)
+                } else {
+                    self.vector_list_initializer(ctx, ids, ctype, len)
+                }
             }
             CTypeKind::Char | CTypeKind::Int => {
                 let id = ids.first().unwrap();
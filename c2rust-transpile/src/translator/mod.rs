@@ -25,17 +25,21 @@ use syntax_pos::{FileName, Span, DUMMY_SP};
 use syntax_pos::edition::Edition;
 
 use crate::rust_ast::pos_to_span;
-use crate::rust_ast::comment_store::CommentStore;
+use crate::rust_ast::comment_store::{CommentStore, CommentTraverser};
 use crate::rust_ast::item_store::ItemStore;
 use crate::rust_ast::traverse::Traversal;
 use c2rust_ast_builder::{mk, Builder, IntoSymbol};
 use c2rust_ast_printer::pprust::{self, Comments, PrintState};
 
 use crate::c_ast;
-use crate::c_ast::iterators::{DFExpr, SomeId};
+use crate::c_ast::iterators::{immediate_children, DFExpr, SomeId};
 use crate::c_ast::*;
 use crate::cfg;
-use crate::convert_type::TypeConverter;
+use crate::convert_type::{primitive_c_type, CTypeProvider, TypeConverter};
+use crate::diagnostics::Diagnostic;
+use crate::header_modules::{
+    local_items, HeaderItem, HeaderModule, HeaderModules, CTYPES_MODULE, HEADERS_MODULE,
+};
 use crate::renamer::Renamer;
 use crate::with_stmts::WithStmts;
 use crate::{ExternCrate, ExternCrateDetails, TranspilerConfig};
@@ -44,16 +48,27 @@ use c2rust_ast_exporter::clang_ast::LRValue;
 mod assembly;
 mod atomics;
 mod builtins;
+mod c_strings;
 mod comments;
+mod cxx;
+mod enums;
+mod for_range;
 mod literals;
 mod main_function;
 mod named_references;
+mod naming;
 mod operators;
+mod ref_params;
 mod simd;
+mod slice_params;
+mod static_initializers;
 mod structs;
+mod tagged_unions;
 mod variadic;
 
 pub use crate::diagnostics::{TranslationError, TranslationErrorKind};
+pub use self::for_range::LocalUses;
+pub use self::naming::RenameMap;
 use crate::CrateSet;
 use crate::PragmaVec;
 
@@ -265,6 +280,22 @@ pub struct Translation<'c> {
     function_context: RefCell<FunContext>,
     potential_flexible_array_members: RefCell<IndexSet<CDeclId>>,
     macro_expansions: RefCell<IndexMap<CDeclId, Option<MacroExpansion>>>,
+    // Locals of the function being translated and the placeholder values they are declared with
+    placeholder_locals: RefCell<IndexSet<(String, String)>>,
+    // `restrict` pointer parameters that are translated into references
+    ref_params: IndexSet<CParamId>,
+    // Pointer parameters that are translated into slices
+    slice_params: IndexSet<CParamId>,
+    // `const char *` parameters that are translated into `&CStr`
+    c_str_params: IndexSet<CParamId>,
+    // Enums that are translated into `#[repr(C)]` Rust enums
+    rust_enums: IndexSet<CEnumId>,
+    // Enums that are translated into `#[repr(transparent)]` flags types
+    flag_enums: IndexSet<CEnumId>,
+    // Unions given a safe Rust enum representation, keyed by their struct
+    tagged_unions: IndexMap<CRecordId, Vec<tagged_unions::TaggedUnion>>,
+    // Statics that are initialized on first access
+    lazy_statics: IndexSet<CDeclId>,
 
     // Comment support
     pub comment_context: CommentContext, // Incoming comments
@@ -424,7 +455,7 @@ pub fn signed_int_expr(value: i64) -> P<Expr> {
 
 // This should only be used for tests
 fn prefix_names(translation: &mut Translation, prefix: &str) {
-    for (&decl_id, ref mut decl) in translation.ast_context.iter_mut_decls() {
+    for (_, ref mut decl) in translation.ast_context.iter_mut_decls() {
         match decl.kind {
             CDeclKind::Function {
                 ref mut name,
@@ -436,9 +467,9 @@ fn prefix_names(translation: &mut Translation, prefix: &str) {
                     continue;
                 }
 
+                // The renamer picks up the prefixed name along with the other
+                // top-level names, so the naming policy applies to it too
                 name.insert_str(0, prefix);
-
-                translation.renamer.borrow_mut().insert(decl_id, &name);
             }
             CDeclKind::Variable {
                 ref mut ident,
@@ -504,6 +535,7 @@ pub fn translate(
     ast_context: TypedAstContext,
     tcfg: &TranspilerConfig,
     main_file: PathBuf,
+    header_modules: &mut HeaderModules,
 ) -> (String, PragmaVec, CrateSet) {
     let mut t = Translation::new(ast_context, tcfg, main_file.as_path());
     let ctx = ExprContext {
@@ -520,7 +552,9 @@ pub fn translate(
 
     // `with_globals` sets up a thread-local variable required by the syntax crate.
     with_globals(Edition::Edition2018, || {
-        t.use_crate(ExternCrate::Libc);
+        if tcfg.c_types == CTypeProvider::Libc {
+            t.use_crate(ExternCrate::Libc);
+        }
 
         // Sort the top-level declarations by file and source location so that we
         // preserve the ordering of all declarations in each file.
@@ -532,6 +566,24 @@ pub fn translate(
         // we simplify the translator output by omitting those.
         t.ast_context.prune_unwanted_decls(tcfg.preserve_unused_functions);
 
+        if tcfg.translate_restrict_refs {
+            t.ref_params = t.compute_ref_params();
+        }
+        if tcfg.translate_slice_params {
+            t.slice_params = t.compute_slice_params();
+        }
+        if tcfg.translate_c_strings && !tcfg.emit_no_std {
+            t.c_str_params = t.compute_c_str_params();
+        }
+        if tcfg.translate_enums {
+            t.flag_enums = t.compute_flag_enums();
+            t.rust_enums = t.compute_rust_enums();
+        }
+        if tcfg.translate_tagged_unions {
+            t.tagged_unions = t.compute_tagged_unions();
+        }
+        t.compute_lazy_statics();
+
         enum Name<'a> {
             VarName(&'a str),
             TypeName(&'a str),
@@ -594,9 +646,10 @@ pub fn translate(
                     {
                         prenamed_decls.insert(decl_id, subdecl_id);
 
+                        let name = t.decl_rust_name(decl_id, name);
                         t.type_converter
                             .borrow_mut()
-                            .declare_decl_name(decl_id, name);
+                            .declare_decl_name(decl_id, &name);
                         t.type_converter
                             .borrow_mut()
                             .alias_decl_name(subdecl_id, decl_id);
@@ -646,11 +699,13 @@ pub fn translate(
                         .declare_decl_name(decl_id, "C2RustUnnamed");
                 }
                 Name::TypeName(name) => {
+                    let name = t.decl_rust_name(decl_id, name);
                     t.type_converter
                         .borrow_mut()
-                        .declare_decl_name(decl_id, name);
+                        .declare_decl_name(decl_id, &name);
                 }
                 Name::VarName(name) => {
+                    let name = t.decl_rust_name(decl_id, name);
                     t.renamer.borrow_mut().insert(decl_id, &name);
                 }
             }
@@ -709,6 +764,34 @@ pub fn translate(
                     convert_type(decl_id, decl);
                 }
             }
+
+            // Export the safe representations of tagged unions, once all field
+            // names are known
+            for &struct_id in t.tagged_unions.keys() {
+                let decl = &t.ast_context[struct_id];
+                let decl_file_id = t.ast_context.file_id(decl);
+                if t.tcfg.reorganize_definitions {
+                    *t.cur_file.borrow_mut() = decl_file_id;
+                }
+                match t.convert_tagged_unions(struct_id) {
+                    Ok(items) => {
+                        for item in items {
+                            t.insert_item(item, decl);
+                        }
+                    }
+                    Err(e) => {
+                        let msg = format!("Skipping tagged unions of {:?} due to error: {}", struct_id, e);
+                        translate_failure(&t.tcfg, &msg);
+                    }
+                }
+                t.cur_file.borrow_mut().take();
+
+                if t.tcfg.reorganize_definitions
+                    && decl_file_id.map_or(false, |id| id != t.main_file)
+                {
+                    t.generate_submodule_imports(struct_id, decl_file_id);
+                }
+            }
         }
 
         // Export top-level value declarations
@@ -791,7 +874,48 @@ pub fn translate(
             store.add_item(initializer_static);
         }
 
+        // Define the wrapper of lazily initialized statics, and make it visible
+        // to the header submodules
+        if !t.lazy_statics.is_empty() {
+            for (file_id, store) in t.items.borrow_mut().iter_mut() {
+                if *file_id == t.main_file {
+                    for item in t.make_lazy_static_items() {
+                        store.add_item(item);
+                    }
+                } else {
+                    store.add_use(vec!["super".into()], "LazyStatic");
+                }
+            }
+        }
+
+        let is_binary = t.tcfg.is_binary(main_file.as_path());
+
+        // Define the C types in the translated module when asked to, and make
+        // them visible to the header submodules. Shared header modules import
+        // them from a module of the crate instead.
+        if t.tcfg.c_types == CTypeProvider::Ctypes {
+            let ctypes = make_ctypes_module(&t.ast_context.target_info);
+            if t.tcfg.header_modules {
+                header_modules.share_ctypes(ctypes_module_source(&ctypes));
+                let crate_path = crate_path(&t.tcfg, is_binary);
+                for store in t.items.borrow_mut().values_mut() {
+                    store.add_use(crate_path.clone(), CTYPES_MODULE);
+                }
+            } else {
+                for (file_id, store) in t.items.borrow_mut().iter_mut() {
+                    if *file_id == t.main_file {
+                        store.add_item(ctypes.clone());
+                    } else {
+                        store.add_use(vec!["super".into()], CTYPES_MODULE);
+                    }
+                }
+            }
+        }
+
         let pragmas = t.get_pragmas();
+        for &extern_crate in t.type_converter.borrow().crates_used() {
+            t.use_crate(extern_crate);
+        }
         let crates = t.extern_crates.borrow().clone();
 
         let mut mod_items: Vec<P<Item>> = Vec::new();
@@ -799,6 +923,13 @@ pub fn translate(
         // Keep track of new uses we need while building header submodules
         let mut new_uses = ItemStore::new();
 
+        // Header Reorganization: Shared Header Modules
+        let shared_headers = if t.tcfg.header_modules {
+            take_shared_headers(&t, header_modules, &mut new_uses, is_binary)
+        } else {
+            vec![]
+        };
+
         // Header Reorganization: Submodule Item Stores
         for (file_id, ref mut mod_item_store) in t.items.borrow_mut().iter_mut() {
             if *file_id != t.main_file {
@@ -825,11 +956,16 @@ pub fn translate(
         // Main file item store
         let (items, foreign_items, uses) = t.items.borrow_mut()[&t.main_file].drain();
 
+        t.resolve_doc_links();
+
         // Re-order comments
         // FIXME: We shouldn't have to replace with an empty comment store here,
         // that's bad design
         let mut traverser = t.comment_store.replace(CommentStore::new()).into_comment_traverser();
 
+        // Shared declarations are printed on their own, along with their comments
+        share_headers(shared_headers, &mut traverser, header_modules);
+
         // Add a comment mapping span to each node that should have a
         // comment printed before it. The pretty printer picks up these
         // spans and uses them to decide when to emit comments.
@@ -862,7 +998,7 @@ pub fn translate(
 
         // pass all converted items to the Rust pretty printer
         let translation = pprust::to_string_with_comments(comments, |s| {
-            print_header(s, &t, t.tcfg.is_binary(main_file.as_path()));
+            print_header(s, &t, is_binary);
 
             for mod_item in mod_items {
                 s.print_item(&*mod_item);
@@ -897,6 +1033,62 @@ pub fn translate(
     })
 }
 
+/// Build the `ctypes` module defining the C types with the layout of the
+/// translated target, so that the output does not need the `libc` crate.
+fn make_ctypes_module(target: &TargetInfo) -> P<Item> {
+    let names = [
+        "c_char",
+        "c_schar",
+        "c_uchar",
+        "c_short",
+        "c_ushort",
+        "c_int",
+        "c_uint",
+        "c_long",
+        "c_ulong",
+        "c_longlong",
+        "c_ulonglong",
+        "c_float",
+        "c_double",
+        "size_t",
+        "ssize_t",
+        "intptr_t",
+        "uintptr_t",
+        "ptrdiff_t",
+    ];
+
+    let mut items = vec![mk()
+        .pub_()
+        .use_simple_item(vec!["", "core", "ffi", "c_void"], None as Option<Ident>)];
+    items.extend(names.iter().map(|name| {
+        let ty = mk().path_ty(vec![primitive_c_type(name, target)]);
+        mk().pub_().type_item(*name, ty)
+    }));
+    mk().pub_().mod_item(CTYPES_MODULE, mk().mod_(items))
+}
+
+/// The source of the `ctypes` module of a crate, as a file of its own
+fn ctypes_module_source(ctypes: &Item) -> String {
+    let mut source = String::from("//! C types of the target\n");
+    if let ItemKind::Mod(ref module) = ctypes.kind {
+        for item in &module.items {
+            source += &pprust::item_to_string(item);
+            source += "\n";
+        }
+    }
+    source
+}
+
+/// Path to the root of the crate from one of its modules, which binaries
+/// refer to by the name of the library crate
+fn crate_path(tcfg: &TranspilerConfig, is_binary: bool) -> Vec<String> {
+    if is_binary {
+        vec![String::new(), tcfg.crate_name()]
+    } else {
+        vec!["crate".into()]
+    }
+}
+
 fn make_submodule(
     ast_context: &TypedAstContext,
     item_store: &mut ItemStore,
@@ -948,6 +1140,182 @@ fn make_submodule(
         .mod_item(mod_name, mk().mod_(items))
 }
 
+/// The declarations of a header shared with the other translation units of the
+/// crate, before they are printed with their comments
+struct SharedHeader {
+    name: String,
+    module: HeaderModule,
+    items: Vec<(String, P<Item>)>,
+    foreign_items: Vec<(String, ForeignItem)>,
+}
+
+/// Take the declarations of the headers of a translation unit out of their
+/// submodules, to share them with the other translation units of the crate
+/// and import them from there, unless they conflict with the shared ones.
+fn take_shared_headers(
+    t: &Translation,
+    header_modules: &HeaderModules,
+    use_item_store: &mut ItemStore,
+    is_binary: bool,
+) -> Vec<SharedHeader> {
+    // Every translation unit has its own copy of the variables other
+    // translation units cannot see
+    let statics: IndexSet<String> = t
+        .ast_context
+        .c_decls_top
+        .iter()
+        .filter_map(|decl_id| match t.ast_context[*decl_id].kind {
+            CDeclKind::Variable {
+                is_defn: true,
+                is_externally_visible: false,
+                ..
+            } => t.renamer.borrow().get(decl_id),
+            _ => None,
+        })
+        .collect();
+
+    let mut item_stores = t.items.borrow_mut();
+    let mut headers = vec![];
+    let mut texts = vec![];
+    for (&file_id, item_store) in item_stores.iter() {
+        if file_id == t.main_file {
+            continue;
+        }
+        let file_path = t.ast_context.get_file_path(file_id);
+        let name = clean_path(&t.mod_names, file_path);
+        let mut module = HeaderModule {
+            path: file_path.map_or_else(|| PathBuf::from(&name), path::Path::to_path_buf),
+            ..HeaderModule::default()
+        };
+        for (path, use_item) in item_store.uses().simple_items() {
+            // Imports from the main module only exist in this translation unit
+            if path.len() == 2 && path[0] == "super" {
+                warn!(
+                    "Not sharing the headers of {}: they import `{}` from its module",
+                    t.ast_context
+                        .get_file_path(t.main_file)
+                        .map_or_else(String::new, |file| file.display().to_string()),
+                    path[1],
+                );
+                return vec![];
+            }
+            module.uses.insert(pprust::item_to_string(&use_item));
+        }
+        let plain = |text: String| HeaderItem { plain: text, text: String::new() };
+        let mut items = vec![];
+        for item in item_store.items() {
+            let text = pprust::item_to_string(item);
+            let ident = item.ident.as_str().to_string();
+            let key = if ident.is_empty() { text.clone() } else { ident };
+            texts.push((key.clone(), text.clone()));
+            module.items.insert(key.clone(), plain(text));
+            items.push(key);
+        }
+        let mut foreign_items = vec![];
+        for item in item_store.foreign_items() {
+            let key = item.ident.as_str().to_string();
+            module.foreign_items.insert(key.clone(), plain(pprust::foreign_item_to_string(item)));
+            foreign_items.push(key);
+        }
+        headers.push((file_id, name, module, items, foreign_items));
+    }
+
+    // Those variables, and the items referring to them, stay in the module of
+    // the translation unit
+    let local = local_items(&texts, statics);
+    for (_, _, module, _, _) in &mut headers {
+        module.items.retain(|key, _| !local.contains(key));
+    }
+
+    let modules = headers
+        .iter()
+        .map(|(_, name, module, _, _)| (name.clone(), module.clone()))
+        .collect();
+    if !header_modules.can_share(&modules) {
+        return vec![];
+    }
+
+    let mut shared = vec![];
+    for (file_id, name, module, item_keys, foreign_item_keys) in headers {
+        let (all_items, foreign_items, _) = item_stores
+            .shift_remove(&file_id)
+            .expect("Missing header item store")
+            .drain();
+        let (item_keys, items): (Vec<_>, Vec<_>) = item_keys
+            .into_iter()
+            .zip(all_items)
+            .filter_map(|(key, item)| {
+                if local.contains(&key) {
+                    item_stores[&t.main_file].add_item(item);
+                    None
+                } else {
+                    Some((key, item))
+                }
+            })
+            .unzip();
+
+        let mut use_path = crate_path(&t.tcfg, is_binary);
+        use_path.push(HEADERS_MODULE.into());
+        use_path.push(name.clone());
+        for item in &items {
+            let ident_name = item.ident.as_str();
+            if ident_name.is_empty() {
+                continue;
+            }
+            let vis = match item.vis.node {
+                VisibilityKind::Public => mk().pub_(),
+                _ => mk(),
+            };
+            use_item_store.add_use_with_attr(use_path.clone(), &ident_name, vis);
+        }
+        for foreign_item in &foreign_items {
+            use_item_store.add_use(use_path.clone(), &foreign_item.ident.as_str());
+        }
+
+        shared.push(SharedHeader {
+            name,
+            module,
+            items: item_keys.into_iter().zip(items).collect(),
+            foreign_items: foreign_item_keys.into_iter().zip(foreign_items).collect(),
+        });
+    }
+    shared
+}
+
+/// Print the declarations of shared headers with their comments, and add them
+/// to the shared header modules
+fn share_headers(
+    shared: Vec<SharedHeader>,
+    traverser: &mut CommentTraverser,
+    header_modules: &mut HeaderModules,
+) {
+    let sm = SourceMap::new(FilePathMapping::empty());
+    sm.new_source_file(FileName::Custom("<dummy>".to_string()), " ".to_string());
+    let print = |comments, f: &dyn Fn(&mut pprust::State)| {
+        let text = pprust::to_string_with_comments(Comments::new(&sm, comments), |s| {
+            f(s);
+            s.print_remaining_comments();
+        });
+        text.trim().to_string()
+    };
+
+    let mut modules = IndexMap::new();
+    for SharedHeader { name, mut module, items, foreign_items } in shared {
+        for (key, item) in items {
+            let item = item.map(|i| traverser.traverse_item(i));
+            let text = print(traverser.take_comments(), &|s| s.print_item(&item));
+            module.items[&key].text = text;
+        }
+        for (key, item) in foreign_items {
+            let item = traverser.traverse_foreign_item(item);
+            let text = print(traverser.take_comments(), &|s| s.print_foreign_item(&item));
+            module.foreign_items[&key].text = text;
+        }
+        modules.insert(name, module);
+    }
+    header_modules.share(modules);
+}
+
 /// Pretty-print the leading pragmas and extern crate declarations
 fn print_header(s: &mut pprust::State, t: &Translation, is_binary: bool) {
     if t.tcfg.emit_modules && !is_binary {
@@ -993,11 +1361,6 @@ fn print_header(s: &mut pprust::State, t: &Translation, is_binary: bool) {
     }
 }
 
-/// Convert a boolean expression to a c_int
-fn bool_to_int(val: P<Expr>) -> P<Expr> {
-    mk().cast_expr(val, mk().path_ty(vec!["libc", "c_int"]))
-}
-
 /// Add a src_loc = "line:col" attribute to an item/foreign_item
 fn add_src_loc_attr(attrs: &mut Vec<ast::Attribute>, src_loc: &Option<SrcLoc>) {
     if let Some(src_loc) = src_loc.as_ref() {
@@ -1052,7 +1415,8 @@ impl<'c> Translation<'c> {
         main_file: &path::Path,
     ) -> Self {
         let comment_context = CommentContext::new(&mut ast_context);
-        let mut type_converter = TypeConverter::new(tcfg.emit_no_std);
+        let mut type_converter =
+            TypeConverter::new(tcfg.emit_no_std, tcfg.c_types, ast_context.target_info);
 
         if tcfg.translate_valist {
             type_converter.translate_valist = true
@@ -1083,6 +1447,14 @@ impl<'c> Translation<'c> {
             function_context: RefCell::new(FunContext::new()),
             potential_flexible_array_members: RefCell::new(IndexSet::new()),
             macro_expansions: RefCell::new(IndexMap::new()),
+            placeholder_locals: RefCell::new(IndexSet::new()),
+            ref_params: IndexSet::new(),
+            slice_params: IndexSet::new(),
+            c_str_params: IndexSet::new(),
+            rust_enums: IndexSet::new(),
+            flag_enums: IndexSet::new(),
+            tagged_unions: IndexMap::new(),
+            lazy_statics: IndexSet::new(),
             comment_context,
             comment_store: RefCell::new(CommentStore::new()),
             spans: HashMap::new(),
@@ -1188,14 +1560,20 @@ impl<'c> Translation<'c> {
         };
 
         // Look for code which can only be translated unsafely
-        let iter = DFExpr::new(&self.ast_context, expr_id.into());
+        let mut iter = DFExpr::new(&self.ast_context, expr_id.into());
 
-        for i in iter {
+        while let Some(i) = iter.next() {
             let expr_id = match i {
                 SomeId::Expr(expr_id) => expr_id,
                 _ => unreachable!("Found static initializer type other than expr"),
             };
 
+            // Integers evaluated during translation become plain literals
+            if self.static_expr_is_literal(expr_id) {
+                iter.prune(immediate_children(&self.ast_context, i).len());
+                continue;
+            }
+
             match self.ast_context[expr_id].kind {
                 CExprKind::DeclRef(_, _, LRValue::LValue) => return true,
                 | CExprKind::ImplicitCast(_, _, cast_kind, _, _)
@@ -1215,10 +1593,6 @@ impl<'c> Translation<'c> {
     /// The purpose of this function is to decide on whether or not a static initializer's
     /// translation is able to be compiled as a valid rust static initializer
     fn static_initializer_is_uncompilable(&self, expr_id: Option<CExprId>, qtype: CQualTypeId) -> bool {
-        use crate::c_ast::BinOp::{Add, Divide, Modulus, Multiply, Subtract};
-        use crate::c_ast::CastKind::{IntegralToPointer, PointerToIntegral};
-        use crate::c_ast::UnOp::{AddressOf, Negate};
-
         let expr_id = match expr_id {
             Some(expr_id) => expr_id,
             None => return false,
@@ -1230,103 +1604,63 @@ impl<'c> Translation<'c> {
             return true;
         }
 
-        let iter = DFExpr::new(&self.ast_context, expr_id.into());
-
-        for i in iter {
-            let expr_id = match i {
-                SomeId::Expr(expr_id) => expr_id,
-                _ => unreachable!("Found static initializer type other than expr"),
-            };
-
-            match self.ast_context[expr_id].kind {
-                // Technically we're being conservative here, but it's only the most
-                // contrived array indexing initializers that would be accepted
-                CExprKind::ArraySubscript(..) => return true,
-                CExprKind::Member(..) => return true,
-
-                CExprKind::Conditional(..) => return true,
-                CExprKind::Unary(typ, Negate, _, _) => {
-                    if self
-                        .ast_context
-                        .resolve_type(typ.ctype)
-                        .kind
-                        .is_unsigned_integral_type()
-                    {
-                        return true;
-                    }
-                }
+        self.static_expr_is_uncompilable(expr_id)
+    }
 
-                // PointerToIntegral is no longer allowed, const-eval throws an
-                // error: "pointer-to-integer cast" needs an rfc before being
-                // allowed inside constants
-                CExprKind::ImplicitCast(_, _, PointerToIntegral, _, _)
-                | CExprKind::ExplicitCast(_, _, PointerToIntegral, _, _) => return true,
+    /// Decides whether a single node of a static initializer can't be compiled, regardless
+    /// of its children. Nodes that are evaluated by `convert_const_eval` are handled by
+    /// `static_expr_is_uncompilable`.
+    fn static_node_is_uncompilable(&self, expr_id: CExprId) -> bool {
+        use crate::c_ast::CastKind::IntegralToPointer;
 
-                CExprKind::Binary(typ, op, _, _, _, _) => {
-                    let problematic_op = match op {
-                        Add | Subtract | Multiply | Divide | Modulus => true,
-                        _ => false,
-                    };
+        match self.ast_context[expr_id].kind {
+            // Lazily initialized statics can only be read once initialized
+            CExprKind::DeclRef(_, decl_id, _) => self.lazy_statics.contains(&decl_id),
 
-                    if problematic_op {
-                        let k = &self.ast_context.resolve_type(typ.ctype).kind;
-                        if k.is_unsigned_integral_type() || k.is_pointer() {
-                            return true;
-                        }
-                    }
-                }
-                CExprKind::Unary(_, AddressOf, expr_id, _) => {
-                    if let CExprKind::Member(_, expr_id, _, _, _) = self.ast_context[expr_id].kind {
-                        if let CExprKind::DeclRef(..) = self.ast_context[expr_id].kind {
-                            return true;
-                        }
-                    }
-                }
-                CExprKind::InitList(qtype, _, _, _) => {
-                    let ty = &self.ast_context.resolve_type(qtype.ctype).kind;
+            CExprKind::InitList(qtype, _, _, _) => {
+                let ty = &self.ast_context.resolve_type(qtype.ctype).kind;
 
-                    match ty {
-                        CTypeKind::Struct(decl_id) => {
-                            let decl = &self.ast_context[*decl_id].kind;
+                match ty {
+                    CTypeKind::Struct(decl_id) => {
+                        let decl = &self.ast_context[*decl_id].kind;
 
-                            if let CDeclKind::Struct {
-                                fields: Some(fields),
-                                ..
-                            } = decl
-                            {
-                                for field_id in fields {
-                                    let field_decl = &self.ast_context[*field_id].kind;
-
-                                    if let CDeclKind::Field {
-                                        bitfield_width: Some(_),
-                                        ..
-                                    } = field_decl
-                                    {
-                                        return true;
-                                    }
+                        if let CDeclKind::Struct {
+                            fields: Some(fields),
+                            ..
+                        } = decl
+                        {
+                            for field_id in fields {
+                                let field_decl = &self.ast_context[*field_id].kind;
+
+                                if let CDeclKind::Field {
+                                    bitfield_width: Some(_),
+                                    ..
+                                } = field_decl
+                                {
+                                    return true;
                                 }
                             }
                         }
-                        _ => {}
+                        false
                     }
+                    _ => false,
                 }
-                CExprKind::ImplicitCast(qtype, _, IntegralToPointer, _, _)
-                | CExprKind::ExplicitCast(qtype, _, IntegralToPointer, _, _) => {
-                    if let CTypeKind::Pointer(qtype) =
+            }
+            CExprKind::ImplicitCast(qtype, _, IntegralToPointer, _, _)
+            | CExprKind::ExplicitCast(qtype, _, IntegralToPointer, _, _) => {
+                if let CTypeKind::Pointer(qtype) =
+                    self.ast_context.resolve_type(qtype.ctype).kind
+                {
+                    if let CTypeKind::Function(..) =
                         self.ast_context.resolve_type(qtype.ctype).kind
                     {
-                        if let CTypeKind::Function(..) =
-                            self.ast_context.resolve_type(qtype.ctype).kind
-                        {
-                            return true;
-                        }
+                        return true;
                     }
                 }
-                _ => {}
+                false
             }
+            _ => false,
         }
-
-        false
     }
 
     fn add_static_initializer_to_section(
@@ -1454,9 +1788,10 @@ impl<'c> Translation<'c> {
                         ref name,
                         ..
                     } = self.ast_context.index(x).kind {
+                        let name = self.field_rust_name(decl_id, name);
                         self.type_converter
                             .borrow_mut()
-                            .declare_field_name(decl_id, x, name);
+                            .declare_field_name(decl_id, x, &name);
                     }
                 }
 
@@ -1575,12 +1910,14 @@ impl<'c> Translation<'c> {
                     let field_decl = self.ast_context.index(x);
                     match field_decl.kind {
                         CDeclKind::Field { ref name, typ, .. } => {
+                            let name = self.field_rust_name(decl_id, name);
                             let name = self
                                 .type_converter
                                 .borrow_mut()
-                                .declare_field_name(decl_id, x, name);
+                                .declare_field_name(decl_id, x, &name);
                             let typ = self.convert_type(typ.ctype)?;
-                            field_syns.push(mk().pub_().struct_field(name, typ))
+                            let span = self.get_span(SomeId::Decl(x)).unwrap_or(DUMMY_SP);
+                            field_syns.push(mk().span(span).pub_().struct_field(name, typ))
                         }
                         _ => {
                             return Err(TranslationError::generic(
@@ -1623,6 +1960,12 @@ impl<'c> Translation<'c> {
                 integral_type: Some(integral_type),
                 ..
             } => {
+                if let Some(item) = self.convert_rust_enum(decl_id, s)? {
+                    return Ok(ConvertedDecl::Item(item));
+                }
+                if let Some(items) = self.convert_flag_enum(decl_id, s)? {
+                    return Ok(ConvertedDecl::Items(items));
+                }
                 let enum_name = &self
                     .type_converter
                     .borrow()
@@ -1635,6 +1978,12 @@ impl<'c> Translation<'c> {
             }
 
             CDeclKind::EnumConstant { value, .. } => {
+                // Variants of Rust enums and constants of flags types are declared along with
+                // their enum
+                let enum_id = self.ast_context.parents[&decl_id];
+                if self.rust_enums.contains(&enum_id) || self.flag_enums.contains(&enum_id) {
+                    return Ok(ConvertedDecl::NoItem);
+                }
                 let name = self
                     .renamer
                     .borrow_mut()
@@ -1715,6 +2064,22 @@ impl<'c> Translation<'c> {
                     }
                 }
 
+                // Portable vectors are passed as structs rather than in vector registers,
+                // so C can't pass them by value to or from Rust
+                if is_global || body.is_none() {
+                    let passes_vector = ret
+                        .iter()
+                        .chain(args.iter().map(|(_, _, typ)| typ))
+                        .any(|typ| self.is_portable_vector(typ.ctype));
+                    if passes_vector {
+                        return Err(format_err!(
+                            "Function {} passes a vector by value to or from C",
+                            name
+                        )
+                        .into());
+                    }
+                }
+
                 let is_main = self.ast_context.c_main == Some(decl_id);
 
                 let converted_function = self.convert_function(
@@ -1838,9 +2203,29 @@ impl<'c> Translation<'c> {
                     .get(&decl_id)
                     .expect("Variables should already be renamed");
 
-                // Collect problematic static initializers and offload them to sections for the linker
-                // to initialize for us
-                let (ty, init) = if self.static_initializer_is_uncompilable(initializer, typ) {
+                // Initialize statics with problematic initializers on first access, or
+                // if they are shared with other translation units, offload them to
+                // sections for the linker to initialize for us
+                let (ty, init) = if self.lazy_statics.contains(&decl_id) {
+                    let comment = String::from("// Initialized on first access");
+                    let comment_pos = if s.is_dummy() {
+                        None
+                    } else {
+                        Some(s.lo())
+                    };
+                    s = self
+                        .comment_store
+                        .borrow_mut()
+                        .extend_existing_comments(
+                            &[comment],
+                            comment_pos,
+                            CommentStyle::Isolated,
+                        )
+                        .map(pos_to_span)
+                        .unwrap_or(s);
+
+                    self.convert_lazy_static(ctx, initializer, typ)?
+                } else if self.static_initializer_is_uncompilable(initializer, typ) {
                     // Note: We don't pass has_static_duration through here. Extracted initializers
                     // are run outside of the static initializer.
                     let (ty, _, init) =
@@ -2040,7 +2425,14 @@ impl<'c> Translation<'c> {
 
             // handle regular (non-variadic) arguments
             for &(decl_id, ref var, typ) in arguments {
-                let (ty, mutbl, _) = self.convert_variable(ctx, None, typ)?;
+                let (mut ty, mutbl, _) = self.convert_variable(ctx, None, typ)?;
+                if self.is_ref_param(decl_id) {
+                    ty = self.convert_ref_param_type(typ)?;
+                } else if self.is_slice_param(decl_id) {
+                    ty = self.convert_slice_param_type(typ)?;
+                } else if self.is_c_str_param(decl_id) {
+                    ty = self.convert_c_str_param_type();
+                }
 
                 let pat = if var.is_empty() {
                     mk().wild_pat()
@@ -2055,7 +2447,7 @@ impl<'c> Translation<'c> {
                     let new_var = self
                         .renamer
                         .borrow_mut()
-                        .insert(decl_id, var.as_str())
+                        .insert(decl_id, &self.decl_rust_name(decl_id, var))
                         .expect(&format!(
                             "Failed to insert argument '{}' while converting '{}'",
                             var, name
@@ -2117,6 +2509,9 @@ impl<'c> Translation<'c> {
                     _ => cfg::ImplicitReturnType::Void,
                 };
 
+                // Don't keep the placeholders of a function whose translation failed
+                self.placeholder_locals.borrow_mut().clear();
+
                 let mut body_stmts = vec![];
                 for &(_, _, typ) in arguments {
                     body_stmts.append(&mut self.compute_variable_array_sizes(ctx, typ.ctype)?);
@@ -2127,6 +2522,17 @@ impl<'c> Translation<'c> {
                     _ => panic!("function body expects to be a compound statement"),
                 };
                 body_stmts.append(&mut self.convert_function_body(ctx, name, body_ids, ret)?);
+                let placeholders = self.placeholder_locals.replace(IndexSet::new());
+                let kept = cfg::definite_assignment::elide_dead_inits(&mut body_stmts, &placeholders);
+                for (local, reason) in kept {
+                    diag!(
+                        Diagnostic::Idioms,
+                        "keeping the zero initializer of `{}` in `{}`: {}",
+                        local,
+                        name,
+                        reason,
+                    );
+                }
                 let mut block = stmts_block(body_stmts);
                 if let Some(span) = self.get_span(SomeId::Stmt(body)) {
                     block.span = span;
@@ -2220,24 +2626,58 @@ impl<'c> Translation<'c> {
         live_in: IndexSet<CDeclId>,
         cut_out_trailing_ret: bool,
     ) -> Result<Vec<Stmt>, TranslationError> {
-        if self.tcfg.dump_function_cfgs {
-            graph
-                .dump_dot_graph(
-                    &self.ast_context,
-                    &store,
-                    self.tcfg.dump_cfg_liveness,
-                    self.tcfg.use_c_loop_info,
-                    format!("{}_{}.dot", "cfg", name),
-                )
-                .expect("Failed to write CFG .dot file");
+        let dump_cfgs = self.dumps_function_cfgs();
+        let dump_stage = self.tcfg.cfg_dump_stage;
+
+        let mut graph = graph;
+        if dump_cfgs && dump_stage == cfg::CfgDumpStage::Raw {
+            self.dump_cfg_graph(name, &graph, &store);
         }
-        if self.tcfg.json_function_cfgs {
-            graph
-                .dump_json_graph(&store, format!("{}_{}.json", "cfg", name))
-                .expect("Failed to write CFG .json file");
+        graph.prune_empty_blocks_mut();
+        graph.prune_unreachable_blocks_mut();
+        if dump_cfgs && dump_stage == cfg::CfgDumpStage::Pruned {
+            self.dump_cfg_graph(name, &graph, &store);
         }
 
-        let (lifted_stmts, relooped) = cfg::relooper::reloop(
+        let complexity_before = graph.cyclomatic_complexity();
+
+        // Merging empty `case`s drops blocks, which would be counted against the copies
+        cfg::node_splitting::merge_switch_empty_cases(&mut graph);
+        let blocks_before = graph.num_blocks();
+
+        let graph = match self.tcfg.split_nodes {
+            Some(budget) => {
+                let graph = cfg::node_splitting::split_switch_fallthrough(graph, budget);
+                cfg::node_splitting::split_nodes(graph, budget, |graph| {
+                    let (_, relooped, _) = cfg::relooper::reloop(
+                        graph.clone(),
+                        store.clone(),
+                        self.tcfg.simplify_structures,
+                        self.tcfg.use_c_loop_info,
+                        self.tcfg.use_c_multiple_info,
+                        live_in.clone(),
+                    );
+                    cfg::structures::count_multiples(&relooped)
+                })
+            }
+            None => graph,
+        };
+        let duplicated_blocks = graph.num_blocks() - blocks_before;
+
+        let label_roles = graph.label_roles().clone();
+
+        let dump_relooped = dump_cfgs && dump_stage == cfg::CfgDumpStage::Relooped;
+        let src_locs = if dump_relooped {
+            if self.tcfg.dump_function_cfgs {
+                // DOT can only draw graphs, so show the one relooper is given
+                self.dump_cfg_dot(name, &graph, &store);
+            }
+            graph.src_locs()
+        } else {
+            IndexMap::new()
+        };
+
+        let (lifted_stmts, relooped, unmatched_loops) = cfg::relooper::reloop(
             graph,
             store,
             self.tcfg.simplify_structures,
@@ -2246,43 +2686,174 @@ impl<'c> Translation<'c> {
             live_in,
         );
 
+        if dump_relooped {
+            if self.tcfg.json_function_cfgs {
+                cfg::dump_json_structures(&relooped, format!("{}_{}.json", "cfg", name))
+                    .expect("Failed to write CFG .json file");
+            }
+            if self.tcfg.html_function_cfgs {
+                cfg::html::dump_html_structures(
+                    &self.ast_context,
+                    &relooped,
+                    &src_locs,
+                    name,
+                    format!("{}_{}.html", "cfg", name),
+                )
+                .expect("Failed to write CFG .html file");
+            }
+        }
+
         if self.tcfg.dump_structures {
             eprintln!("Relooped structures:");
             for s in &relooped {
                 eprintln!("  {:#?}", s);
             }
+            eprintln!(
+                "{}: {} current_block dispatch(es)",
+                name,
+                cfg::structures::count_multiples(&relooped),
+            );
+        }
+
+        if dump_cfgs && self.tcfg.json_function_cfgs {
+            let metrics = cfg::metrics::FunctionMetrics {
+                function: name.to_owned(),
+                multiples: cfg::structures::count_multiples(&relooped),
+                unmatched_loops,
+                loop_labels: cfg::structures::count_loop_labels(&relooped)?,
+                duplicated_blocks,
+                complexity_before,
+                complexity_after: cfg::metrics::structures_complexity(&relooped),
+            };
+            metrics
+                .dump_json(format!("{}_{}.json", "metrics", name))
+                .expect("Failed to write CFG metrics .json file");
         }
 
         let current_block_ident = self.renamer.borrow_mut().pick_name("current_block");
         let current_block = mk().ident_expr(&current_block_ident);
-        let mut stmts: Vec<Stmt> = lifted_stmts;
-        if cfg::structures::has_multiple(&relooped) {
+        let mut stmts: Vec<Stmt> = vec![];
+        let block_labels = if cfg::structures::has_multiple(&relooped) {
             if self.tcfg.fail_on_multiple {
                 panic!("Uses of `current_block' are illegal with `--fail-on-multiple'.");
             }
 
-            let current_block_ty = if self.tcfg.debug_relooper_labels {
-                mk().ref_lt_ty("'static", mk().path_ty(vec!["str"]))
+            // Values of `current_block` are variants of a local enum, unless readable strings
+            // were asked for
+            let block_labels = if self.tcfg.debug_relooper_labels {
+                cfg::structures::BlockLabels::Strings
             } else {
-                mk().path_ty(vec!["u64"])
+                // The enum is a type, so it must not shadow a C type of the same name
+                cfg::structures::BlockLabels::new_enum(
+                    self.type_converter.borrow().local_type_name("Block"),
+                    &cfg::structures::dispatch_labels(&relooped)?,
+                    &label_roles,
+                    &self.ast_context,
+                )
             };
+            if let Some(item) = block_labels.enum_item() {
+                stmts.push(mk().item_stmt(item));
+            }
 
             let local = mk().local(
                 mk().mutbl().ident_pat(current_block_ident),
-                Some(current_block_ty),
+                Some(block_labels.block_ty()),
                 None as Option<P<Expr>>,
             );
-            stmts.push(mk().local_stmt(P(local)))
-        }
+            stmts.push(mk().local_stmt(P(local)));
+            block_labels
+        } else {
+            cfg::structures::BlockLabels::Strings
+        };
 
-        stmts.extend(cfg::structures::structured_cfg(
+        let mut body = cfg::structures::structured_cfg(
             &relooped,
             &mut self.comment_store.borrow_mut(),
             current_block,
-            self.tcfg.debug_relooper_labels,
+            &block_labels,
             cut_out_trailing_ret,
-        )?);
-        Ok(stmts)
+            self.tcfg.simplify_structures,
+            &self.local_variable_names(),
+        )?;
+        let mut lifted_stmts = cfg::decl_placement::place_lifted_decls(lifted_stmts, &mut body);
+        lifted_stmts.append(&mut stmts);
+        lifted_stmts.append(&mut body);
+        Ok(lifted_stmts)
+    }
+
+    /// The Rust names of the parameters and automatic variables of the function being translated
+    fn local_variable_names(&self) -> IndexSet<String> {
+        self.renamer
+            .borrow()
+            .scoped_names()
+            .filter(|&(&decl_id, _)| match self.ast_context.index(decl_id).kind {
+                CDeclKind::Variable {
+                    has_static_duration,
+                    has_thread_duration,
+                    ..
+                } => !has_static_duration && !has_thread_duration,
+                _ => false,
+            })
+            .map(|(_, name)| name.to_owned())
+            .collect()
+    }
+
+    /// Whether CFGs of the function being translated should be dumped, in any format
+    fn dumps_function_cfgs(&self) -> bool {
+        if !(self.tcfg.dump_function_cfgs
+            || self.tcfg.json_function_cfgs
+            || self.tcfg.html_function_cfgs)
+        {
+            return false;
+        }
+        match self.tcfg.cfg_dump_filter {
+            Some(ref filter) => filter.is_match(self.function_context.borrow().get_name()),
+            None => true,
+        }
+    }
+
+    /// Dump a CFG in the graph formats that were asked for
+    fn dump_cfg_graph(
+        &self,
+        name: &str,
+        graph: &cfg::Cfg<cfg::Label, cfg::StmtOrDecl>,
+        store: &cfg::DeclStmtStore,
+    ) {
+        if self.tcfg.dump_function_cfgs {
+            self.dump_cfg_dot(name, graph, store);
+        }
+        if self.tcfg.json_function_cfgs {
+            graph
+                .dump_json_graph(store, format!("{}_{}.json", "cfg", name))
+                .expect("Failed to write CFG .json file");
+        }
+        if self.tcfg.html_function_cfgs {
+            graph
+                .dump_html_graph(
+                    &self.ast_context,
+                    store,
+                    name,
+                    format!("{}_{}.html", "cfg", name),
+                )
+                .expect("Failed to write CFG .html file");
+        }
+    }
+
+    fn dump_cfg_dot(
+        &self,
+        name: &str,
+        graph: &cfg::Cfg<cfg::Label, cfg::StmtOrDecl>,
+        store: &cfg::DeclStmtStore,
+    ) {
+        graph
+            .dump_dot_graph(
+                &self.ast_context,
+                store,
+                self.tcfg.dump_cfg_liveness,
+                self.tcfg.use_c_loop_info,
+                format!("{}_{}.dot", "cfg", name),
+            )
+            .expect("Failed to write CFG .dot file");
     }
 
     fn convert_function_body(
@@ -2336,6 +2907,10 @@ impl<'c> Translation<'c> {
                 }))
             };
 
+        if let Some(test) = self.convert_flag_test(ctx, target, cond_id)? {
+            return Ok(test);
+        }
+
         match self.ast_context[cond_id].kind {
             CExprKind::Binary(_, c_ast::BinOp::EqualEqual, null_expr, ptr, _, _)
                 if self.ast_context.is_null_expr(null_expr) =>
@@ -2416,19 +2991,18 @@ impl<'c> Translation<'c> {
                 typ,
                 ..
             } => {
-                if self.static_initializer_is_uncompilable(initializer, typ) {
+                if self.lazy_statics.contains(&decl_id) {
                     let ident2 = self
                         .renamer
                         .borrow_mut()
-                        .insert_root(decl_id, ident)
+                        .insert_root(decl_id, &self.decl_rust_name(decl_id, ident))
                         .ok_or_else(|| {
                             TranslationError::generic(
                                 "Unable to rename function scoped static initializer",
                             )
                         })?;
-                    let (ty, _, init) = self.convert_variable(ctx.static_(), initializer, typ)?;
-                    let default_init = self.implicit_default_expr(typ.ctype, true)?.to_expr();
-                    let comment = String::from("// Initialized in run_static_initializers");
+                    let (ty, init) = self.convert_lazy_static(ctx, initializer, typ)?;
+                    let comment = String::from("// Initialized on first access");
                     let span = self
                         .comment_store
                         .borrow_mut()
@@ -2438,12 +3012,8 @@ impl<'c> Translation<'c> {
                     let static_item =
                         mk().span(span)
                             .mutbl()
-                            .static_item(&ident2, ty, default_init);
-                    let mut init = init?;
-                    init.set_unsafe();
-                    let mut init = init.to_expr();
+                            .static_item(&ident2, ty, init);
 
-                    self.add_static_initializer_to_section(&ident2, typ, &mut init)?;
                     self.items.borrow_mut()[&self.main_file].add_item(static_item);
 
                     return Ok(cfg::DeclStmtInfo::empty());
@@ -2471,7 +3041,7 @@ impl<'c> Translation<'c> {
                 let rust_name = self
                     .renamer
                     .borrow_mut()
-                    .insert(decl_id, &ident)
+                    .insert(decl_id, &self.decl_rust_name(decl_id, ident))
                     .expect(&format!("Failed to insert variable '{}'", ident));
 
                 if self.ast_context.is_va_list(typ.ctype) {
@@ -2511,6 +3081,9 @@ impl<'c> Translation<'c> {
                 } else {
                     zeroed.to_pure_expr()
                 }.expect("Expected decl initializer to not have any statements");
+                self.placeholder_locals
+                    .borrow_mut()
+                    .insert((rust_name.clone(), pprust::expr_to_string(&zeroed)));
                 let pat_mut = mk().set_mutbl("mut").ident_pat(rust_name.clone());
                 let local_mut = mk().local(pat_mut, Some(ty.clone()), Some(zeroed));
                 if has_self_reference {
@@ -2558,6 +3131,7 @@ impl<'c> Translation<'c> {
 
             ref decl => {
                 let inserted = if let Some(ident) = decl.get_name() {
+                    let ident = self.decl_rust_name(decl_id, ident);
                     self.renamer.borrow_mut().insert(decl_id, &ident).is_some()
                 } else {
                     false
@@ -2700,6 +3274,9 @@ impl<'c> Translation<'c> {
         TranslationError,
     > {
         let init = match initializer {
+            Some(x) if self.is_reference_type(typ.ctype) => {
+                self.convert_reference_binding(ctx.used(), x)
+            }
             Some(x) => self.convert_expr(ctx.used(), x),
             None => self.implicit_default_expr(typ.ctype, ctx.is_static),
         };
@@ -2989,6 +3566,16 @@ impl<'c> Translation<'c> {
         Ok(WithStmts::new_val(call))
     }
 
+    /// The named C type (`c_int`, `size_t`, ...) from the configured provider
+    pub fn c_type(&self, name: &str) -> P<Ty> {
+        self.type_converter.borrow().c_type(name)
+    }
+
+    /// Convert a boolean expression to a c_int
+    fn bool_to_int(&self, val: P<Expr>) -> P<Expr> {
+        mk().cast_expr(val, self.c_type("c_int"))
+    }
+
     fn convert_exprs(&self, ctx: ExprContext, exprs: &[CExprId])
                      -> Result<WithStmts<Vec<P<Expr>>>, TranslationError>
     {
@@ -2998,6 +3585,30 @@ impl<'c> Translation<'c> {
             .collect()
     }
 
+    /// Translate an argument of a direct call, given the parameter it is
+    /// passed to. Parameters translated into references, slices or `&CStr`
+    /// need their arguments to be converted accordingly.
+    fn convert_call_arg(
+        &self,
+        ctx: ExprContext,
+        param_id: Option<CParamId>,
+        arg: CExprId,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        match param_id {
+            Some(param_id) if self.is_ref_param(param_id) => {
+                self.convert_ref_arg(ctx, param_id, arg)
+            }
+            Some(param_id) if self.is_slice_param(param_id) => {
+                self.convert_slice_arg(ctx, param_id, arg)
+            }
+            Some(param_id) if self.is_c_str_param(param_id) => self.convert_c_str_arg(ctx, arg),
+            _ => match self.c_str_param_ref(arg) {
+                Some(param) => self.convert_c_str_ptr(ctx, param),
+                None => self.convert_expr(ctx, arg),
+            },
+        }
+    }
+
     /// Translate a C expression into a Rust one, possibly collecting side-effecting statements
     /// to run before the expression.
     ///
@@ -3032,6 +3643,14 @@ impl<'c> Translation<'c> {
             }
         }
 
+        // Static initializers can't use the usual translation of some constant
+        // expressions, so those are evaluated here
+        if ctx.is_static && self.needs_const_eval(expr_id) {
+            if let Some(converted) = self.convert_const_eval(ctx, expr_id)? {
+                return Ok(converted);
+            }
+        }
+
         match *expr_kind {
             CExprKind::DesignatedInitExpr(..) => {
                 Err(TranslationError::generic("Unexpected designated init expr"))
@@ -3068,7 +3687,7 @@ impl<'c> Translation<'c> {
                     UnTypeOp::PreferredAlignOf => self.compute_align_of_type(arg_ty.ctype, true)?,
                 };
 
-                Ok(result.map(|x| mk().cast_expr(x, mk().path_ty(vec!["libc", "c_ulong"]))))
+                Ok(result.map(|x| mk().cast_expr(x, self.c_type("c_ulong"))))
             }
 
             CExprKind::ConstantExpr(_ty, child, value) => {
@@ -3101,8 +3720,11 @@ impl<'c> Translation<'c> {
                     .get(&decl_id)
                     .ok_or_else(|| format_err!("name not declared: '{}'", varname))?;
 
+                // Variants of Rust enums import their enum instead
+                let variant_path = self.enum_variant_path(decl_id);
+
                 // Import the referenced global decl into our submodule
-                if self.tcfg.reorganize_definitions {
+                if self.tcfg.reorganize_definitions && variant_path.is_none() {
                     if let Some(cur_file) = self.cur_file.borrow().as_ref() {
                         self.add_import(*cur_file, decl_id, &rustname);
                         // match decl {
@@ -3112,7 +3734,15 @@ impl<'c> Translation<'c> {
                     }
                 }
 
-                let mut val = mk().path_expr(vec![rustname]);
+                let mut val = variant_path.unwrap_or_else(|| mk().path_expr(vec![rustname]));
+
+                // C++ references are pointers that are implicitly dereferenced,
+                // as are the wrappers of lazily initialized statics
+                let mut set_unsafe = false;
+                if self.is_reference_decl(decl_id) || self.lazy_statics.contains(&decl_id) {
+                    val = mk().unary_expr(ast::UnOp::Deref, val);
+                    set_unsafe = true;
+                }
 
                 // If the variable is volatile and used as something that isn't an LValue, this
                 // constitutes a volatile read.
@@ -3125,6 +3755,9 @@ impl<'c> Translation<'c> {
                 // this function assumes `DeclRef`'s to `EnumConstants`'s will translate to casts.
                 if let &CDeclKind::EnumConstant { .. } = decl {
                     let ty = self.convert_type(qual_ty.ctype)?;
+                    if self.flag_enums.contains(&self.ast_context.parents[&decl_id]) {
+                        val = mk().field_expr(val, "0");
+                    }
                     val = mk().cast_expr(val, ty);
                 }
 
@@ -3136,7 +3769,6 @@ impl<'c> Translation<'c> {
 
                 // If we are referring to a function and need its address, we
                 // need to cast it to fn() to ensure that it has a real address.
-                let mut set_unsafe = false;
                 if ctx.needs_address() {
                     if let &CDeclKind::Function { ref parameters, .. } = decl {
                         let ty = self.convert_type(qual_ty.ctype)?;
@@ -3373,6 +4005,14 @@ impl<'c> Translation<'c> {
                     .kind
                     .is_vector()
                 {
+                    // Portable vectors are indexed like arrays
+                    if self.is_portable_vector(lhs_node_type) {
+                        let lhs = self.convert_expr(ctx.used(), *lhs)?;
+                        let rhs = self.convert_expr(ctx.used(), *rhs)?;
+                        return lhs.and_then(|lhs| {
+                            Ok(rhs.map(|rhs| mk().index_expr(lhs, cast_int(rhs, "usize", false))))
+                        });
+                    }
                     return Err(TranslationError::new(
                         self.ast_context.display_loc(src_loc),
                         err_msg("Attempting to index a vector type")
@@ -3382,6 +4022,12 @@ impl<'c> Translation<'c> {
 
                 let rhs = self.convert_expr(ctx.used(), *rhs)?;
                 rhs.and_then(|rhs| {
+                    // Slice parameters are indexed directly, with bounds checks
+                    if let Some(param) = self.slice_param_ref(*lhs) {
+                        let lhs = self.convert_expr(ctx.used(), param)?;
+                        return Ok(lhs.map(|lhs| mk().index_expr(lhs, cast_int(rhs, "usize", false))));
+                    }
+
                     let simple_index_array = if ctx.needs_address() {
                         // We can't necessarily index into an array if we're using
                         // that element to compute an address.
@@ -3474,6 +4120,7 @@ impl<'c> Translation<'c> {
                     Some(CTypeKind::Function(_, _, is_variadic, _, _)) => *is_variadic,
                     _ => false,
                 };
+                let mut callee_params = vec![];
                 let func = match self.ast_context[func].kind {
                     // Direct function call
                     CExprKind::ImplicitCast(_, fexp, CastKind::FunctionToPointerDecay, _, _)
@@ -3484,6 +4131,9 @@ impl<'c> Translation<'c> {
                             _ => false,
                         } =>
                     {
+                        if let CExprKind::DeclRef(_, callee, _) = self.ast_context[fexp].kind {
+                            callee_params = self.pointer_params_of(callee);
+                        }
                         self.convert_expr(ctx.used(), fexp)?
                     }
 
@@ -3545,11 +4195,32 @@ impl<'c> Translation<'c> {
                     // We want to decay refs only when function is variadic
                     ctx.decay_ref = DecayRef::from(is_variadic);
 
-                    let args = self.convert_exprs(ctx.used(), args)?;
+                    let args = args
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &arg)| {
+                            let binds_reference = match fn_ty {
+                                Some(CTypeKind::Function(_, ref params, ..)) => params
+                                    .get(i)
+                                    .map_or(false, |ty| self.is_reference_type(ty.ctype)),
+                                _ => false,
+                            };
+                            if binds_reference {
+                                return self.convert_reference_binding(ctx.used(), arg);
+                            }
+                            self.convert_call_arg(ctx.used(), callee_params.get(i).cloned(), arg)
+                        })
+                        .collect::<Result<WithStmts<Vec<P<Expr>>>, TranslationError>>()?;
 
-                    let res: Result<_, TranslationError> = Ok(
-                        args.map(|args| mk().call_expr(func, args))
-                    );
+                    let returns_reference = self.returns_reference(fn_ty);
+                    let res: Result<_, TranslationError> = Ok(args.map(|args| {
+                        let call = mk().call_expr(func, args);
+                        if returns_reference {
+                            mk().unary_expr(ast::UnOp::Deref, call)
+                        } else {
+                            call
+                        }
+                    }));
                     res
                 })?;
 
@@ -3983,7 +4654,7 @@ impl<'c> Translation<'c> {
                 }
                 let target_ty = self.convert_type(ty.ctype)?;
                 val.and_then(|x| {
-                    let intptr_t = mk().path_ty(vec!["libc", "intptr_t"]);
+                    let intptr_t = self.c_type("intptr_t");
                     let intptr = mk().cast_expr(x, intptr_t.clone());
                     Ok(WithStmts::new_unsafe_val(
                         transmute_expr(intptr_t, target_ty, intptr, self.tcfg.emit_no_std)
@@ -4003,6 +4674,7 @@ impl<'c> Translation<'c> {
                 let source_ty_ctype_id = source_ty.ctype;
 
                 let source_ty = self.convert_type(source_ty_ctype_id)?;
+                let val = val.map(|x| self.flags_to_int(source_ty_ctype_id, x));
                 if let CTypeKind::LongDouble = target_ty_ctype {
                     self.use_crate(ExternCrate::F128);
 
@@ -4013,6 +4685,9 @@ impl<'c> Translation<'c> {
                 } else if let &CTypeKind::Enum(enum_decl_id) = target_ty_ctype {
                     // Casts targeting `enum` types...
                     let expr = expr.ok_or_else(|| format_err!("Casts to enums require a C ExprId"))?;
+                    if self.flag_enums.contains(&enum_decl_id) {
+                        return self.convert_flags_cast(ctx, ty.ctype, enum_decl_id, expr, val);
+                    }
                     Ok(self.enum_cast(ty.ctype, enum_decl_id, expr, val, source_ty, target_ty))
                 } else {
                     // Other numeric casts translate to Rust `as` casts,
@@ -4056,6 +4731,13 @@ impl<'c> Translation<'c> {
                 let expr_kind = expr.map(|e| &self.ast_context.index(e).kind);
                 match expr_kind {
                     Some(&CExprKind::Literal(_, CLiteral::String(ref bytes, 1))) if is_const => {
+                        if !ctx.is_static && !ctx.is_const {
+                            if let Some(lit) = self.c_str_literal(bytes) {
+                                let ptr = mk().method_call_expr(lit, "as_ptr", vec![] as Vec<P<Expr>>);
+                                return Ok(WithStmts::new_val(ptr));
+                            }
+                        }
+
                         let target_ty = self.convert_type(ty.ctype)?;
 
                         let mut bytes = bytes.to_owned();
@@ -4152,6 +4834,11 @@ impl<'c> Translation<'c> {
                 "TODO casts with complex numbers not supported",
             )),
 
+            CastKind::VectorSplat if self.is_portable_vector(ty.ctype) => {
+                self.use_crate(ExternCrate::C2RustSimd);
+                let splat = mk().path_expr(vec!["c2rust_simd", "Vector", "splat"]);
+                Ok(val.map(|x| mk().call_expr(splat, vec![x])))
+            }
             CastKind::VectorSplat => Err(TranslationError::generic(
                 "TODO vector splat casts not supported",
             )),
@@ -4298,7 +4985,11 @@ impl<'c> Translation<'c> {
             Ok(self.implicit_default_expr(inner, is_static)?
                .map(|val| vec_expr(val, count)))
         } else if let &CTypeKind::Vector(CQualTypeId { ctype, .. }, len) = resolved_ty {
-            self.implicit_vector_default(ctype, len, is_static)
+            if self.is_portable_vector(ty_id) {
+                self.portable_vector_default(ctype, len, is_static)
+            } else {
+                self.implicit_vector_default(ctype, len, is_static)
+            }
         } else {
             Err(format_err!("Unsupported default initializer: {:?}", resolved_ty).into())
         }
@@ -4481,10 +5172,12 @@ impl<'c> Translation<'c> {
                 }
             }
 
-            let val = if ty.is_enum() {
-                mk().cast_expr(val, mk().path_ty(vec!["u64"]))
-            } else {
-                val
+            let val = match *ty {
+                CTypeKind::Enum(enum_id) if self.flag_enums.contains(&enum_id) => {
+                    mk().field_expr(val, "0")
+                }
+                CTypeKind::Enum(_) => mk().cast_expr(val, mk().path_ty(vec!["u64"])),
+                _ => val,
             };
 
             // The backup is to just compare against zero
@@ -4638,8 +5331,10 @@ impl<'c> Translation<'c> {
                     self.import_type(param_id.ctype, decl_file_id);
                 }
             }
-            Vector(..) => {
-                // Handled in `import_simd_typedef`
+            Vector(CQualTypeId { ctype, .. }, _) => {
+                // x86 SIMD types are handled in `import_simd_typedef`, other
+                // vectors are portable and need no import
+                self.import_type(*ctype, decl_file_id)
             }
             TypeOfExpr(_) | BuiltinFn => {}
         }
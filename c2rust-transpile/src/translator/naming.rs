@@ -0,0 +1,123 @@
+#![deny(missing_docs)]
+//! This module implements the naming policy for translated declarations.
+//!
+//! By default declarations keep their C names, which the renamer only changes
+//! to avoid collisions and Rust keywords. With `rust_naming`, names follow the
+//! Rust conventions instead: structs, unions, enums and typedefs become
+//! `CamelCase`, functions, local variables and fields `snake_case`, and
+//! statics and constants `SCREAMING_SNAKE_CASE`. A user supplied rename map
+//! overrides the names of individual declarations and fields.
+//!
+//! Functions and statics shared with C keep their symbol names, since
+//! `mk_linkage` adds `#[export_name]` or `#[link_name]` attributes to any of
+//! them whose Rust name differs from the C one.
+
+use std::fs;
+use std::path::Path;
+
+use failure::Error;
+
+use super::*;
+use crate::renamer::{camel_case, screaming_snake_case, snake_case};
+
+/// Renames of C declarations and fields, read from a TOML file such as:
+///
+/// ```toml
+/// [names]
+/// struct_foo_t = "Foo"
+/// foo_init = "init"
+///
+/// [fields.struct_foo_t]
+/// len = "length"
+/// ```
+///
+/// Records are looked up by their tag, or by the typedef naming them when
+/// they have none. Local variables are never renamed by the map.
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct RenameMap {
+    /// Rust names of top-level declarations, by C name
+    #[serde(default)]
+    names: HashMap<String, String>,
+    /// Rust names of fields, by C names of their record and of the field
+    #[serde(default)]
+    fields: HashMap<String, HashMap<String, String>>,
+}
+
+impl RenameMap {
+    /// Reads a rename map from a TOML file
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+}
+
+impl<'c> Translation<'c> {
+    /// The name a declaration named `name` in C should be given in Rust,
+    /// before the renamer resolves collisions
+    pub fn decl_rust_name(&self, decl_id: CDeclId, name: &str) -> String {
+        let kind = &self.ast_context[decl_id].kind;
+        let is_local = match kind {
+            CDeclKind::Variable { .. } => !self.ast_context.c_decls_top.contains(&decl_id),
+            _ => false,
+        };
+        if !is_local {
+            if let Some(rename) = self.tcfg.rename_map.names.get(name) {
+                return rename.clone();
+            }
+        }
+        if !self.tcfg.rust_naming {
+            return name.to_string();
+        }
+
+        match kind {
+            CDeclKind::Struct { .. }
+            | CDeclKind::Union { .. }
+            | CDeclKind::Enum { .. }
+            | CDeclKind::Typedef { .. } => camel_case(name),
+
+            // SIMD functions are imported and keep their names
+            CDeclKind::Function { .. } if name.starts_with("_mm") => name.to_string(),
+            CDeclKind::Function { .. } => snake_case(name),
+
+            // Variants of Rust enums are types rather than constants
+            CDeclKind::EnumConstant { .. } => match self.ast_context.parents.get(&decl_id) {
+                Some(enum_id) if self.rust_enums.contains(enum_id) => camel_case(name),
+                _ => screaming_snake_case(name),
+            },
+
+            CDeclKind::Variable {
+                has_static_duration,
+                has_thread_duration,
+                ..
+            } if *has_static_duration || *has_thread_duration => screaming_snake_case(name),
+            CDeclKind::MacroObject { .. } => screaming_snake_case(name),
+
+            _ => snake_case(name),
+        }
+    }
+
+    /// The name a field named `name` in C of a struct or union should be
+    /// given in Rust, before the renamer resolves collisions
+    pub fn field_rust_name(&self, record_id: CRecordId, name: &str) -> String {
+        let record_name = match self.ast_context[record_id].kind {
+            CDeclKind::Struct { name: Some(ref name), .. }
+            | CDeclKind::Union { name: Some(ref name), .. } => Some(name.as_str()),
+            _ => self
+                .ast_context
+                .prenamed_decls
+                .iter()
+                .find(|&(_, &subdecl_id)| subdecl_id == record_id)
+                .and_then(|(&typedef_id, _)| self.ast_context[typedef_id].kind.get_name())
+                .map(String::as_str),
+        };
+        let rename = record_name
+            .and_then(|record_name| self.tcfg.rename_map.fields.get(record_name))
+            .and_then(|fields| fields.get(name));
+        match rename {
+            Some(rename) => rename.clone(),
+            None if self.tcfg.rust_naming => snake_case(name),
+            None => name.to_string(),
+        }
+    }
+}
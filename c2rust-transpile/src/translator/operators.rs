@@ -68,7 +68,7 @@ impl<'c> Translation<'c> {
                 let lhs = self.convert_condition(ctx, true, lhs)?;
                 let rhs = self.convert_condition(ctx, true, rhs)?;
                 lhs
-                    .map(|x| bool_to_int(mk().binary_expr(BinOpKind::from(op), x, rhs.to_expr())))
+                    .map(|x| self.bool_to_int(mk().binary_expr(BinOpKind::from(op), x, rhs.to_expr())))
                     .and_then(|out| {
                         if ctx.is_unused() {
                             Ok(WithStmts::new(
@@ -203,7 +203,7 @@ impl<'c> Translation<'c> {
 
                 mk().call_expr(fn_path, args)
             } else {
-                mk().cast_expr(read, lhs_type.clone())
+                mk().cast_expr(self.flags_to_int(lhs_ty.ctype, read), lhs_type.clone())
             };
             let ty = self.convert_type(compute_res_ty.ctype)?;
             let val = self.convert_binary_operator(
@@ -222,7 +222,9 @@ impl<'c> Translation<'c> {
                 .kind
                 .is_enum();
             let result_type = self.convert_type(lhs_ty.ctype)?;
-            let val = if is_enum_result {
+            let val = if let Some(enum_id) = self.flag_enum_of(lhs_ty.ctype) {
+                WithStmts::new_val(self.int_to_flags(enum_id, val)?)
+            } else if is_enum_result {
                 if ctx.is_const { self.use_feature("const_transmute"); }
                 WithStmts::new_unsafe_val(transmute_expr(lhs_type, result_type, val, self.tcfg.emit_no_std))
             } else {
@@ -250,6 +252,9 @@ impl<'c> Translation<'c> {
         compute_type: Option<CQualTypeId>,
         result_type: Option<CQualTypeId>,
     ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        if let Some(assignment) = self.convert_flags_assignment(ctx, op, lhs, rhs)? {
+            return Ok(assignment);
+        }
         let rhs_type_id = self
             .ast_context
             .index(rhs)
@@ -389,7 +394,8 @@ impl<'c> Translation<'c> {
                         } else {
                             let lhs_type = self.convert_type(compute_type.unwrap().ctype)?;
                             let write_type = self.convert_type(qtype.ctype)?;
-                            let lhs = mk().cast_expr(read.clone(), lhs_type.clone());
+                            let read = self.flags_to_int(qtype.ctype, read.clone());
+                            let lhs = mk().cast_expr(read, lhs_type.clone());
                             let ty = self.convert_type(result_type_id.ctype)?;
                             let val = self.convert_binary_operator(
                                 ctx,
@@ -408,7 +414,9 @@ impl<'c> Translation<'c> {
                                 .kind
                                 .is_enum();
                             let result_type = self.convert_type(qtype.ctype)?;
-                            let val = if is_enum_result {
+                            let val = if let Some(enum_id) = self.flag_enum_of(qtype.ctype) {
+                                self.int_to_flags(enum_id, val)?
+                            } else if is_enum_result {
                                 is_unsafe = true;
                                 if ctx.is_const { self.use_feature("const_transmute"); }
                                 transmute_expr(lhs_type, result_type, val, self.tcfg.emit_no_std)
@@ -590,6 +598,10 @@ impl<'c> Translation<'c> {
         rhs: P<Expr>,
         lhs_rhs_ids: Option<(CExprId, CExprId)>,
     ) -> Result<P<Expr>, TranslationError> {
+        if self.is_portable_vector(lhs_type.ctype) {
+            return Ok(self.convert_vector_binary_operator(op, lhs, rhs));
+        }
+
         let is_unsigned_integral_type = self
             .ast_context
             .index(ctype)
@@ -610,7 +622,9 @@ impl<'c> Translation<'c> {
             }
             c_ast::BinOp::Multiply => Ok(mk().binary_expr(BinOpKind::Mul, lhs, rhs)),
 
-            c_ast::BinOp::Divide if is_unsigned_integral_type => {
+            // `wrapping_div` and `wrapping_rem` aren't const fns yet, but they
+            // don't differ from `/` and `%` on unsigned integers
+            c_ast::BinOp::Divide if is_unsigned_integral_type && !ctx.is_static => {
                 if ctx.is_const {
                     return Err(TranslationError::generic(
                         "Cannot use wrapping division in a const expression",
@@ -620,7 +634,7 @@ impl<'c> Translation<'c> {
             }
             c_ast::BinOp::Divide => Ok(mk().binary_expr(BinOpKind::Div, lhs, rhs)),
 
-            c_ast::BinOp::Modulus if is_unsigned_integral_type => {
+            c_ast::BinOp::Modulus if is_unsigned_integral_type && !ctx.is_static => {
                 if ctx.is_const {
                     return Err(TranslationError::generic(
                         "Cannot use wrapping remainder in a const expression",
@@ -655,7 +669,7 @@ impl<'c> Translation<'c> {
                     mk().binary_expr(BinOpKind::Eq, lhs, rhs)
                 };
 
-                Ok(bool_to_int(expr))
+                Ok(self.bool_to_int(expr))
             }
             c_ast::BinOp::NotEqual => {
                 // Using is_some method for null comparison means we don't have to
@@ -677,12 +691,12 @@ impl<'c> Translation<'c> {
                     mk().binary_expr(BinOpKind::Ne, lhs, rhs)
                 };
 
-                Ok(bool_to_int(expr))
+                Ok(self.bool_to_int(expr))
             }
-            c_ast::BinOp::Less => Ok(bool_to_int(mk().binary_expr(BinOpKind::Lt, lhs, rhs))),
-            c_ast::BinOp::Greater => Ok(bool_to_int(mk().binary_expr(BinOpKind::Gt, lhs, rhs))),
-            c_ast::BinOp::GreaterEqual => Ok(bool_to_int(mk().binary_expr(BinOpKind::Ge, lhs, rhs))),
-            c_ast::BinOp::LessEqual => Ok(bool_to_int(mk().binary_expr(BinOpKind::Le, lhs, rhs))),
+            c_ast::BinOp::Less => Ok(self.bool_to_int(mk().binary_expr(BinOpKind::Lt, lhs, rhs))),
+            c_ast::BinOp::Greater => Ok(self.bool_to_int(mk().binary_expr(BinOpKind::Gt, lhs, rhs))),
+            c_ast::BinOp::GreaterEqual => Ok(self.bool_to_int(mk().binary_expr(BinOpKind::Ge, lhs, rhs))),
+            c_ast::BinOp::LessEqual => Ok(self.bool_to_int(mk().binary_expr(BinOpKind::Le, lhs, rhs))),
 
             c_ast::BinOp::BitAnd => Ok(mk().binary_expr(BinOpKind::BitAnd, lhs, rhs)),
             c_ast::BinOp::BitOr => Ok(mk().binary_expr(BinOpKind::BitOr, lhs, rhs)),
@@ -1031,7 +1045,7 @@ impl<'c> Translation<'c> {
 
             c_ast::UnOp::Not => {
                 let val = self.convert_condition(ctx, false, arg)?;
-                Ok(val.map(|x| mk().cast_expr(x, mk().path_ty(vec!["libc", "c_int"]))))
+                Ok(val.map(|x| mk().cast_expr(x, self.c_type("c_int"))))
             }
             c_ast::UnOp::Extension => {
                 let arg = self.convert_expr(ctx, arg)?;
@@ -0,0 +1,469 @@
+#![deny(missing_docs)]
+//! This module implements the opt-in translation of `restrict`-qualified
+//! pointer parameters into Rust references (`&T` or `&mut T`).
+//!
+//! The analysis is deliberately conservative and runs over the whole
+//! translation unit before any code is emitted. A parameter is only turned
+//! into a reference when
+//!
+//!   * its function is `static`, prototyped, not variadic and only ever called
+//!     directly, so that we can see (and adjust) every call site;
+//!   * the body only uses the parameter through `*p` and `p->field`, so it is
+//!     never offset, compared, stored or passed on;
+//!   * every call site passes the address of a variable (or of a field of one),
+//!     which cannot be null, and no other argument of that call mentions the
+//!     same variable.
+//!
+//! Every other `restrict` parameter keeps its raw pointer type.
+
+use super::*;
+use crate::diagnostics::Diagnostic;
+use std::collections::HashSet;
+
+impl<'c> Translation<'c> {
+    /// Compute the set of `restrict` pointer parameters that can be translated
+    /// into references. Parameters that were considered but rejected are
+    /// reported through the `idioms` diagnostic.
+    pub fn compute_ref_params(&self) -> IndexSet<CParamId> {
+        // All `restrict` pointer parameters, mapped to their function
+        let mut candidates: IndexMap<CParamId, CDeclId> = IndexMap::new();
+        let mut rejected: IndexMap<CParamId, String> = IndexMap::new();
+
+        for &decl_id in &self.ast_context.c_decls_top {
+            let (is_global, typ, parameters) = match self.ast_context[decl_id].kind {
+                CDeclKind::Function {
+                    is_global,
+                    typ,
+                    ref parameters,
+                    body: Some(_),
+                    ..
+                } => (is_global, typ, parameters),
+                _ => continue,
+            };
+            let fn_error = self.pointer_params_fn_error(is_global, typ);
+
+            for &param_id in parameters {
+                let typ = match self.ast_context[param_id].kind {
+                    CDeclKind::Variable { typ, .. } if typ.qualifiers.is_restrict => typ,
+                    _ => continue,
+                };
+                candidates.insert(param_id, decl_id);
+                if let Some(err) = fn_error.or_else(|| self.pointer_param_type_error(typ)) {
+                    rejected.insert(param_id, err.to_string());
+                }
+            }
+        }
+
+        let functions: IndexSet<CDeclId> = candidates
+            .iter()
+            .filter(|&(param_id, _)| !rejected.contains_key(param_id))
+            .map(|(_, &fn_id)| fn_id)
+            .collect();
+        if functions.is_empty() {
+            self.report_rejected_params(&candidates, &rejected, "a reference");
+            return IndexSet::new();
+        }
+
+        // Check that the parameters are only ever dereferenced in the body
+        for &fn_id in &functions {
+            let body = match self.ast_context[fn_id].kind {
+                CDeclKind::Function {
+                    body: Some(body), ..
+                } => body,
+                _ => continue,
+            };
+
+            let mut derefs: HashSet<CExprId> = HashSet::new();
+            for node in DFExpr::new(&self.ast_context, body.into()) {
+                let expr_id = match node {
+                    SomeId::Expr(expr_id) => expr_id,
+                    _ => continue,
+                };
+                match self.ast_context[expr_id].kind {
+                    CExprKind::Unary(_, c_ast::UnOp::Deref, ptr, _)
+                    | CExprKind::Member(_, ptr, _, MemberKind::Arrow, _) => {
+                        if let Some((declref, _)) = self.deref_param(ptr) {
+                            derefs.insert(declref);
+                        }
+                    }
+                    // `&*p` is just `p` again, so it would escape
+                    CExprKind::Unary(_, c_ast::UnOp::AddressOf, arg, _) => {
+                        if let CExprKind::Unary(_, c_ast::UnOp::Deref, ptr, _) =
+                            self.ast_context[arg].kind
+                        {
+                            if let Some((_, param_id)) = self.deref_param(ptr) {
+                                if candidates.contains_key(&param_id) {
+                                    rejected.entry(param_id).or_insert_with(|| {
+                                        "its address is re-taken with `&*`".to_string()
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    CExprKind::DeclRef(_, param_id, _)
+                        if candidates.contains_key(&param_id) && !derefs.contains(&expr_id) =>
+                    {
+                        rejected.entry(param_id).or_insert_with(|| {
+                            "it is used other than through `*` or `->`".to_string()
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let (calls, address_taken) = self.direct_calls(&functions);
+
+        for (&param_id, fn_id) in &candidates {
+            if address_taken.contains(fn_id) {
+                rejected
+                    .entry(param_id)
+                    .or_insert_with(|| "the address of the function is taken".to_string());
+            }
+        }
+
+        // Check that every call site passes a non-null, non-aliased reference
+        for (callee, call) in calls {
+            let args = match self.ast_context[call].kind {
+                CExprKind::Call(_, _, ref args) => args,
+                _ => continue,
+            };
+            let parameters = match self.ast_context[callee].kind {
+                CDeclKind::Function { ref parameters, .. } => parameters,
+                _ => continue,
+            };
+
+            let mut roots = vec![];
+            for (&param_id, &arg) in parameters.iter().zip(args.iter()) {
+                if !candidates.contains_key(&param_id) || rejected.contains_key(&param_id) {
+                    continue;
+                }
+                match self.ref_arg_root(arg) {
+                    Some(root) => roots.push((param_id, arg, root)),
+                    None => {
+                        let loc = self.ast_context.display_loc(&self.ast_context[arg].loc);
+                        let reason = match loc {
+                            Some(loc) => format!("the call at {} may pass a null pointer", loc),
+                            None => "a call site may pass a null pointer".to_string(),
+                        };
+                        rejected.insert(param_id, reason);
+                    }
+                }
+            }
+
+            for (param_id, arg, root) in roots {
+                let aliased = args
+                    .iter()
+                    .any(|&other| other != arg && self.expr_mentions_decl(other, root));
+                if aliased {
+                    rejected
+                        .entry(param_id)
+                        .or_insert_with(|| "a call site passes aliasing arguments".to_string());
+                }
+            }
+        }
+
+        self.report_rejected_params(&candidates, &rejected, "a reference");
+
+        candidates
+            .keys()
+            .filter(|param_id| !rejected.contains_key(*param_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Is the given parameter translated into a reference?
+    pub fn is_ref_param(&self, param_id: CParamId) -> bool {
+        self.ref_params.contains(&param_id)
+    }
+
+    /// The parameters of the given function, if any of the pointer parameters
+    /// of the translation unit are translated into references, slices or
+    /// `&CStr`.
+    pub fn pointer_params_of(&self, fn_id: CDeclId) -> Vec<CParamId> {
+        if self.ref_params.is_empty()
+            && self.slice_params.is_empty()
+            && self.c_str_params.is_empty()
+        {
+            return vec![];
+        }
+        match self.ast_context[self.canonical_decl(fn_id)].kind {
+            CDeclKind::Function { ref parameters, .. } => parameters.clone(),
+            _ => vec![],
+        }
+    }
+
+    /// Convert the type of a parameter translated into a reference. `&T` is
+    /// used for `const` pointees and `&mut T` for everything else.
+    pub fn convert_ref_param_type(&self, typ: CQualTypeId) -> Result<P<Ty>, TranslationError> {
+        let pointee = self
+            .ast_context
+            .get_pointee_qual_type(typ.ctype)
+            .ok_or_else(|| format_err!("reference parameter is not a pointer"))?;
+        let ty = self.convert_type(pointee.ctype)?;
+        let mutbl = if pointee.qualifiers.is_const {
+            Mutability::Immutable
+        } else {
+            Mutability::Mutable
+        };
+        Ok(mk().set_mutbl(mutbl).ref_ty(ty))
+    }
+
+    /// Convert a call argument passed to a parameter translated into a
+    /// reference. The analysis guarantees that the argument is of the form
+    /// `&lvalue`, which we borrow directly instead of going through a raw
+    /// pointer.
+    pub fn convert_ref_arg(
+        &self,
+        ctx: ExprContext,
+        param_id: CParamId,
+        arg: CExprId,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        let lvalue = match self.ast_context[arg].kind {
+            CExprKind::Unary(_, c_ast::UnOp::AddressOf, lvalue, _) => lvalue,
+            CExprKind::ImplicitCast(_, arg, CastKind::NoOp, _, _) => {
+                return self.convert_ref_arg(ctx, param_id, arg)
+            }
+            _ => {
+                return Err(format_translation_err!(
+                    self.ast_context.display_loc(&self.ast_context[arg].loc),
+                    "Unexpected argument for reference parameter",
+                ))
+            }
+        };
+
+        let mutbl = match self.ast_context[param_id].kind {
+            CDeclKind::Variable { typ, .. } => {
+                match self.ast_context.get_pointee_qual_type(typ.ctype) {
+                    Some(pointee) if pointee.qualifiers.is_const => Mutability::Immutable,
+                    _ => Mutability::Mutable,
+                }
+            }
+            _ => Mutability::Mutable,
+        };
+
+        let lvalue = self.convert_expr(ctx.used().set_needs_address(true), lvalue)?;
+        Ok(lvalue.map(|lv| mk().set_mutbl(mutbl).addr_of_expr(lv)))
+    }
+
+    /// Explain why the pointer parameters of a function cannot be changed, if
+    /// they can't. We need to see every call site of the function.
+    pub fn pointer_params_fn_error(
+        &self,
+        is_global: bool,
+        typ: CFuncTypeId,
+    ) -> Option<&'static str> {
+        if is_global {
+            return Some("the function is externally visible");
+        }
+        match self.ast_context.resolve_type(typ).kind {
+            CTypeKind::Function(_, _, false, _, true) => None,
+            _ => Some("the function is variadic or has no prototype"),
+        }
+    }
+
+    /// Explain why a pointer parameter of the given type cannot become a
+    /// reference or a slice, if it can't.
+    pub fn pointer_param_type_error(&self, typ: CQualTypeId) -> Option<&'static str> {
+        let pointee = match self.ast_context.resolve_type(typ.ctype).kind {
+            CTypeKind::Pointer(pointee) => pointee,
+            _ => return Some("it is not a pointer"),
+        };
+        if pointee.qualifiers.is_volatile {
+            return Some("its pointee is volatile");
+        }
+        if self.ast_context.is_va_list(typ.ctype) {
+            return Some("it is a `va_list`");
+        }
+        match self.ast_context.resolve_type(pointee.ctype).kind {
+            CTypeKind::Void => Some("it is a void pointer"),
+            CTypeKind::Function(..) => Some("it is a function pointer"),
+            CTypeKind::ConstantArray(..)
+            | CTypeKind::IncompleteArray(..)
+            | CTypeKind::VariableArray(..) => Some("it points to an array"),
+            _ => None,
+        }
+    }
+
+    /// If the expression reads a parameter or variable, return the id of the
+    /// `DeclRef` expression and of the referenced declaration.
+    pub fn deref_param(&self, ptr: CExprId) -> Option<(CExprId, CDeclId)> {
+        match self.ast_context[ptr].kind {
+            CExprKind::ImplicitCast(_, declref, CastKind::LValueToRValue, _, _) => {
+                match self.ast_context[declref].kind {
+                    CExprKind::DeclRef(_, decl_id, _) => Some((declref, decl_id)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// If the argument takes the address of a variable, or of a (nested)
+    /// field of a variable, return that variable.
+    fn ref_arg_root(&self, arg: CExprId) -> Option<CDeclId> {
+        let mut lvalue = match self.ast_context[arg].kind {
+            CExprKind::Unary(_, c_ast::UnOp::AddressOf, lvalue, _) => lvalue,
+            CExprKind::ImplicitCast(_, arg, CastKind::NoOp, _, _) => return self.ref_arg_root(arg),
+            _ => return None,
+        };
+        loop {
+            match self.ast_context[lvalue].kind {
+                CExprKind::Member(_, base, _, MemberKind::Dot, _) => lvalue = base,
+                CExprKind::DeclRef(_, decl_id, _) => {
+                    return match self.ast_context[decl_id].kind {
+                        CDeclKind::Variable { .. } => Some(decl_id),
+                        _ => None,
+                    }
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    /// Does the expression refer to the given declaration anywhere?
+    pub fn expr_mentions_decl(&self, expr_id: CExprId, decl_id: CDeclId) -> bool {
+        DFExpr::new(&self.ast_context, expr_id.into()).any(|node| match node {
+            SomeId::Expr(e) => match self.ast_context[e].kind {
+                CExprKind::DeclRef(_, d, _) => d == decl_id,
+                _ => false,
+            },
+            _ => false,
+        })
+    }
+
+    /// The canonical declaration of a (possibly redeclared) function.
+    pub fn canonical_decl(&self, decl_id: CDeclId) -> CDeclId {
+        match self.ast_context.get_decl(&decl_id).map(|d| &d.kind) {
+            Some(&CDeclKind::NonCanonicalDecl { canonical_decl }) => canonical_decl,
+            _ => decl_id,
+        }
+    }
+
+    /// Collect the direct calls to the given functions (as pairs of callee
+    /// and call expression) and the functions that are used in any other way,
+    /// which lets them escape to unknown callers.
+    pub fn direct_calls(
+        &self,
+        functions: &IndexSet<CDeclId>,
+    ) -> (Vec<(CDeclId, CExprId)>, IndexSet<CDeclId>) {
+        let mut callees: HashSet<CExprId> = HashSet::new();
+        let mut address_taken: IndexSet<CDeclId> = IndexSet::new();
+        let mut calls = vec![];
+        for root in self.pointer_params_scan_roots() {
+            for node in DFExpr::new(&self.ast_context, root) {
+                let expr_id = match node {
+                    SomeId::Expr(expr_id) => expr_id,
+                    _ => continue,
+                };
+                match self.ast_context[expr_id].kind {
+                    CExprKind::Call(_, func, _) => {
+                        if let Some((fexp, callee)) = self.direct_callee(func) {
+                            callees.insert(fexp);
+                            if functions.contains(&callee) {
+                                calls.push((callee, expr_id));
+                            }
+                        }
+                    }
+                    CExprKind::DeclRef(_, decl_id, _) if !callees.contains(&expr_id) => {
+                        let decl_id = self.canonical_decl(decl_id);
+                        if functions.contains(&decl_id) {
+                            address_taken.insert(decl_id);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        (calls, address_taken)
+    }
+
+    /// If the callee of a call is a function named directly, return the
+    /// `DeclRef` expression and the canonical function declaration.
+    pub fn direct_callee(&self, func: CExprId) -> Option<(CExprId, CDeclId)> {
+        match self.ast_context[func].kind {
+            CExprKind::ImplicitCast(_, fexp, CastKind::FunctionToPointerDecay, _, _) => {
+                match self.ast_context[fexp].kind {
+                    CExprKind::DeclRef(_, callee, _) => Some((fexp, self.canonical_decl(callee))),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// All function bodies and global initializers of the translation unit.
+    fn pointer_params_scan_roots(&self) -> Vec<SomeId> {
+        self.ast_context
+            .c_decls_top
+            .iter()
+            .filter_map(|&decl_id| match self.ast_context[decl_id].kind {
+                CDeclKind::Function {
+                    body: Some(body), ..
+                } => Some(body.into()),
+                CDeclKind::Variable {
+                    initializer: Some(init),
+                    ..
+                } => Some(init.into()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Reject the parameters an argument is passed on from or to, given as
+    /// `(source, param)` pairs, when the other end is rejected: both have to
+    /// keep the same type. Repeats until no more parameters get rejected.
+    pub fn reject_forwarded_params(
+        &self,
+        forwards: &[(CParamId, CParamId)],
+        rejected: &mut IndexMap<CParamId, String>,
+    ) {
+        loop {
+            let mut changed = false;
+            for &(source, param_id) in forwards {
+                let (from, to) = match (
+                    rejected.contains_key(&source),
+                    rejected.contains_key(&param_id),
+                ) {
+                    (true, false) => (source, param_id),
+                    (false, true) => (param_id, source),
+                    _ => continue,
+                };
+                let from = self.ast_context[from]
+                    .kind
+                    .get_name()
+                    .map_or("", String::as_str);
+                rejected.insert(
+                    to,
+                    format!("it is passed on from or to `{}`, which is kept", from),
+                );
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Report the rejected pointer parameters through the `idioms` diagnostic.
+    /// `instead_of` names the type they would otherwise have had.
+    pub fn report_rejected_params(
+        &self,
+        candidates: &IndexMap<CParamId, CDeclId>,
+        rejected: &IndexMap<CParamId, String>,
+        instead_of: &str,
+    ) {
+        for (param_id, reason) in rejected {
+            let param = self.ast_context[*param_id].kind.get_name();
+            let function = self.ast_context[candidates[param_id]].kind.get_name();
+            diag!(
+                Diagnostic::Idioms,
+                "keeping parameter `{}` of `{}` as a raw pointer instead of {}: {}",
+                param.map_or("", String::as_str),
+                function.map_or("", String::as_str),
+                instead_of,
+                reason,
+            );
+        }
+    }
+}
@@ -1,5 +1,11 @@
 #![deny(missing_docs)]
 //! This module provides translation for SIMD operations and expressions.
+//!
+//! Vector types named by the x86 SIMD typedefs, and the intrinsics operating
+//! on them, are imported from `std::arch`. Other vector types, declared with
+//! the GCC vector extensions, are translated to the portable vectors of the
+//! `c2rust-simd` crate, which implement the C operators lane by lane and so
+//! translate on every architecture.
 
 use super::*;
 
@@ -67,77 +73,110 @@ static SIMD_X86_64_ONLY: &[&str] = &[
     "_mm_crc32_u64",
 ];
 
+/// Public API SIMD typedefs, imported from `std::arch`
+static SIMD_TYPEDEFS: [&str; 7] = [
+    "__m128i", "__m128", "__m128d", "__m64", "__m256", "__m256d", "__m256i",
+];
+
+/// These seem to be C internal types only, and shouldn't need any explicit support.
+/// See https://internals.rust-lang.org/t/getting-explicit-simd-on-stable-rust/4380/115
+static SIMD_INTERNAL_TYPEDEFS: [&str; 34] = [
+    "__v1di",
+    "__v2si",
+    "__v4hi",
+    "__v8qi",
+    "__v4si",
+    "__v4sf",
+    "__v4su",
+    "__v2df",
+    "__v2di",
+    "__v8hi",
+    "__v16qi",
+    "__v2du",
+    "__v8hu",
+    "__v16qu",
+    "__v32qu",
+    "__v4df",
+    "__v8sf",
+    "__v4di",
+    "__v8si",
+    "__v16hi",
+    "__v32qi",
+    "__v4du",
+    "__v8di_aligned",
+    "__v8df_aligned",
+    "__v16sf_aligned",
+    "__v8sf_aligned",
+    "__v4df_aligned",
+    "__v4di_aligned",
+    "__v16qs",
+    "__v32qs",
+    "__v8su",
+    "__v16hu",
+    "__mm_loadh_pi_v2f32",
+    "__mm_loadl_pi_v2f32",
+];
+
 impl<'c> Translation<'c> {
     /// Given the name of a typedef check if its one of the SIMD types.
     /// This function returns `true` when the name of the type is one that
     /// it knows how to implement and no further translation should be done.
     pub fn import_simd_typedef(&self, name: &str) -> bool {
-        match name {
-            // Public API SIMD typedefs:
-            "__m128i" | "__m128" | "__m128d" | "__m64" | "__m256" | "__m256d" | "__m256i" => {
-                // __m64 is still behind a feature gate
-                if name == "__m64" {
-                    self.use_feature("stdsimd");
-                }
+        if SIMD_TYPEDEFS.contains(&name) {
+            // __m64 is still behind a feature gate
+            if name == "__m64" {
+                self.use_feature("stdsimd");
+            }
 
-                self.with_cur_file_item_store(|item_store| {
-                    let x86_attr = mk().call_attr("cfg", vec!["target_arch = \"x86\""]).pub_();
-                    let x86_64_attr = mk()
-                        .call_attr("cfg", vec!["target_arch = \"x86_64\""])
-                        .pub_();
-                    let std_or_core = if self.tcfg.emit_no_std { "core" } else { "std" }.to_string();
+            self.with_cur_file_item_store(|item_store| {
+                let x86_attr = mk().call_attr("cfg", vec!["target_arch = \"x86\""]).pub_();
+                let x86_64_attr = mk()
+                    .call_attr("cfg", vec!["target_arch = \"x86_64\""])
+                    .pub_();
+                let std_or_core = if self.tcfg.emit_no_std { "core" } else { "std" }.to_string();
 
-                    item_store.add_use_with_attr(
-                        vec![std_or_core.clone(), "arch".into(), "x86".into()],
-                        name,
-                        x86_attr,
-                    );
-                    item_store.add_use_with_attr(
-                        vec![std_or_core, "arch".into(), "x86_64".into()],
-                        name,
-                        x86_64_attr,
-                    );
-                });
+                item_store.add_use_with_attr(
+                    vec![std_or_core.clone(), "arch".into(), "x86".into()],
+                    name,
+                    x86_attr,
+                );
+                item_store.add_use_with_attr(
+                    vec![std_or_core, "arch".into(), "x86_64".into()],
+                    name,
+                    x86_64_attr,
+                );
+            });
 
-                true
+            true
+        } else {
+            SIMD_INTERNAL_TYPEDEFS.contains(&name)
+        }
+    }
+
+    /// Determine whether a vector type is translated to a portable
+    /// `c2rust_simd::Vector` rather than to an x86 SIMD type, which is the case
+    /// unless it is named by one of the x86 SIMD typedefs.
+    pub fn is_portable_vector(&self, mut ctype: CTypeId) -> bool {
+        loop {
+            match self.ast_context[ctype].kind {
+                CTypeKind::Typedef(decl_id) => match self.ast_context[decl_id].kind {
+                    CDeclKind::Typedef { ref name, typ, .. } => {
+                        if SIMD_TYPEDEFS.contains(&name.as_str())
+                            || SIMD_INTERNAL_TYPEDEFS.contains(&name.as_str())
+                        {
+                            return false;
+                        }
+                        ctype = typ.ctype;
+                    }
+                    _ => return false,
+                },
+                CTypeKind::Elaborated(ty) | CTypeKind::Paren(ty) | CTypeKind::TypeOf(ty) => {
+                    ctype = ty
+                }
+                CTypeKind::Attributed(ty, _) => ctype = ty.ctype,
+                CTypeKind::Vector(..) => return true,
+                _ => return false,
             }
-            // These seem to be C internal types only, and shouldn't need any explicit support.
-            // See https://internals.rust-lang.org/t/getting-explicit-simd-on-stable-rust/4380/115
-            "__v1di"
-            | "__v2si"
-            | "__v4hi"
-            | "__v8qi"
-            | "__v4si"
-            | "__v4sf"
-            | "__v4su"
-            | "__v2df"
-            | "__v2di"
-            | "__v8hi"
-            | "__v16qi"
-            | "__v2du"
-            | "__v8hu"
-            | "__v16qu"
-            | "__v32qu"
-            | "__v4df"
-            | "__v8sf"
-            | "__v4di"
-            | "__v8si"
-            | "__v16hi"
-            | "__v32qi"
-            | "__v4du"
-            | "__v8di_aligned"
-            | "__v8df_aligned"
-            | "__v16sf_aligned"
-            | "__v8sf_aligned"
-            | "__v4df_aligned"
-            | "__v4di_aligned"
-            | "__v16qs"
-            | "__v32qs"
-            | "__v8su"
-            | "__v16hu"
-            | "__mm_loadh_pi_v2f32"
-            | "__mm_loadl_pi_v2f32" => true,
-            _ => false,
         }
     }
 
@@ -356,6 +395,142 @@ impl<'c> Translation<'c> {
         })
     }
 
+    /// Build a portable vector from an array of its lanes, of type `[ty; len]`.
+    /// Static initializers can't call `Vector::new`, which isn't a const fn,
+    /// so they transmute the array as they do for x86 vectors.
+    fn portable_vector_expr(
+        &self,
+        ctype: CTypeId,
+        len: usize,
+        lanes: P<Expr>,
+        is_static: bool,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        self.use_crate(ExternCrate::C2RustSimd);
+        if is_static {
+            self.use_feature("const_transmute");
+
+            let ty = self.convert_type(ctype)?;
+            let lanes_ty = mk().array_ty(ty, mk().lit_expr(mk().int_lit(len as u128, "")));
+            let vector_ty = mk().path_ty(vec![
+                mk().path_segment("c2rust_simd"),
+                mk().path_segment_with_args(
+                    "Vector",
+                    mk().angle_bracketed_args(vec![lanes_ty.clone()]),
+                ),
+            ]);
+            Ok(WithStmts::new_unsafe_val(transmute_expr(
+                lanes_ty,
+                vector_ty,
+                lanes,
+                self.tcfg.emit_no_std,
+            )))
+        } else {
+            let new = mk().path_expr(vec!["c2rust_simd", "Vector", "new"]);
+            Ok(WithStmts::new_val(mk().call_expr(new, vec![lanes])))
+        }
+    }
+
+    /// Generate a zero value for a portable vector type, given its element
+    /// type and number of elements.
+    pub fn portable_vector_default(
+        &self,
+        ctype: CTypeId,
+        len: usize,
+        is_static: bool,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        self.implicit_default_expr(ctype, is_static)?.and_then(|zero| {
+            let lanes = mk().repeat_expr(zero, mk().lit_expr(mk().int_lit(len as u128, "")));
+            self.portable_vector_expr(ctype, len, lanes, is_static)
+        })
+    }
+
+    /// Translate a list initializer of a portable vector type. Lanes without
+    /// an initializer are zero.
+    pub fn portable_vector_list_initializer(
+        &self,
+        ctx: ExprContext,
+        ids: &[CExprId],
+        ctype: CTypeId,
+        len: usize,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        let lanes = self.convert_exprs(ctx.used(), ids)?;
+        let zero = self.implicit_default_expr(ctype, ctx.is_static)?;
+        lanes.and_then(|mut lanes| {
+            zero.and_then(|zero| {
+                lanes.resize(len, zero);
+                self.portable_vector_expr(ctype, len, mk().array_expr(lanes), ctx.is_static)
+            })?
+            .and_then(|vector| {
+                if ctx.is_used() {
+                    Ok(WithStmts::new_val(vector))
+                } else {
+                    Ok(WithStmts::new(
+                        vec![mk().expr_stmt(vector)],
+                        self.panic_or_err("No value for unused vector initializer"),
+                    ))
+                }
+            })
+        })
+    }
+
+    /// Translate a non-assignment binary operator on portable vectors. The
+    /// vectors implement the arithmetic and bitwise operators, comparisons
+    /// produce masks through their `simd_*` methods.
+    pub fn convert_vector_binary_operator(
+        &self,
+        op: c_ast::BinOp,
+        lhs: P<Expr>,
+        rhs: P<Expr>,
+    ) -> P<Expr> {
+        let method = match op {
+            c_ast::BinOp::EqualEqual => "simd_eq",
+            c_ast::BinOp::NotEqual => "simd_ne",
+            c_ast::BinOp::Less => "simd_lt",
+            c_ast::BinOp::LessEqual => "simd_le",
+            c_ast::BinOp::Greater => "simd_gt",
+            c_ast::BinOp::GreaterEqual => "simd_ge",
+            op => return mk().binary_expr(BinOpKind::from(op), lhs, rhs),
+        };
+        mk().method_call_expr(lhs, method, vec![rhs])
+    }
+
+    /// Convert a `__builtin_shufflevector` call on portable vectors, which
+    /// selects any number of lanes of its two vector arguments by constant
+    /// indices, into a call to `Vector::shuffle`.
+    fn convert_portable_shuffle_vector(
+        &self,
+        ctx: ExprContext,
+        child_expr_ids: &[CExprId],
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        let indices = child_expr_ids[2..]
+            .iter()
+            .map(|&expr_id| {
+                let index = self
+                    .eval_const_int(expr_id)
+                    .ok_or_else(|| format_err!("Shuffle vector index is not a constant"))?;
+                // An index of -1 leaves the lane undefined
+                Ok(mk().lit_expr(mk().int_lit(index.max(0) as u128, "usize")))
+            })
+            .collect::<Result<Vec<_>, TranslationError>>()?;
+
+        let param_translation = self.convert_exprs(ctx.used(), &child_expr_ids[..2])?;
+        param_translation.and_then(|params| {
+            let mut params = params.into_iter();
+            let first = params.next().ok_or("Missing first param in convert_shuffle_vector")?;
+            let second = params.next().ok_or("Missing second param in convert_shuffle_vector")?;
+            let call = mk().method_call_expr(first, "shuffle", vec![second, mk().array_expr(indices)]);
+
+            if ctx.is_used() {
+                Ok(WithStmts::new_val(call))
+            } else {
+                Ok(WithStmts::new(
+                    vec![mk().expr_stmt(call)],
+                    self.panic_or_err("No value for unused shuffle vector return"),
+                ))
+            }
+        })
+    }
+
     /// Convert a shuffle operation into the equivalent Rust SIMD library calls.
     ///
     /// Because clang implements some shuffle operations as macros around intrinsic
@@ -366,6 +541,14 @@ impl<'c> Translation<'c> {
         ctx: ExprContext,
         child_expr_ids: &[CExprId],
     ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        let first_ty = self.ast_context[child_expr_ids[0]]
+            .kind
+            .get_type()
+            .ok_or_else(|| format_err!("bad shuffle vector type"))?;
+        if self.is_portable_vector(first_ty) {
+            return self.convert_portable_shuffle_vector(ctx, child_expr_ids);
+        }
+
         // There are three shuffle vector functions which are actually functions, not superbuiltins/macros,
         // which do not need to be handled here: _mm_shuffle_pi8, _mm_shuffle_epi8, _mm256_shuffle_epi8
 
@@ -0,0 +1,500 @@
+#![deny(missing_docs)]
+//! This module implements the opt-in translation of pointer parameters that
+//! are paired with a length, as in `f(int *buf, size_t n)`, into Rust slices
+//! (`&[T]` or `&mut [T]`).
+//!
+//! Like the translation of `restrict` parameters into references, the
+//! analysis runs over the whole translation unit and is conservative. A
+//! parameter becomes a slice only when
+//!
+//!   * its function is `static`, prototyped, not variadic and only ever called
+//!     directly;
+//!   * it is immediately followed by an integer (length) parameter, which the
+//!     body never modifies;
+//!   * the body only indexes it (`buf[i]`, not `i[buf]`) with the counter of
+//!     an enclosing `for (i = 0; i < n; i++)` loop bounded by its length, or
+//!     passes it on to another slice parameter, and never takes the address
+//!     of one of its elements;
+//!   * every call site passes either a local array and a constant length that
+//!     does not exceed its size, or a slice parameter of the caller and its
+//!     length, and no other argument of that call mentions the same variable.
+//!
+//! Every index is then within the slice, so the bounds checks Rust adds to
+//! indexing cannot panic where the C code was well-defined.
+
+use super::*;
+use std::collections::HashSet;
+
+impl<'c> Translation<'c> {
+    /// Compute the set of pointer parameters that can be translated into
+    /// slices. Parameters that look like a buffer and a length but were
+    /// rejected are reported through the `idioms` diagnostic.
+    pub fn compute_slice_params(&self) -> IndexSet<CParamId> {
+        // All pointer parameters followed by a length, mapped to their function
+        let mut candidates: IndexMap<CParamId, CDeclId> = IndexMap::new();
+        let mut lengths: IndexMap<CParamId, CParamId> = IndexMap::new();
+        let mut rejected: IndexMap<CParamId, String> = IndexMap::new();
+
+        for &decl_id in &self.ast_context.c_decls_top {
+            let (is_global, typ, parameters) = match self.ast_context[decl_id].kind {
+                CDeclKind::Function {
+                    is_global,
+                    typ,
+                    ref parameters,
+                    body: Some(_),
+                    ..
+                } => (is_global, typ, parameters),
+                _ => continue,
+            };
+            let fn_error = self.pointer_params_fn_error(is_global, typ);
+
+            for pair in parameters.windows(2) {
+                let (param_id, len_id) = (pair[0], pair[1]);
+                let typ = match self.ast_context[param_id].kind {
+                    CDeclKind::Variable { typ, .. } if self.is_pointer_type(typ) => typ,
+                    _ => continue,
+                };
+                match self.ast_context[len_id].kind {
+                    CDeclKind::Variable { typ: len_typ, .. }
+                        if self
+                            .ast_context
+                            .resolve_type(len_typ.ctype)
+                            .kind
+                            .is_integral_type() => {}
+                    _ => continue,
+                }
+                candidates.insert(param_id, decl_id);
+                lengths.insert(param_id, len_id);
+                let err = fn_error
+                    .or_else(|| self.pointer_param_type_error(typ))
+                    .or_else(|| Some("it is volatile").filter(|_| typ.qualifiers.is_volatile));
+                if let Some(err) = err {
+                    rejected.insert(param_id, err.to_string());
+                }
+            }
+        }
+
+        let functions: IndexSet<CDeclId> = candidates
+            .iter()
+            .filter(|&(param_id, _)| !rejected.contains_key(param_id))
+            .map(|(_, &fn_id)| fn_id)
+            .collect();
+        if functions.is_empty() {
+            self.report_rejected_params(&candidates, &rejected, "a slice");
+            return IndexSet::new();
+        }
+
+        // Check that the parameters are only indexed within their length, or
+        // passed on, in the body
+        for &fn_id in &functions {
+            let (body, parameters) = match self.ast_context[fn_id].kind {
+                CDeclKind::Function {
+                    body: Some(body),
+                    ref parameters,
+                    ..
+                } => (body, parameters),
+                _ => continue,
+            };
+
+            for param_id in parameters {
+                let len_id = match lengths.get(param_id) {
+                    Some(&len_id) => len_id,
+                    None => continue,
+                };
+                let mut len_vars = IndexSet::new();
+                len_vars.insert(len_id);
+                if self.modifies_any(body.into(), &len_vars) {
+                    rejected
+                        .entry(*param_id)
+                        .or_insert_with(|| "its length is modified".to_string());
+                }
+            }
+
+            let bounded = self.bounded_subscripts(body, &lengths);
+            let mut indexed: HashSet<CExprId> = HashSet::new();
+            for node in DFExpr::new(&self.ast_context, body.into()) {
+                let expr_id = match node {
+                    SomeId::Expr(expr_id) => expr_id,
+                    _ => continue,
+                };
+                match self.ast_context[expr_id].kind {
+                    CExprKind::ArraySubscript(_, lhs, rhs, _) => {
+                        for &side in &[lhs, rhs] {
+                            if let Some((declref, param_id)) = self.deref_param(side) {
+                                indexed.insert(declref);
+                                if candidates.contains_key(&param_id) && !bounded.contains(&expr_id)
+                                {
+                                    rejected.entry(param_id).or_insert_with(|| {
+                                        "it is indexed by something its length may not bound"
+                                            .to_string()
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    // Element addresses may point one past the end of the
+                    // array, which a slice cannot express
+                    CExprKind::Unary(_, c_ast::UnOp::AddressOf, arg, _) => {
+                        for param_id in self.indexed_params(arg) {
+                            if candidates.contains_key(&param_id) {
+                                rejected.entry(param_id).or_insert_with(|| {
+                                    "the address of an element is taken".to_string()
+                                });
+                            }
+                        }
+                    }
+                    // Passing the parameter on is fine if the callee takes a
+                    // slice too, which is checked at the call site below
+                    CExprKind::Call(_, func, ref args) => {
+                        let callee_params = match self.direct_callee(func) {
+                            Some((_, callee)) => match self.ast_context[callee].kind {
+                                CDeclKind::Function { ref parameters, .. } => parameters.clone(),
+                                _ => vec![],
+                            },
+                            None => vec![],
+                        };
+                        for (i, &arg) in args.iter().enumerate() {
+                            let forwarded = match self.slice_arg_source(arg) {
+                                Some(SliceArgSource::Param(declref, _)) => declref,
+                                _ => continue,
+                            };
+                            if callee_params
+                                .get(i)
+                                .map_or(false, |p| candidates.contains_key(p))
+                            {
+                                indexed.insert(forwarded);
+                            }
+                        }
+                    }
+                    CExprKind::DeclRef(_, param_id, _)
+                        if candidates.contains_key(&param_id) && !indexed.contains(&expr_id) =>
+                    {
+                        rejected.entry(param_id).or_insert_with(|| {
+                            "it is used other than through indexing".to_string()
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let (calls, address_taken) = self.direct_calls(&functions);
+
+        for (&param_id, fn_id) in &candidates {
+            if address_taken.contains(fn_id) {
+                rejected
+                    .entry(param_id)
+                    .or_insert_with(|| "the address of the function is taken".to_string());
+            }
+        }
+
+        // Check that every call site passes a local array or another slice.
+        // Slices passed on from parameter to parameter stand or fall together.
+        let mut forwards: Vec<(CParamId, CParamId)> = vec![];
+        for (callee, call) in calls {
+            let args = match self.ast_context[call].kind {
+                CExprKind::Call(_, _, ref args) => args,
+                _ => continue,
+            };
+            let parameters = match self.ast_context[callee].kind {
+                CDeclKind::Function { ref parameters, .. } => parameters,
+                _ => continue,
+            };
+
+            for (i, (&param_id, &arg)) in parameters.iter().zip(args.iter()).enumerate() {
+                if !candidates.contains_key(&param_id) {
+                    continue;
+                }
+                // The body of the caller counts passing its slice on as indexing
+                // it, so it must be kept along with the parameter it is passed to
+                if rejected.contains_key(&param_id) {
+                    if let Some(SliceArgSource::Param(_, source)) = self.slice_arg_source(arg) {
+                        if candidates.contains_key(&source) {
+                            forwards.push((source, param_id));
+                        }
+                    }
+                    continue;
+                }
+                let len_arg = match args.get(i + 1) {
+                    Some(&len_arg) => len_arg,
+                    None => continue,
+                };
+                let source = match self.slice_arg_source(arg) {
+                    Some(SliceArgSource::Array(var)) => {
+                        if !self.len_fits_array(len_arg, var) {
+                            rejected.insert(
+                                param_id,
+                                self.call_site_error(
+                                    arg,
+                                    "passes a length that may exceed its array",
+                                ),
+                            );
+                            continue;
+                        }
+                        var
+                    }
+                    Some(SliceArgSource::Param(_, source)) if candidates.contains_key(&source) => {
+                        if self.read_len(len_arg) != lengths.get(&source).cloned() {
+                            rejected.insert(
+                                param_id,
+                                self.call_site_error(
+                                    arg,
+                                    "does not pass on the length of its slice",
+                                ),
+                            );
+                            continue;
+                        }
+                        forwards.push((source, param_id));
+                        source
+                    }
+                    _ => {
+                        rejected.insert(
+                            param_id,
+                            self.call_site_error(arg, "does not pass a local array"),
+                        );
+                        continue;
+                    }
+                };
+
+                let aliased = args
+                    .iter()
+                    .any(|&other| other != arg && self.expr_mentions_decl(other, source));
+                if aliased {
+                    rejected
+                        .entry(param_id)
+                        .or_insert_with(|| "a call site passes aliasing arguments".to_string());
+                }
+            }
+        }
+
+        self.reject_forwarded_params(&forwards, &mut rejected);
+
+        self.report_rejected_params(&candidates, &rejected, "a slice");
+
+        candidates
+            .keys()
+            .filter(|param_id| !rejected.contains_key(*param_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Is the given parameter translated into a slice?
+    pub fn is_slice_param(&self, param_id: CParamId) -> bool {
+        self.slice_params.contains(&param_id)
+    }
+
+    /// If the expression reads a parameter translated into a slice, return
+    /// the `DeclRef` expression of that parameter.
+    pub fn slice_param_ref(&self, expr_id: CExprId) -> Option<CExprId> {
+        match self.deref_param(expr_id) {
+            Some((declref, param_id)) if self.is_slice_param(param_id) => Some(declref),
+            _ => None,
+        }
+    }
+
+    /// Convert the type of a parameter translated into a slice. `&[T]` is
+    /// used for `const` pointees and `&mut [T]` for everything else.
+    pub fn convert_slice_param_type(&self, typ: CQualTypeId) -> Result<P<Ty>, TranslationError> {
+        let pointee = self
+            .ast_context
+            .get_pointee_qual_type(typ.ctype)
+            .ok_or_else(|| format_err!("slice parameter is not a pointer"))?;
+        let ty = self.convert_type(pointee.ctype)?;
+        Ok(mk()
+            .set_mutbl(self.slice_param_mutbl(pointee))
+            .ref_ty(mk().slice_ty(ty)))
+    }
+
+    /// Convert a call argument passed to a parameter translated into a slice.
+    /// The analysis guarantees that the argument is either a local array,
+    /// which we borrow as a whole, or a slice parameter of the caller, which
+    /// is reborrowed implicitly.
+    pub fn convert_slice_arg(
+        &self,
+        ctx: ExprContext,
+        param_id: CParamId,
+        arg: CExprId,
+    ) -> Result<WithStmts<P<Expr>>, TranslationError> {
+        let pointee = match self.ast_context[param_id].kind {
+            CDeclKind::Variable { typ, .. } => self.ast_context.get_pointee_qual_type(typ.ctype),
+            _ => None,
+        };
+        let mutbl = pointee.map_or(Mutability::Mutable, |p| self.slice_param_mutbl(p));
+
+        match self.strip_noop_casts(arg) {
+            CExprKind::ImplicitCast(_, arr, CastKind::ArrayToPointerDecay, _, _) => {
+                let arr = self.convert_expr(ctx.used(), *arr)?;
+                Ok(arr.map(|arr| mk().set_mutbl(mutbl).addr_of_expr(arr)))
+            }
+            CExprKind::ImplicitCast(_, declref, CastKind::LValueToRValue, _, _) => {
+                self.convert_expr(ctx.used(), *declref)
+            }
+            _ => Err(format_translation_err!(
+                self.ast_context.display_loc(&self.ast_context[arg].loc),
+                "Unexpected argument for slice parameter",
+            )),
+        }
+    }
+
+    fn slice_param_mutbl(&self, pointee: CQualTypeId) -> Mutability {
+        if pointee.qualifiers.is_const {
+            Mutability::Immutable
+        } else {
+            Mutability::Mutable
+        }
+    }
+
+    fn is_pointer_type(&self, typ: CQualTypeId) -> bool {
+        self.ast_context.resolve_type(typ.ctype).kind.is_pointer()
+    }
+
+    /// Skip the implicit casts that only add qualifiers to a pointer.
+    pub fn strip_noop_casts(&self, mut expr_id: CExprId) -> &CExprKind {
+        while let CExprKind::ImplicitCast(_, inner, CastKind::NoOp, _, _) =
+            self.ast_context[expr_id].kind
+        {
+            expr_id = inner;
+        }
+        &self.ast_context[expr_id].kind
+    }
+
+    /// Classify a call argument passed to a potential slice parameter.
+    fn slice_arg_source(&self, arg: CExprId) -> Option<SliceArgSource> {
+        match *self.strip_noop_casts(arg) {
+            CExprKind::ImplicitCast(_, arr, CastKind::ArrayToPointerDecay, _, _) => {
+                let var = match self.ast_context[arr].kind {
+                    CExprKind::DeclRef(_, var, _) => var,
+                    _ => return None,
+                };
+                match self.ast_context[var].kind {
+                    CDeclKind::Variable {
+                        has_static_duration: false,
+                        has_thread_duration: false,
+                        typ,
+                        ..
+                    } => match self.ast_context.resolve_type(typ.ctype).kind {
+                        CTypeKind::ConstantArray(..) => Some(SliceArgSource::Array(var)),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            CExprKind::ImplicitCast(_, declref, CastKind::LValueToRValue, _, _) => {
+                match self.ast_context[declref].kind {
+                    CExprKind::DeclRef(_, param_id, _) => {
+                        Some(SliceArgSource::Param(declref, param_id))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The parameters and variables that are indexed anywhere in the
+    /// expression.
+    fn indexed_params(&self, expr_id: CExprId) -> Vec<CDeclId> {
+        DFExpr::new(&self.ast_context, expr_id.into())
+            .filter_map(|node| match node {
+                SomeId::Expr(e) => match self.ast_context[e].kind {
+                    CExprKind::ArraySubscript(_, lhs, rhs, _) => self
+                        .deref_param(lhs)
+                        .or_else(|| self.deref_param(rhs))
+                        .map(|(_, param_id)| param_id),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The subscripts of slice candidates in a function body whose index is
+    /// the counter of an enclosing `for` loop that starts at a constant and
+    /// stops before reaching the length of the candidate.
+    fn bounded_subscripts(
+        &self,
+        body: CStmtId,
+        lengths: &IndexMap<CParamId, CParamId>,
+    ) -> HashSet<CExprId> {
+        let uses = LocalUses::new(&self.ast_context, &[body]);
+        let mut bounded = HashSet::new();
+        for node in DFExpr::new(&self.ast_context, body.into()) {
+            let (for_id, loop_body) = match node {
+                SomeId::Stmt(stmt_id) => match self.ast_context[stmt_id].kind {
+                    CStmtKind::ForLoop { body, .. } => (stmt_id, body),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let range = match self.recognize_for_range(for_id, &uses) {
+                Some(range) if !range.inclusive => range,
+                _ => continue,
+            };
+            if self.int_constant(range.start).is_none() {
+                continue;
+            }
+            let len_id = match self.read_len(range.end) {
+                Some(len_id) => len_id,
+                None => continue,
+            };
+
+            for expr_id in DFExpr::new(&self.ast_context, loop_body.into()).flat_map(SomeId::expr) {
+                let (lhs, rhs) = match self.ast_context[expr_id].kind {
+                    CExprKind::ArraySubscript(_, lhs, rhs, _) => (lhs, rhs),
+                    _ => continue,
+                };
+                // Reversed subscripts (`i[buf]`) are left unbounded, which
+                // rejects their parameter
+                let param_id = match self.deref_param(lhs) {
+                    Some((_, param_id)) => param_id,
+                    None => continue,
+                };
+                if lengths.get(&param_id) == Some(&len_id) && self.read_len(rhs) == Some(range.var)
+                {
+                    bounded.insert(expr_id);
+                }
+            }
+        }
+        bounded
+    }
+
+    /// The variable an integer expression reads, looking through integral
+    /// casts.
+    fn read_len(&self, expr_id: CExprId) -> Option<CDeclId> {
+        match self.ast_context[expr_id].kind {
+            CExprKind::ImplicitCast(_, e, CastKind::IntegralCast, _, _) => self.read_len(e),
+            _ => self.read_var(expr_id),
+        }
+    }
+
+    /// Is the length passed along with a local array a constant no larger
+    /// than the array?
+    fn len_fits_array(&self, len_arg: CExprId, array: CDeclId) -> bool {
+        let size = match self.ast_context[array].kind {
+            CDeclKind::Variable { typ, .. } => {
+                match self.ast_context.resolve_type(typ.ctype).kind {
+                    CTypeKind::ConstantArray(_, size) => size as u64,
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        };
+        self.int_constant(len_arg).map_or(false, |len| len <= size)
+    }
+
+    fn call_site_error(&self, arg: CExprId, problem: &str) -> String {
+        match self.ast_context.display_loc(&self.ast_context[arg].loc) {
+            Some(loc) => format!("the call at {} {}", loc, problem),
+            None => format!("a call site {}", problem),
+        }
+    }
+}
+
+/// Where the argument passed to a potential slice parameter comes from.
+enum SliceArgSource {
+    /// A local array variable
+    Array(CDeclId),
+    /// A parameter (or other pointer variable) of the caller, along with the
+    /// `DeclRef` expression reading it
+    Param(CExprId, CDeclId),
+}
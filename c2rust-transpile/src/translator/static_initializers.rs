@@ -0,0 +1,805 @@
+#![deny(missing_docs)]
+//! This module translates the initializers of statics that Rust can't compile
+//! as they are usually translated.
+//!
+//! C allows a static to be initialized with any constant expression, which
+//! includes the addresses of other statics, their members and array elements,
+//! pointer arithmetic on them, null pointer arithmetic in the style of
+//! `offsetof` and conditionals. Their usual translations call methods such as
+//! `offset` that can't be used in a Rust static, so they are evaluated during
+//! translation instead and emitted as
+//!
+//!   * an integer literal, for integer expressions;
+//!   * `&S.field[index] as *const _` cast to the pointer type, for addresses;
+//!   * the branch that is taken, for conditionals.
+//!
+//! Statics whose initializers still can't be compiled are wrapped in a
+//! `LazyStatic`, which holds the value along with the function that
+//! initializes it, and runs that function the first time the static is
+//! accessed. References to such statics dereference the wrapper. Statics that
+//! are visible to other translation units have to keep the type they have in
+//! C, so they are still initialized by `run_static_initializers` before
+//! `main` runs.
+
+use super::*;
+
+/// The name of the type that wraps lazily initialized statics
+const LAZY_STATIC: &str = "LazyStatic";
+
+/// A constant address, made of a static (or a null pointer) and the members
+/// and array elements it is offset to
+#[derive(Clone)]
+struct ConstAddress {
+    /// The static and the `DeclRef` to it, or `None` for a null pointer
+    root: Option<(CDeclId, CExprId)>,
+    path: Vec<PathElem>,
+}
+
+#[derive(Clone, Copy)]
+enum PathElem {
+    Field(CFieldId),
+    Index {
+        index: i128,
+        len: Option<usize>,
+        elt: CTypeId,
+    },
+}
+
+/// The result of evaluating an expression during translation
+enum Folded {
+    Int(i128),
+    Address(ConstAddress),
+    Branch(CExprId),
+}
+
+impl<'c> Translation<'c> {
+    /// Finds the static variables whose initializers can't be compiled and
+    /// that are not visible to other translation units, which are then lazily
+    /// initialized. This includes the statics whose initializers refer to them.
+    pub fn compute_lazy_statics(&mut self) {
+        loop {
+            let lazy: Vec<CDeclId> = self
+                .ast_context
+                .iter_decls()
+                .filter_map(|(&decl_id, decl)| match decl.kind {
+                    CDeclKind::Variable {
+                        has_static_duration,
+                        has_thread_duration,
+                        is_externally_visible: false,
+                        is_defn: true,
+                        initializer,
+                        typ,
+                        ..
+                    } if (has_static_duration || has_thread_duration)
+                        && !self.lazy_statics.contains(&decl_id)
+                        && self.static_initializer_is_uncompilable(initializer, typ) =>
+                    {
+                        Some(decl_id)
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            if lazy.is_empty() {
+                break;
+            }
+            self.lazy_statics.extend(lazy);
+        }
+    }
+
+    /// Whether the translation of an expression in a static initializer can't
+    /// be compiled, once the expressions that need it are evaluated
+    pub fn static_expr_is_uncompilable(&self, expr_id: CExprId) -> bool {
+        if self.needs_const_eval(expr_id) {
+            return match self.fold_static_expr(expr_id) {
+                Some(Folded::Branch(branch)) => self.static_expr_is_uncompilable(branch),
+                Some(_) => false,
+                None => true,
+            };
+        }
+
+        if self.static_node_is_uncompilable(expr_id) {
+            return true;
+        }
+
+        immediate_children(&self.ast_context, expr_id.into())
+            .into_iter()
+            .any(|child| match child {
+                SomeId::Expr(child) => self.static_expr_is_uncompilable(child),
+                _ => true,
+            })
+    }
+
+    /// Whether an expression in a static initializer is evaluated into an
+    /// integer literal
+    pub fn static_expr_is_literal(&self, expr_id: CExprId) -> bool {
+        self.needs_const_eval(expr_id)
+            && match self.fold_static_expr(expr_id) {
+                Some(Folded::Int(_)) => true,
+                _ => false,
+            }
+    }
+
+    /// Whether an expression in a static initializer has to be evaluated
+    /// during translation, as its usual translation can't be compiled
+    pub fn needs_const_eval(&self, expr_id: CExprId) -> bool {
+        match self.ast_context[expr_id].kind {
+            CExprKind::Conditional(..) => true,
+
+            CExprKind::ImplicitCast(_, _, CastKind::PointerToIntegral, _, _)
+            | CExprKind::ExplicitCast(_, _, CastKind::PointerToIntegral, _, _) => true,
+
+            CExprKind::ImplicitCast(_, arr, CastKind::ArrayToPointerDecay, _, _) => {
+                self.lvalue_needs_const_eval(arr)
+            }
+
+            CExprKind::Binary(_, c_ast::BinOp::Add, lhs, rhs, _, _)
+            | CExprKind::Binary(_, c_ast::BinOp::Subtract, lhs, rhs, _, _) => {
+                self.is_pointer_expr(lhs) || self.is_pointer_expr(rhs)
+            }
+
+            CExprKind::Unary(_, c_ast::UnOp::AddressOf, arg, _) => match self.ast_context[arg].kind
+            {
+                CExprKind::DeclRef(..) => false,
+                _ => true,
+            },
+
+            CExprKind::Member(..) | CExprKind::ArraySubscript(..) => {
+                self.lvalue_needs_const_eval(expr_id)
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Translates an expression of a static initializer that needs to be
+    /// evaluated, or returns `None` if it can't be
+    pub fn convert_const_eval(
+        &self,
+        ctx: ExprContext,
+        expr_id: CExprId,
+    ) -> Result<Option<WithStmts<P<Expr>>>, TranslationError> {
+        let ty = match self.ast_context[expr_id].kind.get_qual_type() {
+            Some(ty) => ty,
+            None => return Ok(None),
+        };
+
+        match self.fold_static_expr(expr_id) {
+            Some(Folded::Branch(branch)) => self.convert_expr(ctx, branch).map(Some),
+
+            Some(Folded::Int(value)) => {
+                let lit = if value >= 0 {
+                    self.mk_int_lit(ty, value as u64, IntBase::Dec)?
+                } else {
+                    mk().cast_expr(signed_int_expr(value as i64), self.convert_type(ty.ctype)?)
+                };
+                Ok(Some(WithStmts::new_val(lit)))
+            }
+
+            Some(Folded::Address(ConstAddress {
+                root: Some((_, root)),
+                path,
+            })) => {
+                let target_ty = self.convert_type(ty.ctype)?;
+                let root = self.convert_expr(ctx.used(), root)?;
+                root.result_map(|mut lvalue| {
+                    for elem in path {
+                        lvalue = match elem {
+                            PathElem::Field(field_id) => {
+                                let record_id = self.ast_context.parents[&field_id];
+                                if self.ast_context.has_inner_struct_decl(record_id) {
+                                    lvalue = mk().field_expr(lvalue, "0");
+                                }
+                                let field_name = self
+                                    .type_converter
+                                    .borrow()
+                                    .resolve_field_name(None, field_id)
+                                    .ok_or_else(|| format_err!("Missing field name"))?;
+                                mk().field_expr(lvalue, field_name)
+                            }
+                            PathElem::Index { index, .. } => {
+                                let index = mk().int_lit(index as u128, LitIntType::Unsuffixed);
+                                mk().index_expr(lvalue, mk().lit_expr(index))
+                            }
+                        };
+                    }
+
+                    let ptr =
+                        mk().cast_expr(mk().addr_of_expr(lvalue), mk().ptr_ty(mk().infer_ty()));
+                    Ok(mk().cast_expr(ptr, target_ty))
+                })
+                .map(Some)
+            }
+
+            _ => Ok(None),
+        }
+    }
+
+    /// Evaluates an expression of a static initializer, where that is needed
+    /// and possible
+    fn fold_static_expr(&self, expr_id: CExprId) -> Option<Folded> {
+        let kind = &self.ast_context[expr_id].kind;
+        if let CExprKind::Conditional(_, cond, lhs, rhs) = *kind {
+            let cond = self.eval_condition(cond)?;
+            return Some(Folded::Branch(if cond { lhs } else { rhs }));
+        }
+
+        let ty = kind.get_type()?;
+        let ty_kind = &self.ast_context.resolve_type(ty).kind;
+        if ty_kind.is_pointer() {
+            self.static_address(expr_id).map(Folded::Address)
+        } else if ty_kind.is_integral_type() {
+            let value = self.eval_const_int(expr_id)?;
+            if value <= u64::max_value() as i128 && value >= i64::min_value() as i128 {
+                Some(Folded::Int(value))
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Whether an lvalue is made of more than static variables, their members
+    /// and the elements of arrays that are members
+    fn lvalue_needs_const_eval(&self, expr_id: CExprId) -> bool {
+        match self.ast_context[expr_id].kind {
+            CExprKind::Paren(_, expr) => self.lvalue_needs_const_eval(expr),
+            CExprKind::Member(_, base, _, MemberKind::Dot, _) => self.lvalue_needs_const_eval(base),
+            CExprKind::DeclRef(..) => false,
+            _ => true,
+        }
+    }
+
+    fn is_pointer_expr(&self, expr_id: CExprId) -> bool {
+        self.ast_context[expr_id]
+            .kind
+            .get_type()
+            .map_or(false, |ty| {
+                self.ast_context.resolve_type(ty).kind.is_pointer()
+            })
+    }
+
+    /// Evaluates the truth of a condition
+    fn eval_condition(&self, expr_id: CExprId) -> Option<bool> {
+        if self.is_pointer_expr(expr_id) {
+            let address = self.const_address(expr_id)?;
+            return Some(address.root.is_some() || !address.path.is_empty());
+        }
+        self.eval_const_int(expr_id).map(|value| value != 0)
+    }
+
+    /// Evaluates an integer constant expression, with the value wrapped to its
+    /// type
+    pub fn eval_const_int(&self, expr_id: CExprId) -> Option<i128> {
+        use crate::c_ast::BinOp::*;
+
+        let kind = &self.ast_context[expr_id].kind;
+        let value = match *kind {
+            CExprKind::Literal(_, CLiteral::Integer(value, _))
+            | CExprKind::Literal(_, CLiteral::Character(value)) => value as i128,
+
+            CExprKind::ConstantExpr(_, _, Some(ConstIntExpr::U(value))) => value as i128,
+            CExprKind::ConstantExpr(_, _, Some(ConstIntExpr::I(value))) => value as i128,
+            CExprKind::ConstantExpr(_, expr, None) | CExprKind::Paren(_, expr) => {
+                self.eval_const_int(expr)?
+            }
+
+            CExprKind::DeclRef(_, decl_id, _) => match self.ast_context[decl_id].kind {
+                CDeclKind::EnumConstant {
+                    value: ConstIntExpr::U(value),
+                    ..
+                } => value as i128,
+                CDeclKind::EnumConstant {
+                    value: ConstIntExpr::I(value),
+                    ..
+                } => value as i128,
+                _ => return None,
+            },
+
+            CExprKind::UnaryType(_, UnTypeOp::SizeOf, _, arg_ty) => {
+                self.type_size(arg_ty.ctype)? as i128
+            }
+            CExprKind::OffsetOf(_, OffsetOfKind::Constant(offset)) => offset as i128,
+
+            CExprKind::ImplicitCast(_, expr, cast_kind, _, _)
+            | CExprKind::ExplicitCast(_, expr, cast_kind, _, _) => match cast_kind {
+                CastKind::IntegralCast | CastKind::NoOp | CastKind::IntegralToBoolean => {
+                    self.eval_const_int(expr)?
+                }
+                CastKind::PointerToBoolean => self.eval_condition(expr)? as i128,
+                CastKind::PointerToIntegral => self.null_offset(expr)?,
+                _ => return None,
+            },
+
+            CExprKind::Unary(_, op, expr, _) => {
+                let value = self.eval_const_int(expr)?;
+                match op {
+                    c_ast::UnOp::Plus | c_ast::UnOp::Extension => value,
+                    c_ast::UnOp::Negate => value.wrapping_neg(),
+                    c_ast::UnOp::Complement => !value,
+                    c_ast::UnOp::Not => (value == 0) as i128,
+                    _ => return None,
+                }
+            }
+
+            CExprKind::Binary(_, Subtract, lhs, rhs, _, _)
+                if self.is_pointer_expr(lhs) && self.is_pointer_expr(rhs) =>
+            {
+                self.eval_pointer_difference(lhs, rhs)?
+            }
+
+            CExprKind::Binary(_, op @ And, lhs, rhs, _, _)
+            | CExprKind::Binary(_, op @ Or, lhs, rhs, _, _) => {
+                let lhs = self.eval_condition(lhs)?;
+                if lhs == (op == Or) {
+                    lhs as i128
+                } else {
+                    self.eval_condition(rhs)? as i128
+                }
+            }
+
+            CExprKind::Binary(_, op, lhs, rhs, _, _) => {
+                let lhs = self.eval_const_int(lhs)?;
+                let rhs = self.eval_const_int(rhs)?;
+                match op {
+                    Multiply => lhs.wrapping_mul(rhs),
+                    Divide => lhs.checked_div(rhs)?,
+                    Modulus => lhs.checked_rem(rhs)?,
+                    Add => lhs.wrapping_add(rhs),
+                    Subtract => lhs.wrapping_sub(rhs),
+                    ShiftLeft if rhs >= 0 && rhs < 128 => lhs.wrapping_shl(rhs as u32),
+                    ShiftRight if rhs >= 0 && rhs < 128 => lhs >> rhs,
+                    Less => (lhs < rhs) as i128,
+                    Greater => (lhs > rhs) as i128,
+                    LessEqual => (lhs <= rhs) as i128,
+                    GreaterEqual => (lhs >= rhs) as i128,
+                    EqualEqual => (lhs == rhs) as i128,
+                    NotEqual => (lhs != rhs) as i128,
+                    BitAnd => lhs & rhs,
+                    BitXor => lhs ^ rhs,
+                    BitOr => lhs | rhs,
+                    _ => return None,
+                }
+            }
+
+            CExprKind::Conditional(_, cond, lhs, rhs) => {
+                self.eval_const_int(if self.eval_condition(cond)? { lhs } else { rhs })?
+            }
+
+            _ => return None,
+        };
+
+        self.wrap_int(kind.get_type()?, value)
+    }
+
+    /// Wraps an integer to the width and signedness of its type
+    fn wrap_int(&self, ty: CTypeId, value: i128) -> Option<i128> {
+        let kind = &self.ast_context.resolve_type(ty).kind;
+        if kind.is_bool() {
+            return Some((value != 0) as i128);
+        }
+
+        let bits = self.int_size(ty)? * 8;
+        if bits >= 128 {
+            return if kind.is_unsigned_integral_type() && value < 0 {
+                None
+            } else {
+                Some(value)
+            };
+        }
+
+        let unused = 128 - bits as u32;
+        let shifted = value << unused;
+        if kind.is_unsigned_integral_type() {
+            Some(((shifted as u128) >> unused) as i128)
+        } else {
+            Some(shifted >> unused)
+        }
+    }
+
+    /// The size of an integer type on the target, in bytes
+    fn int_size(&self, ty: CTypeId) -> Option<u64> {
+        let target = &self.ast_context.target_info;
+        let size = match self.ast_context.resolve_type(ty).kind {
+            CTypeKind::Bool => 1,
+            CTypeKind::Char | CTypeKind::SChar | CTypeKind::UChar => target.char_width / 8,
+            CTypeKind::Short | CTypeKind::UShort => target.short_width / 8,
+            CTypeKind::Int | CTypeKind::UInt => target.int_width / 8,
+            CTypeKind::Long | CTypeKind::ULong => target.long_width / 8,
+            CTypeKind::LongLong | CTypeKind::ULongLong => target.long_long_width / 8,
+            CTypeKind::Int128 | CTypeKind::UInt128 => 16,
+            CTypeKind::Enum(enum_id) => match self.ast_context[enum_id].kind {
+                CDeclKind::Enum {
+                    integral_type: Some(integral_type),
+                    ..
+                } => self.int_size(integral_type.ctype)?,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(size)
+    }
+
+    /// The size of a type on the target, in bytes
+    fn type_size(&self, ty: CTypeId) -> Option<u64> {
+        match self.ast_context.resolve_type(ty).kind {
+            CTypeKind::Float => Some(4),
+            CTypeKind::Double => Some(8),
+            CTypeKind::Pointer(..) => Some(self.ast_context.target_info.pointer_width / 8),
+            CTypeKind::ConstantArray(elt, len) => Some(self.type_size(elt)? * len as u64),
+            CTypeKind::Struct(struct_id) => match self.ast_context[struct_id].kind {
+                CDeclKind::Struct {
+                    fields: Some(_),
+                    platform_byte_size,
+                    ..
+                } => Some(platform_byte_size),
+                _ => None,
+            },
+            _ => self.int_size(ty),
+        }
+    }
+
+    /// The offset in bytes of an address based on a null pointer, as in
+    /// `(size_t)&((struct s *)0)->field`
+    fn null_offset(&self, expr_id: CExprId) -> Option<i128> {
+        let address = self.const_address(expr_id)?;
+        if address.root.is_some() {
+            return None;
+        }
+
+        let mut offset = 0;
+        for elem in address.path {
+            offset += match elem {
+                PathElem::Field(field_id) => match self.ast_context[field_id].kind {
+                    CDeclKind::Field {
+                        bitfield_width: None,
+                        platform_bit_offset,
+                        ..
+                    } if platform_bit_offset % 8 == 0 => (platform_bit_offset / 8) as i128,
+                    _ => return None,
+                },
+                PathElem::Index { index, elt, .. } => index * self.type_size(elt)? as i128,
+            };
+        }
+        Some(offset)
+    }
+
+    /// Evaluates the difference between two pointers to the same array
+    fn eval_pointer_difference(&self, lhs: CExprId, rhs: CExprId) -> Option<i128> {
+        let mut lhs = self.const_address(lhs)?;
+        let mut rhs = self.const_address(rhs)?;
+        if lhs.root.map(|(decl_id, _)| decl_id) != rhs.root.map(|(decl_id, _)| decl_id) {
+            return None;
+        }
+
+        match (lhs.path.pop()?, rhs.path.pop()?) {
+            (PathElem::Index { index: l, .. }, PathElem::Index { index: r, .. })
+                if self.same_path(&lhs.path, &rhs.path) =>
+            {
+                Some(l - r)
+            }
+            _ => None,
+        }
+    }
+
+    fn same_path(&self, lhs: &[PathElem], rhs: &[PathElem]) -> bool {
+        lhs.len() == rhs.len()
+            && lhs.iter().zip(rhs).all(|pair| match pair {
+                (PathElem::Field(l), PathElem::Field(r)) => l == r,
+                (PathElem::Index { index: l, .. }, PathElem::Index { index: r, .. }) => l == r,
+                _ => false,
+            })
+    }
+
+    /// Evaluates an address that can be translated as a reference to a
+    /// static, which excludes out of bounds elements and lazy statics
+    fn static_address(&self, expr_id: CExprId) -> Option<ConstAddress> {
+        let address = self.const_address(expr_id)?;
+        let (decl_id, _) = address.root?;
+        if self.lazy_statics.contains(&decl_id) {
+            return None;
+        }
+
+        let in_bounds = address.path.iter().all(|elem| match *elem {
+            PathElem::Field(_) => true,
+            PathElem::Index { index, len, .. } => {
+                index >= 0 && len.map_or(false, |len| index < len as i128)
+            }
+        });
+        if in_bounds {
+            Some(address)
+        } else {
+            None
+        }
+    }
+
+    /// Evaluates a pointer expression into a constant address
+    fn const_address(&self, expr_id: CExprId) -> Option<ConstAddress> {
+        match self.ast_context[expr_id].kind {
+            CExprKind::Paren(_, expr) => self.const_address(expr),
+
+            CExprKind::Unary(_, c_ast::UnOp::AddressOf, lvalue, _) => self.const_lvalue(lvalue),
+
+            CExprKind::ImplicitCast(_, expr, cast_kind, _, _)
+            | CExprKind::ExplicitCast(_, expr, cast_kind, _, _) => match cast_kind {
+                CastKind::ArrayToPointerDecay => {
+                    let mut address = self.const_lvalue(expr)?;
+                    let ty = self.ast_context[expr].kind.get_type()?;
+                    let (elt, len) = match self.ast_context.resolve_type(ty).kind {
+                        CTypeKind::ConstantArray(elt, len) => (elt, Some(len)),
+                        CTypeKind::IncompleteArray(elt) => (elt, None),
+                        _ => return None,
+                    };
+                    address.path.push(PathElem::Index { index: 0, len, elt });
+                    Some(address)
+                }
+                CastKind::BitCast | CastKind::NoOp => self.const_address(expr),
+                CastKind::NullToPointer => Some(ConstAddress {
+                    root: None,
+                    path: vec![],
+                }),
+                CastKind::IntegralToPointer if self.eval_const_int(expr)? == 0 => {
+                    Some(ConstAddress {
+                        root: None,
+                        path: vec![],
+                    })
+                }
+                _ => None,
+            },
+
+            CExprKind::Binary(ty, op, lhs, rhs, _, _) => {
+                let sign = match op {
+                    c_ast::BinOp::Add => 1,
+                    c_ast::BinOp::Subtract => -1,
+                    _ => return None,
+                };
+                let (ptr, index) = if self.is_pointer_expr(lhs) {
+                    (lhs, rhs)
+                } else {
+                    (rhs, lhs)
+                };
+                let address = self.const_address(ptr)?;
+                let delta = self.eval_const_int(index)? * sign;
+                let pointee = self.ast_context.get_pointee_qual_type(ty.ctype)?;
+                self.offset_address(address, delta, pointee.ctype)
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Evaluates an lvalue into the constant address it designates
+    fn const_lvalue(&self, expr_id: CExprId) -> Option<ConstAddress> {
+        match self.ast_context[expr_id].kind {
+            CExprKind::Paren(_, expr) => self.const_lvalue(expr),
+
+            CExprKind::DeclRef(_, decl_id, _) => match self.ast_context[decl_id].kind {
+                CDeclKind::Variable {
+                    has_static_duration: true,
+                    ..
+                } => Some(ConstAddress {
+                    root: Some((decl_id, expr_id)),
+                    path: vec![],
+                }),
+                _ => None,
+            },
+
+            CExprKind::Member(_, base, field_id, kind, _) => {
+                let mut address = match kind {
+                    MemberKind::Dot => self.const_lvalue(base)?,
+                    MemberKind::Arrow => self.const_address(base)?,
+                };
+                address.path.push(PathElem::Field(field_id));
+                Some(address)
+            }
+
+            CExprKind::ArraySubscript(ty, lhs, rhs, _) => {
+                let (ptr, index) = if self.is_pointer_expr(lhs) {
+                    (lhs, rhs)
+                } else {
+                    (rhs, lhs)
+                };
+                let address = self.const_address(ptr)?;
+                let delta = self.eval_const_int(index)?;
+                self.offset_address(address, delta, ty.ctype)
+            }
+
+            CExprKind::Unary(_, c_ast::UnOp::Deref, ptr, _) => self.const_address(ptr),
+
+            _ => None,
+        }
+    }
+
+    /// Offsets an address to an array element by a number of elements
+    fn offset_address(
+        &self,
+        mut address: ConstAddress,
+        delta: i128,
+        pointee: CTypeId,
+    ) -> Option<ConstAddress> {
+        if delta == 0 {
+            return Some(address);
+        }
+
+        match address.path.last_mut() {
+            Some(PathElem::Index { index, elt, .. })
+                if self.ast_context.resolve_type_id(*elt)
+                    == self.ast_context.resolve_type_id(pointee) =>
+            {
+                *index += delta;
+                Some(address)
+            }
+            _ => None,
+        }
+    }
+
+    /// Translates a lazily initialized static, returning its type and value
+    pub fn convert_lazy_static(
+        &self,
+        ctx: ExprContext,
+        initializer: Option<CExprId>,
+        typ: CQualTypeId,
+    ) -> Result<(P<Ty>, P<Expr>), TranslationError> {
+        let (ty, _, init) = self.convert_variable(ctx.not_static(), initializer, typ)?;
+        let mut init = init?;
+        if self.static_initializer_is_unsafe(initializer, typ) {
+            init.set_unsafe();
+        }
+        let block = if init.is_unsafe() {
+            let (mut stmts, val) = init.discard_unsafe();
+            stmts.push(mk().expr_stmt(val));
+            mk().unsafe_().block(stmts)
+        } else {
+            init.to_block()
+        };
+        let default_init = self.implicit_default_expr(typ.ctype, true)?.to_expr();
+
+        let std_or_core = if self.tcfg.emit_no_std { "core" } else { "std" };
+        let init_fn = mk().closure_expr(
+            CaptureBy::Ref,
+            Movability::Movable,
+            mk().fn_decl(vec![], FunctionRetTy::Default(DUMMY_SP)),
+            mk().block_expr(block),
+        );
+        let value = mk().struct_expr(
+            vec![LAZY_STATIC],
+            vec![
+                mk().field(
+                    "value",
+                    mk().call_expr(
+                        mk().path_expr(vec!["", std_or_core, "cell", "UnsafeCell", "new"]),
+                        vec![default_init],
+                    ),
+                ),
+                mk().field(
+                    "init",
+                    mk().call_expr(
+                        mk().path_expr(vec!["", std_or_core, "cell", "Cell", "new"]),
+                        vec![mk().call_expr(mk().path_expr(vec!["Some"]), vec![init_fn])],
+                    ),
+                ),
+            ],
+        );
+
+        let ty =
+            mk().path_ty(vec![mk().path_segment_with_args(
+                LAZY_STATIC,
+                mk().angle_bracketed_args(vec![ty]),
+            )]);
+        Ok((ty, value))
+    }
+
+    /// Defines `LazyStatic`, which wraps a value that is initialized on first
+    /// access
+    pub fn make_lazy_static_items(&self) -> Vec<P<Item>> {
+        let std_or_core = if self.tcfg.emit_no_std { "core" } else { "std" };
+        let t = || mk().path_ty(vec!["T"]);
+        let lazy_ty = || {
+            mk().path_ty(vec![mk().path_segment_with_args(
+                LAZY_STATIC,
+                mk().angle_bracketed_args(vec![t()]),
+            )])
+        };
+        let cell_ty = |name: &str, ty: P<Ty>| {
+            mk().path_ty(vec![
+                mk().path_segment(""),
+                mk().path_segment(std_or_core),
+                mk().path_segment("cell"),
+                mk().path_segment_with_args(name, mk().angle_bracketed_args(vec![ty])),
+            ])
+        };
+        let self_field = |name: &str| mk().field_expr(mk().path_expr(vec!["self"]), name);
+        let value_ptr =
+            || mk().method_call_expr(self_field("value"), "get", vec![] as Vec<P<Expr>>);
+        let unsafe_expr =
+            |expr: P<Expr>| mk().block_expr(mk().unsafe_().block(vec![mk().expr_stmt(expr)]));
+
+        let init_fn_ty = mk().barefn_ty(mk().fn_decl(vec![], FunctionRetTy::Ty(t())));
+        let init_ty = cell_ty(
+            "Cell",
+            mk().path_ty(vec![mk().path_segment_with_args(
+                "Option",
+                mk().angle_bracketed_args(vec![init_fn_ty]),
+            )]),
+        );
+        let lazy_struct = mk().pub_().generic_over(mk().ty_param("T")).struct_item(
+            LAZY_STATIC,
+            vec![
+                mk().pub_()
+                    .struct_field("value", cell_ty("UnsafeCell", t())),
+                mk().pub_().struct_field("init", init_ty),
+            ],
+            false,
+        );
+
+        // Run the initializer if it hasn't run yet, then hand out the value
+        let run_init = mk().match_expr(
+            mk().method_call_expr(self_field("init"), "take", vec![] as Vec<P<Expr>>),
+            vec![
+                mk().arm(
+                    mk().tuple_struct_pat(vec!["Some"], vec![mk().ident_pat("init")]),
+                    None as Option<P<Expr>>,
+                    unsafe_expr(mk().assign_expr(
+                        mk().unary_expr(ast::UnOp::Deref, value_ptr()),
+                        mk().call_expr(mk().path_expr(vec!["init"]), vec![] as Vec<P<Expr>>),
+                    )),
+                ),
+                mk().arm(
+                    mk().wild_pat(),
+                    None as Option<P<Expr>>,
+                    mk().block_expr(mk().block(vec![] as Vec<Stmt>)),
+                ),
+            ],
+        );
+        let deref = mk().fn_decl(
+            vec![mk().self_arg(SelfKind::Region(None, Mutability::Immutable))],
+            FunctionRetTy::Ty(mk().ref_ty(t())),
+        );
+        let deref_body =
+            unsafe_expr(mk().addr_of_expr(mk().unary_expr(ast::UnOp::Deref, value_ptr())));
+        let deref_impl = mk().generic_over(mk().ty_param("T")).trait_impl_item(
+            vec!["", std_or_core, "ops", "Deref"],
+            lazy_ty(),
+            vec![
+                mk().ty_impl_item("Target", t()),
+                mk().fn_impl_item(
+                    "deref",
+                    deref,
+                    mk().block(vec![mk().semi_stmt(run_init), mk().expr_stmt(deref_body)]),
+                ),
+            ],
+        );
+
+        let deref_mut = mk().fn_decl(
+            vec![mk().self_arg(SelfKind::Region(None, Mutability::Mutable))],
+            FunctionRetTy::Ty(mk().mutbl().ref_ty(t())),
+        );
+        let call_deref = mk().call_expr(
+            mk().path_expr(vec!["", std_or_core, "ops", "Deref", "deref"]),
+            vec![mk().path_expr(vec!["self"])],
+        );
+        let deref_mut_body = unsafe_expr(
+            mk().mutbl()
+                .addr_of_expr(mk().unary_expr(ast::UnOp::Deref, value_ptr())),
+        );
+        let deref_mut_impl = mk().generic_over(mk().ty_param("T")).trait_impl_item(
+            vec!["", std_or_core, "ops", "DerefMut"],
+            lazy_ty(),
+            vec![mk().fn_impl_item(
+                "deref_mut",
+                deref_mut,
+                mk().block(vec![
+                    mk().local_stmt(P(mk().local(
+                        mk().wild_pat(),
+                        None as Option<P<Ty>>,
+                        Some(call_deref),
+                    ))),
+                    mk().expr_stmt(deref_mut_body),
+                ]),
+            )],
+        );
+
+        vec![lazy_struct, deref_impl, deref_mut_impl]
+    }
+}
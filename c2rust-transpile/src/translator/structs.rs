@@ -6,6 +6,7 @@ use std::collections::HashSet;
 use std::ops::Index;
 
 use super::TranslationError;
+use crate::c_ast::iterators::SomeId;
 use crate::c_ast::{BinOp, CDeclId, CDeclKind, CExprId, CRecordId, CTypeId};
 use crate::translator::{ExprContext, Translation, PADDING_SUFFIX};
 use crate::with_stmts::WithStmts;
@@ -17,7 +18,7 @@ use syntax::ast::{
 };
 use syntax::ptr::P;
 use syntax::source_map::symbol::Symbol;
-use syntax_pos::DUMMY_SP;
+use syntax_pos::{Span, DUMMY_SP};
 
 use itertools::EitherOrBoth::{Both, Right};
 use itertools::Itertools;
@@ -29,6 +30,7 @@ enum FieldType {
         field_name: String,
         bytes: u64,
         attrs: Vec<(String, P<Ty>, String)>,
+        span: Span,
     },
     Padding {
         bytes: u64,
@@ -106,6 +108,7 @@ impl<'a> Translation<'a> {
 
                 let ctype = typ.ctype;
                 let mut ty = self.convert_type(ctype)?;
+                let field_span = self.get_span(SomeId::Decl(*field_id)).unwrap_or(DUMMY_SP);
                 let bitfield_width = match bitfield_width {
                     // Bitfield widths of 0 should just be markers for clang,
                     // we shouldn't need to explicitly handle it ourselves
@@ -159,7 +162,10 @@ impl<'a> Translation<'a> {
                             })
                         }
 
-                        let field = mk().pub_().struct_field(field_name.clone(), ty);
+                        let field = mk()
+                            .span(field_span)
+                            .pub_()
+                            .struct_field(field_name.clone(), ty);
 
                         reorganized_fields.push(FieldType::Regular {
                             name: field_name,
@@ -188,6 +194,7 @@ impl<'a> Translation<'a> {
                         field_name: ref mut name,
                         ref mut bytes,
                         ref mut attrs,
+                        ref mut span,
                     }) => {
                         name.push('_');
                         name.push_str(&field_name);
@@ -210,6 +217,16 @@ impl<'a> Translation<'a> {
                         let bit_range = format!("{}..={}", bit_start, bit_end);
 
                         attrs.push((field_name.clone(), ty, bit_range));
+
+                        // The group is a single Rust field, so it carries the
+                        // comments of all of its bitfields
+                        if span.is_dummy() {
+                            *span = field_span;
+                        } else if !field_span.is_dummy() {
+                            self.comment_store
+                                .borrow_mut()
+                                .move_comments(field_span.lo(), span.lo());
+                        }
                     }
                     Some(_) => unreachable!("Found last bitfield group which is not a group"),
                     None => {
@@ -235,6 +252,7 @@ impl<'a> Translation<'a> {
                             field_name,
                             bytes,
                             attrs,
+                            span: field_span,
                         });
                     }
                 }
@@ -300,12 +318,13 @@ impl<'a> Translation<'a> {
                     field_name,
                     bytes,
                     attrs,
+                    span,
                 } => {
                     let ty = mk().array_ty(
                         mk().ident_ty("u8"),
                         mk().lit_expr(mk().int_lit(bytes.into(), LitIntType::Unsuffixed)),
                     );
-                    let mut field = mk();
+                    let mut field = mk().span(span);
                     let field_attrs = attrs.iter().map(|attr| {
                         let ty_str = match &attr.1.kind {
                             TyKind::Path(_, path) => pprust::path_to_string(path),
@@ -0,0 +1,1046 @@
+#![deny(missing_docs)]
+//! This module implements the opt-in recovery of tagged unions: unions stored
+//! in a struct next to a tag field which tells which of their members is in
+//! use.
+//!
+//! The union itself keeps its C layout, so that the struct can still be shared
+//! with C code, but the struct gets a Rust enum representation of the union and
+//! its tag along with conversion functions:
+//!
+//! ```ignore
+//! #[derive(Copy, Clone)]
+//! pub enum value_u {
+//!     INT(libc::c_int),
+//!     DOUBLE(libc::c_double),
+//! }
+//! impl value {
+//!     pub unsafe fn u(&self) -> Option<value_u> { ... }
+//!     pub fn set_u(&mut self, u: value_u) { ... }
+//! }
+//! ```
+//!
+//! Reading the union is unsafe: the tag and the union are public fields, so
+//! nothing prevents them from disagreeing, and the member the tag selects may
+//! then hold an invalid value (a `bool`, a Rust enum, a function pointer) or
+//! uninitialized bytes.
+//!
+//! The analysis runs over the whole translation unit and pairs a union field
+//! with a sibling tag field of integer or enum type when every access to a
+//! member of the union is guarded by a test of the tag of the same struct: an
+//! `if` or `?:` condition comparing the tag to a constant, a `case` of a
+//! `switch` over the tag, or an assignment of a constant to the tag. Each value
+//! of the tag must select a single member, and the union must never be used as
+//! a whole, through pointers, or outside of that field.
+
+use super::*;
+use crate::c_ast::iterators::immediate_children_all_types;
+use crate::diagnostics::Diagnostic;
+use crate::renamer::camel_case;
+
+/// A union field of a struct whose members are selected by a sibling tag field
+#[derive(Clone, Debug)]
+pub struct TaggedUnion {
+    /// The tag field
+    tag: CFieldId,
+    /// The union field
+    union: CFieldId,
+    /// The values of the tag and the members of the union they select
+    variants: Vec<(Tag, CFieldId)>,
+}
+
+/// A value of a tag, along with the enum constant it was written as, if any
+#[derive(Copy, Clone, Debug)]
+struct Tag {
+    value: i64,
+    constant: Option<CEnumConstantId>,
+}
+
+/// A step in the path to a struct object from a variable
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum PathElem {
+    Var(CDeclId),
+    Field(CFieldId),
+    Deref,
+    Index(u64),
+}
+
+/// A tag field of the struct object reached by a path
+type Key = (Vec<PathElem>, CFieldId);
+
+/// The tag values known to be held by tag fields at some point of a function
+type Guards = IndexMap<Key, Vec<Tag>>;
+
+/// What is known of the accesses to a union field with respect to one of its
+/// candidate tag fields
+enum Selection {
+    /// The members selected by each tag value seen so far
+    Members(IndexMap<i64, (Tag, CFieldId)>),
+    /// Why the tag does not select the members of the union
+    Invalid(&'static str),
+}
+
+/// Walks function bodies, tracking the guards that hold at each statement
+struct GuardWalker<'a, 'c: 'a> {
+    t: &'a Translation<'c>,
+    /// Candidate union fields, along with the struct they belong to
+    unions: IndexMap<CFieldId, CRecordId>,
+    /// Candidate tag fields of each struct
+    tags: IndexMap<CRecordId, Vec<CFieldId>>,
+    selections: IndexMap<(CFieldId, CFieldId), Selection>,
+    rejected: IndexMap<CFieldId, &'static str>,
+    /// The tags tested by the enclosing `switch` statements
+    switches: Vec<Option<Key>>,
+}
+
+impl<'c> Translation<'c> {
+    /// Compute the unions that can be given a safe tagged representation,
+    /// keyed by the struct storing them. Rejected candidates are reported
+    /// through the `idioms` diagnostic.
+    pub fn compute_tagged_unions(&self) -> IndexMap<CRecordId, Vec<TaggedUnion>> {
+        let mut walker = GuardWalker {
+            t: self,
+            unions: IndexMap::new(),
+            tags: IndexMap::new(),
+            selections: IndexMap::new(),
+            rejected: IndexMap::new(),
+            switches: vec![],
+        };
+
+        for (&decl_id, decl) in self.ast_context.iter_decls() {
+            let fields = match decl.kind {
+                CDeclKind::Struct {
+                    fields: Some(ref fields),
+                    manual_alignment: None,
+                    ..
+                } => fields,
+                _ => continue,
+            };
+            let mut tags = vec![];
+            for &field_id in fields {
+                let typ = match self.ast_context[field_id].kind {
+                    CDeclKind::Field {
+                        typ,
+                        bitfield_width: None,
+                        ..
+                    } => typ,
+                    _ => continue,
+                };
+                if self.tagged_union_of(typ.ctype).is_some() {
+                    walker.unions.insert(field_id, decl_id);
+                } else if self.is_tag_type(typ.ctype) {
+                    tags.push(field_id);
+                }
+            }
+            walker.tags.insert(decl_id, tags);
+        }
+        let tags = &walker.tags;
+        walker.unions.retain(|_, struct_id| !tags[struct_id].is_empty());
+
+        // A union must only be stored in its field
+        let mut fields_of_union: IndexMap<CRecordId, Vec<CFieldId>> = IndexMap::new();
+        for &union_field in walker.unions.keys() {
+            if let CDeclKind::Field { typ, .. } = self.ast_context[union_field].kind {
+                if let Some(union_id) = self.tagged_union_of(typ.ctype) {
+                    fields_of_union.entry(union_id).or_insert_with(Vec::new).push(union_field);
+                }
+            }
+        }
+        for (&decl_id, decl) in self.ast_context.iter_decls() {
+            let ty = match decl.kind {
+                CDeclKind::Variable { typ, .. } => typ.ctype,
+                CDeclKind::Field { typ, .. } if !walker.unions.contains_key(&decl_id) => typ.ctype,
+                CDeclKind::Function { typ, .. } => match self.ast_context.resolve_type(typ).kind {
+                    CTypeKind::Function(ret, ..) => ret.ctype,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if let Some(union_id) = self.union_in(ty) {
+                for &union_field in fields_of_union.get(&union_id).into_iter().flatten() {
+                    walker.reject(union_field, "its union type is used elsewhere");
+                }
+            }
+        }
+        for union_fields in fields_of_union.values() {
+            if union_fields.len() > 1 {
+                for &union_field in union_fields {
+                    walker.reject(union_field, "its union type is used elsewhere");
+                }
+            }
+        }
+
+        for &decl_id in &self.ast_context.c_decls_top {
+            match self.ast_context[decl_id].kind {
+                CDeclKind::Function {
+                    body: Some(body), ..
+                } => {
+                    walker.walk_stmt(body, Guards::new());
+                }
+                CDeclKind::Variable {
+                    initializer: Some(init),
+                    ..
+                } => walker.walk_expr(init, &Guards::new()),
+                _ => {}
+            }
+        }
+
+        let mut tagged: IndexMap<CRecordId, Vec<TaggedUnion>> = IndexMap::new();
+        for (&union_field, &struct_id) in &walker.unions {
+            if walker.rejected.contains_key(&union_field) {
+                continue;
+            }
+            let mut reason = "its members are never accessed";
+            let mut found = None;
+            for &tag in &walker.tags[&struct_id] {
+                match walker.selections.get(&(union_field, tag)) {
+                    Some(Selection::Members(members)) => {
+                        found = Some(TaggedUnion {
+                            tag,
+                            union: union_field,
+                            variants: members.values().cloned().collect(),
+                        });
+                        break;
+                    }
+                    Some(Selection::Invalid(why)) => reason = why,
+                    None => {}
+                }
+            }
+            match found {
+                Some(tagged_union) => {
+                    tagged.entry(struct_id).or_insert_with(Vec::new).push(tagged_union)
+                }
+                None => {
+                    walker.rejected.insert(union_field, reason);
+                }
+            }
+        }
+
+        for (&union_field, reason) in &walker.rejected {
+            let struct_id = walker.unions[&union_field];
+            let struct_name = self.type_converter.borrow().resolve_decl_name(struct_id);
+            diag!(
+                Diagnostic::Idioms,
+                "keeping union field `{}` of `{}` as a C union: {}",
+                self.ast_context[union_field].kind.get_name().map_or("", String::as_str),
+                struct_name.as_ref().map_or("", String::as_str),
+                reason,
+            );
+        }
+        tagged
+    }
+
+    /// The enum and accessors giving a safe representation of the tagged
+    /// unions of a struct
+    pub fn convert_tagged_unions(
+        &self,
+        struct_id: CRecordId,
+    ) -> Result<Vec<P<Item>>, TranslationError> {
+        let tagged_unions = match self.tagged_unions.get(&struct_id) {
+            Some(tagged_unions) => tagged_unions,
+            None => return Ok(vec![]),
+        };
+        let span = self.get_span(SomeId::Decl(struct_id)).unwrap_or(DUMMY_SP);
+        let struct_name = self
+            .type_converter
+            .borrow()
+            .resolve_decl_name(struct_id)
+            .ok_or_else(|| format_err!("Struct not named"))?;
+        let field_name = |field_id: CFieldId| {
+            self.type_converter
+                .borrow()
+                .resolve_field_name(None, field_id)
+                .ok_or_else(|| format_err!("Field not named"))
+        };
+        let self_field = |name: &str| mk().field_expr(mk().path_expr(vec!["self"]), name);
+
+        let mut items = vec![];
+        let mut methods = vec![];
+        for tagged_union in tagged_unions {
+            let union_name = field_name(tagged_union.union)?;
+            let tag_name = field_name(tagged_union.tag)?;
+            let tag_ty = match self.ast_context[tagged_union.tag].kind {
+                CDeclKind::Field { typ, .. } => typ.ctype,
+                _ => return Err(format_err!("Tag is not a field").into()),
+            };
+            let mut enum_name = format!("{}_{}", struct_name, union_name);
+            if self.tcfg.rust_naming {
+                enum_name = camel_case(&enum_name);
+            }
+            let enum_name = self
+                .type_converter
+                .borrow_mut()
+                .declare_decl_name(tagged_union.union, &enum_name);
+
+            let mut variants = vec![];
+            let mut get_arms = vec![];
+            let mut set_arms = vec![];
+            for &(tag, member) in &tagged_union.variants {
+                let member_ty = match self.ast_context[member].kind {
+                    CDeclKind::Field { typ, .. } => typ.ctype,
+                    _ => return Err(format_err!("Union member is not a field").into()),
+                };
+                if let Some(cur_file) = *self.cur_file.borrow() {
+                    self.import_type(member_ty, cur_file);
+                }
+                let member_name = field_name(member)?;
+                let variant_name = match tag.constant {
+                    Some(constant) => self
+                        .renamer
+                        .borrow()
+                        .get(&constant)
+                        .ok_or_else(|| format_err!("Enum constant not named"))?,
+                    None if tag.value < 0 => format!("TagMinus{}", -tag.value),
+                    None => format!("Tag{}", tag.value),
+                };
+                let variant_name = if self.tcfg.rust_naming {
+                    camel_case(&variant_name)
+                } else {
+                    variant_name
+                };
+                let variant_path = vec![enum_name.clone(), variant_name.clone()];
+                let tag_value = self.tag_expr(tag_ty, tag);
+                let member_field = mk().field_expr(self_field(&union_name), &member_name);
+
+                variants.push(mk().variant(
+                    &variant_name,
+                    VariantData::Tuple(
+                        vec![mk().enum_field(self.convert_type(member_ty)?)],
+                        DUMMY_NODE_ID,
+                    ),
+                ));
+                let wrapped = mk().call_expr(mk().path_expr(variant_path.clone()), vec![member_field.clone()]);
+                get_arms.push(mk().arm(
+                    mk().lit_pat(tag_value.clone()),
+                    None,
+                    mk().call_expr(mk().path_expr(vec!["Some"]), vec![wrapped]),
+                ));
+                let set_tag = mk().assign_expr(self_field(&tag_name), tag_value);
+                let set_member = mk().assign_expr(member_field, mk().path_expr(vec!["value"]));
+                set_arms.push(mk().arm(
+                    mk().tuple_struct_pat(variant_path, vec![mk().ident_pat("value")]),
+                    None,
+                    mk().block_expr(mk().block(vec![
+                        mk().semi_stmt(set_tag),
+                        mk().semi_stmt(set_member),
+                    ])),
+                ));
+            }
+            let exhaustive = match self.ast_context.resolve_type(tag_ty).kind {
+                CTypeKind::Enum(enum_id) if self.rust_enums.contains(&enum_id) => {
+                    match self.ast_context[enum_id].kind {
+                        CDeclKind::Enum { ref variants, .. } => {
+                            variants.len() == tagged_union.variants.len()
+                        }
+                        _ => false,
+                    }
+                }
+                _ => false,
+            };
+            if !exhaustive {
+                get_arms.push(mk().arm(mk().wild_pat(), None, mk().path_expr(vec!["None"])));
+            }
+
+            items.push(
+                mk().span(span)
+                    .pub_()
+                    .call_attr("derive", vec!["Copy", "Clone"])
+                    .enum_item(&enum_name, variants),
+            );
+
+            let enum_ty = || mk().path_ty(vec![enum_name.as_str()]);
+            let get = mk().fn_decl(
+                vec![mk().self_arg(SelfKind::Region(None, Mutability::Immutable))],
+                FunctionRetTy::Ty(mk().path_ty(vec![mk().path_segment_with_args(
+                    "Option",
+                    mk().angle_bracketed_args(vec![enum_ty()]),
+                )])),
+            );
+            let get_body = mk().match_expr(self_field(&tag_name), get_arms);
+            methods.push(
+                mk().pub_()
+                    .unsafe_()
+                    .doc_comment(format!(
+                        "/// The member of `{}` that `{}` selects",
+                        union_name, tag_name
+                    ))
+                    .doc_comment("///")
+                    .doc_comment("/// # Safety")
+                    .doc_comment("///")
+                    .doc_comment(format!(
+                        "/// The member `{}` selects must be the last one written to `{}`.",
+                        tag_name, union_name
+                    ))
+                    .fn_impl_item(&union_name, get, mk().block(vec![mk().expr_stmt(get_body)])),
+            );
+
+            let set = mk().fn_decl(
+                vec![
+                    mk().self_arg(SelfKind::Region(None, Mutability::Mutable)),
+                    mk().arg(enum_ty(), mk().ident_pat(&union_name)),
+                ],
+                FunctionRetTy::Default(DUMMY_SP),
+            );
+            let set_body = mk().match_expr(mk().path_expr(vec![union_name.as_str()]), set_arms);
+            methods.push(mk().pub_().fn_impl_item(
+                format!("set_{}", union_name),
+                set,
+                mk().block(vec![mk().expr_stmt(set_body)]),
+            ));
+        }
+
+        items.push(
+            mk().span(span)
+                .impl_item(mk().path_ty(vec![struct_name]), methods),
+        );
+        Ok(items)
+    }
+
+    /// The expression of a tag value stored in a tag field, which is also
+    /// used as its pattern
+    fn tag_expr(&self, tag_ty: CTypeId, tag: Tag) -> P<Expr> {
+        if let Some(constant) = tag.constant {
+            let enum_id = self.ast_context.parents.get(&constant).cloned();
+            let same_enum = match self.ast_context.resolve_type(tag_ty).kind {
+                CTypeKind::Enum(tag_enum) => Some(tag_enum) == enum_id,
+                _ => false,
+            };
+            if same_enum {
+                if let Some(path) = self.enum_variant_path(constant) {
+                    return path;
+                }
+                if let Some(name) = self.renamer.borrow().get(&constant) {
+                    if let Some(cur_file) = *self.cur_file.borrow() {
+                        self.add_import(cur_file, constant, &name);
+                    }
+                    return mk().path_expr(vec![name]);
+                }
+            }
+        }
+        signed_int_expr(tag.value)
+    }
+
+    /// The union a field of this type stores, if it may be a tagged union
+    fn tagged_union_of(&self, ty: CTypeId) -> Option<CRecordId> {
+        let union_id = match self.ast_context.resolve_type(ty).kind {
+            CTypeKind::Union(union_id) => union_id,
+            _ => return None,
+        };
+        match self.ast_context[union_id].kind {
+            CDeclKind::Union {
+                fields: Some(ref fields),
+                ..
+            } if !fields.is_empty() => {
+                let plain = fields.iter().all(|&f| match self.ast_context[f].kind {
+                    CDeclKind::Field { bitfield_width, .. } => bitfield_width.is_none(),
+                    _ => false,
+                });
+                if plain {
+                    Some(union_id)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a field of this type may be the tag of a union
+    fn is_tag_type(&self, ty: CTypeId) -> bool {
+        let kind = &self.ast_context.resolve_type(ty).kind;
+        match *kind {
+            CTypeKind::Bool => false,
+            CTypeKind::Enum(enum_id) => !self.flag_enums.contains(&enum_id),
+            _ => kind.is_integral_type(),
+        }
+    }
+
+    /// The union a type stores or points to, looking through arrays and
+    /// pointers
+    fn union_in(&self, ty: CTypeId) -> Option<CRecordId> {
+        match self.ast_context.resolve_type(ty).kind {
+            CTypeKind::ConstantArray(elt, _)
+            | CTypeKind::IncompleteArray(elt)
+            | CTypeKind::VariableArray(elt, _) => self.union_in(elt),
+            CTypeKind::Pointer(pointee) => self.union_in(pointee.ctype),
+            CTypeKind::Union(union_id) => Some(union_id),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, 'c> GuardWalker<'a, 'c> {
+    fn reject(&mut self, union_field: CFieldId, reason: &'static str) {
+        self.rejected.entry(union_field).or_insert(reason);
+    }
+
+    fn ast(&self) -> &'a TypedAstContext {
+        &self.t.ast_context
+    }
+
+    /// Walk a statement under the given guards, returning the guards that hold
+    /// after it
+    fn walk_stmt(&mut self, stmt_id: CStmtId, guards: Guards) -> Guards {
+        let ast = self.ast();
+        match ast[stmt_id].kind {
+            CStmtKind::Compound(ref stmts) => self.walk_stmts(stmts, guards),
+            CStmtKind::Expr(expr) => {
+                self.walk_expr(expr, &guards);
+                let assigned = self.tag_assignment(expr);
+                let mut guards = self.invalidate(guards, SomeId::Stmt(stmt_id));
+                if let Some((key, tag)) = assigned {
+                    guards.insert(key, vec![tag]);
+                }
+                guards
+            }
+            CStmtKind::If {
+                scrutinee,
+                true_variant,
+                false_variant,
+            } => {
+                self.walk_expr(scrutinee, &guards);
+                let after = self.invalidate(guards.clone(), SomeId::Stmt(stmt_id));
+                let base = self.invalidate(guards, SomeId::Expr(scrutinee));
+                self.walk_stmt(true_variant, self.with_guards(&base, scrutinee, true));
+                if let Some(false_variant) = false_variant {
+                    self.walk_stmt(false_variant, self.with_guards(&base, scrutinee, false));
+                }
+                after
+            }
+            CStmtKind::While {
+                condition, body, ..
+            }
+            | CStmtKind::ForLoop {
+                condition: Some(condition),
+                body,
+                ..
+            } => {
+                let guards = self.invalidate(guards, SomeId::Stmt(stmt_id));
+                for child in immediate_children_all_types(ast, stmt_id.into()) {
+                    match child {
+                        SomeId::Expr(expr) => self.walk_expr(expr, &guards),
+                        SomeId::Stmt(stmt) if stmt == body => {
+                            self.walk_stmt(body, self.with_guards(&guards, condition, true));
+                        }
+                        SomeId::Stmt(stmt) => {
+                            self.walk_stmt(stmt, guards.clone());
+                        }
+                        _ => {}
+                    }
+                }
+                guards
+            }
+            CStmtKind::Switch { scrutinee, body } => {
+                self.walk_expr(scrutinee, &guards);
+                let guards = self.invalidate(guards, SomeId::Stmt(stmt_id));
+                let key = self.tag_key(scrutinee);
+                self.switches.push(key.clone());
+                match ast[body].kind {
+                    CStmtKind::Compound(ref stmts) => self.walk_cases(stmts, key, &guards),
+                    _ => {
+                        self.walk_stmt(body, guards.clone());
+                    }
+                }
+                self.switches.pop();
+                guards
+            }
+            CStmtKind::Case(_, sub, _) | CStmtKind::Default(sub) => {
+                // Cases that are not directly in the body of their `switch` are
+                // not known to be guarded by its tag
+                let mut guards = guards;
+                if let Some(Some(key)) = self.switches.last() {
+                    guards.remove(key);
+                }
+                self.walk_stmt(sub, guards)
+            }
+            CStmtKind::Label(sub) => self.walk_stmt(sub, Guards::new()),
+            _ => {
+                let after = self.invalidate(guards.clone(), SomeId::Stmt(stmt_id));
+                for child in immediate_children_all_types(ast, stmt_id.into()) {
+                    match child {
+                        SomeId::Expr(expr) => self.walk_expr(expr, &guards),
+                        SomeId::Stmt(stmt) => {
+                            self.walk_stmt(stmt, after.clone());
+                        }
+                        SomeId::Decl(decl) => {
+                            if let CDeclKind::Variable {
+                                initializer: Some(init),
+                                ..
+                            } = ast[decl].kind
+                            {
+                                self.walk_expr(init, &guards);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                after
+            }
+        }
+    }
+
+    /// Walk a list of statements. Assignments to union members of an object
+    /// that are immediately followed by the assignment of a constant to a tag
+    /// of that object are guarded by that constant.
+    fn walk_stmts(&mut self, stmts: &[CStmtId], mut guards: Guards) -> Guards {
+        for (i, &stmt) in stmts.iter().enumerate() {
+            let mut current = guards.clone();
+            for &next in &stmts[i..] {
+                let is_member_write = match self.ast()[next].kind {
+                    CStmtKind::Expr(expr) => self.member_write(expr),
+                    _ => false,
+                };
+                if is_member_write {
+                    continue;
+                }
+                if let CStmtKind::Expr(expr) = self.ast()[next].kind {
+                    if let Some((key, tag)) = self.tag_assignment(expr) {
+                        if next != stmt {
+                            current.insert(key, vec![tag]);
+                        }
+                    }
+                }
+                break;
+            }
+            let after = self.walk_stmt(stmt, current);
+            guards = self.invalidate(guards, SomeId::Stmt(stmt));
+            for (key, tags) in after {
+                guards.insert(key, tags);
+            }
+        }
+        guards
+    }
+
+    /// Walk the statements of the body of a `switch`, each `case` of which is
+    /// guarded by the values of the tag that reach it
+    fn walk_cases(&mut self, stmts: &[CStmtId], key: Option<Key>, guards: &Guards) {
+        let mut current = guards.clone();
+        let mut region: Option<Vec<Tag>> = None;
+        let mut falls_through = false;
+        for &stmt in stmts {
+            let mut sub = stmt;
+            let mut labels = vec![];
+            loop {
+                match self.ast()[sub].kind {
+                    CStmtKind::Case(expr, next, value) => {
+                        labels.push(Some(Tag {
+                            value: const_value(value),
+                            constant: self.enum_constant(expr),
+                        }));
+                        sub = next;
+                    }
+                    CStmtKind::Default(next) => {
+                        labels.push(None);
+                        sub = next;
+                    }
+                    _ => break,
+                }
+            }
+            if !labels.is_empty() {
+                let mut tags = match region.take() {
+                    Some(tags) if falls_through => Some(tags),
+                    _ if falls_through => None,
+                    _ => Some(vec![]),
+                };
+                for label in labels {
+                    tags = match (tags, label) {
+                        (Some(mut tags), Some(tag)) => {
+                            tags.push(tag);
+                            Some(tags)
+                        }
+                        _ => None,
+                    };
+                }
+                region = tags;
+                current = guards.clone();
+                if let Some(ref key) = key {
+                    match region {
+                        Some(ref tags) => {
+                            current.insert(key.clone(), tags.clone());
+                        }
+                        None => {
+                            current.remove(key);
+                        }
+                    }
+                }
+            }
+            current = self.walk_stmt(sub, current);
+            falls_through = !diverges(self.ast(), sub);
+        }
+    }
+
+    /// Walk an expression under the given guards
+    fn walk_expr(&mut self, expr_id: CExprId, guards: &Guards) {
+        let ast = self.ast();
+        if let Some(union_id) = ast[expr_id].kind.get_type().and_then(|t| self.t.union_in(t)) {
+            let is_member_base = match ast[expr_id].kind {
+                CExprKind::Member(..) => false,
+                _ => true,
+            };
+            // Member accesses through the union are handled by their parent
+            if is_member_base {
+                for (&union_field, _) in self.unions.clone().iter() {
+                    if let CDeclKind::Field { typ, .. } = ast[union_field].kind {
+                        if self.t.tagged_union_of(typ.ctype) == Some(union_id) {
+                            self.reject(union_field, "the union is used as a whole");
+                        }
+                    }
+                }
+            }
+        }
+
+        match ast[expr_id].kind {
+            CExprKind::Member(_, base, member, _, _) => {
+                if let CExprKind::Member(_, object, union_field, kind, _) = ast[strip(ast, base)].kind
+                {
+                    if self.unions.contains_key(&union_field) {
+                        self.record_access(object, kind, union_field, member, guards);
+                        return self.walk_expr(object, guards);
+                    }
+                }
+                if let CExprKind::Member(_, _, field, _, _) = ast[expr_id].kind {
+                    if self.unions.contains_key(&field) {
+                        self.reject(field, "the union is used as a whole");
+                    }
+                }
+                self.walk_expr(base, guards);
+            }
+            CExprKind::Binary(_, c_ast::BinOp::And, lhs, rhs, _, _) => {
+                self.walk_expr(lhs, guards);
+                self.walk_expr(rhs, &self.with_guards(guards, lhs, true));
+            }
+            CExprKind::Binary(_, c_ast::BinOp::Or, lhs, rhs, _, _) => {
+                self.walk_expr(lhs, guards);
+                self.walk_expr(rhs, &self.with_guards(guards, lhs, false));
+            }
+            CExprKind::Conditional(_, cond, lhs, rhs) => {
+                self.walk_expr(cond, guards);
+                let guards = self.invalidate(guards.clone(), SomeId::Expr(cond));
+                self.walk_expr(lhs, &self.with_guards(&guards, cond, true));
+                self.walk_expr(rhs, &self.with_guards(&guards, cond, false));
+            }
+            _ => {
+                for child in immediate_children_all_types(ast, expr_id.into()) {
+                    match child {
+                        SomeId::Expr(expr) => self.walk_expr(expr, guards),
+                        SomeId::Stmt(stmt) => {
+                            self.walk_stmt(stmt, guards.clone());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record an access to a member of a candidate union field of `object`
+    fn record_access(
+        &mut self,
+        object: CExprId,
+        kind: MemberKind,
+        union_field: CFieldId,
+        member: CFieldId,
+        guards: &Guards,
+    ) {
+        let path = object_path(self.ast(), object, kind);
+        let struct_id = self.unions[&union_field];
+        for &tag_field in &self.tags[&struct_id] {
+            let tags = path
+                .as_ref()
+                .and_then(|path| guards.get(&(path.clone(), tag_field)));
+            let selection = self
+                .selections
+                .entry((union_field, tag_field))
+                .or_insert_with(|| Selection::Members(IndexMap::new()));
+            let members = match *selection {
+                Selection::Members(ref mut members) => members,
+                Selection::Invalid(_) => continue,
+            };
+            let tags = match tags {
+                Some(tags) => tags,
+                None => {
+                    *selection = Selection::Invalid("a member is accessed without testing a tag");
+                    continue;
+                }
+            };
+            let mut conflict = false;
+            for &tag in tags {
+                let entry = members.entry(tag.value).or_insert((tag, member));
+                if entry.0.constant.is_none() {
+                    entry.0.constant = tag.constant;
+                }
+                conflict |= entry.1 != member;
+            }
+            if conflict {
+                *selection = Selection::Invalid("a value of the tag selects several members");
+            }
+        }
+    }
+
+    /// The guards that hold when `cond` evaluates to `truth`, on top of the
+    /// given ones
+    fn with_guards(&self, guards: &Guards, cond: CExprId, truth: bool) -> Guards {
+        let mut guards = guards.clone();
+        for (key, tags) in self.condition_guards(cond, truth) {
+            guards.insert(key, tags);
+        }
+        guards
+    }
+
+    /// The tag values known to be held when `cond` evaluates to `truth`
+    fn condition_guards(&self, cond: CExprId, truth: bool) -> Vec<(Key, Vec<Tag>)> {
+        let ast = self.ast();
+        match ast[strip_casts(ast, cond)].kind {
+            CExprKind::Binary(_, c_ast::BinOp::EqualEqual, lhs, rhs, _, _) if truth => {
+                self.tag_test(lhs, rhs).into_iter().map(|(key, tag)| (key, vec![tag])).collect()
+            }
+            CExprKind::Binary(_, c_ast::BinOp::NotEqual, lhs, rhs, _, _) if !truth => {
+                self.tag_test(lhs, rhs).into_iter().map(|(key, tag)| (key, vec![tag])).collect()
+            }
+            CExprKind::Binary(_, c_ast::BinOp::And, lhs, rhs, _, _) if truth => {
+                let mut guards = self.condition_guards(lhs, true);
+                guards.extend(self.condition_guards(rhs, true));
+                guards
+            }
+            CExprKind::Binary(_, c_ast::BinOp::Or, lhs, rhs, _, _) if !truth => {
+                let mut guards = self.condition_guards(lhs, false);
+                guards.extend(self.condition_guards(rhs, false));
+                guards
+            }
+            CExprKind::Binary(_, c_ast::BinOp::Or, lhs, rhs, _, _) => {
+                let lhs = self.condition_guards(lhs, true);
+                let rhs = self.condition_guards(rhs, true);
+                match (lhs.as_slice(), rhs.as_slice()) {
+                    ([(lhs_key, lhs_tags)], [(rhs_key, rhs_tags)]) if lhs_key == rhs_key => {
+                        let mut tags = lhs_tags.clone();
+                        tags.extend(rhs_tags.iter().cloned());
+                        vec![(lhs_key.clone(), tags)]
+                    }
+                    _ => vec![],
+                }
+            }
+            CExprKind::Unary(_, c_ast::UnOp::Not, arg, _) => self.condition_guards(arg, !truth),
+            _ => vec![],
+        }
+    }
+
+    /// The tag and value compared by `lhs == rhs`
+    fn tag_test(&self, lhs: CExprId, rhs: CExprId) -> Option<(Key, Tag)> {
+        let test = |field: CExprId, value: CExprId| {
+            let key = self.tag_key(field)?;
+            let tag = self.tag_value(key.1, value)?;
+            Some((key, tag))
+        };
+        test(lhs, rhs).or_else(|| test(rhs, lhs))
+    }
+
+    /// The assignment of a constant to a tag done by an expression
+    fn tag_assignment(&self, expr: CExprId) -> Option<(Key, Tag)> {
+        match self.ast()[expr].kind {
+            CExprKind::Binary(_, c_ast::BinOp::Assign, lhs, rhs, _, _) => {
+                let key = self.tag_key(lhs)?;
+                let tag = self.tag_value(key.1, rhs)?;
+                Some((key, tag))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether an expression assigns a member of a candidate union
+    fn member_write(&self, expr: CExprId) -> bool {
+        let ast = self.ast();
+        match ast[expr].kind {
+            CExprKind::Binary(_, c_ast::BinOp::Assign, lhs, _, _, _) => match ast[lhs].kind {
+                CExprKind::Member(_, base, _, _, _) => match ast[strip(ast, base)].kind {
+                    CExprKind::Member(_, _, field, _, _) => self.unions.contains_key(&field),
+                    _ => false,
+                },
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// The tag field an expression reads or writes
+    fn tag_key(&self, expr: CExprId) -> Option<Key> {
+        let ast = self.ast();
+        match ast[strip_casts(ast, expr)].kind {
+            CExprKind::Member(_, object, field, kind, _) => {
+                let struct_id = *ast.parents.get(&field)?;
+                if !self.tags.get(&struct_id)?.contains(&field) {
+                    return None;
+                }
+                Some((object_path(ast, object, kind)?, field))
+            }
+            _ => None,
+        }
+    }
+
+    /// The constant value of an expression compared to or stored in a tag
+    fn tag_value(&self, tag_field: CFieldId, expr: CExprId) -> Option<Tag> {
+        let ast = self.ast();
+        let tag = match ast[strip_casts(ast, expr)].kind {
+            CExprKind::DeclRef(_, decl_id, _) => match ast[decl_id].kind {
+                CDeclKind::EnumConstant { value, .. } => Tag {
+                    value: const_value(value),
+                    constant: Some(decl_id),
+                },
+                _ => return None,
+            },
+            CExprKind::Literal(_, CLiteral::Integer(value, _)) => Tag {
+                value: value as i64,
+                constant: None,
+            },
+            CExprKind::Unary(_, c_ast::UnOp::Negate, arg, _) => match ast[strip_casts(ast, arg)].kind
+            {
+                CExprKind::Literal(_, CLiteral::Integer(value, _)) => Tag {
+                    value: -(value as i64),
+                    constant: None,
+                },
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let tag_ty = match ast[tag_field].kind {
+            CDeclKind::Field { typ, .. } => typ.ctype,
+            _ => return None,
+        };
+        if tag.value < 0 && ast.resolve_type(tag_ty).kind.is_unsigned_integral_type() {
+            return None;
+        }
+        Some(tag)
+    }
+
+    /// The enum constant a `case` expression refers to
+    fn enum_constant(&self, expr: CExprId) -> Option<CEnumConstantId> {
+        let ast = self.ast();
+        match ast[strip_casts(ast, expr)].kind {
+            CExprKind::DeclRef(_, decl_id, _) => match ast[decl_id].kind {
+                CDeclKind::EnumConstant { .. } => Some(decl_id),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Drop the guards that may no longer hold once a node is evaluated,
+    /// since it writes to their tag or to an object on the path to it
+    fn invalidate(&self, mut guards: Guards, node: SomeId) -> Guards {
+        if guards.is_empty() {
+            return guards;
+        }
+        let ast = self.ast();
+        let mut written = vec![];
+        for id in DFExpr::new(ast, node) {
+            let target = match id {
+                SomeId::Expr(expr) => match ast[expr].kind {
+                    CExprKind::Binary(_, op, lhs, _, _, _)
+                        if op == c_ast::BinOp::Assign || op.underlying_assignment().is_some() =>
+                    {
+                        lhs
+                    }
+                    CExprKind::Unary(_, op, arg, _) => match op {
+                        c_ast::UnOp::PreIncrement
+                        | c_ast::UnOp::PostIncrement
+                        | c_ast::UnOp::PreDecrement
+                        | c_ast::UnOp::PostDecrement
+                        | c_ast::UnOp::AddressOf => arg,
+                        _ => continue,
+                    },
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if let Some(path) = path(ast, target) {
+                written.push(path);
+            }
+        }
+        guards.retain(|(object, tag), _| {
+            !written.iter().any(|path| {
+                object.starts_with(path)
+                    || (path.len() == object.len() + 1
+                        && path.starts_with(object)
+                        && path[object.len()] == PathElem::Field(*tag))
+            })
+        });
+        guards
+    }
+}
+
+/// Whether control never flows past the end of a statement
+fn diverges(ast: &TypedAstContext, stmt_id: CStmtId) -> bool {
+    match ast[stmt_id].kind {
+        CStmtKind::Break
+        | CStmtKind::Continue
+        | CStmtKind::Return(_)
+        | CStmtKind::Goto(_) => true,
+        CStmtKind::Compound(ref stmts) => stmts.last().map_or(false, |&s| diverges(ast, s)),
+        _ => false,
+    }
+}
+
+fn const_value(value: ConstIntExpr) -> i64 {
+    match value {
+        ConstIntExpr::I(value) => value,
+        ConstIntExpr::U(value) => value as i64,
+    }
+}
+
+/// Look through parentheses and conversions that keep the object an
+/// expression designates
+fn strip(ast: &TypedAstContext, mut expr: CExprId) -> CExprId {
+    loop {
+        expr = match ast[expr].kind {
+            CExprKind::Paren(_, sub)
+            | CExprKind::ImplicitCast(_, sub, CastKind::LValueToRValue, _, _)
+            | CExprKind::ImplicitCast(_, sub, CastKind::NoOp, _, _) => sub,
+            _ => return expr,
+        }
+    }
+}
+
+/// Look through parentheses, implicit conversions and constant expressions
+fn strip_casts(ast: &TypedAstContext, mut expr: CExprId) -> CExprId {
+    loop {
+        expr = match ast[expr].kind {
+            CExprKind::Paren(_, sub)
+            | CExprKind::ImplicitCast(_, sub, _, _, _)
+            | CExprKind::ConstantExpr(_, sub, _) => sub,
+            _ => return expr,
+        }
+    }
+}
+
+/// The path to the object an lvalue expression designates
+fn path(ast: &TypedAstContext, expr: CExprId) -> Option<Vec<PathElem>> {
+    match ast[strip(ast, expr)].kind {
+        CExprKind::DeclRef(_, decl_id, _) => Some(vec![PathElem::Var(decl_id)]),
+        CExprKind::Member(_, base, field, kind, _) => {
+            let mut path = object_path(ast, base, kind)?;
+            path.push(PathElem::Field(field));
+            Some(path)
+        }
+        CExprKind::Unary(_, c_ast::UnOp::Deref, arg, _) => {
+            let mut path = path(ast, arg)?;
+            path.push(PathElem::Deref);
+            Some(path)
+        }
+        CExprKind::ArraySubscript(_, base, index, _) => match ast[strip_casts(ast, index)].kind {
+            CExprKind::Literal(_, CLiteral::Integer(index, _)) => {
+                let mut path = path(ast, base)?;
+                path.push(PathElem::Index(index));
+                Some(path)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The path to the struct object whose field a member expression designates
+fn object_path(ast: &TypedAstContext, base: CExprId, kind: MemberKind) -> Option<Vec<PathElem>> {
+    let mut path = path(ast, base)?;
+    if let MemberKind::Arrow = kind {
+        path.push(PathElem::Deref);
+    }
+    Some(path)
+}
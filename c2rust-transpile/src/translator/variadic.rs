@@ -147,7 +147,7 @@ impl<'c> Translation<'c> {
                 .map_or(false, |ty| self.ast_context.is_forward_declared_type(ty.ctype))
             {
                 real_arg_ty = Some(arg_ty.clone());
-                arg_ty = mk().mutbl().ptr_ty(mk().path_ty(vec!["libc", "c_void"]));
+                arg_ty = mk().mutbl().ptr_ty(self.c_type("c_void"));
             }
 
             val.and_then(|val| {
@@ -8,7 +8,9 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use c2rust_transpile::{Diagnostic, ReplaceMode, TranspilerConfig};
+use c2rust_transpile::{
+    CfgDumpStage, CTypeProvider, Diagnostic, RenameMap, ReplaceMode, TranspilerConfig,
+};
 
 fn main() {
     let yaml = load_yaml!("../transpile.yaml");
@@ -42,12 +44,29 @@ fn main() {
         _ => panic!("Invalid log level"),
     };
 
+    let rename_map = match matches.value_of("rename-map") {
+        Some(path) => RenameMap::from_file(Path::new(path)).unwrap_or_else(|e| {
+            panic!("Could not load rename map from {}: {}", path, e)
+        }),
+        None => RenameMap::default(),
+    };
+
     let mut tcfg = TranspilerConfig {
         dump_untyped_context: matches.is_present("dump-untyped-clang-ast"),
         dump_typed_context: matches.is_present("dump-typed-clang-ast"),
         pretty_typed_context: matches.is_present("pretty-typed-clang-ast"),
         dump_function_cfgs: matches.is_present("dump-function-cfgs"),
         json_function_cfgs: matches.is_present("json-function-cfgs"),
+        html_function_cfgs: matches.is_present("html-function-cfgs"),
+        cfg_dump_filter: matches
+            .value_of("cfg-dump-filter")
+            .map(|filter| Regex::new(filter).unwrap()),
+        cfg_dump_stage: match matches.value_of("cfg-dump-stage") {
+            Some("raw") => CfgDumpStage::Raw,
+            Some("pruned") => CfgDumpStage::Pruned,
+            Some("relooped") => CfgDumpStage::Relooped,
+            _ => panic!("Invalid option"),
+        },
         dump_cfg_liveness: matches.is_present("dump-cfgs-liveness"),
         dump_structures: matches.is_present("dump-structures"),
         debug_ast_exporter: matches.is_present("debug-ast-exporter"),
@@ -56,6 +75,11 @@ fn main() {
         incremental_relooper: !matches.is_present("no-incremental-relooper"),
         fail_on_error: matches.is_present("fail-on-error"),
         fail_on_multiple: matches.is_present("fail-on-multiple"),
+        split_nodes: if matches.is_present("split-nodes") {
+            Some(value_t!(matches, "split-nodes-budget", usize).unwrap_or_else(|e| e.exit()))
+        } else {
+            None
+        },
         filter: {
             if matches.is_present("filter") {
                 let filter = matches.value_of("filter").unwrap();
@@ -79,6 +103,13 @@ fn main() {
 
         translate_const_macros: matches.is_present("translate-const-macros"),
         translate_fn_macros: matches.is_present("translate-fn-macros"),
+        translate_restrict_refs: matches.is_present("translate-restrict-refs"),
+        translate_slice_params: matches.is_present("translate-slice-params"),
+        translate_c_strings: matches.is_present("translate-c-strings"),
+        translate_enums: matches.is_present("translate-enums"),
+        translate_tagged_unions: matches.is_present("translate-tagged-unions"),
+        rust_naming: matches.is_present("rust-naming"),
+        rename_map,
         disable_refactoring: matches.is_present("disable-refactoring"),
         preserve_unused_functions: matches.is_present("preserve-unused-functions"),
 
@@ -88,6 +119,7 @@ fn main() {
         overwrite_existing: matches.is_present("overwrite-existing"),
         reduce_type_annotations: matches.is_present("reduce-type-annotations"),
         reorganize_definitions: matches.is_present("reorganize-definitions"),
+        header_modules: matches.is_present("header-modules"),
         emit_modules: matches.is_present("emit-modules"),
         emit_build_files: matches.is_present("emit-build-files"),
         output_dir: matches.value_of("output-dir").map(PathBuf::from),
@@ -103,10 +135,21 @@ fn main() {
             }
         },
         replace_unsupported_decls: ReplaceMode::Extern,
+        c_types: match matches.value_of("c-types") {
+            Some("libc") => CTypeProvider::Libc,
+            Some("core-ffi") => CTypeProvider::CoreFfi,
+            Some("ctypes") => CTypeProvider::Ctypes,
+            _ => panic!("Invalid option"),
+        },
         emit_no_std: matches.is_present("emit-no-std"),
         enabled_warnings,
         log_level,
     };
+    // header-modules groups definitions by header, and shares them in the crate
+    if tcfg.header_modules {
+        tcfg.reorganize_definitions = true;
+        tcfg.emit_build_files = true;
+    };
     // binaries imply emit-build-files
     if !tcfg.binaries.is_empty() {
         tcfg.emit_build_files = true
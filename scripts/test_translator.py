@@ -6,6 +6,7 @@ import sys
 import logging
 import argparse
 import re
+import shutil
 
 from common import (
     config as c,
@@ -61,6 +62,16 @@ class CStaticLibrary:
         self.obj_files = obj_files
 
 
+def flag_value(flags: Set[str], name: str) -> Optional[str]:
+    """
+    The value of a `name=value` flag, if it is given.
+    """
+    for flag in flags:
+        if flag.startswith(name + "="):
+            return flag[len(name) + 1:]
+    return None
+
+
 class CFile:
     def __init__(self, logLevel: str, path: str, flags: Set[str] = None) -> None:
         if not flags:
@@ -73,11 +84,53 @@ class CFile:
         self.translate_const_macros = "translate_const_macros" in flags
         self.reorganize_definitions = "reorganize_definitions" in flags
         self.emit_build_files = "emit_build_files" in flags
+        self.translate_restrict_refs = "translate_restrict_refs" in flags
+        self.translate_slice_params = "translate_slice_params" in flags
+        self.translate_c_strings = "translate_c_strings" in flags
+        self.split_nodes = "split_nodes" in flags
+        self.translate_enums = "translate_enums" in flags
+        self.translate_tagged_unions = "translate_tagged_unions" in flags
+        self.rust_naming = "rust_naming" in flags
+        self.header_modules = "header_modules" in flags
+        self.translate_cxx = "translate" in flags
+        self.dump_cfgs = "dump_cfgs" in flags
+        self.cfg_dump_filter = flag_value(flags, "cfg_dump_filter")
+        self.cfg_dump_stage = flag_value(flags, "cfg_dump_stage")
+        self.c_types = flag_value(flags, "c_types")
+        self.emit_no_std = "emit_no_std" in flags
+
+    def cfg_dump_dir(self) -> str:
+        """
+        The directory CFGs are dumped into, which the transpiler runs in.
+        """
+        extensionless_file, _ = os.path.splitext(self.path)
+        return extensionless_file + "_cfgs"
 
     def translate(self, cc_db, ld_lib_path, extra_args: List[str] = []) -> RustFile:
         extensionless_file, _ = os.path.splitext(self.path)
 
-        # run the transpiler
+        retcode, stderr = self._run_transpiler(cc_db, ld_lib_path, extra_args)
+
+        if retcode != 0:
+            raise NonZeroReturn(stderr)
+
+        return RustFile(extensionless_file + ".rs")
+
+    def missing_errors(self, cc_db, ld_lib_path, extra_args: List[str] = []) -> List[str]:
+        """
+        Run the transpiler on a file that is expected not to translate, and
+        return the errors given by its `// expected-error: ...` comments that
+        the exporter did not report.
+        """
+        with open(self.path, 'r', encoding="utf-8") as file:
+            expected = re.findall(r"// expected-error: (.*)\n", file.read())
+
+        _, stderr = self._run_transpiler(cc_db, ld_lib_path, extra_args)
+
+        return [error for error in expected
+                if "c2rust: " + error.strip() not in stderr]
+
+    def _run_transpiler(self, cc_db, ld_lib_path, extra_args: List[str]):
         transpiler = get_cmd_or_die(c.TRANSPILER)
 
         args = [
@@ -97,6 +150,34 @@ class CFile:
             args.append("--reorganize-definitions")
         if self.emit_build_files:
             args.append("--emit-build-files")
+        if self.translate_restrict_refs:
+            args.append("--translate-restrict-refs")
+        if self.translate_slice_params:
+            args.append("--translate-slice-params")
+        if self.translate_c_strings:
+            args.append("--translate-c-strings")
+        if self.translate_enums:
+            args.append("--translate-enums")
+        if self.translate_tagged_unions:
+            args.append("--translate-tagged-unions")
+        if self.rust_naming:
+            args.append("--rust-naming")
+        if self.split_nodes:
+            args.append("--split-nodes")
+        if self.header_modules:
+            args.append("--header-modules")
+        if self.dump_cfgs:
+            args.append("--ddump-function-cfgs")
+            args.append("--json-function-cfgs")
+            args.append("--html-function-cfgs")
+        if self.cfg_dump_filter:
+            args.extend(["--cfg-dump-filter", self.cfg_dump_filter])
+        if self.cfg_dump_stage:
+            args.extend(["--cfg-dump-stage", self.cfg_dump_stage])
+        if self.c_types:
+            args.extend(["--c-types", self.c_types])
+        if self.emit_no_std:
+            args.append("--emit-no-std")
 
         if self.logLevel == 'DEBUG':
             args.append("--log-level=debug")
@@ -104,7 +185,14 @@ class CFile:
         args.append("--")
         args.extend(extra_args)
 
-        with pb.local.env(RUST_BACKTRACE='1', LD_LIBRARY_PATH=ld_lib_path):
+        # CFGs are dumped into the working directory, so give them one of their own
+        cwd = os.getcwd()
+        if self.dump_cfgs:
+            cwd = self.cfg_dump_dir()
+            shutil.rmtree(cwd, ignore_errors=True)
+            ensure_dir(cwd)
+
+        with pb.local.env(RUST_BACKTRACE='1', LD_LIBRARY_PATH=ld_lib_path), pb.local.cwd(cwd):
             # log the command in a format that's easy to re-run
             translation_cmd = "LD_LIBRARY_PATH=" + ld_lib_path + " \\\n"
             translation_cmd += str(transpiler[args])
@@ -115,10 +203,7 @@ class CFile:
             logging.debug("stdout:\n%s", stdout)
             logging.debug("stderr:\n%s", stderr)
 
-        if retcode != 0:
-            raise NonZeroReturn(stderr)
-
-        return RustFile(extensionless_file + ".rs")
+        return retcode, stderr
 
 
 def build_static_library(c_files: Iterable[CFile],
@@ -191,6 +276,7 @@ class TestFile(RustFile):
 class TestDirectory:
     def __init__(self, full_path: str, files: str, keep: List[str], logLevel: str) -> None:
         self.c_files = []
+        self.cpp_files = []
         self.rs_test_files = []
         self.full_path = full_path
         self.full_path_src = os.path.join(full_path, "src")
@@ -218,6 +304,15 @@ class TestDirectory:
                     if c_file:
                         self.c_files.append(c_file)
 
+                elif ext == ".cpp":
+                    cpp_file = self._read_c_file(path)
+
+                    # C++ files in the supported subset are tested like C files
+                    if cpp_file and cpp_file.translate_cxx:
+                        self.c_files.append(cpp_file)
+                    elif cpp_file:
+                        self.cpp_files.append(cpp_file)
+
                 elif (filename.startswith("test_") and ext == ".rs" and
                       files.search(filename)):
                     rs_test_file = self._read_rust_test_file(path)
@@ -274,18 +369,18 @@ class TestDirectory:
         if message:
             sys.stdout.write(message)
 
-    def _generate_cc_db(self, c_file_path: str) -> None:
-        directory, cfile = os.path.split(c_file_path)
-
-        compile_commands = """ \
-        [
+    def _generate_cc_db(self, *c_file_paths: str) -> None:
+        commands = []
+        for c_file_path in c_file_paths:
+            directory, cfile = os.path.split(c_file_path)
+            commands.append("""
           {{
             "arguments": [ "cc", "-D_FORTIFY_SOURCE=0", "-c", "{0}" ],
             "directory": "{1}",
             "file": "{0}"
-          }}
-        ]
-        """.format(cfile, directory)
+          }}""".format(cfile, directory))
+
+        compile_commands = " [" + ",".join(commands) + "\n]\n"
 
         cc_db = os.path.join(directory, "compile_commands.json")
 
@@ -357,8 +452,18 @@ class TestDirectory:
         if 'LD_LIBRARY_PATH' in pb.local.env:
             ld_lib_path += ':' + pb.local.env['LD_LIBRARY_PATH']
 
+        # .c -> .rs, for the files sharing the modules of their headers, which
+        # are translated together
+        shared_c_files = [c_file for c_file in self.c_files if c_file.header_modules]
+        if shared_c_files:
+            outcomes.extend(self._translate_shared(shared_c_files, ld_lib_path,
+                                                   rust_file_builder))
+
         # .c -> .rs
         for c_file in self.c_files:
+            if c_file.header_modules:
+                continue
+
             _, c_file_short = os.path.split(c_file.path)
             description = "{}: translating the C file into Rust...".format(
                 c_file_short)
@@ -383,6 +488,8 @@ class TestDirectory:
                 continue
 
             self.generated_files["rust_src"].append(translated_rust_file)
+            if c_file.dump_cfgs:
+                self.generated_files["rust_src"].append(c_file.cfg_dump_dir())
             if c_file.emit_build_files:
                 self.generated_files["rust_src"].append(self.full_path + "/src/Cargo.toml")
                 self.generated_files["rust_src"].append(self.full_path + "/src/build.rs")
@@ -395,6 +502,33 @@ class TestDirectory:
             rust_file_builder.add_mod(RustMod(extensionless_rust_file,
                                               RustVisibility.Public))
 
+        # .cpp -> diagnostics
+        for cpp_file in self.cpp_files:
+            _, cpp_file_short = os.path.split(cpp_file.path)
+            description = "{}: checking the reported errors...".format(
+                cpp_file_short)
+
+            self.print_status(Colors.WARNING, "RUNNING", description)
+
+            self._generate_cc_db(cpp_file.path)
+
+            missing = cpp_file.missing_errors(self.generated_files["cc_db"],
+                                              ld_lib_path)
+            if missing:
+                self.print_status(Colors.FAIL, "FAILED", "check errors of " +
+                                  cpp_file_short)
+                sys.stdout.write('\n')
+                for error in missing:
+                    sys.stdout.write("missing error: {}\n".format(error))
+
+                outcomes.append(TestOutcome.UnexpectedFailure)
+            else:
+                self.print_status(Colors.OKGREEN, "OK", "check errors of " +
+                                  cpp_file_short)
+                sys.stdout.write('\n')
+
+                outcomes.append(TestOutcome.Success)
+
         match_arms = []
         rustc_extra_args = ["-C", "target-cpu=native"]
 
@@ -524,6 +658,61 @@ class TestDirectory:
             self.print_status(Colors.OKBLUE, "N/A", display_text)
         return outcomes
 
+    def _translate_shared(self, c_files: List[CFile], ld_lib_path: str,
+                          rust_file_builder: RustFileBuilder) -> List[TestOutcome]:
+        """
+        Translate C files into the modules of one crate, sharing the modules
+        of the headers they include, and add them to the test binary.
+        """
+        description = "translating the C files sharing headers into Rust..."
+
+        self.print_status(Colors.WARNING, "RUNNING", description)
+
+        self._generate_cc_db(*[c_file.path for c_file in c_files])
+
+        try:
+            logging.debug("translating %d files sharing headers", len(c_files))
+            c_files[0].translate(self.generated_files["cc_db"], ld_lib_path,
+                                 extra_args=["-march=native"])
+        except NonZeroReturn as exception:
+            self.print_status(Colors.FAIL, "FAILED", "translate files sharing headers")
+            sys.stdout.write('\n')
+            sys.stdout.write(str(exception))
+
+            return [TestOutcome.UnexpectedFailure]
+
+        for c_file in c_files:
+            extensionless_file, _ = os.path.splitext(c_file.path)
+            self.generated_files["rust_src"].append(RustFile(extensionless_file + ".rs"))
+
+            _, extensionless_file_short = os.path.split(extensionless_file)
+            rust_file_builder.add_mod(RustMod(extensionless_file_short,
+                                              RustVisibility.Public))
+
+        for build_file in ["Cargo.toml", "build.rs", "c2rust-lib.rs", "rust-toolchain"]:
+            self.generated_files["rust_src"].append(self.full_path_src + "/" + build_file)
+
+        # The translated files import the shared declarations from
+        # `crate::c2rust_headers`, so the test binary declares it
+        headers_dir = os.path.join(self.full_path_src, "c2rust_headers")
+        ensure_dir(headers_dir)
+        headers = sorted(os.path.splitext(entry)[0] for entry in os.listdir(headers_dir)
+                         if entry.endswith(".rs") and entry != "mod.rs")
+        with open(os.path.join(headers_dir, "mod.rs"), 'w') as fh:
+            for header in headers:
+                fh.write("pub mod {};\n".format(header))
+
+        self.generated_files["rust_src"].append(headers_dir)
+        rust_file_builder.add_mod(RustMod("c2rust_headers", RustVisibility.Public))
+
+        # With `c_types=ctypes`, the C types are in a module of the crate too
+        ctypes_file = os.path.join(self.full_path_src, "ctypes.rs")
+        if os.path.exists(ctypes_file):
+            self.generated_files["rust_src"].append(ctypes_file)
+            rust_file_builder.add_mod(RustMod("ctypes", RustVisibility.Public))
+
+        return []
+
     def cleanup(self) -> None:
         if "all" in self.keep:
             return
@@ -537,7 +726,11 @@ class TestDirectory:
                 try:
                     # FIXME: Hacky. Some items are string paths,
                     # others are classes with a path attribute
-                    os.remove(getattr(file_path, "path", file_path))
+                    path = getattr(file_path, "path", file_path)
+                    if os.path.isdir(path):
+                        shutil.rmtree(path)
+                    else:
+                        os.remove(path)
                 except OSError:
                     pass
 
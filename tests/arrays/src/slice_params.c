@@ -0,0 +1,54 @@
+//! translate_slice_params
+#include <stddef.h>
+
+static int sum(const int *buf, size_t n) {
+    int total = 0;
+    for (size_t i = 0; i < n; i++) {
+        total += buf[i];
+    }
+    return total;
+}
+
+static void fill(int *buf, size_t n, int value) {
+    for (size_t i = 0; i < n; i++) {
+        buf[i] = value + (int)i;
+    }
+}
+
+static int forward(const int *buf, size_t n) {
+    return sum(buf, n);
+}
+
+// Reads past its length, so it has to keep a raw pointer
+static int past_end(const int *buf, size_t n) {
+    return buf[n];
+}
+
+// Modifies its length, so neither it nor its caller can take a slice
+static int halves(const int *data, size_t n) {
+    int total = 0;
+    for (size_t i = 0; i < n; i++) {
+        total += data[i];
+    }
+    n /= 2;
+    return total + (int)n;
+}
+
+static int forward_halves(const int *vals, size_t n) {
+    return halves(vals, n);
+}
+
+// Indexes with the pointer on the right, which keeps a raw pointer
+static int reversed(const int *items, size_t n) {
+    int total = 0;
+    for (size_t i = 0; i < n; i++) {
+        total += i[items];
+    }
+    return total;
+}
+
+int slice_params(int value) {
+    int arr[8];
+    fill(arr, 8, value);
+    return forward(arr, 8) + past_end(arr, 4) + forward_halves(arr, 8) + reversed(arr, 8);
+}
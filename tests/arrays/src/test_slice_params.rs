@@ -0,0 +1,33 @@
+extern crate libc;
+
+use slice_params::rust_slice_params;
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn slice_params(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("slice_params.rs");
+
+pub fn test_slice_params() {
+    for &value in &[1, -3, 100] {
+        unsafe {
+            assert_eq!(slice_params(value), rust_slice_params(value));
+        }
+    }
+    unsafe {
+        assert_eq!(rust_slice_params(1), 117);
+    }
+}
+
+pub fn test_slice_param_types() {
+    assert!(TRANSLATED.contains("buf: &[libc::c_int]"));
+    assert!(TRANSLATED.contains("buf: &mut [libc::c_int]"));
+    assert!(TRANSLATED.contains("buf: *const libc::c_int"));
+    assert!(TRANSLATED.contains("data: *const libc::c_int"));
+    assert!(TRANSLATED.contains("vals: *const libc::c_int"));
+    assert!(TRANSLATED.contains("items: *const libc::c_int"));
+}
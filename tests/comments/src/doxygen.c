@@ -0,0 +1,21 @@
+struct pair {
+  int first;
+  int second;
+};
+
+/// Adds the members of a pair.
+int pair_sum(struct pair p) {
+  return p.first + p.second;
+}
+
+/**
+ * @brief Scales the sum of a pair.
+ *
+ * @param p the pair, see @ref pair
+ * @param factor the factor
+ * @return the scaled sum
+ * @see pair_sum
+ */
+int pair_scale(struct pair p, int factor) {
+  return pair_sum(p) * factor;
+}
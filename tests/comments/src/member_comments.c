@@ -0,0 +1,33 @@
+/** A pair with documented members */
+struct pair {
+  int first; ///< the first member
+  /* before second */
+  int second; /* trailing comment on second */
+  /* end of pair */
+};
+
+enum shade {
+  LIGHT, ///< the light shade
+  DARK /* trailing comment on DARK */
+};
+
+int pair_score(struct pair p) {
+  int score = 0;
+  switch (p.first) {
+    /* before the first case */
+    case 1:
+    /* between case labels */
+    case 2:
+      score = 3; /* trailing in a case */
+      break;
+    /* before default */
+    default:
+      score = p.first;
+  }
+  if (p.second > 0) {
+    score += DARK;
+    /* end of if */
+  }
+  return score + p.second;
+  /* end of pair_score */
+}
@@ -0,0 +1,27 @@
+use crate::doxygen::{pair, rust_pair_scale};
+
+const TRANSLATED: &str = include_str!("doxygen.rs");
+
+pub fn test_doxygen() {
+    let scaled = unsafe { rust_pair_scale(pair { first: 2, second: 4 }, 3) };
+    assert_eq!(18, scaled);
+
+    assert!(TRANSLATED.contains("/// Adds the members of a pair.\n"));
+    assert!(TRANSLATED.contains(
+        "/// Scales the sum of a pair.
+///
+/// # Arguments
+///
+/// * `p` - the pair, see [`pair`]
+/// * `factor` - the factor
+///
+/// # Returns
+///
+/// the scaled sum
+///
+/// # See also
+///
+/// * [`pair_sum`](rust_pair_sum)
+"
+    ));
+}
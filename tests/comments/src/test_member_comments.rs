@@ -0,0 +1,34 @@
+use crate::member_comments::{pair, rust_pair_score};
+
+const TRANSLATED: &str = include_str!("member_comments.rs");
+
+fn position(needle: &str) -> usize {
+    TRANSLATED
+        .find(needle)
+        .unwrap_or_else(|| panic!("missing {:?} in the translation", needle))
+}
+
+pub fn test_member_comments() {
+    let score = unsafe { rust_pair_score(pair { first: 2, second: 4 }) };
+    assert_eq!(8, score);
+
+    // Doc comments stay on the items they document
+    assert!(position("/// A pair with documented members") < position("pub struct pair"));
+
+    // Member comments become doc comments on the field or variant they follow
+    assert!(position("/// the first member") < position("pub first:"));
+    assert!(position("/// the light shade") < position("LIGHT"));
+
+    // Trailing comments stay after their field or variant
+    assert!(position("pub second:") < position("/* trailing comment on second */"));
+    assert!(position("/* before second */") < position("pub second:"));
+    assert!(position("pub second:") < position("/* end of pair */"));
+    assert!(position("DARK") < position("/* trailing comment on DARK */"));
+
+    // Comments between case labels and at the end of blocks are kept in order
+    assert!(position("/* before the first case */") < position("/* between case labels */"));
+    assert!(position("/* between case labels */") < position("/* trailing in a case */"));
+    assert!(position("/* trailing in a case */") < position("/* before default */"));
+    assert!(position("/* end of if */") < position("/* end of pair_score */"));
+    assert!(position("return score + p.second") < position("/* end of pair_score */"));
+}
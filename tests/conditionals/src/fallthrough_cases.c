@@ -0,0 +1,18 @@
+int fallthrough_cases(int x) {
+    int total = 0;
+    switch (x) {
+        case 1:
+        /* one and two share their arm */
+        case 2:
+            total += 10;
+            break;
+        case 3:
+            total += 3;
+        case 4:
+            total += 4;
+            break;
+        default:
+            total = -1;
+    }
+    return total;
+}
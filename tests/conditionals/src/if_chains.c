@@ -0,0 +1,54 @@
+int char_class(int c) {
+    int r;
+    if (c == 'a')
+        r = 1;
+    else if (c == 'b' || c == 'c')
+        r = 2;
+    else if (c == 0)
+        r = 3;
+    else
+        r = 4;
+    return r;
+}
+
+int double_class(double x) {
+    int r;
+    if (x == 0)
+        r = 1;
+    else if (x == 1)
+        r = 2;
+    else if (x == 2.5)
+        r = 3;
+    else
+        r = 4;
+    return r;
+}
+
+int pointee_class(int *p) {
+    int r;
+    if (*p == 1)
+        r = 1;
+    else if (*p == 2)
+        r = 2;
+    else if (*p == 3)
+        r = 3;
+    else
+        r = 4;
+    return r;
+}
+
+static int current_mode;
+
+int static_class(int m) {
+    int r;
+    current_mode = m;
+    if (current_mode == 1)
+        r = 1;
+    else if (current_mode == 2)
+        r = 2;
+    else if (current_mode == 3)
+        r = 3;
+    else
+        r = 4;
+    return r;
+}
@@ -0,0 +1,25 @@
+extern crate libc;
+
+use self::libc::c_int;
+use fallthrough_cases::rust_fallthrough_cases;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn fallthrough_cases(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("fallthrough_cases.rs");
+
+pub fn test_fallthrough_cases() {
+    unsafe {
+        for x in 0..6 {
+            assert_eq!(rust_fallthrough_cases(x), fallthrough_cases(x));
+        }
+    }
+    // Empty cases share the arm they fall into rather than getting a copy of it
+    assert!(TRANSLATED.contains("1 | 2 =>"));
+    assert_eq!(TRANSLATED.matches("+= 10").count(), 1);
+    // Without --split-nodes, the case fallen into from a non-empty case isn't copied either
+    assert_eq!(TRANSLATED.matches("+= 4").count(), 1);
+}
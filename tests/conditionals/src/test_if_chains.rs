@@ -0,0 +1,44 @@
+extern crate libc;
+
+use if_chains::{rust_char_class, rust_double_class, rust_pointee_class, rust_static_class};
+
+use self::libc::{c_double, c_int};
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn char_class(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn double_class(_: c_double) -> c_int;
+
+    #[no_mangle]
+    fn pointee_class(_: *mut c_int) -> c_int;
+
+    #[no_mangle]
+    fn static_class(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("if_chains.rs");
+
+pub fn test_if_chains() {
+    unsafe {
+        for &c in &[0, 'a' as c_int, 'b' as c_int, 'c' as c_int, 'd' as c_int] {
+            assert_eq!(char_class(c), rust_char_class(c));
+        }
+        for &x in &[0.0, 0.5, 1.0, 2.5, 3.0] {
+            assert_eq!(double_class(x), rust_double_class(x));
+        }
+        for mut n in 0..5 {
+            assert_eq!(pointee_class(&mut n), rust_pointee_class(&mut n));
+        }
+        for n in 0..5 {
+            assert_eq!(static_class(n), rust_static_class(n));
+        }
+    }
+
+    // Only the chain comparing a local integer variable becomes a `match`
+    assert!(TRANSLATED.contains("match c {"));
+    assert!(TRANSLATED.contains("98 | 99 =>"));
+    assert_eq!(TRANSLATED.matches("match ").count(), 1);
+}
@@ -0,0 +1,24 @@
+//! translate_enums
+
+enum perms {
+    PERM_NONE = 0,
+    PERM_READ = 1,
+    PERM_WRITE = 2,
+    PERM_EXEC = 4,
+    PERM_RW = PERM_READ | PERM_WRITE,
+};
+
+static int allows(enum perms p, enum perms wanted) {
+    return (p & wanted) == wanted;
+}
+
+int flag_values(int n) {
+    enum perms p = PERM_READ;
+    if (n & 1)
+        p |= PERM_WRITE;
+    if (n & 2)
+        p = p | PERM_EXEC;
+    if (n & 4)
+        p &= PERM_RW;
+    return allows(p, PERM_RW) * 100 + allows(p, PERM_EXEC) * 10 + (int)p;
+}
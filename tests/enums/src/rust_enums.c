@@ -0,0 +1,109 @@
+//! translate_enums
+
+#include <string.h>
+
+/* Only used by functions of this file: becomes a Rust enum */
+enum color { RED, GREEN, BLUE };
+
+/* Parameter of an exported function, which other files may call with any value */
+enum dir { NORTH, EAST, SOUTH, WEST };
+
+/* Reaches an exported function through a pointer to a struct */
+enum shape { CIRCLE, SQUARE };
+
+/* Type of an exported variable through a typedef */
+typedef enum { OFF, ON } state;
+
+struct figure {
+    enum shape shape;
+    int size;
+};
+
+/* Stored in a union through a struct, where the other member may hold any value */
+enum suit { HEARTS, SPADES };
+
+struct card {
+    enum suit suit;
+    int rank;
+};
+
+union slot {
+    struct card card;
+    int raw[2];
+};
+
+/* Written through a `void *` by `memset`, along with the struct holding it */
+enum mood { CALM, ANGRY };
+
+struct person {
+    enum mood mood;
+    int age;
+};
+
+state power = OFF;
+
+static enum color next_color(enum color c) {
+    switch (c) {
+    case RED:
+        return GREEN;
+    case GREEN:
+        return BLUE;
+    case BLUE:
+        return RED;
+    }
+    return RED;
+}
+
+int turn_right(enum dir d) {
+    switch (d) {
+    case NORTH:
+        return EAST;
+    case EAST:
+        return SOUTH;
+    case SOUTH:
+        return WEST;
+    case WEST:
+        return NORTH;
+    }
+    return NORTH;
+}
+
+int area(const struct figure *f) {
+    switch (f->shape) {
+    case CIRCLE:
+        return 3 * f->size * f->size;
+    case SQUARE:
+        return f->size * f->size;
+    }
+    return 0;
+}
+
+int enum_values(int n) {
+    enum color c = RED;
+    struct figure f = { SQUARE, n };
+    for (int i = 0; i < n; i++)
+        c = next_color(c);
+    power = n > 2 ? ON : OFF;
+    return (int)c * 1000 + area(&f) * 10 + (power == ON);
+}
+
+static int suit_score(enum suit s) {
+    switch (s) {
+    case HEARTS:
+        return 1;
+    case SPADES:
+        return 2;
+    }
+    return 0;
+}
+
+int enum_punning(int n) {
+    union slot slot;
+    struct person p;
+
+    slot.raw[0] = n % 3;
+    slot.raw[1] = n;
+    memset(&p, 0, sizeof p);
+    p.age = n;
+    return suit_score(slot.card.suit) * 100 + (p.mood == CALM) * 10 + p.age;
+}
@@ -0,0 +1,28 @@
+extern crate libc;
+
+use flag_enums::rust_flag_values;
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn flag_values(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("flag_enums.rs");
+
+pub fn test_flag_enums() {
+    unsafe {
+        for n in 0..8 {
+            assert_eq!(flag_values(n), rust_flag_values(n));
+        }
+    }
+
+    assert!(TRANSLATED.contains("pub struct perms(pub libc::c_uint);"));
+    // Each associated constant is printed on a line of its own
+    assert!(TRANSLATED.contains("pub const PERM_READ: perms = perms(1);"));
+    assert!(TRANSLATED.contains("pub const PERM_RW: perms = perms(3);"));
+    assert!(TRANSLATED.contains("impl ::std::ops::BitOr for perms {"));
+    assert!(TRANSLATED.contains("type Output = perms;"));
+}
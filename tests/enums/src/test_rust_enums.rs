@@ -0,0 +1,42 @@
+extern crate libc;
+
+use rust_enums::{rust_enum_punning, rust_enum_values, rust_turn_right};
+
+use self::libc::{c_int, c_uint};
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn enum_values(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn turn_right(_: c_uint) -> c_int;
+
+    #[no_mangle]
+    fn enum_punning(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("rust_enums.rs");
+
+pub fn test_rust_enums() {
+    unsafe {
+        for n in 0..7 {
+            assert_eq!(enum_values(n), rust_enum_values(n));
+        }
+        for d in 0..4 {
+            assert_eq!(turn_right(d), rust_turn_right(d));
+        }
+        for n in 0..7 {
+            assert_eq!(enum_punning(n), rust_enum_punning(n));
+        }
+    }
+
+    assert!(TRANSLATED.contains("pub enum color {"));
+    // Enums other translation units can see keep their integer type
+    assert!(TRANSLATED.contains("pub type dir = libc::c_uint;"));
+    assert!(TRANSLATED.contains("pub type shape = libc::c_uint;"));
+    assert!(!TRANSLATED.contains("pub enum state"));
+    // A union member or a pointer cast may store any value into a struct field
+    assert!(TRANSLATED.contains("pub type suit = libc::c_uint;"));
+    assert!(TRANSLATED.contains("pub type mood = libc::c_uint;"));
+}
@@ -0,0 +1,15 @@
+/* `out` is only assigned by inline assembly, which has to stay in the scope of its declaration */
+int asm_operands(int n) {
+again:;
+    int out;
+#if defined(__x86_64__) || defined(__i386__)
+    __asm__("mov %1, %0" : "=r"(out) : "r"(n));
+#else
+    out = n;
+#endif
+    if (out < 10) {
+        n = out + 3;
+        goto again;
+    }
+    return out;
+}
@@ -0,0 +1,51 @@
+//! allow_current_block
+
+#include <limits.h>
+
+// Shares its name with the enum of the labels `current_block` dispatches on
+typedef struct {
+    int value;
+} Block;
+
+int block_type(int x) {
+    Block b = { x };
+
+l1:
+    if (b.value < 6) {
+        b.value += 1;
+        goto l3;
+    }
+
+l2:
+    if (b.value < 9) {
+        b.value += 2;
+        goto l1;
+    }
+
+l3:
+    if (b.value < 20) {
+        b.value += 90;
+        goto l2;
+    }
+
+    return b.value;
+}
+
+// Jumps into the code of a case labelled with the smallest `long long`
+int case_minimum(long long x) {
+    int r = 0;
+    if (x > 100)
+        goto inside;
+
+    switch (x) {
+        case LLONG_MIN:
+            r = 1;
+        inside:
+            r += 2;
+            break;
+        case -1:
+            r = 3;
+    }
+
+    return r;
+}
@@ -0,0 +1,17 @@
+//! dump_cfgs, cfg_dump_stage=pruned, cfg_dump_filter=^rust_pruned_dumped$
+
+int pruned_dumped(int n) {
+    int total = 0;
+
+    for (int i = 0; i < n; i++) {
+        if (i % 3 == 0)
+            continue;
+        total += i;
+    }
+
+    return total;
+}
+
+int pruned_skipped(int n) {
+    return n * 2;
+}
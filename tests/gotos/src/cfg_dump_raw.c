@@ -0,0 +1,17 @@
+//! dump_cfgs, cfg_dump_stage=raw, cfg_dump_filter=^rust_raw_dumped$
+
+int raw_dumped(int n) {
+    int total = 0;
+
+    for (int i = 0; i < n; i++) {
+        if (i % 3 == 0)
+            continue;
+        total += i;
+    }
+
+    return total;
+}
+
+int raw_skipped(int n) {
+    return n * 2;
+}
@@ -0,0 +1,17 @@
+//! dump_cfgs, cfg_dump_stage=relooped, cfg_dump_filter=^rust_relooped_dumped$
+
+int relooped_dumped(int n) {
+    int total = 0;
+
+    for (int i = 0; i < n; i++) {
+        if (i % 3 == 0)
+            continue;
+        total += i;
+    }
+
+    return total;
+}
+
+int relooped_skipped(int n) {
+    return n * 2;
+}
@@ -0,0 +1,37 @@
+//! dump_cfgs, cfg_dump_stage=relooped
+
+int metrics_straight(int x) {
+    return x + 1;
+}
+
+int metrics_irreducible(int x) {
+    if (x > 10)
+        goto inside;
+
+    while (x < 20) {
+        x += 3;
+inside:
+        x += 5;
+    }
+
+    return x;
+}
+
+int metrics_commented_cases(int x) {
+    int n = 0;
+
+    switch (x) {
+    case 1:
+        /* the first label */
+    case 2:
+        /* the second label */
+        n += 1;
+    case 3:
+        n += 2;
+        break;
+    default:
+        n = 10;
+    }
+
+    return n;
+}
@@ -0,0 +1,44 @@
+static int released;
+
+static void release(int resource) {
+    released += resource;
+}
+
+int cleanup_single(int fail) {
+    int status = 0;
+    released = 0;
+    if (fail == 1) {
+        status = -1;
+        goto out;
+    }
+    status += 10;
+    if (fail == 2) {
+        status = -2;
+        goto out;
+    }
+    status += 20;
+    if (fail == 3)
+        goto out;
+    status += 30;
+out:
+    release(1);
+    return status * 100 + released;
+}
+
+int cleanup_do_while(int fail) {
+    int status = 0;
+    released = 0;
+    do {
+        if (fail == 1)
+            break;
+        status += 1;
+        if (fail == 2)
+            break;
+        status += 2;
+        if (fail == 3)
+            continue;
+        status += 4;
+    } while (0);
+    release(status);
+    return status * 100 + released;
+}
@@ -0,0 +1,24 @@
+static int released;
+
+static void release(int resource) {
+    released += resource;
+}
+
+int cleanup_nested(int fail) {
+    int status = -1;
+    released = 0;
+    if (fail == 1)
+        goto fail_a;
+    if (fail == 2)
+        goto fail_b;
+    if (fail == 3)
+        goto fail_a;
+    if (fail == 4)
+        goto fail_b;
+    status = 0;
+fail_b:
+    release(2);
+fail_a:
+    release(1);
+    return status * 100 + released;
+}
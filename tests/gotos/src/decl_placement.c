@@ -0,0 +1,45 @@
+// The gotos jump past declarations, so the relooper lifts them to the top of
+// the function before they are moved back down next to their uses.
+
+// `scaled` is only used in the block it is declared in, where it is moved
+// back and merged with its initialization.
+int decl_placement_merged(int n) {
+    int total = 0;
+
+    if (n > 0) {
+        if (n > 100)
+            goto clamp;
+
+        int scaled = n * 3;
+        total = scaled;
+        goto done;
+
+    clamp:
+        total = 301;
+    }
+
+done:
+    return total;
+}
+
+// `step` is used across iterations of the loop once it is first assigned
+// past its declaration, so it is only moved down to just before the loop.
+int decl_placement_loop(int n) {
+    int total = 0;
+    int i = 0;
+
+    while (i < n) {
+        if (i % 3 == 0)
+            goto skip;
+
+        int step = i * 2;
+        total += step;
+
+    skip:
+        step = i;
+        total += step;
+        i++;
+    }
+
+    return total;
+}
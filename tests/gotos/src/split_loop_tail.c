@@ -0,0 +1,21 @@
+//! split_nodes
+
+// The shared tail has a loop, so it is not copied as a whole
+int split_loop_tail(int a, int n) {
+    int r = 0;
+    if (a) {
+        r = 1;
+        goto tail;
+    }
+    if (n > 3) {
+        r = 2;
+        goto tail;
+    }
+    r = 3;
+tail:
+    while (n > 0) {
+        r += n;
+        n--;
+    }
+    return r;
+}
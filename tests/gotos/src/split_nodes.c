@@ -0,0 +1,34 @@
+//! split_nodes
+
+// Both labels are reached from two places that don't nest
+int split_exits(int a, int b) {
+    int r = 0;
+    if (a) {
+        r += 1;
+        goto out;
+    }
+    if (b) {
+        r += 2;
+        goto err;
+    }
+    r += 4;
+err:
+    r *= 10;
+out:
+    return r;
+}
+
+// Case 3 falls through into case 4, which gets copied into its arm
+int split_fallthrough(int x) {
+    int total = 0;
+    switch (x) {
+        case 3:
+            total += 30;
+        case 4:
+            total += 40;
+            break;
+        default:
+            total = -1;
+    }
+    return total;
+}
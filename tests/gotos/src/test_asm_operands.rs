@@ -0,0 +1,21 @@
+//! feature_asm
+
+extern crate libc;
+
+use asm_operands::rust_asm_operands;
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn asm_operands(_: c_int) -> c_int;
+}
+
+pub fn test_asm_operands() {
+    for n in -5..15 {
+        unsafe {
+            assert_eq!(asm_operands(n), rust_asm_operands(n));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+extern crate libc;
+
+use self::libc::{c_int, c_longlong};
+use block_type::{rust_block_type, rust_case_minimum};
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn block_type(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn case_minimum(_: c_longlong) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("block_type.rs");
+
+pub fn test_block_type() {
+    unsafe {
+        for i in 0..20 {
+            assert_eq!(rust_block_type(i), block_type(i));
+        }
+    }
+    assert!(TRANSLATED.contains("pub struct Block {"));
+    assert!(TRANSLATED.contains("enum Block_0 {"));
+}
+
+pub fn test_case_minimum() {
+    for &x in &[c_longlong::min_value(), -1, 0, 7, 101] {
+        unsafe {
+            assert_eq!(rust_case_minimum(x), case_minimum(x));
+        }
+    }
+}
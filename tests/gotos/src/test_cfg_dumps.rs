@@ -0,0 +1,113 @@
+extern crate libc;
+
+use self::libc::c_int;
+use cfg_dump_pruned::{rust_pruned_dumped, rust_pruned_skipped};
+use cfg_dump_raw::{rust_raw_dumped, rust_raw_skipped};
+use cfg_dump_relooped::{rust_relooped_dumped, rust_relooped_skipped};
+
+use std::fs;
+use std::path::Path;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn raw_dumped(_: c_int) -> c_int;
+    #[no_mangle]
+    fn raw_skipped(_: c_int) -> c_int;
+    #[no_mangle]
+    fn pruned_dumped(_: c_int) -> c_int;
+    #[no_mangle]
+    fn pruned_skipped(_: c_int) -> c_int;
+    #[no_mangle]
+    fn relooped_dumped(_: c_int) -> c_int;
+    #[no_mangle]
+    fn relooped_skipped(_: c_int) -> c_int;
+}
+
+const SRC_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// Path of a file dumped while translating `cfg_dump_<stage>.c`
+fn dump_path(stage: &str, file: &str) -> String {
+    format!("{}/cfg_dump_{}_cfgs/{}", SRC_DIR, stage, file)
+}
+
+fn read_dump(stage: &str, file: &str) -> String {
+    let path = dump_path(stage, file);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+/// Number of blocks drawn in a `.dot` file
+fn dot_blocks(dot: &str) -> usize {
+    dot.matches(":\\l-----").count()
+}
+
+fn assert_only_dumped(stage: &str) {
+    let dumped = format!("rust_{}_dumped", stage);
+    let skipped = format!("rust_{}_skipped", stage);
+
+    for prefix in &["cfg", "metrics"] {
+        let ext = if *prefix == "cfg" { &["dot", "json", "html"][..] } else { &["json"][..] };
+        for ext in ext {
+            let file = format!("{}_{}.{}", prefix, dumped, ext);
+            assert!(Path::new(&dump_path(stage, &file)).is_file(), "missing {}", file);
+
+            let file = format!("{}_{}.{}", prefix, skipped, ext);
+            assert!(!Path::new(&dump_path(stage, &file)).exists(), "unfiltered {}", file);
+        }
+    }
+}
+
+pub fn test_cfg_dump_translations() {
+    unsafe {
+        for i in 0..10 {
+            assert_eq!(rust_raw_dumped(i), raw_dumped(i));
+            assert_eq!(rust_raw_skipped(i), raw_skipped(i));
+            assert_eq!(rust_pruned_dumped(i), pruned_dumped(i));
+            assert_eq!(rust_pruned_skipped(i), pruned_skipped(i));
+            assert_eq!(rust_relooped_dumped(i), relooped_dumped(i));
+            assert_eq!(rust_relooped_skipped(i), relooped_skipped(i));
+        }
+    }
+}
+
+pub fn test_cfg_dump_filter() {
+    for stage in &["raw", "pruned", "relooped"] {
+        assert_only_dumped(stage);
+    }
+}
+
+pub fn test_cfg_dump_raw_and_pruned() {
+    let raw = read_dump("raw", "cfg_rust_raw_dumped.dot");
+    let pruned = read_dump("pruned", "cfg_rust_pruned_dumped.dot");
+
+    // The blocks left empty by `continue` and after `return` are only in the raw graph
+    assert!(
+        dot_blocks(&raw) > dot_blocks(&pruned),
+        "raw:\n{}\npruned:\n{}",
+        raw,
+        pruned,
+    );
+
+    for stage in &["raw", "pruned"] {
+        let json = read_dump(stage, &format!("cfg_rust_{}_dumped.json", stage));
+        assert!(json.starts_with("{\"entries\":"), "{}", json);
+        assert!(json.contains("\"nodes\":"), "{}", json);
+
+        let html = read_dump(stage, &format!("cfg_rust_{}_dumped.html", stage));
+        assert!(html.contains("<pre class=\"mermaid\">"));
+        assert!(!html.contains("[\"loop\"]"), "{}", html);
+    }
+}
+
+pub fn test_cfg_dump_relooped() {
+    let dot = read_dump("relooped", "cfg_rust_relooped_dumped.dot");
+    assert!(dot.starts_with("digraph cfg {"));
+
+    let json = read_dump("relooped", "cfg_rust_relooped_dumped.json");
+    assert!(json.starts_with('['), "{}", json);
+    assert!(json.contains("{\"Loop\":"), "{}", json);
+
+    let html = read_dump("relooped", "cfg_rust_relooped_dumped.html");
+    assert!(html.contains("<pre class=\"mermaid\">"));
+    assert!(html.contains("[\"loop\"]"), "{}", html);
+}
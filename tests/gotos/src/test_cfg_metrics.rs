@@ -0,0 +1,76 @@
+extern crate libc;
+
+use self::libc::c_int;
+use cfg_metrics::{rust_metrics_commented_cases, rust_metrics_irreducible, rust_metrics_straight};
+
+use std::fs;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn metrics_straight(_: c_int) -> c_int;
+    #[no_mangle]
+    fn metrics_irreducible(_: c_int) -> c_int;
+    #[no_mangle]
+    fn metrics_commented_cases(_: c_int) -> c_int;
+}
+
+const DUMP_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/cfg_metrics_cfgs");
+
+fn metrics(function: &str) -> String {
+    let path = format!("{}/metrics_{}.json", DUMP_DIR, function);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+fn assert_metric(metrics: &str, field: &str, value: usize) {
+    let needle = format!("\"{}\": {}", field, value);
+    let found = metrics
+        .match_indices(&needle)
+        .any(|(i, _)| !metrics[i + needle.len()..].starts_with(char::is_numeric));
+    assert!(found, "expected {} in {}", needle, metrics);
+}
+
+pub fn test_straight_line_metrics() {
+    unsafe {
+        for i in 0..5 {
+            assert_eq!(rust_metrics_straight(i), metrics_straight(i));
+        }
+    }
+
+    let metrics = metrics("rust_metrics_straight");
+    assert!(metrics.contains("\"function\": \"rust_metrics_straight\""));
+    assert_metric(&metrics, "multiples", 0);
+    assert_metric(&metrics, "unmatched_loops", 0);
+    assert_metric(&metrics, "loop_labels", 0);
+    assert_metric(&metrics, "duplicated_blocks", 0);
+    assert_metric(&metrics, "complexity_before", 1);
+    assert_metric(&metrics, "complexity_after", 1);
+}
+
+pub fn test_irreducible_metrics() {
+    unsafe {
+        for i in 0..30 {
+            assert_eq!(rust_metrics_irreducible(i), metrics_irreducible(i));
+        }
+    }
+
+    // Jumping into the loop gives it two entries, which take a `current_block`
+    // dispatch to tell apart when no nodes are split
+    let metrics = metrics("rust_metrics_irreducible");
+    assert!(!metrics.contains("\"multiples\": 0,"), "{}", metrics);
+    assert_metric(&metrics, "duplicated_blocks", 0);
+}
+
+pub fn test_commented_cases_metrics() {
+    unsafe {
+        for i in 0..5 {
+            assert_eq!(rust_metrics_commented_cases(i), metrics_commented_cases(i));
+        }
+    }
+
+    // The commented labels share one arm, and only `case 3`, which `case 2`
+    // falls through into, is copied
+    let metrics = metrics("rust_metrics_commented_cases");
+    assert_metric(&metrics, "multiples", 0);
+    assert_metric(&metrics, "duplicated_blocks", 1);
+}
@@ -0,0 +1,48 @@
+extern crate libc;
+
+use cleanup::{rust_cleanup_do_while, rust_cleanup_single};
+use cleanup_nested::rust_cleanup_nested;
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn cleanup_single(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn cleanup_nested(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn cleanup_do_while(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("cleanup.rs");
+
+pub fn test_cleanup_single() {
+    for fail in 0..4 {
+        unsafe {
+            assert_eq!(cleanup_single(fail), rust_cleanup_single(fail));
+        }
+    }
+    // Each `goto out` is an early exit from the block labelled after the cleanup label
+    assert!(TRANSLATED.contains("'out: {"));
+    assert_eq!(TRANSLATED.matches("break 'out;").count(), 3);
+    assert!(!TRANSLATED.contains("current_block"));
+}
+
+pub fn test_cleanup_nested() {
+    for fail in 0..5 {
+        unsafe {
+            assert_eq!(cleanup_nested(fail), rust_cleanup_nested(fail));
+        }
+    }
+}
+
+pub fn test_cleanup_do_while() {
+    for fail in 0..4 {
+        unsafe {
+            assert_eq!(cleanup_do_while(fail), rust_cleanup_do_while(fail));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+extern crate libc;
+
+use decl_placement::{rust_decl_placement_loop, rust_decl_placement_merged};
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn decl_placement_merged(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn decl_placement_loop(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("decl_placement.rs");
+
+pub fn test_decl_placement() {
+    unsafe {
+        for n in -1..120 {
+            assert_eq!(rust_decl_placement_merged(n), decl_placement_merged(n));
+            assert_eq!(rust_decl_placement_loop(n), decl_placement_loop(n));
+        }
+    }
+
+    // Moved into the inner block and merged with its first assignment
+    assert!(TRANSLATED.contains("let mut scaled: libc::c_int = n * 3"));
+    assert!(!TRANSLATED.contains("let mut scaled: libc::c_int = 0;"));
+
+    // Not moved into the loop, so neither merged: the placeholder only moves
+    // down past the declarations of the other locals
+    assert!(TRANSLATED.contains("let mut step: libc::c_int = 0;"));
+    assert!(TRANSLATED.contains("step = i * 2"));
+    assert!(!TRANSLATED.contains("let mut step: libc::c_int = i"));
+    let step = TRANSLATED.find("let mut step: libc::c_int = 0;").unwrap();
+    let i = TRANSLATED.find("let mut i: libc::c_int").unwrap();
+    assert!(i < step);
+}
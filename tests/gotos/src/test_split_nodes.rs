@@ -0,0 +1,46 @@
+extern crate libc;
+
+use split_loop_tail::rust_split_loop_tail;
+use split_nodes::{rust_split_exits, rust_split_fallthrough};
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn split_exits(_: c_int, _: c_int) -> c_int;
+
+    #[no_mangle]
+    fn split_fallthrough(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn split_loop_tail(_: c_int, _: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("split_nodes.rs");
+
+pub fn test_split_exits() {
+    for &(a, b) in &[(0, 0), (0, 1), (1, 0), (1, 1)] {
+        unsafe {
+            assert_eq!(split_exits(a, b), rust_split_exits(a, b));
+        }
+    }
+    assert!(!TRANSLATED.contains("current_block"));
+}
+
+pub fn test_split_fallthrough() {
+    for x in 2..6 {
+        unsafe {
+            assert_eq!(split_fallthrough(x), rust_split_fallthrough(x));
+        }
+    }
+    assert_eq!(TRANSLATED.matches("+= 40").count(), 2);
+}
+
+pub fn test_split_loop_tail() {
+    for &(a, n) in &[(0, 0), (0, 5), (1, 2), (1, 7), (0, 2)] {
+        unsafe {
+            assert_eq!(split_loop_tail(a, n), rust_split_loop_tail(a, n));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+/* Every file including the header has a count of its own */
+static int count;
+
+static inline int bump(void) {
+    return ++count;
+}
+
+struct pair {
+    int a;
+    int b;
+};
+
+static inline int pair_sum(struct pair p) {
+    return p.a + p.b;
+}
@@ -0,0 +1,15 @@
+//! header_modules
+
+#include "counter.h"
+
+int first_bump(int n) {
+    int last = 0;
+    for (int i = 0; i < n; i++)
+        last = bump();
+    return last;
+}
+
+int first_sum(int a, int b) {
+    struct pair p = { a, b };
+    return pair_sum(p);
+}
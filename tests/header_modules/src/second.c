@@ -0,0 +1,15 @@
+//! header_modules
+
+#include "counter.h"
+
+int second_bump(int n) {
+    int last = 0;
+    for (int i = 0; i < n; i++)
+        last = bump();
+    return last;
+}
+
+int second_sum(int a, int b) {
+    struct pair p = { b, a };
+    return pair_sum(p);
+}
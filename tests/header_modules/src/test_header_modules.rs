@@ -0,0 +1,57 @@
+extern crate libc;
+
+use crate::first::{rust_first_bump, rust_first_sum};
+use crate::second::{rust_second_bump, rust_second_sum};
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn first_bump(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn first_sum(_: c_int, _: c_int) -> c_int;
+
+    #[no_mangle]
+    fn second_bump(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn second_sum(_: c_int, _: c_int) -> c_int;
+}
+
+const FIRST: &str = include_str!("first.rs");
+const SECOND: &str = include_str!("second.rs");
+const COUNTER: &str = include_str!("c2rust_headers/counter_h.rs");
+
+pub fn test_shared_declarations() {
+    unsafe {
+        assert_eq!(rust_first_sum(2, 3), first_sum(2, 3));
+        assert_eq!(rust_second_sum(4, 5), second_sum(4, 5));
+    }
+
+    assert!(COUNTER.contains("pub struct pair {"));
+    assert!(COUNTER.contains("fn rust_pair_sum("));
+    assert!(!FIRST.contains("pub struct pair {"));
+    assert!(!SECOND.contains("pub struct pair {"));
+}
+
+pub fn test_static_per_file() {
+    unsafe {
+        assert_eq!(first_bump(3), 3);
+        assert_eq!(second_bump(2), 2);
+        assert_eq!(first_bump(1), 4);
+
+        assert_eq!(rust_first_bump(3), 3);
+        assert_eq!(rust_second_bump(2), 2);
+        assert_eq!(rust_first_bump(1), 4);
+    }
+
+    // The header `static` and the function using it are defined in each file
+    for translated in &[FIRST, SECOND] {
+        assert!(translated.contains("static mut count: libc::c_int"));
+        assert!(translated.contains("fn rust_bump("));
+    }
+    assert!(!COUNTER.contains("count"));
+    assert!(!COUNTER.contains("rust_bump"));
+}
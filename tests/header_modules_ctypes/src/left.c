@@ -0,0 +1,8 @@
+//! header_modules, c_types=ctypes
+
+#include "shape.h"
+
+long left_area(long width, long height) {
+    struct shape s = { width, height };
+    return shape_area(s);
+}
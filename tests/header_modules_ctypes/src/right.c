@@ -0,0 +1,8 @@
+//! header_modules, c_types=ctypes
+
+#include "shape.h"
+
+long right_area(long width, long height) {
+    struct shape s = { height, width };
+    return shape_area(s) * 2;
+}
@@ -0,0 +1,8 @@
+struct shape {
+    long width;
+    long height;
+};
+
+static inline long shape_area(struct shape s) {
+    return s.width * s.height;
+}
@@ -0,0 +1,41 @@
+extern crate libc;
+
+use crate::left::rust_left_area;
+use crate::right::rust_right_area;
+
+use self::libc::c_long;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn left_area(_: c_long, _: c_long) -> c_long;
+
+    #[no_mangle]
+    fn right_area(_: c_long, _: c_long) -> c_long;
+}
+
+const LEFT: &str = include_str!("left.rs");
+const RIGHT: &str = include_str!("right.rs");
+const SHAPE: &str = include_str!("c2rust_headers/shape_h.rs");
+const CTYPES: &str = include_str!("ctypes.rs");
+
+pub fn test_shared_ctypes() {
+    unsafe {
+        assert_eq!(rust_left_area(3, 4), left_area(3, 4));
+        assert_eq!(rust_right_area(5, 6), right_area(5, 6));
+    }
+
+    // The header is shared even though its declarations use the C types
+    assert!(SHAPE.contains("pub struct shape {"));
+    assert!(SHAPE.contains("use crate::ctypes;"));
+    assert!(!LEFT.contains("pub struct shape {"));
+    assert!(!RIGHT.contains("pub struct shape {"));
+
+    // The C types are defined once, in a module of the crate
+    assert!(CTYPES.contains("pub type c_long = "));
+    for translated in &[LEFT, RIGHT] {
+        assert!(translated.contains("use crate::ctypes;"));
+        assert!(!translated.contains("pub mod ctypes"));
+        assert!(!translated.contains("libc::"));
+    }
+}
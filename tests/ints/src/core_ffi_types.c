@@ -0,0 +1,13 @@
+//! c_types=core-ffi
+
+#include <stddef.h>
+
+long core_ffi_types(int n, unsigned char c, const char *s, size_t len) {
+    long total = n + c;
+
+    for (size_t i = 0; i < len; i++) {
+        total += s[i];
+    }
+
+    return total;
+}
@@ -0,0 +1,19 @@
+//! c_types=ctypes, emit_no_std
+
+#include <stddef.h>
+
+struct sized {
+    char c;
+    short s;
+    long l;
+};
+
+unsigned long no_std_ctypes(const char *s, size_t len) {
+    unsigned long total = sizeof(struct sized) * 100 + sizeof(long) * 10;
+
+    for (size_t i = 0; i < len; i++) {
+        total += s[i];
+    }
+
+    return total;
+}
@@ -0,0 +1,55 @@
+//! extern_crate_core
+
+extern crate libc;
+
+use core_ffi_types::rust_core_ffi_types;
+use no_std_ctypes::rust_no_std_ctypes;
+use self::libc::{c_char, c_int, c_long, c_uchar, c_ulong, size_t};
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn core_ffi_types(_: c_int, _: c_uchar, _: *const c_char, _: size_t) -> c_long;
+
+    #[no_mangle]
+    fn no_std_ctypes(_: *const c_char, _: size_t) -> c_ulong;
+}
+
+const CORE_FFI: &str = include_str!("core_ffi_types.rs");
+const NO_STD: &str = include_str!("no_std_ctypes.rs");
+
+// The last byte tells a signed `char` from an unsigned one
+const BYTES: &[u8] = b"c2rust\xff";
+
+pub fn test_core_ffi_types() {
+    let s = BYTES.as_ptr() as *const c_char;
+
+    unsafe {
+        for n in -2..3 {
+            let expected = core_ffi_types(n, 200, s, BYTES.len());
+            assert_eq!(rust_core_ffi_types(n, 200, s as _, BYTES.len() as _), expected);
+        }
+    }
+
+    // The C types are the primitive types of the target, not paths into
+    // `core::ffi` or `libc`
+    assert!(CORE_FFI.contains("n: i32"));
+    assert!(!CORE_FFI.contains("core::ffi::c_int"));
+    assert!(!CORE_FFI.contains("libc::"));
+}
+
+pub fn test_no_std_ctypes() {
+    let s = BYTES.as_ptr() as *const c_char;
+
+    unsafe {
+        let expected = no_std_ctypes(s, BYTES.len());
+        assert_eq!(rust_no_std_ctypes(s as _, BYTES.len() as _), expected);
+    }
+
+    assert!(NO_STD.contains("pub mod ctypes {"));
+    assert!(NO_STD.contains("pub type c_int = i32;"));
+    assert!(NO_STD.contains("ctypes::c_ulong"));
+    assert!(NO_STD.contains("::core::mem::size_of::<sized>()"));
+    assert!(!NO_STD.contains("::std::"));
+    assert!(!NO_STD.contains("libc::"));
+}
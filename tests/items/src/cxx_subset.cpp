@@ -0,0 +1,37 @@
+//! translate
+
+// The subset of C++ that C code can consume, which translates as C does
+
+struct point {
+    int x;
+    int y;
+};
+
+static void shift(point &p, int dx) {
+    p.x += dx;
+    p.y -= dx;
+}
+
+static point make_point(int x, int y) {
+    point p = {x, y};
+    return p;
+}
+
+static int sum(const point &p) {
+    return p.x + p.y;
+}
+
+extern "C" int cxx_subset(int n) {
+    // Returned by value, then copied
+    point p = make_point(n, 2 * n);
+    point q = p;
+    shift(q, 3);
+
+    int *none = nullptr;
+    bool positive = n > 0;
+    long wide = static_cast<long>(q.x) * 100 + q.y;
+    const void *raw = reinterpret_cast<const void *>(&p);
+    const point *back = reinterpret_cast<const point *>(raw);
+
+    return static_cast<int>(wide) + sum(*back) * 10000 + (none == nullptr) + positive;
+}
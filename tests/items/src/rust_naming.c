@@ -0,0 +1,24 @@
+//! rust_naming
+
+typedef struct point_t {
+    int xPos;
+    int yPos;
+} point_t;
+
+enum { MAX_POINTS = 4 };
+
+static int pointCount = 0;
+
+static int addPoint(const point_t *p) {
+    pointCount++;
+    return p->xPos + p->yPos;
+}
+
+int sumPoints(int n) {
+    point_t origin = { 1, 2 };
+    int totalSum = 0;
+    for (int i = 0; i < n && i < MAX_POINTS; i++) {
+        totalSum += addPoint(&origin);
+    }
+    return totalSum + pointCount;
+}
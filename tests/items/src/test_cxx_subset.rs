@@ -0,0 +1,26 @@
+extern crate libc;
+
+use cxx_subset::rust_cxx_subset;
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn cxx_subset(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("cxx_subset.rs");
+
+pub fn test_cxx_subset() {
+    unsafe {
+        for n in -3..4 {
+            assert_eq!(rust_cxx_subset(n), cxx_subset(n));
+        }
+    }
+
+    // References become raw pointers
+    assert!(TRANSLATED.contains("p: *mut point"));
+    assert!(TRANSLATED.contains("p: *const point"));
+    assert!(TRANSLATED.contains("let mut positive: bool ="));
+}
@@ -0,0 +1,30 @@
+extern crate libc;
+
+use crate::rust_naming::{rust_sum_points, Point};
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn sumPoints(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("rust_naming.rs");
+
+pub fn test_rust_naming() {
+    unsafe {
+        for n in 0..6 {
+            assert_eq!(sumPoints(n), rust_sum_points(n));
+        }
+    }
+
+    let point = Point { x_pos: 1, y_pos: 2 };
+    assert_eq!(point.x_pos + point.y_pos, 3);
+
+    // Exported functions keep their C symbol
+    assert!(TRANSLATED.contains("#[export_name = \"rust_sumPoints\"]"));
+    assert!(TRANSLATED.contains("fn rust_add_point("));
+    assert!(TRANSLATED.contains("static mut RUST_POINT_COUNT: "));
+    assert!(TRANSLATED.contains("let mut total_sum: "));
+}
@@ -0,0 +1,35 @@
+// C++ constructs outside the subset the exporter supports, each preceded by
+// the error it has to report for it.
+
+// expected-error: namespaces are outside the supported C++ subset
+namespace geometry {
+int area(int w, int h) { return w * h; }
+}
+
+// expected-error: templates are outside the supported C++ subset
+template <typename T> T max_of(T a, T b) { return a < b ? b : a; }
+
+// expected-error: only POD structs are in the supported C++ subset
+struct Counter {
+    Counter() : count(0) {}
+    int count;
+};
+
+// expected-error: externally visible functions without extern "C" linkage are outside the supported C++ subset
+int mangled(int x) { return x; }
+
+struct pair {
+    int a;
+    int b;
+};
+
+static pair make_pair_of(int x) {
+    pair p = {x, x};
+    return p;
+}
+
+extern "C" int unsupported_cxx(int x) {
+    // expected-error: binding a reference to a temporary is outside the supported C++ subset
+    const pair &bound = make_pair_of(x);
+    return geometry::area(x, max_of(x, 2)) + bound.a;
+}
@@ -0,0 +1,45 @@
+int signed_range(int n) {
+    int sum = 0;
+    for (int i = 0; i < n; i++)
+        sum += i;
+    return sum;
+}
+
+int inclusive_range(void) {
+    int sum = 0;
+    for (int j = -5; j <= 5; j++)
+        sum += j * j;
+    for (unsigned u = 0; u <= 10; u += 3)
+        sum += u;
+    return sum;
+}
+
+/* `c <= 4294967295u` always holds, so only the `break` ends the loop */
+int inclusive_to_max(void) {
+    int count = 0;
+    for (unsigned c = 4294967290u; c <= 4294967295u; c++) {
+        if (++count == 10)
+            break;
+    }
+    return count;
+}
+
+/* `d += 2` wraps around past 4294967294u instead of reaching the bound */
+int step_past_max(void) {
+    int count = 0;
+    for (unsigned d = 4294967200u; d < 4294967295u; d += 2) {
+        if (++count == 100)
+            break;
+    }
+    return count;
+}
+
+int mutated_counter(int n) {
+    int sum = 0;
+    for (int k = 0; k < n; k++) {
+        if (k % 3 == 0)
+            k++;
+        sum += k;
+    }
+    return sum;
+}
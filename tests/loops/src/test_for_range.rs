@@ -0,0 +1,48 @@
+extern crate libc;
+
+use for_range::{
+    rust_inclusive_range, rust_inclusive_to_max, rust_mutated_counter, rust_signed_range,
+    rust_step_past_max,
+};
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn signed_range(_: c_int) -> c_int;
+
+    #[no_mangle]
+    fn inclusive_range() -> c_int;
+
+    #[no_mangle]
+    fn inclusive_to_max() -> c_int;
+
+    #[no_mangle]
+    fn step_past_max() -> c_int;
+
+    #[no_mangle]
+    fn mutated_counter(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("for_range.rs");
+
+pub fn test_for_range() {
+    unsafe {
+        for n in -1..10 {
+            assert_eq!(signed_range(n), rust_signed_range(n));
+            assert_eq!(mutated_counter(n), rust_mutated_counter(n));
+        }
+        assert_eq!(inclusive_range(), rust_inclusive_range());
+        assert_eq!(inclusive_to_max(), rust_inclusive_to_max());
+        assert_eq!(step_past_max(), rust_step_past_max());
+    }
+
+    assert!(TRANSLATED.contains("for i in "));
+    assert!(TRANSLATED.contains("for j in "));
+    assert!(TRANSLATED.contains("for u in "));
+    // Loops that could overflow their counter, or that modify it, stay `while` loops
+    assert!(!TRANSLATED.contains("for c in "));
+    assert!(!TRANSLATED.contains("for d in "));
+    assert!(!TRANSLATED.contains("for k in "));
+}
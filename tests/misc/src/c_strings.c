@@ -0,0 +1,14 @@
+//! translate_c_strings
+#include <string.h>
+
+static size_t length(const char *s) {
+    return strlen(s);
+}
+
+static int same(const char *a, const char *b) {
+    return strcmp(a, b) == 0;
+}
+
+int c_strings(void) {
+    return (int)length("hello") * 10 + same("abc", "abc");
+}
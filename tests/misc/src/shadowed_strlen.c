@@ -0,0 +1,18 @@
+//! translate_c_strings
+
+// Shares its name with libc's strlen, which this file does not include
+static unsigned long strlen(const char *s) {
+    unsigned long n = 0;
+    while (s[n] != 'x') {
+        n++;
+    }
+    return n;
+}
+
+static unsigned long length(const char *s) {
+    return strlen(s);
+}
+
+int shadowed_strlen(void) {
+    return (int)length("abxcd");
+}
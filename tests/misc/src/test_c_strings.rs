@@ -0,0 +1,38 @@
+//! extern_crate_cstr
+
+extern crate libc;
+
+use c_strings::rust_c_strings;
+use shadowed_strlen::rust_shadowed_strlen;
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn c_strings() -> c_int;
+
+    #[no_mangle]
+    fn shadowed_strlen() -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("c_strings.rs");
+const TRANSLATED_SHADOWED: &str = include_str!("shadowed_strlen.rs");
+
+pub fn test_c_strings() {
+    unsafe {
+        assert_eq!(c_strings(), rust_c_strings());
+        assert_eq!(rust_c_strings(), 51);
+    }
+    assert!(TRANSLATED.contains("s: &::std::ffi::CStr"));
+    assert!(TRANSLATED.contains("cstr!(\"hello\")"));
+}
+
+pub fn test_shadowed_strlen() {
+    unsafe {
+        assert_eq!(shadowed_strlen(), rust_shadowed_strlen());
+        assert_eq!(rust_shadowed_strlen(), 2);
+    }
+    assert!(TRANSLATED_SHADOWED.contains("s: *const libc::c_char"));
+    assert!(!TRANSLATED_SHADOWED.contains("&::std::ffi::CStr"));
+}
@@ -0,0 +1,35 @@
+//! translate_restrict_refs
+
+struct point {
+    int x;
+    int y;
+};
+
+static void swap(int *restrict a, int *restrict b) {
+    int tmp = *a;
+    *a = *b;
+    *b = tmp;
+}
+
+static void scale(struct point *restrict p, int k) {
+    p->x *= k;
+    p->y *= k;
+}
+
+static int get(const int *restrict p) {
+    return *p;
+}
+
+// Compared against null, so it has to stay a raw pointer
+static int get_or_zero(const int *restrict p) {
+    return p ? *p : 0;
+}
+
+int restrict_refs(int a, int b) {
+    swap(&a, &b);
+
+    struct point p = { a, b };
+    scale(&p, 3);
+
+    return get(&p.x) * 100 + get_or_zero(&p.y) + get_or_zero(0);
+}
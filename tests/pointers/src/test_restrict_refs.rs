@@ -0,0 +1,31 @@
+extern crate libc;
+
+use restrict_refs::rust_restrict_refs;
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn restrict_refs(_: c_int, _: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("restrict_refs.rs");
+
+pub fn test_restrict_refs() {
+    for &(a, b) in &[(1, 2), (-4, 7), (0, 0)] {
+        unsafe {
+            assert_eq!(restrict_refs(a, b), rust_restrict_refs(a, b));
+        }
+    }
+    unsafe {
+        assert_eq!(rust_restrict_refs(1, 2), 603);
+    }
+}
+
+pub fn test_restrict_ref_types() {
+    assert!(TRANSLATED.contains("a: &mut libc::c_int"));
+    assert!(TRANSLATED.contains("p: &mut point"));
+    assert!(TRANSLATED.contains("p: &libc::c_int"));
+    assert!(TRANSLATED.contains("p: *const libc::c_int"));
+}
@@ -0,0 +1,51 @@
+extern crate libc;
+
+use vectors::rust_vector_ops;
+use self::libc::{c_int, c_uint};
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn vector_ops(_: c_uint, _: *mut c_int);
+}
+
+const BUFFER_SIZE: usize = 100;
+
+const TRANSLATED: &str = include_str!("vectors.rs");
+const CARGO_TOML: &str = include_str!("Cargo.toml");
+
+pub fn test_vector_ops() {
+    let mut buffer = [0; BUFFER_SIZE];
+    let mut rust_buffer = [0; BUFFER_SIZE];
+    let expected_buffer = [
+        5, 3, -3, 101, -3, -7, 9, 99, 4, -10,
+        -18, 100, 0, 0, 0, 100, 1, -2, 3, 0,
+        0, 4, 2, 0, 5, -1, -5, 101, 5, -5,
+        -7, 101, -1, 2, -3, -100, -2, 1, -4, -101,
+        4, -8, 12, 400, 2, 2, -3, 0, 0, 0,
+        0, 0, -1, -1, 0, 0, 0, 0, -1, -1,
+        2, -1, 4, 101, 1, 2, 3, 0, 5, 42,
+        -3, 101, 15, 24, 24, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 4, 9, 10, 11, 5, 5,
+        -3, 1, 42, 42, 101, -6, 101, 5, 0, 0,
+    ];
+
+    unsafe {
+        vector_ops(BUFFER_SIZE as u32, buffer.as_mut_ptr());
+        rust_vector_ops(BUFFER_SIZE as u32, rust_buffer.as_mut_ptr());
+    }
+
+    for index in 0..BUFFER_SIZE {
+        assert_eq!(buffer[index], rust_buffer[index]);
+        assert_eq!(buffer[index], expected_buffer[index]);
+    }
+}
+
+pub fn test_vector_crate() {
+    // Using portable vectors makes the crate depend on `c2rust-simd`
+    assert!(TRANSLATED.contains("use ::c2rust_simd;"));
+    assert!(CARGO_TOML.contains("c2rust-simd = \"0.1\""));
+
+    // C passes vectors by value in registers, unlike the portable ones
+    assert!(!TRANSLATED.contains("fn rust_vector_sum("));
+}
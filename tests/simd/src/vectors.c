@@ -0,0 +1,69 @@
+//! emit_build_files
+
+// Generic vector extension types, translated to portable vectors on every
+// architecture
+
+typedef int v2si __attribute__((vector_size(8)));
+typedef int v4si __attribute__((vector_size(16)));
+typedef int v8si __attribute__((vector_size(32)));
+typedef float v4sf __attribute__((vector_size(16)));
+typedef unsigned char v8qu __attribute__((vector_size(8)));
+
+v4si static_v4si = {1, 2, 3};
+v4sf static_zeros;
+
+static void push(unsigned buffer_size, int buffer[], unsigned *i, v4si v) {
+    for (int j = 0; j < 4 && *i < buffer_size; j++) {
+        buffer[(*i)++] = v[j];
+    }
+}
+
+void vector_ops(unsigned buffer_size, int buffer[]) {
+    unsigned i = 0;
+    v4si a = {1, -2, 3, 100};
+    v4si b = {4, 5, -6, 1};
+
+    push(buffer_size, buffer, &i, a + b);
+    push(buffer_size, buffer, &i, a - b);
+    push(buffer_size, buffer, &i, a * b);
+    push(buffer_size, buffer, &i, a / b);
+    push(buffer_size, buffer, &i, a % b);
+    push(buffer_size, buffer, &i, a & b);
+    push(buffer_size, buffer, &i, a | b);
+    push(buffer_size, buffer, &i, a ^ b);
+    push(buffer_size, buffer, &i, -a);
+    push(buffer_size, buffer, &i, ~a);
+    push(buffer_size, buffer, &i, a << 2);
+    push(buffer_size, buffer, &i, b >> 1);
+    push(buffer_size, buffer, &i, a == b);
+    push(buffer_size, buffer, &i, a < b);
+    push(buffer_size, buffer, &i, a >= b);
+    push(buffer_size, buffer, &i, a + 1);
+    push(buffer_size, buffer, &i, static_v4si);
+
+    a += b;
+    a[1] = 42;
+    push(buffer_size, buffer, &i, a);
+
+    v4sf f = {1.5f, 2.0f, -3.0f, 0.25f};
+    v4sf g = f * f + f;
+    push(buffer_size, buffer, &i, (v4si){g[0] * 4, g[1] * 4, g[2] * 4, g[3] * 4});
+    push(buffer_size, buffer, &i, f > g);
+    push(buffer_size, buffer, &i, (v4si){static_zeros[0], static_zeros[3]});
+
+    v8qu c = {250, 251, 252, 253, 254, 255, 0, 1};
+    c = c + 10;
+    push(buffer_size, buffer, &i, (v4si){c[0], c[5], c[6], c[7]});
+
+    v8si wide = __builtin_shufflevector(a, b, 0, 5, 2, 7, 1, 1, 3, 6);
+    push(buffer_size, buffer, &i, (v4si){wide[0], wide[1], wide[2], wide[3]});
+    push(buffer_size, buffer, &i, (v4si){wide[4], wide[5], wide[6], wide[7]});
+
+    v2si narrow = __builtin_shufflevector(a, a, 3, 0);
+    push(buffer_size, buffer, &i, (v4si){narrow[0], narrow[1]});
+}
+
+/* Not translated, since C passes the vectors in registers */
+v4si vector_sum(v4si a, v4si b) {
+    return a + b;
+}
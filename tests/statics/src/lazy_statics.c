@@ -0,0 +1,33 @@
+#include <stddef.h>
+
+struct point {
+    int x;
+    int y;
+};
+
+struct packed {
+    unsigned a : 3;
+    unsigned b : 5;
+};
+
+static int table[4] = {1, 2, 3, 4};
+static struct point origin = {5, 7};
+
+// These are evaluated during translation:
+static int *third = &table[2];
+static int *past_first = table + 1;
+static int *origin_y = &origin.y;
+static size_t y_offset = (size_t) &((struct point *)0)->y;
+static ptrdiff_t span = &table[3] - &table[0];
+static size_t table_len = sizeof(table) / sizeof(table[0]);
+static int *pick = sizeof(int) == 4 ? &table[0] : &table[1];
+
+// These are initialized on first access:
+static struct packed bits = {5, 17};
+static struct packed *bits_ptr = &bits;
+
+int lazy_sum(void) {
+    bits_ptr->a += 1;
+    return *third + *past_first + *origin_y + (int) y_offset + (int) span
+        + (int) table_len + *pick + bits.a + bits.b + bits_ptr->b;
+}
@@ -0,0 +1,26 @@
+extern crate libc;
+
+use lazy_statics::rust_lazy_sum;
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn lazy_sum() -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("lazy_statics.rs");
+
+pub fn test_lazy_statics() {
+    unsafe {
+        assert_eq!(lazy_sum(), rust_lazy_sum());
+        assert_eq!(lazy_sum(), rust_lazy_sum());
+    }
+
+    // Addresses of statics are computed by the compiler, not at startup
+    assert!(!TRANSLATED.contains("run_static_initializers"));
+    assert!(TRANSLATED.contains("static mut bits: LazyStatic<packed>"));
+    assert!(TRANSLATED.contains("static mut bits_ptr: LazyStatic<*mut packed>"));
+    assert!(!TRANSLATED.contains("static mut third: LazyStatic"));
+}
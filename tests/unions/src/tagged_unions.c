@@ -0,0 +1,45 @@
+//! translate_tagged_unions
+
+enum kind { KIND_INT, KIND_DOUBLE };
+
+struct value {
+    enum kind kind;
+    union {
+        int i;
+        double d;
+    } u;
+};
+
+static void set_int(struct value *v, int i) {
+    v->kind = KIND_INT;
+    v->u.i = i;
+}
+
+static void set_double(struct value *v, double d) {
+    v->kind = KIND_DOUBLE;
+    v->u.d = d;
+}
+
+static double as_double(const struct value *v) {
+    switch (v->kind) {
+    case KIND_INT:
+        return v->u.i;
+    case KIND_DOUBLE:
+        return v->u.d;
+    }
+    return 0;
+}
+
+int tagged_sum(int n) {
+    struct value values[4];
+    double sum = 0;
+    for (int i = 0; i < 4; i++) {
+        if ((n + i) % 2)
+            set_int(&values[i], n + i);
+        else
+            set_double(&values[i], (n + i) / 2.0);
+    }
+    for (int i = 0; i < 4; i++)
+        sum += as_double(&values[i]);
+    return (int)(sum * 10);
+}
@@ -0,0 +1,32 @@
+extern crate libc;
+
+use tagged_unions::{rust_tagged_sum, value, value_u};
+
+use self::libc::c_int;
+
+#[link(name = "test")]
+extern "C" {
+    #[no_mangle]
+    fn tagged_sum(_: c_int) -> c_int;
+}
+
+const TRANSLATED: &str = include_str!("tagged_unions.rs");
+
+pub fn test_tagged_unions() {
+    unsafe {
+        for n in 0..6 {
+            assert_eq!(tagged_sum(n), rust_tagged_sum(n));
+        }
+    }
+
+    let mut v: value = unsafe { ::std::mem::zeroed() };
+    v.set_u(value_u::KIND_DOUBLE(2.5));
+    match unsafe { v.u() } {
+        Some(value_u::KIND_DOUBLE(d)) => assert_eq!(d, 2.5),
+        _ => panic!("the tag should select the double"),
+    }
+
+    // Safe code can make the tag disagree with the union, so reading it is unsafe
+    assert!(TRANSLATED.contains("pub unsafe fn u(&self) -> Option<value_u> {"));
+    assert!(TRANSLATED.contains("/// # Safety"));
+}